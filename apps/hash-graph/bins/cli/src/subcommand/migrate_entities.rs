@@ -0,0 +1,142 @@
+use authorization::NoAuthorization;
+use clap::Parser;
+use error_stack::{Report, Result, ResultExt, ensure};
+use graph::store::{
+    DatabaseConnectionInfo, DatabasePoolConfig, EntityStore, PostgresStorePool, StorePool,
+    knowledge::{MigrateEntitiesParams, PropertyMapping},
+};
+use graph_types::{
+    account::AccountId,
+    knowledge::entity::{ActorType, OriginProvenance, OriginType, ProvidedEntityEditionProvenance},
+};
+use hash_graph_store::filter::Filter;
+use tokio_postgres::NoTls;
+use type_system::url::VersionedUrl;
+use uuid::Uuid;
+
+use crate::error::GraphError;
+
+#[derive(Debug, Parser)]
+#[clap(version, author, about, long_about = None)]
+pub struct MigrateEntitiesArgs {
+    #[clap(flatten)]
+    pub db_info: DatabaseConnectionInfo,
+
+    #[clap(flatten)]
+    pub pool_config: DatabasePoolConfig,
+
+    /// The account which creates the new editions of the migrated entities.
+    #[clap(long)]
+    pub actor_id: Uuid,
+
+    /// The entity type the entities are migrated from.
+    #[clap(long)]
+    pub from: VersionedUrl,
+
+    /// The entity type the entities are migrated to.
+    #[clap(long)]
+    pub to: VersionedUrl,
+
+    /// A JSON encoded filter to restrict the migrated entities.
+    ///
+    /// If not specified, all entities of the old entity type are migrated.
+    #[clap(long)]
+    pub filter: Option<String>,
+
+    /// A JSON encoded list of property mappings applied to every migrated entity.
+    ///
+    /// Each mapping either renames a property (`{"op": "rename", "from": ..., "to": ...}`),
+    /// removes a property (`{"op": "remove", "property": ...}`), or sets a property if it has no
+    /// value yet (`{"op": "default", "property": ..., "value": ...}`).
+    #[clap(long)]
+    pub property_mappings: Option<String>,
+
+    /// Whether to migrate draft entities as well.
+    #[clap(long)]
+    pub include_drafts: bool,
+}
+
+pub async fn migrate_entities(args: MigrateEntitiesArgs) -> Result<(), GraphError> {
+    let filter = match &args.filter {
+        Some(filter) => serde_json::from_str(filter)
+            .change_context(GraphError)
+            .attach_printable("Invalid filter")?,
+        None => Filter::All(Vec::new()),
+    };
+    let property_mappings: Vec<PropertyMapping> = match &args.property_mappings {
+        Some(property_mappings) => serde_json::from_str(property_mappings)
+            .change_context(GraphError)
+            .attach_printable("Invalid property mappings")?,
+        None => Vec::new(),
+    };
+
+    let pool = PostgresStorePool::new(&args.db_info, &args.pool_config, NoTls)
+        .await
+        .change_context(GraphError)
+        .map_err(|report| {
+            tracing::error!(error = ?report, "Failed to connect to database");
+            report
+        })?;
+
+    let mut store = pool
+        .acquire(NoAuthorization, None)
+        .await
+        .change_context(GraphError)
+        .map_err(|report| {
+            tracing::error!(error = ?report, "Failed to acquire database connection");
+            report
+        })?;
+
+    let response = store
+        .migrate_entities(AccountId::new(args.actor_id), MigrateEntitiesParams {
+            filter,
+            from_entity_type_id: args.from,
+            to_entity_type_id: args.to,
+            property_mappings,
+            include_drafts: args.include_drafts,
+            provenance: ProvidedEntityEditionProvenance {
+                sources: Vec::new(),
+                actor_type: Some(ActorType::Machine),
+                origin: Some(OriginProvenance {
+                    ty: OriginType::Migration,
+                    id: None,
+                    version: Some(env!("CARGO_PKG_VERSION").to_owned()),
+                    semantic_version: None,
+                    environment: None,
+                    device_id: None,
+                    session_id: None,
+                    api_key_public_id: None,
+                    user_agent: None,
+                }),
+            },
+        })
+        .await
+        .change_context(GraphError)
+        .map_err(|report| {
+            tracing::error!(error = ?report, "Failed to read the entities to migrate");
+            report
+        })?;
+
+    for failure in &response.failures {
+        tracing::error!(
+            entity_id = %failure.entity_id,
+            reason = %failure.reason,
+            "Failed to migrate entity"
+        );
+    }
+    tracing::info!(
+        migrated = response.migrated.len(),
+        failed = response.failures.len(),
+        "Migrated entities"
+    );
+
+    ensure!(
+        response.failures.is_empty(),
+        Report::new(GraphError).attach_printable(format!(
+            "{} entities could not be migrated",
+            response.failures.len()
+        ))
+    );
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 mod completions;
 mod migrate;
+mod migrate_entities;
 mod reindex_cache;
 mod server;
 mod snapshot;
@@ -18,6 +19,7 @@ pub use self::test_server::{TestServerArgs, test_server};
 pub use self::{
     completions::{CompletionsArgs, completions},
     migrate::{MigrateArgs, migrate},
+    migrate_entities::{MigrateEntitiesArgs, migrate_entities},
     server::{ServerArgs, server},
     snapshot::{SnapshotArgs, snapshot},
     type_fetcher::{TypeFetcherArgs, type_fetcher},
@@ -34,6 +36,12 @@ pub enum Subcommand {
     Server(ServerArgs),
     /// Run database migrations required by the Graph.
     Migrate(MigrateArgs),
+    /// Migrate entities from one entity type to another.
+    ///
+    /// Every entity is validated against the new entity type and a new edition is created for it.
+    /// Entities which cannot be migrated are reported and do not abort the migration of the other
+    /// entities.
+    MigrateEntities(MigrateEntitiesArgs),
     /// Run the type fetcher to request external types.
     TypeFetcher(TypeFetcherArgs),
     /// Generate a completion script for the given shell and outputs it to stdout.
@@ -72,6 +80,7 @@ impl Subcommand {
         match self {
             Self::Server(args) => block_on(server(args), tracing_config),
             Self::Migrate(args) => block_on(migrate(args), tracing_config),
+            Self::MigrateEntities(args) => block_on(migrate_entities(args), tracing_config),
             Self::TypeFetcher(args) => block_on(type_fetcher(args), tracing_config),
             Self::Completions(ref args) => {
                 completions(args);
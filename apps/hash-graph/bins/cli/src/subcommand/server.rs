@@ -19,7 +19,11 @@ use graph::{
         DatabaseConnectionInfo, DatabasePoolConfig, FetchingPool, PostgresStorePool, StorePool,
     },
 };
-use graph_api::rest::{RestRouterDependencies, rest_api_router};
+use graph_api::{
+    rest::{RestRouterDependencies, rest_api_router},
+    rpc::{GraphRpc, PreSharedTokenVerifier, RpcConfig, serve_rpc},
+};
+use libp2p::Multiaddr;
 use regex::Regex;
 use reqwest::{Client, Url};
 use temporal_client::TemporalClientConfig;
@@ -71,6 +75,38 @@ pub struct ServerArgs {
     #[clap(flatten)]
     pub api_address: ApiAddress,
 
+    /// Starts the RPC server alongside the REST server.
+    #[clap(long, default_value_t = false, env = "HASH_GRAPH_RPC_ENABLED")]
+    pub rpc_enabled: bool,
+
+    /// The address the RPC server is listening at.
+    ///
+    /// Use `/unix/<path>` to listen on a Unix domain socket, e.g. for co-located services.
+    #[clap(
+        long,
+        default_value = "/ip4/127.0.0.1/tcp/4002",
+        env = "HASH_GRAPH_RPC_ADDRESS"
+    )]
+    pub rpc_address: Multiaddr,
+
+    /// The token RPC clients need to present to authenticate their session.
+    ///
+    /// Required if the RPC server is enabled.
+    #[clap(long, env = "HASH_GRAPH_RPC_TOKEN")]
+    pub rpc_token: Option<String>,
+
+    /// Time in seconds an RPC procedure has to respond to a request before it is aborted.
+    #[clap(long, default_value_t = 60, env = "HASH_GRAPH_RPC_REQUEST_TIMEOUT")]
+    pub rpc_request_timeout: u64,
+
+    /// Number of requests a single RPC session can have in flight at the same time.
+    #[clap(long, default_value_t = 64, env = "HASH_GRAPH_RPC_CONCURRENCY_LIMIT")]
+    pub rpc_concurrency_limit: usize,
+
+    /// Number of requests a single RPC session can send per second.
+    #[clap(long, default_value_t = 1_000, env = "HASH_GRAPH_RPC_RATE_LIMIT")]
+    pub rpc_rate_limit: u64,
+
     /// The address for the type fetcher RPC server is listening at.
     #[clap(flatten)]
     pub type_fetcher_address: TypeFetcherAddress,
@@ -186,12 +222,12 @@ pub async fn server(args: ServerArgs) -> Result<(), GraphError> {
     let mut zanzibar_client = ZanzibarClient::new(spicedb_client);
     zanzibar_client.seed().await.change_context(GraphError)?;
 
-    let router = rest_api_router(RestRouterDependencies {
+    let dependencies = RestRouterDependencies {
         store: Arc::new(pool),
         authorization_api: Arc::new(zanzibar_client),
         domain_regex: DomainValidator::new(args.allowed_url_domain),
         temporal_client: if let Some(host) = args.temporal_host {
-            Some(
+            Some(Arc::new(
                 TemporalClientConfig::new(
                     Url::from_str(&format!("{}:{}", host, args.temporal_port))
                         .change_context(GraphError)?,
@@ -199,11 +235,43 @@ pub async fn server(args: ServerArgs) -> Result<(), GraphError> {
                 .change_context(GraphError)?
                 .await
                 .change_context(GraphError)?,
-            )
+            ))
         } else {
             None
         },
-    });
+    };
+
+    if args.rpc_enabled {
+        let Some(rpc_token) = args.rpc_token else {
+            return Err(Report::new(GraphError)
+                .attach_printable("An RPC token is required if the RPC server is enabled"));
+        };
+        let verifier = PreSharedTokenVerifier::new().with_token(rpc_token);
+
+        let rpc = GraphRpc::new(
+            Arc::clone(&dependencies.store),
+            Arc::clone(&dependencies.authorization_api),
+            dependencies.temporal_client.clone(),
+            dependencies.domain_regex.clone(),
+        );
+
+        let config = RpcConfig {
+            request_timeout: Duration::from_secs(args.rpc_request_timeout),
+            concurrency_limit: args.rpc_concurrency_limit,
+            rate_limit: args.rpc_rate_limit,
+            ..RpcConfig::new(args.rpc_address)
+        };
+
+        let address = config.address.clone();
+        let rpc_server = serve_rpc(config, rpc, verifier)
+            .await
+            .change_context(GraphError)?;
+
+        tracing::info!("Listening for RPC requests on {address}");
+        tokio::spawn(rpc_server);
+    }
+
+    let router = rest_api_router(dependencies);
 
     tracing::info!("Listening on {}", args.api_address);
     axum::serve(
@@ -0,0 +1,147 @@
+//! Web routes for reading the change feed of the graph.
+
+use alloc::sync::Arc;
+use core::time::Duration;
+
+use authorization::AuthorizationApiPool;
+use axum::{Extension, Router, response::Response, routing::post};
+use graph::store::{
+    ChangeFeedStore, StorePool,
+    change_feed::{
+        Change, ChangeFeedPosition, ChangeKind, ChangedRecord, ReadChangesParams,
+        ReadChangesResponse,
+    },
+};
+use serde::Deserialize;
+use temporal_client::TemporalClient;
+use tokio::time::{Instant, sleep};
+use utoipa::{OpenApi, ToSchema};
+
+use crate::rest::{
+    AuthenticatedUserHeader, api_resource::RoutedResource, json::Json, status::report_to_response,
+};
+
+/// The maximum time a request waits for new changes.
+const MAX_TIMEOUT: Duration = Duration::from_secs(60);
+/// The interval in which the store is polled for new changes while waiting.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        read_changes,
+    ),
+    components(
+        schemas(
+            ReadChangesRequest,
+            ReadChangesResponse,
+            Change,
+            ChangeFeedPosition,
+            ChangeKind,
+            ChangedRecord,
+        ),
+    ),
+    tags(
+        (name = "ChangeFeed", description = "Change feed API")
+    )
+)]
+pub(crate) struct ChangeFeedResource;
+
+impl RoutedResource for ChangeFeedResource {
+    /// Create routes for reading the change feed.
+    fn routes<S, A>() -> Router
+    where
+        S: StorePool + Send + Sync + 'static,
+        A: AuthorizationApiPool + Send + Sync + 'static,
+    {
+        Router::new().nest(
+            "/changes",
+            Router::new().route("/query", post(read_changes::<S, A>)),
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct ReadChangesRequest {
+    /// Only changes after this position are returned. If not specified, the feed is read from the
+    /// beginning.
+    #[serde(default)]
+    after: Option<ChangeFeedPosition>,
+    /// The maximum number of changes to return.
+    limit: usize,
+    /// The time in milliseconds to wait for new changes if none are available yet.
+    ///
+    /// The request is answered as soon as a change is available. The timeout is capped at one
+    /// minute. If not specified, the request is answered immediately.
+    #[serde(default)]
+    timeout_ms: u64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/changes/query",
+    request_body = ReadChangesRequest,
+    tag = "ChangeFeed",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            body = ReadChangesResponse,
+            description = "The changes after the requested position, which is empty if no change was written until the timeout elapsed",
+        ),
+        (status = 422, content_type = "text/plain", description = "Provided request is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn read_changes<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(request): Json<ReadChangesRequest>,
+) -> Result<Json<ReadChangesResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let params = ReadChangesParams {
+        after: request.after,
+        limit: request.limit,
+    };
+    let deadline = Instant::now() + Duration::from_millis(request.timeout_ms).min(MAX_TIMEOUT);
+
+    loop {
+        // The store is acquired for every poll so no connection is held while waiting.
+        let authorization_api = authorization_api_pool
+            .acquire()
+            .await
+            .map_err(report_to_response)?;
+        let store = store_pool
+            .acquire(authorization_api, temporal_client.0.clone())
+            .await
+            .map_err(report_to_response)?;
+
+        let response = store
+            .read_changes(actor_id, params)
+            .await
+            .map_err(report_to_response)?;
+        drop(store);
+
+        // The position may advance without returning changes if the actor is not permitted to view
+        // them, which is still reported so the caller does not have to read them again.
+        let now = Instant::now();
+        if !response.changes.is_empty() || response.position != params.after || now >= deadline {
+            return Ok(Json(response));
+        }
+
+        sleep(POLL_INTERVAL.min(deadline - now)).await;
+    }
+}
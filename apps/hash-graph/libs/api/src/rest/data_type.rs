@@ -22,15 +22,16 @@ use error_stack::{Report, ResultExt};
 use graph::{
     ontology::{
         domain_validator::{DomainValidator, ValidateOntologyType},
-        patch_id_and_parse,
+        parse_next_version,
     },
     store::{
         DataTypeStore, OntologyVersionDoesNotExist, StorePool,
-        error::VersionedUrlAlreadyExists,
+        error::{BreakingOntologyTypeChange, VersionedUrlAlreadyExists},
         ontology::{
-            ArchiveDataTypeParams, CreateDataTypeParams, GetDataTypeSubgraphParams,
-            GetDataTypesParams, GetDataTypesResponse, UnarchiveDataTypeParams,
-            UpdateDataTypeEmbeddingParams, UpdateDataTypesParams,
+            ArchiveDataTypeParams, BreakingChangeBehavior, CreateDataTypeParams,
+            GetDataTypeSubgraphParams, GetDataTypesParams, GetDataTypesResponse,
+            UnarchiveDataTypeParams, UpdateDataTypeEmbeddingParams, UpdateDataTypesParams,
+            UpdateDataTypesResponse,
         },
     },
 };
@@ -52,7 +53,7 @@ use type_system::{
         ConversionDefinition, ConversionExpression, ConversionValue, Conversions, DataType,
         DataTypeId, Operator, Variable,
     },
-    url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+    url::{BaseUrl, VersionedUrl},
 };
 use utoipa::{OpenApi, ToSchema};
 
@@ -97,6 +98,7 @@ use crate::rest::{
             DataTypeQueryToken,
             GetDataTypesParams,
             GetDataTypesResponse,
+            UpdateDataTypesResponse,
             GetDataTypeSubgraphParams,
             GetDataTypeSubgraphResponse,
             ArchiveDataTypeParams,
@@ -436,11 +438,20 @@ where
 
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-struct GetDataTypeSubgraphResponse {
+pub struct GetDataTypeSubgraphResponse {
     subgraph: Subgraph,
     cursor: Option<VersionedUrl>,
 }
 
+impl From<graph::store::ontology::GetDataTypeSubgraphResponse> for GetDataTypeSubgraphResponse {
+    fn from(response: graph::store::ontology::GetDataTypeSubgraphResponse) -> Self {
+        Self {
+            subgraph: Subgraph::from(response.subgraph),
+            cursor: response.cursor,
+        }
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/data-types/query/subgraph",
@@ -497,12 +508,7 @@ where
         )
         .await
         .map_err(report_to_response)
-        .map(|response| {
-            Json(GetDataTypeSubgraphResponse {
-                subgraph: Subgraph::from(response.subgraph),
-                cursor: response.cursor,
-            })
-        })
+        .map(|response| Json(GetDataTypeSubgraphResponse::from(response)))
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -517,6 +523,8 @@ struct UpdateDataTypeRequest {
         skip_serializing_if = "ProvidedOntologyEditionProvenance::is_empty"
     )]
     provenance: ProvidedOntologyEditionProvenance,
+    #[serde(default)]
+    breaking_changes: BreakingChangeBehavior,
     conversions: HashMap<BaseUrl, Conversions>,
 }
 
@@ -530,9 +538,10 @@ struct UpdateDataTypeRequest {
         ("limit" = Option<usize>, Query, description = "The maximum number of data types to read"),
     ),
     responses(
-        (status = 200, content_type = "application/json", description = "The metadata of the updated data type", body = DataTypeMetadata),
+        (status = 200, content_type = "application/json", description = "The metadata of the updated data type and the detected changes if they were requested to be reported", body = UpdateDataTypesResponse),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
+        (status = 400, description = "The new version of the data type contains breaking changes which were rejected"),
         (status = 404, description = "Base data type ID was not found"),
         (status = 500, description = "Store error occurred"),
     ),
@@ -548,22 +557,21 @@ async fn update_data_type<S, A>(
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
     body: Json<UpdateDataTypeRequest>,
-) -> Result<Json<DataTypeMetadata>, Response>
+) -> Result<Json<UpdateDataTypesResponse>, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
 {
     let Json(UpdateDataTypeRequest {
         schema,
-        mut type_to_update,
+        type_to_update,
         relationships,
         provenance,
+        breaking_changes,
         conversions,
     }) = body;
 
-    type_to_update.version = OntologyTypeVersion::new(type_to_update.version.inner() + 1);
-
-    let data_type = patch_id_and_parse(&type_to_update, schema).map_err(report_to_response)?;
+    let data_type = parse_next_version(&type_to_update, schema).map_err(report_to_response)?;
 
     let authorization_api = authorization_api_pool
         .acquire()
@@ -580,10 +588,16 @@ where
             schema: data_type,
             relationships,
             provenance,
+            breaking_changes,
             conversions,
         })
         .await
-        .map_err(report_to_response)
+        .map_err(|mut report| {
+            if report.contains::<BreakingOntologyTypeChange>() {
+                report = report.attach(hash_status::StatusCode::FailedPrecondition);
+            }
+            report_to_response(report)
+        })
         .map(Json)
 }
 
@@ -1,6 +1,7 @@
 //! Web routes for CRU operations on entities.
 
-use alloc::sync::Arc;
+use alloc::{borrow::Cow, sync::Arc};
+use core::pin::pin;
 use std::collections::HashMap;
 
 use authorization::{
@@ -14,20 +15,27 @@ use authorization::{
     zanzibar::Consistency,
 };
 use axum::{
-    Extension, Router,
+    BoxError, Extension, Router,
+    body::Body,
     extract::Path,
-    http::StatusCode,
-    response::Response,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
     routing::{get, post},
 };
+use bytes::BytesMut;
+use codec::bytes::JsonLinesEncoder;
 use error_stack::{Report, ResultExt};
+use futures::{StreamExt, stream};
 use graph::store::{
-    EntityQueryCursor, EntityQuerySorting, EntityQuerySortingRecord, EntityStore,
-    EntityValidationType, NullOrdering, Ordering, StorePool,
-    error::{EntityDoesNotExist, RaceConditionOnUpdate},
+    AggregationFunction, EntityQueryCursor, EntityQuerySorting, EntityQuerySortingRecord,
+    EntityStore, EntityValidationType, NullOrdering, Ordering, StorePool,
+    error::{EntityDoesNotExist, EntityEditionConflict, RaceConditionOnUpdate},
     knowledge::{
-        CountEntitiesParams, CreateEntityRequest, DiffEntityParams, DiffEntityResult,
-        GetEntitiesParams, GetEntitiesResponse, GetEntitySubgraphParams, PatchEntityParams,
+        AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+        CreateEntityRequest, DiffEntityParams, DiffEntityResult, EntityAggregation,
+        EntityAggregationGroup, EntityPath, EntityPathHop, EntityProjection, EntityTraversalHop,
+        EntityTraversalPath, FindEntityPathsParams, FindEntityPathsResponse, GetEntitiesParams,
+        GetEntitiesResponse, GetEntitySubgraphParams, LinkTraversalDirection, PatchEntityParams,
         QueryConversion, UpdateEntityEmbeddingsParams, ValidateEntityParams,
     },
 };
@@ -61,6 +69,9 @@ use hash_graph_store::{
 };
 use serde::{Deserialize, Serialize};
 use temporal_client::TemporalClient;
+use tokio::sync::{mpsc, oneshot};
+use tokio_util::codec::Encoder;
+use tracing::Instrument;
 use type_system::url::VersionedUrl;
 use utoipa::{OpenApi, ToSchema};
 use validation::ValidateEntityComponents;
@@ -78,8 +89,11 @@ use crate::rest::{
         validate_entity,
         check_entity_permission,
         get_entities,
+        stream_entities,
         get_entity_subgraph,
         count_entities,
+        aggregate_entities,
+        find_entity_paths,
         patch_entity,
         update_entity_embeddings,
         diff_entity,
@@ -101,6 +115,15 @@ use crate::rest::{
             PropertyWithMetadataObject,
             ValidateEntityParams,
             CountEntitiesParams,
+            AggregateEntitiesParams,
+            AggregateEntitiesResponse,
+            AggregationFunction,
+            EntityAggregation,
+            EntityAggregationGroup,
+            FindEntityPathsParams,
+            FindEntityPathsResponse,
+            EntityPath,
+            EntityPathHop,
             EntityValidationType,
             ValidateEntityComponents,
             Embedding,
@@ -133,6 +156,10 @@ use crate::rest::{
             GetEntitiesResponse,
             GetEntitySubgraphResponse,
             QueryConversion,
+            EntityProjection,
+            EntityTraversalPath,
+            EntityTraversalHop,
+            LinkTraversalDirection,
 
             Entity,
             Property,
@@ -221,8 +248,11 @@ impl RoutedResource for EntityResource {
                     "/query",
                     Router::new()
                         .route("/", post(get_entities::<S, A>))
+                        .route("/stream", post(stream_entities::<S, A>))
                         .route("/subgraph", post(get_entity_subgraph::<S, A>))
-                        .route("/count", post(count_entities::<S, A>)),
+                        .route("/count", post(count_entities::<S, A>))
+                        .route("/aggregate", post(aggregate_entities::<S, A>))
+                        .route("/paths", post(find_entity_paths::<S, A>)),
                 ),
         )
     }
@@ -431,6 +461,7 @@ fn generate_sorting_paths(
     paths: Option<Vec<EntityQuerySortingRecord<'_>>>,
     limit: Option<usize>,
     cursor: Option<EntityQueryCursor<'_>>,
+    relevance: Option<Cow<'_, str>>,
     temporal_axes: &QueryTemporalAxesUnresolved,
 ) -> EntityQuerySorting<'static> {
     let temporal_axes_sorting_path = match temporal_axes {
@@ -514,6 +545,7 @@ fn generate_sorting_paths(
     EntityQuerySorting {
         paths: sorting,
         cursor: cursor.map(EntityQueryCursor::into_owned),
+        relevance: relevance.map(|query| Cow::Owned(query.into_owned())),
     }
 }
 
@@ -523,7 +555,7 @@ fn generate_sorting_paths(
     clippy::struct_excessive_bools,
     reason = "Parameter struct deserialized from JSON"
 )]
-struct GetEntitiesRequest<'q, 's, 'p> {
+pub(crate) struct GetEntitiesRequest<'q, 's, 'p> {
     #[serde(borrow)]
     filter: Filter<'q, Entity>,
     temporal_axes: QueryTemporalAxesUnresolved,
@@ -531,10 +563,14 @@ struct GetEntitiesRequest<'q, 's, 'p> {
     limit: Option<usize>,
     #[serde(borrow, default)]
     conversions: Vec<QueryConversion<'p>>,
+    #[serde(borrow, default)]
+    projection: Option<EntityProjection<'p>>,
     #[serde(borrow)]
     sorting_paths: Option<Vec<EntityQuerySortingRecord<'p>>>,
     #[serde(borrow)]
     cursor: Option<EntityQueryCursor<'s>>,
+    #[serde(borrow, default)]
+    sorting_relevance: Option<Cow<'p, str>>,
     #[serde(default)]
     include_count: bool,
     #[serde(default)]
@@ -547,6 +583,31 @@ struct GetEntitiesRequest<'q, 's, 'p> {
     include_type_ids: bool,
 }
 
+impl<'p> GetEntitiesRequest<'p, '_, 'p> {
+    pub(crate) fn into_params(self) -> GetEntitiesParams<'p> {
+        GetEntitiesParams {
+            filter: self.filter,
+            sorting: generate_sorting_paths(
+                self.sorting_paths,
+                self.limit,
+                self.cursor,
+                self.sorting_relevance,
+                &self.temporal_axes,
+            ),
+            limit: self.limit,
+            conversions: self.conversions,
+            projection: self.projection,
+            include_drafts: self.include_drafts,
+            include_count: self.include_count,
+            temporal_axes: self.temporal_axes,
+            include_web_ids: self.include_web_ids,
+            include_created_by_ids: self.include_created_by_ids,
+            include_edition_created_by_ids: self.include_edition_created_by_ids,
+            include_type_ids: self.include_type_ids,
+        }
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/entities/query",
@@ -597,24 +658,7 @@ where
         .map_err(Report::from)
         .map_err(report_to_response)?;
     store
-        .get_entities(actor_id, GetEntitiesParams {
-            filter: request.filter,
-            sorting: generate_sorting_paths(
-                request.sorting_paths,
-                request.limit,
-                request.cursor,
-                &request.temporal_axes,
-            ),
-            limit: request.limit,
-            conversions: request.conversions,
-            include_drafts: request.include_drafts,
-            include_count: request.include_count,
-            temporal_axes: request.temporal_axes,
-            include_web_ids: request.include_web_ids,
-            include_created_by_ids: request.include_created_by_ids,
-            include_edition_created_by_ids: request.include_edition_created_by_ids,
-            include_type_ids: request.include_type_ids,
-        })
+        .get_entities(actor_id, request.into_params())
         .await
         .map(|response| {
             Json(GetEntitiesResponse {
@@ -630,25 +674,132 @@ where
         .map_err(report_to_response)
 }
 
+/// The number of entities which are buffered while streaming entities to a client.
+const ENTITY_STREAM_BUFFER_SIZE: usize = 100;
+
+#[utoipa::path(
+    post,
+    path = "/entities/query/stream",
+    request_body = GetEntitiesRequest,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/x-ndjson",
+            body = Entity,
+            description = "The entities that satisfy the given query, one JSON object per line.",
+        ),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client, request)
+)]
+async fn stream_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Response, Response>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    // The request is validated before the response is started, so an invalid request is rejected
+    // with a proper status code.
+    GetEntitiesRequest::deserialize(&request)
+        .map_err(Report::from)
+        .map_err(report_to_response)?;
+
+    let authorization_api = authorization_api_pool
+        .acquire_owned()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire_owned(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    // The entities are read in a separate task which owns the store. Sending them through a
+    // bounded channel only reads more entities from the store when the client keeps up.
+    let (started_sender, started_receiver) = oneshot::channel();
+    let (entity_sender, mut entity_receiver) = mpsc::channel(ENTITY_STREAM_BUFFER_SIZE);
+    tokio::spawn(
+        async move {
+            let request = GetEntitiesRequest::deserialize(&request)
+                .expect("request should have been validated");
+            let entities = match store.stream_entities(actor_id, request.into_params()).await {
+                Ok(entities) => entities,
+                Err(report) => {
+                    // The receiver is only dropped when the client disconnected.
+                    let _: Result<_, _> = started_sender.send(Err(report));
+                    return;
+                }
+            };
+            if started_sender.send(Ok(())).is_err() {
+                return;
+            }
+
+            let mut entities = pin!(entities);
+            while let Some(entity) = entities.next().await {
+                if entity_sender.send(entity).await.is_err() {
+                    break;
+                }
+            }
+        }
+        .in_current_span(),
+    );
+
+    started_receiver
+        .await
+        .map_err(Report::new)
+        .map_err(report_to_response)?
+        .map_err(report_to_response)?;
+
+    let mut encoder = JsonLinesEncoder::default();
+    let body = Body::from_stream(
+        stream::poll_fn(move |context| entity_receiver.poll_recv(context)).map(move |entity| {
+            let mut buffer = BytesMut::new();
+            encoder.encode(entity?, &mut buffer)?;
+            Ok::<_, BoxError>(buffer.freeze())
+        }),
+    );
+
+    Ok(([(header::CONTENT_TYPE, "application/x-ndjson")], body).into_response())
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 #[expect(
     clippy::struct_excessive_bools,
     reason = "Parameter struct deserialized from JSON"
 )]
-struct GetEntitySubgraphRequest<'q, 's, 'p> {
+pub(crate) struct GetEntitySubgraphRequest<'q, 's, 'p> {
     #[serde(borrow)]
     filter: Filter<'q, Entity>,
     graph_resolve_depths: GraphResolveDepths,
+    #[serde(borrow, default)]
+    traversal_paths: Vec<EntityTraversalPath<'q>>,
     temporal_axes: QueryTemporalAxesUnresolved,
     include_drafts: bool,
     limit: Option<usize>,
     #[serde(borrow, default)]
     conversions: Vec<QueryConversion<'p>>,
+    #[serde(borrow, default)]
+    projection: Option<EntityProjection<'p>>,
     #[serde(borrow)]
     sorting_paths: Option<Vec<EntityQuerySortingRecord<'p>>>,
     #[serde(borrow)]
     cursor: Option<EntityQueryCursor<'s>>,
+    #[serde(borrow, default)]
+    sorting_relevance: Option<Cow<'p, str>>,
     #[serde(default)]
     include_count: bool,
     #[serde(default)]
@@ -661,9 +812,36 @@ struct GetEntitySubgraphRequest<'q, 's, 'p> {
     include_type_ids: bool,
 }
 
+impl<'p> GetEntitySubgraphRequest<'p, '_, 'p> {
+    pub(crate) fn into_params(self) -> GetEntitySubgraphParams<'p> {
+        GetEntitySubgraphParams {
+            filter: self.filter,
+            sorting: generate_sorting_paths(
+                self.sorting_paths,
+                self.limit,
+                self.cursor,
+                self.sorting_relevance,
+                &self.temporal_axes,
+            ),
+            limit: self.limit,
+            conversions: self.conversions,
+            projection: self.projection,
+            graph_resolve_depths: self.graph_resolve_depths,
+            traversal_paths: self.traversal_paths,
+            include_drafts: self.include_drafts,
+            include_count: self.include_count,
+            temporal_axes: self.temporal_axes,
+            include_web_ids: self.include_web_ids,
+            include_created_by_ids: self.include_created_by_ids,
+            include_edition_created_by_ids: self.include_edition_created_by_ids,
+            include_type_ids: self.include_type_ids,
+        }
+    }
+}
+
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-struct GetEntitySubgraphResponse<'r> {
+pub struct GetEntitySubgraphResponse<'r> {
     subgraph: Subgraph,
     #[serde(borrow)]
     cursor: Option<EntityQueryCursor<'r>>,
@@ -682,6 +860,22 @@ struct GetEntitySubgraphResponse<'r> {
     type_ids: Option<HashMap<VersionedUrl, usize>>,
 }
 
+impl From<graph::store::knowledge::GetEntitySubgraphResponse<'_>>
+    for GetEntitySubgraphResponse<'static>
+{
+    fn from(response: graph::store::knowledge::GetEntitySubgraphResponse<'_>) -> Self {
+        Self {
+            subgraph: Subgraph::from(response.subgraph),
+            cursor: response.cursor.map(EntityQueryCursor::into_owned),
+            count: response.count,
+            web_ids: response.web_ids,
+            created_by_ids: response.created_by_ids,
+            edition_created_by_ids: response.edition_created_by_ids,
+            type_ids: response.type_ids,
+        }
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/entities/query/subgraph",
@@ -732,37 +926,9 @@ where
         .map_err(Report::from)
         .map_err(report_to_response)?;
     store
-        .get_entity_subgraph(actor_id, GetEntitySubgraphParams {
-            filter: request.filter,
-            sorting: generate_sorting_paths(
-                request.sorting_paths,
-                request.limit,
-                request.cursor,
-                &request.temporal_axes,
-            ),
-            limit: request.limit,
-            conversions: request.conversions,
-            graph_resolve_depths: request.graph_resolve_depths,
-            include_drafts: request.include_drafts,
-            include_count: request.include_count,
-            temporal_axes: request.temporal_axes,
-            include_web_ids: request.include_web_ids,
-            include_created_by_ids: request.include_created_by_ids,
-            include_edition_created_by_ids: request.include_edition_created_by_ids,
-            include_type_ids: request.include_type_ids,
-        })
+        .get_entity_subgraph(actor_id, request.into_params())
         .await
-        .map(|response| {
-            Json(GetEntitySubgraphResponse {
-                subgraph: response.subgraph.into(),
-                cursor: response.cursor.map(EntityQueryCursor::into_owned),
-                count: response.count,
-                web_ids: response.web_ids,
-                created_by_ids: response.created_by_ids,
-                edition_created_by_ids: response.edition_created_by_ids,
-                type_ids: response.type_ids,
-            })
-        })
+        .map(|response| Json(GetEntitySubgraphResponse::from(response)))
         .map_err(report_to_response)
 }
 
@@ -822,6 +988,121 @@ where
         .map_err(report_to_response)
 }
 
+#[utoipa::path(
+    post,
+    path = "/entities/query/aggregate",
+    request_body = AggregateEntitiesParams,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            body = AggregateEntitiesResponse,
+            description = "The aggregated values for each group of entities",
+        ),
+        (status = 400, content_type = "text/plain", description = "An aggregation other than a count is missing a path"),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client, request)
+)]
+async fn aggregate_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<AggregateEntitiesResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .aggregate_entities(
+            actor_id,
+            AggregateEntitiesParams::deserialize(&request)
+                .map_err(Report::from)
+                .map_err(report_to_response)?,
+        )
+        .await
+        .map(Json)
+        .map_err(report_to_response)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/query/paths",
+    request_body = FindEntityPathsParams,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            body = FindEntityPathsResponse,
+            description = "The shortest paths of links between the two entities",
+        ),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client, request)
+)]
+async fn find_entity_paths<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<FindEntityPathsResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .find_entity_paths(
+            actor_id,
+            FindEntityPathsParams::deserialize(&request)
+                .map_err(Report::from)
+                .map_err(report_to_response)?,
+        )
+        .await
+        .map(Json)
+        .map_err(report_to_response)
+}
+
 #[utoipa::path(
     patch,
     path = "/entities",
@@ -832,6 +1113,7 @@ where
     responses(
         (status = 200, content_type = "application/json", description = "The updated entity", body = Entity),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 409, content_type = "text/plain", description = "The entity is not at the expected edition"),
         (status = 423, content_type = "text/plain", description = "The entity that should be updated was unexpectedly updated at the same time"),
 
         (status = 404, description = "Entity ID or Entity Type URL was not found"),
@@ -870,6 +1152,8 @@ where
         .map_err(|report| {
             if report.contains::<EntityDoesNotExist>() {
                 report.attach(hash_status::StatusCode::NotFound)
+            } else if report.contains::<EntityEditionConflict>() {
+                report.attach(hash_status::StatusCode::Aborted)
             } else if report.contains::<RaceConditionOnUpdate>() {
                 report.attach(hash_status::StatusCode::Cancelled)
             } else {
@@ -24,15 +24,19 @@ use error_stack::{Report, ResultExt};
 use graph::{
     ontology::{
         domain_validator::{DomainValidator, ValidateOntologyType},
-        patch_id_and_parse,
+        parse_next_version,
     },
     store::{
         EntityTypeStore, StorePool,
-        error::{BaseUrlAlreadyExists, OntologyVersionDoesNotExist, VersionedUrlAlreadyExists},
+        error::{
+            BaseUrlAlreadyExists, BreakingOntologyTypeChange, OntologyVersionDoesNotExist,
+            VersionedUrlAlreadyExists,
+        },
         ontology::{
-            ArchiveEntityTypeParams, CreateEntityTypeParams, GetEntityTypeSubgraphParams,
-            GetEntityTypesParams, GetEntityTypesResponse, UnarchiveEntityTypeParams,
-            UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
+            ArchiveEntityTypeParams, BreakingChangeBehavior, CreateEntityTypeParams,
+            GetEntityTypeSubgraphParams, GetEntityTypesParams, GetEntityTypesResponse,
+            UnarchiveEntityTypeParams, UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
+            UpdateEntityTypesResponse,
         },
     },
 };
@@ -53,7 +57,7 @@ use temporal_client::TemporalClient;
 use time::OffsetDateTime;
 use type_system::{
     schema::EntityType,
-    url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+    url::{BaseUrl, VersionedUrl},
 };
 use utoipa::{OpenApi, ToSchema};
 
@@ -103,6 +107,7 @@ use crate::rest::{
             EntityTypeQueryToken,
             GetEntityTypesParams,
             GetEntityTypesResponse,
+            UpdateEntityTypesResponse,
             GetEntityTypeSubgraphParams,
             GetEntityTypeSubgraphResponse,
             ArchiveEntityTypeParams,
@@ -736,7 +741,7 @@ where
 
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-struct GetEntityTypeSubgraphResponse {
+pub struct GetEntityTypeSubgraphResponse {
     subgraph: Subgraph,
     cursor: Option<VersionedUrl>,
     count: Option<usize>,
@@ -748,6 +753,18 @@ struct GetEntityTypeSubgraphResponse {
     edition_created_by_ids: Option<HashMap<EditionCreatedById, usize>>,
 }
 
+impl From<graph::store::ontology::GetEntityTypeSubgraphResponse> for GetEntityTypeSubgraphResponse {
+    fn from(response: graph::store::ontology::GetEntityTypeSubgraphResponse) -> Self {
+        Self {
+            subgraph: Subgraph::from(response.subgraph),
+            cursor: response.cursor,
+            count: response.count,
+            web_ids: response.web_ids,
+            edition_created_by_ids: response.edition_created_by_ids,
+        }
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/entity-types/query/subgraph",
@@ -801,15 +818,7 @@ where
         )
         .await
         .map_err(report_to_response)
-        .map(|response| {
-            Json(GetEntityTypeSubgraphResponse {
-                subgraph: Subgraph::from(response.subgraph),
-                cursor: response.cursor,
-                count: response.count,
-                web_ids: response.web_ids,
-                edition_created_by_ids: response.edition_created_by_ids,
-            })
-        })
+        .map(|response| Json(GetEntityTypeSubgraphResponse::from(response)))
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -825,6 +834,8 @@ struct UpdateEntityTypeRequest {
     relationships: Vec<EntityTypeRelationAndSubject>,
     #[serde(default)]
     provenance: ProvidedOntologyEditionProvenance,
+    #[serde(default)]
+    breaking_changes: BreakingChangeBehavior,
 }
 
 #[utoipa::path(
@@ -837,9 +848,10 @@ struct UpdateEntityTypeRequest {
         ("limit" = Option<usize>, Query, description = "The maximum number of entity types to read"),
     ),
     responses(
-        (status = 200, content_type = "application/json", description = "The metadata of the updated entity type", body = EntityTypeMetadata),
+        (status = 200, content_type = "application/json", description = "The metadata of the updated entity type and the detected changes if they were requested to be reported", body = UpdateEntityTypesResponse),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
+        (status = 400, description = "The new version of the entity type contains breaking changes which were rejected"),
         (status = 404, description = "Base entity type ID was not found"),
         (status = 500, description = "Store error occurred"),
     ),
@@ -855,23 +867,22 @@ async fn update_entity_type<S, A>(
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
     body: Json<UpdateEntityTypeRequest>,
-) -> Result<Json<EntityTypeMetadata>, Response>
+) -> Result<Json<UpdateEntityTypesResponse>, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
 {
     let Json(UpdateEntityTypeRequest {
         schema,
-        mut type_to_update,
+        type_to_update,
         label_property,
         icon,
         relationships,
         provenance,
+        breaking_changes,
     }) = body;
 
-    type_to_update.version = OntologyTypeVersion::new(type_to_update.version.inner() + 1);
-
-    let entity_type = patch_id_and_parse(&type_to_update, schema).map_err(report_to_response)?;
+    let entity_type = parse_next_version(&type_to_update, schema).map_err(report_to_response)?;
 
     let authorization_api = authorization_api_pool
         .acquire()
@@ -890,9 +901,15 @@ where
             icon,
             relationships,
             provenance,
+            breaking_changes,
         })
         .await
-        .map_err(report_to_response)
+        .map_err(|mut report| {
+            if report.contains::<BreakingOntologyTypeChange>() {
+                report = report.attach(hash_status::StatusCode::FailedPrecondition);
+            }
+            report_to_response(report)
+        })
         .map(Json)
 }
 
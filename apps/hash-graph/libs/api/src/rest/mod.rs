@@ -9,6 +9,7 @@ pub mod status;
 mod utoipa_typedef;
 
 mod account;
+mod change_feed;
 mod data_type;
 mod entity;
 mod entity_type;
@@ -31,7 +32,10 @@ use axum::{
 use error_stack::{Report, ResultExt};
 use graph::{
     ontology::domain_validator::DomainValidator,
-    store::{Store, StorePool, TypeFetcher, error::VersionedUrlAlreadyExists},
+    store::{
+        Store, StorePool, TypeFetcher, error::VersionedUrlAlreadyExists,
+        ontology::BreakingChangeBehavior,
+    },
 };
 use graph_types::{
     account::{AccountId, CreatedById, EditionArchivedById, EditionCreatedById},
@@ -68,7 +72,10 @@ use temporal_versioning::{
     ClosedTemporalBound, DecisionTime, LeftClosedTemporalInterval, LimitedTemporalBound,
     OpenTemporalBound, RightBoundedTemporalInterval, TemporalBound, Timestamp, TransactionTime,
 };
-use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+use type_system::{
+    schema::TypeChange,
+    url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+};
 use utoipa::{
     Modify, OpenApi, ToSchema,
     openapi::{
@@ -92,6 +99,14 @@ use self::{
         },
     },
 };
+pub use self::{
+    data_type::GetDataTypeSubgraphResponse, entity::GetEntitySubgraphResponse,
+    entity_type::GetEntityTypeSubgraphResponse, property_type::GetPropertyTypeSubgraphResponse,
+};
+pub(crate) use self::{
+    entity::{GetEntitiesRequest, GetEntitySubgraphRequest},
+    utoipa_typedef::ListOrValue,
+};
 
 pub struct AuthenticatedUserHeader(pub AccountId);
 
@@ -179,6 +194,7 @@ where
         entity_type::EntityTypeResource::routes::<S, A>(),
         entity::EntityResource::routes::<S, A>(),
         web::WebResource::routes::<S, A>(),
+        change_feed::ChangeFeedResource::routes::<S, A>(),
     ]
 }
 
@@ -190,6 +206,7 @@ fn api_documentation() -> Vec<openapi::OpenApi> {
         entity_type::EntityTypeResource::documentation(),
         entity::EntityResource::documentation(),
         web::WebResource::documentation(),
+        change_feed::ChangeFeedResource::documentation(),
     ]
 }
 
@@ -200,7 +217,7 @@ where
 {
     pub store: Arc<S>,
     pub authorization_api: Arc<A>,
-    pub temporal_client: Option<TemporalClient>,
+    pub temporal_client: Option<Arc<TemporalClient>>,
     pub domain_regex: DomainValidator,
 }
 
@@ -241,7 +258,7 @@ where
         .layer(SentryHttpLayer::with_transaction())
         .layer(Extension(dependencies.store))
         .layer(Extension(dependencies.authorization_api))
-        .layer(Extension(dependencies.temporal_client.map(Arc::new)))
+        .layer(Extension(dependencies.temporal_client))
         .layer(Extension(dependencies.domain_regex))
         .layer(span_trace_layer())
         .merge(openapi_only_router())
@@ -292,6 +309,8 @@ async fn serve_static_schema(Path(path): Path<String>) -> Result<Response, Statu
             ProvidedOntologyEditionProvenance,
             OntologyTypeRecordId,
             OntologyTemporalMetadata,
+            BreakingChangeBehavior,
+            TypeChange,
             DataTypeMetadata,
             MaybeListOfDataTypeMetadata,
             PropertyTypeMetadata,
@@ -691,6 +710,142 @@ impl Modify for FilterSchemaAddon {
                                 )
                                 .required("containsSegment"),
                         )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("FullTextSearchFilter"))
+                                .property(
+                                    "fullTextSearch",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("fullTextSearch"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("MatchesFilter"))
+                                .property(
+                                    "matches",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("matches"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("MatchesIgnoreCaseFilter"))
+                                .property(
+                                    "matchesIgnoreCase",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("matchesIgnoreCase"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("EqualIgnoreCaseFilter"))
+                                .property(
+                                    "equalIgnoreCase",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("equalIgnoreCase"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("StartsWithIgnoreCaseFilter"))
+                                .property(
+                                    "startsWithIgnoreCase",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("startsWithIgnoreCase"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("EndsWithIgnoreCaseFilter"))
+                                .property(
+                                    "endsWithIgnoreCase",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("endsWithIgnoreCase"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("ContainsSegmentIgnoreCaseFilter"))
+                                .property(
+                                    "containsSegmentIgnoreCase",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("containsSegmentIgnoreCase"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("ExistsFilter"))
+                                .property("exists", Ref::from_schema_name("FilterExpression"))
+                                .required("exists"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("IsNullFilter"))
+                                .property("isNull", Ref::from_schema_name("FilterExpression"))
+                                .required("isNull"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("InFilter"))
+                                .property(
+                                    "in",
+                                    ArrayBuilder::new()
+                                        .items(
+                                            OneOfBuilder::new()
+                                                .item(Ref::from_schema_name("FilterExpression"))
+                                                .item(ArrayBuilder::new().items(Any::schema().1)),
+                                        )
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("in"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("ArrayContainsFilter"))
+                                .property(
+                                    "arrayContains",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("arrayContains"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("ArrayOverlapsFilter"))
+                                .property(
+                                    "arrayOverlaps",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("arrayOverlaps"),
+                        )
                         .build(),
                 )
                 .into(),
@@ -734,6 +889,21 @@ impl Modify for FilterSchemaAddon {
                 )
                 .into(),
             );
+            components.schemas.insert(
+                "EntityQueryPath".to_owned(),
+                schema::Schema::Array(
+                    ArrayBuilder::new()
+                        .items(
+                            OneOfBuilder::new()
+                                .item(Ref::from_schema_name("EntityQueryToken"))
+                                .item(Ref::from_schema_name("Selector"))
+                                .item(ObjectBuilder::new().schema_type(SchemaType::String))
+                                .item(ObjectBuilder::new().schema_type(SchemaType::Number)),
+                        )
+                        .build(),
+                )
+                .into(),
+            );
             components.schemas.insert(
                 "EntityQuerySortingPath".to_owned(),
                 schema::Schema::Array(
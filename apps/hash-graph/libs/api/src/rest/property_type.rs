@@ -23,15 +23,16 @@ use error_stack::{Report, ResultExt};
 use graph::{
     ontology::{
         domain_validator::{DomainValidator, ValidateOntologyType},
-        patch_id_and_parse,
+        parse_next_version,
     },
     store::{
         OntologyVersionDoesNotExist, PropertyTypeStore, StorePool,
-        error::VersionedUrlAlreadyExists,
+        error::{BreakingOntologyTypeChange, VersionedUrlAlreadyExists},
         ontology::{
-            ArchivePropertyTypeParams, CreatePropertyTypeParams, GetPropertyTypeSubgraphParams,
-            GetPropertyTypesParams, GetPropertyTypesResponse, UnarchivePropertyTypeParams,
-            UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
+            ArchivePropertyTypeParams, BreakingChangeBehavior, CreatePropertyTypeParams,
+            GetPropertyTypeSubgraphParams, GetPropertyTypesParams, GetPropertyTypesResponse,
+            UnarchivePropertyTypeParams, UpdatePropertyTypeEmbeddingParams,
+            UpdatePropertyTypesParams, UpdatePropertyTypesResponse,
         },
     },
 };
@@ -48,10 +49,7 @@ use hash_status::Status;
 use serde::{Deserialize, Serialize};
 use temporal_client::TemporalClient;
 use time::OffsetDateTime;
-use type_system::{
-    schema::PropertyType,
-    url::{OntologyTypeVersion, VersionedUrl},
-};
+use type_system::{schema::PropertyType, url::VersionedUrl};
 use utoipa::{OpenApi, ToSchema};
 
 use super::api_resource::RoutedResource;
@@ -99,6 +97,7 @@ use crate::rest::{
             PropertyTypeQueryToken,
             GetPropertyTypesParams,
             GetPropertyTypesResponse,
+            UpdatePropertyTypesResponse,
             GetPropertyTypeSubgraphParams,
             GetPropertyTypeSubgraphResponse,
             ArchivePropertyTypeParams,
@@ -424,11 +423,22 @@ where
 
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-struct GetPropertyTypeSubgraphResponse {
+pub struct GetPropertyTypeSubgraphResponse {
     subgraph: Subgraph,
     cursor: Option<VersionedUrl>,
 }
 
+impl From<graph::store::ontology::GetPropertyTypeSubgraphResponse>
+    for GetPropertyTypeSubgraphResponse
+{
+    fn from(response: graph::store::ontology::GetPropertyTypeSubgraphResponse) -> Self {
+        Self {
+            subgraph: Subgraph::from(response.subgraph),
+            cursor: response.cursor,
+        }
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/property-types/query/subgraph",
@@ -487,12 +497,7 @@ where
         )
         .await
         .map_err(report_to_response)
-        .map(|response| {
-            Json(GetPropertyTypeSubgraphResponse {
-                subgraph: Subgraph::from(response.subgraph),
-                cursor: response.cursor,
-            })
-        })
+        .map(|response| Json(GetPropertyTypeSubgraphResponse::from(response)))
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -507,6 +512,8 @@ struct UpdatePropertyTypeRequest {
         skip_serializing_if = "ProvidedOntologyEditionProvenance::is_empty"
     )]
     provenance: ProvidedOntologyEditionProvenance,
+    #[serde(default)]
+    breaking_changes: BreakingChangeBehavior,
 }
 
 #[utoipa::path(
@@ -519,9 +526,10 @@ struct UpdatePropertyTypeRequest {
         ("limit" = Option<usize>, Query, description = "The maximum number of property types to read"),
     ),
     responses(
-        (status = 200, content_type = "application/json", description = "The metadata of the updated property type", body = PropertyTypeMetadata),
+        (status = 200, content_type = "application/json", description = "The metadata of the updated property type and the detected changes if they were requested to be reported", body = UpdatePropertyTypesResponse),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
+        (status = 400, description = "The new version of the property type contains breaking changes which were rejected"),
         (status = 404, description = "Base property type ID was not found"),
         (status = 500, description = "Store error occurred"),
     ),
@@ -537,21 +545,20 @@ async fn update_property_type<S, A>(
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
     body: Json<UpdatePropertyTypeRequest>,
-) -> Result<Json<PropertyTypeMetadata>, Response>
+) -> Result<Json<UpdatePropertyTypesResponse>, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
 {
     let Json(UpdatePropertyTypeRequest {
         schema,
-        mut type_to_update,
+        type_to_update,
         relationships,
         provenance,
+        breaking_changes,
     }) = body;
 
-    type_to_update.version = OntologyTypeVersion::new(type_to_update.version.inner() + 1);
-
-    let property_type = patch_id_and_parse(&type_to_update, schema).map_err(report_to_response)?;
+    let property_type = parse_next_version(&type_to_update, schema).map_err(report_to_response)?;
 
     let authorization_api = authorization_api_pool
         .acquire()
@@ -568,9 +575,15 @@ where
             schema: property_type,
             relationships,
             provenance,
+            breaking_changes,
         })
         .await
-        .map_err(report_to_response)
+        .map_err(|mut report| {
+            if report.contains::<BreakingOntologyTypeChange>() {
+                report = report.attach(hash_status::StatusCode::FailedPrecondition);
+            }
+            report_to_response(report)
+        })
         .map(Json)
 }
 
@@ -0,0 +1,100 @@
+//! RPC service for managing accounts.
+
+use authorization::{AuthorizationApiPool, schema::WebOwnerSubject};
+use error_stack::{Report, ResultExt};
+use graph::store::StorePool;
+use graph_types::{
+    account::{AccountGroupId, AccountId},
+    owned_by_id::OwnedById,
+};
+use harpc_service::role::Server;
+use hash_graph_store::account::{AccountStore, InsertAccountGroupIdParams, InsertAccountIdParams};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::rpc::{GraphRpc, GraphSession, RpcError, actor_id};
+
+/// Payload of [`AccountService::create_account`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAccount {
+    /// The id of the account to create, a random id is used if not specified.
+    #[serde(default)]
+    pub account_id: Option<AccountId>,
+}
+
+/// Payload of [`AccountService::create_account_group`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAccountGroup {
+    /// The id of the account group to create, a random id is used if not specified.
+    #[serde(default)]
+    pub account_group_id: Option<AccountGroupId>,
+}
+
+#[harpc_service::service(id = 0x00, version = "0.0")]
+pub trait AccountService {
+    /// Creates a new account and returns its id.
+    #[procedure(id = 0x00)]
+    fn create_account(&self, payload: CreateAccount) -> Result<AccountId, Report<RpcError>>;
+
+    /// Creates a new account group owned by the actor and returns its id.
+    #[procedure(id = 0x01)]
+    fn create_account_group(
+        &self,
+        payload: CreateAccountGroup,
+    ) -> Result<AccountGroupId, Report<RpcError>>;
+}
+
+impl<S, A> AccountService<Server<GraphSession>> for GraphRpc<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    async fn create_account(
+        &self,
+        session: &GraphSession,
+        payload: CreateAccount,
+    ) -> Result<AccountId, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        let account_id = payload
+            .account_id
+            .unwrap_or_else(|| AccountId::new(Uuid::new_v4()));
+        store
+            .insert_account_id(actor_id, InsertAccountIdParams { account_id })
+            .await
+            .change_context(RpcError::Store)?;
+
+        Ok(account_id)
+    }
+
+    async fn create_account_group(
+        &self,
+        session: &GraphSession,
+        payload: CreateAccountGroup,
+    ) -> Result<AccountGroupId, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        let account = store
+            .identify_owned_by_id(OwnedById::from(actor_id))
+            .await
+            .change_context(RpcError::Store)?;
+        if account != (WebOwnerSubject::Account { id: actor_id }) {
+            return Err(Report::new(RpcError::NotFound)
+                .attach_printable("Account does not exist in the graph"));
+        }
+
+        let account_group_id = payload
+            .account_group_id
+            .unwrap_or_else(|| AccountGroupId::new(Uuid::new_v4()));
+        store
+            .insert_account_group_id(actor_id, InsertAccountGroupIdParams { account_group_id })
+            .await
+            .change_context(RpcError::Store)?;
+
+        Ok(account_group_id)
+    }
+}
@@ -0,0 +1,78 @@
+//! RPC service for authenticating sessions.
+
+use alloc::sync::Arc;
+
+use error_stack::{Report, ResultExt};
+use graph_types::account::AccountId;
+use harpc_server::auth::{Authentication, PreSharedToken, Verifier};
+use harpc_service::role::Server;
+use serde::{Deserialize, Serialize};
+
+use crate::rpc::{GraphSession, RpcError};
+
+/// Payload of [`AuthenticationService::authenticate`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Authenticate {
+    /// The token shared between the client and the Graph.
+    pub token: String,
+    /// The account all subsequent requests of the session are made on behalf of.
+    pub actor_id: AccountId,
+}
+
+#[harpc_service::service(id = 0x05, version = "0.0")]
+pub trait AuthenticationService {
+    /// Authenticates the session, every other procedure requires an authenticated session.
+    ///
+    /// Authenticating an already authenticated session replaces its actor.
+    #[procedure(id = 0x00)]
+    fn authenticate(&self, payload: Authenticate) -> Result<(), Report<RpcError>>;
+}
+
+/// Implementation of the [`AuthenticationService`], verifying credentials using `V`.
+pub struct Authenticator<V> {
+    verifier: Arc<V>,
+}
+
+impl<V> Authenticator<V> {
+    #[must_use]
+    pub fn new(verifier: V) -> Self {
+        Self {
+            verifier: Arc::new(verifier),
+        }
+    }
+}
+
+impl<V> Clone for Authenticator<V> {
+    fn clone(&self) -> Self {
+        Self {
+            verifier: Arc::clone(&self.verifier),
+        }
+    }
+}
+
+impl<V> AuthenticationService<Server<GraphSession>> for Authenticator<V>
+where
+    V: Verifier<PreSharedToken<AccountId>, Identity = AccountId>,
+{
+    async fn authenticate(
+        &self,
+        session: &GraphSession,
+        payload: Authenticate,
+    ) -> Result<(), Report<RpcError>> {
+        let actor_id = self
+            .verifier
+            .verify(PreSharedToken {
+                token: payload.token,
+                identity: payload.actor_id,
+            })
+            .await
+            .change_context(RpcError::Unauthenticated)?;
+
+        session
+            .update(Authentication::authenticated(actor_id))
+            .await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,327 @@
+//! RPC service for querying and modifying data types.
+
+use std::collections::HashMap;
+
+use authorization::{AuthorizationApiPool, schema::DataTypeRelationAndSubject};
+use error_stack::{Report, ResultExt};
+use graph::{
+    ontology::{PatchAndParseError, domain_validator::ValidateOntologyType, parse_next_version},
+    store::{
+        DataTypeStore, StorePool,
+        ontology::{
+            ArchiveDataTypeParams, BreakingChangeBehavior, CreateDataTypeParams,
+            GetDataTypeSubgraphParams, GetDataTypesParams, GetDataTypesResponse,
+            UnarchiveDataTypeParams, UpdateDataTypeEmbeddingParams, UpdateDataTypesParams,
+            UpdateDataTypesResponse,
+        },
+    },
+};
+use graph_types::{
+    ontology::{
+        DataTypeMetadata, OntologyTemporalMetadata, OntologyTypeClassificationMetadata,
+        ProvidedOntologyEditionProvenance,
+    },
+    owned_by_id::OwnedById,
+};
+use harpc_service::role::Server;
+use hash_graph_store::ConflictBehavior;
+use serde::Deserialize;
+use type_system::{
+    schema::{Conversions, DataType},
+    url::{BaseUrl, VersionedUrl},
+};
+
+use crate::{
+    rest::GetDataTypeSubgraphResponse,
+    rpc::{
+        GraphRpc, GraphSession, RpcError, actor_id, deserialize_list_or_value, deserialize_owned,
+    },
+};
+
+/// Payload of [`DataTypeService::get_data_types`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct GetDataTypes {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: GetDataTypesParams<'static>,
+}
+
+/// Payload of [`DataTypeService::get_data_type_subgraph`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct GetDataTypeSubgraph {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: GetDataTypeSubgraphParams<'static>,
+}
+
+/// Payload of [`DataTypeService::create_data_types`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateDataTypes {
+    /// The data types to create, encoded either as a single schema or as a list of schemas.
+    #[serde(deserialize_with = "deserialize_list_or_value")]
+    pub schema: Vec<DataType>,
+    pub owned_by_id: OwnedById,
+    pub relationships: Vec<DataTypeRelationAndSubject>,
+    #[serde(default)]
+    pub provenance: ProvidedOntologyEditionProvenance,
+    pub conversions: HashMap<BaseUrl, Conversions>,
+}
+
+/// Payload of [`DataTypeService::update_data_type`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint, the schema is
+/// parsed as the next version of `typeToUpdate`.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "UpdateDataTypeRequest")]
+pub struct UpdateDataType {
+    pub params: UpdateDataTypesParams<Vec<DataTypeRelationAndSubject>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct UpdateDataTypeRequest {
+    schema: serde_json::Value,
+    type_to_update: VersionedUrl,
+    relationships: Vec<DataTypeRelationAndSubject>,
+    #[serde(default)]
+    provenance: ProvidedOntologyEditionProvenance,
+    #[serde(default)]
+    breaking_changes: BreakingChangeBehavior,
+    conversions: HashMap<BaseUrl, Conversions>,
+}
+
+impl TryFrom<UpdateDataTypeRequest> for UpdateDataType {
+    type Error = Report<PatchAndParseError>;
+
+    fn try_from(request: UpdateDataTypeRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            params: UpdateDataTypesParams {
+                schema: parse_next_version(&request.type_to_update, request.schema)?,
+                relationships: request.relationships,
+                provenance: request.provenance,
+                breaking_changes: request.breaking_changes,
+                conversions: request.conversions,
+            },
+        })
+    }
+}
+
+/// Payload of [`DataTypeService::archive_data_type`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct ArchiveDataType {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: ArchiveDataTypeParams<'static>,
+}
+
+/// Payload of [`DataTypeService::unarchive_data_type`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct UnarchiveDataType {
+    pub params: UnarchiveDataTypeParams,
+}
+
+/// Payload of [`DataTypeService::update_data_type_embeddings`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct UpdateDataTypeEmbeddings {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: UpdateDataTypeEmbeddingParams<'static>,
+}
+
+#[harpc_service::service(id = 0x01, version = "0.0")]
+pub trait DataTypeService {
+    /// Gets a list of data types that satisfy the given query.
+    #[procedure(id = 0x00)]
+    fn get_data_types(
+        &self,
+        payload: GetDataTypes,
+    ) -> Result<GetDataTypesResponse, Report<RpcError>>;
+
+    /// Gets a subgraph rooted at all data types that satisfy the given query.
+    #[procedure(id = 0x01)]
+    fn get_data_type_subgraph(
+        &self,
+        payload: GetDataTypeSubgraph,
+    ) -> Result<GetDataTypeSubgraphResponse, Report<RpcError>>;
+
+    /// Creates the given data types and returns their metadata in the same order.
+    #[procedure(id = 0x02)]
+    fn create_data_types(
+        &self,
+        payload: CreateDataTypes,
+    ) -> Result<Vec<DataTypeMetadata>, Report<RpcError>>;
+
+    /// Creates a new version of an existing data type.
+    #[procedure(id = 0x03)]
+    fn update_data_type(
+        &self,
+        payload: UpdateDataType,
+    ) -> Result<UpdateDataTypesResponse, Report<RpcError>>;
+
+    /// Archives the given version of a data type.
+    #[procedure(id = 0x04)]
+    fn archive_data_type(
+        &self,
+        payload: ArchiveDataType,
+    ) -> Result<OntologyTemporalMetadata, Report<RpcError>>;
+
+    /// Restores an archived version of a data type.
+    #[procedure(id = 0x05)]
+    fn unarchive_data_type(
+        &self,
+        payload: UnarchiveDataType,
+    ) -> Result<OntologyTemporalMetadata, Report<RpcError>>;
+
+    /// Updates the embeddings of a data type.
+    #[procedure(id = 0x06)]
+    fn update_data_type_embeddings(
+        &self,
+        payload: UpdateDataTypeEmbeddings,
+    ) -> Result<(), Report<RpcError>>;
+}
+
+impl<S, A> DataTypeService<Server<GraphSession>> for GraphRpc<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    async fn get_data_types(
+        &self,
+        session: &GraphSession,
+        payload: GetDataTypes,
+    ) -> Result<GetDataTypesResponse, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let store = self.store().await?;
+
+        store
+            .get_data_types(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn get_data_type_subgraph(
+        &self,
+        session: &GraphSession,
+        payload: GetDataTypeSubgraph,
+    ) -> Result<GetDataTypeSubgraphResponse, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let store = self.store().await?;
+
+        store
+            .get_data_type_subgraph(actor_id, payload.params)
+            .await
+            .map(GetDataTypeSubgraphResponse::from)
+            .change_context(RpcError::Store)
+    }
+
+    async fn create_data_types(
+        &self,
+        session: &GraphSession,
+        payload: CreateDataTypes,
+    ) -> Result<Vec<DataTypeMetadata>, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+
+        let CreateDataTypes {
+            schema,
+            owned_by_id,
+            relationships,
+            provenance,
+            conversions,
+        } = payload;
+
+        let params = schema
+            .into_iter()
+            .map(|schema| {
+                self.domain_validator
+                    .validate(&schema)
+                    .change_context(RpcError::InvalidRequest)?;
+
+                Ok(CreateDataTypeParams {
+                    schema,
+                    classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+                    relationships: relationships.clone(),
+                    conflict_behavior: ConflictBehavior::Fail,
+                    provenance: provenance.clone(),
+                    conversions: conversions.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, Report<RpcError>>>()?;
+
+        let mut store = self.store().await?;
+
+        store
+            .create_data_types(actor_id, params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn update_data_type(
+        &self,
+        session: &GraphSession,
+        payload: UpdateDataType,
+    ) -> Result<UpdateDataTypesResponse, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .update_data_type(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn archive_data_type(
+        &self,
+        session: &GraphSession,
+        payload: ArchiveDataType,
+    ) -> Result<OntologyTemporalMetadata, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .archive_data_type(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn unarchive_data_type(
+        &self,
+        session: &GraphSession,
+        payload: UnarchiveDataType,
+    ) -> Result<OntologyTemporalMetadata, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .unarchive_data_type(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn update_data_type_embeddings(
+        &self,
+        session: &GraphSession,
+        payload: UpdateDataTypeEmbeddings,
+    ) -> Result<(), Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .update_data_type_embeddings(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+}
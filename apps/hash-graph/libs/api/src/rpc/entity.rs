@@ -0,0 +1,249 @@
+//! RPC service for querying and modifying entities.
+
+use authorization::{AuthorizationApiPool, schema::EntityRelationAndSubject};
+use error_stack::{Report, ResultExt};
+use graph::store::{
+    EntityStore, StorePool,
+    knowledge::{
+        CountEntitiesParams, CreateEntityParams, GetEntitiesParams, GetEntitiesResponse,
+        GetEntitySubgraphParams, PatchEntityParams, UpdateEntityEmbeddingsParams,
+    },
+};
+use graph_types::knowledge::entity::Entity;
+use harpc_service::role::Server;
+use serde::{Deserialize, Deserializer};
+
+use crate::{
+    rest::{GetEntitiesRequest, GetEntitySubgraphRequest, GetEntitySubgraphResponse},
+    rpc::{GraphRpc, GraphSession, RpcError, actor_id, deserialize_owned},
+};
+
+/// Payload of [`EntityService::get_entities`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct GetEntities {
+    #[serde(deserialize_with = "deserialize_get_entities")]
+    pub params: GetEntitiesParams<'static>,
+}
+
+fn deserialize_get_entities<'de, D>(deserializer: D) -> Result<GetEntitiesParams<'static>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_owned::<_, GetEntitiesRequest<'static, 'static, 'static>>(deserializer)
+        .map(GetEntitiesRequest::into_params)
+}
+
+/// Payload of [`EntityService::get_entity_subgraph`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct GetEntitySubgraph {
+    #[serde(deserialize_with = "deserialize_get_entity_subgraph")]
+    pub params: GetEntitySubgraphParams<'static>,
+}
+
+fn deserialize_get_entity_subgraph<'de, D>(
+    deserializer: D,
+) -> Result<GetEntitySubgraphParams<'static>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_owned::<_, GetEntitySubgraphRequest<'static, 'static, 'static>>(deserializer)
+        .map(GetEntitySubgraphRequest::into_params)
+}
+
+/// Payload of [`EntityService::count_entities`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct CountEntities {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: CountEntitiesParams<'static>,
+}
+
+/// Payload of [`EntityService::create_entity`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct CreateEntity {
+    pub params: CreateEntityParams<Vec<EntityRelationAndSubject>>,
+}
+
+/// Payload of [`EntityService::create_entities`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct CreateEntities {
+    pub params: Vec<CreateEntityParams<Vec<EntityRelationAndSubject>>>,
+}
+
+/// Payload of [`EntityService::patch_entity`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct PatchEntity {
+    pub params: PatchEntityParams,
+}
+
+/// Payload of [`EntityService::update_entity_embeddings`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct UpdateEntityEmbeddings {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: UpdateEntityEmbeddingsParams<'static>,
+}
+
+#[harpc_service::service(id = 0x04, version = "0.0")]
+pub trait EntityService {
+    /// Gets a list of entities that satisfy the given query.
+    #[procedure(id = 0x00)]
+    fn get_entities(
+        &self,
+        payload: GetEntities,
+    ) -> Result<GetEntitiesResponse<'static>, Report<RpcError>>;
+
+    /// Gets a subgraph rooted at all entities that satisfy the given query.
+    #[procedure(id = 0x01)]
+    fn get_entity_subgraph(
+        &self,
+        payload: GetEntitySubgraph,
+    ) -> Result<GetEntitySubgraphResponse<'static>, Report<RpcError>>;
+
+    /// Counts the number of entities that satisfy the given query.
+    #[procedure(id = 0x02)]
+    fn count_entities(&self, payload: CountEntities) -> Result<usize, Report<RpcError>>;
+
+    /// Creates a new entity.
+    #[procedure(id = 0x03)]
+    fn create_entity(&self, payload: CreateEntity) -> Result<Entity, Report<RpcError>>;
+
+    /// Creates the given entities and returns them in the same order.
+    #[procedure(id = 0x04)]
+    fn create_entities(&self, payload: CreateEntities) -> Result<Vec<Entity>, Report<RpcError>>;
+
+    /// Creates a new edition of an entity by applying the given patch.
+    #[procedure(id = 0x05)]
+    fn patch_entity(&self, payload: PatchEntity) -> Result<Entity, Report<RpcError>>;
+
+    /// Updates the embeddings of an entity.
+    #[procedure(id = 0x06)]
+    fn update_entity_embeddings(
+        &self,
+        payload: UpdateEntityEmbeddings,
+    ) -> Result<(), Report<RpcError>>;
+}
+
+impl<S, A> EntityService<Server<GraphSession>> for GraphRpc<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    async fn get_entities(
+        &self,
+        session: &GraphSession,
+        payload: GetEntities,
+    ) -> Result<GetEntitiesResponse<'static>, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let store = self.store().await?;
+
+        store
+            .get_entities(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn get_entity_subgraph(
+        &self,
+        session: &GraphSession,
+        payload: GetEntitySubgraph,
+    ) -> Result<GetEntitySubgraphResponse<'static>, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let store = self.store().await?;
+
+        store
+            .get_entity_subgraph(actor_id, payload.params)
+            .await
+            .map(GetEntitySubgraphResponse::from)
+            .change_context(RpcError::Store)
+    }
+
+    async fn count_entities(
+        &self,
+        session: &GraphSession,
+        payload: CountEntities,
+    ) -> Result<usize, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let store = self.store().await?;
+
+        store
+            .count_entities(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn create_entity(
+        &self,
+        session: &GraphSession,
+        payload: CreateEntity,
+    ) -> Result<Entity, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .create_entity(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn create_entities(
+        &self,
+        session: &GraphSession,
+        payload: CreateEntities,
+    ) -> Result<Vec<Entity>, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .create_entities(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn patch_entity(
+        &self,
+        session: &GraphSession,
+        payload: PatchEntity,
+    ) -> Result<Entity, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .patch_entity(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn update_entity_embeddings(
+        &self,
+        session: &GraphSession,
+        payload: UpdateEntityEmbeddings,
+    ) -> Result<(), Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .update_entity_embeddings(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+}
@@ -0,0 +1,334 @@
+//! RPC service for querying and modifying entity types.
+
+use authorization::{AuthorizationApiPool, schema::EntityTypeRelationAndSubject};
+use error_stack::{Report, ResultExt};
+use graph::{
+    ontology::{PatchAndParseError, domain_validator::ValidateOntologyType, parse_next_version},
+    store::{
+        EntityTypeStore, StorePool,
+        ontology::{
+            ArchiveEntityTypeParams, BreakingChangeBehavior, CreateEntityTypeParams,
+            GetEntityTypeSubgraphParams, GetEntityTypesParams, GetEntityTypesResponse,
+            UnarchiveEntityTypeParams, UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
+            UpdateEntityTypesResponse,
+        },
+    },
+};
+use graph_types::{
+    ontology::{
+        EntityTypeMetadata, OntologyTemporalMetadata, OntologyTypeClassificationMetadata,
+        ProvidedOntologyEditionProvenance,
+    },
+    owned_by_id::OwnedById,
+};
+use harpc_service::role::Server;
+use hash_graph_store::ConflictBehavior;
+use serde::Deserialize;
+use type_system::{
+    schema::EntityType,
+    url::{BaseUrl, VersionedUrl},
+};
+
+use crate::{
+    rest::GetEntityTypeSubgraphResponse,
+    rpc::{
+        GraphRpc, GraphSession, RpcError, actor_id, deserialize_list_or_value, deserialize_owned,
+    },
+};
+
+/// Payload of [`EntityTypeService::get_entity_types`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct GetEntityTypes {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: GetEntityTypesParams<'static>,
+}
+
+/// Payload of [`EntityTypeService::get_entity_type_subgraph`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct GetEntityTypeSubgraph {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: GetEntityTypeSubgraphParams<'static>,
+}
+
+/// Payload of [`EntityTypeService::create_entity_types`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateEntityTypes {
+    /// The entity types to create, encoded either as a single schema or as a list of schemas.
+    #[serde(deserialize_with = "deserialize_list_or_value")]
+    pub schema: Vec<EntityType>,
+    pub owned_by_id: OwnedById,
+    #[serde(default)]
+    pub label_property: Option<BaseUrl>,
+    #[serde(default)]
+    pub icon: Option<String>,
+    pub relationships: Vec<EntityTypeRelationAndSubject>,
+    #[serde(default)]
+    pub provenance: ProvidedOntologyEditionProvenance,
+}
+
+/// Payload of [`EntityTypeService::update_entity_type`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint, the schema is
+/// parsed as the next version of `typeToUpdate`.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "UpdateEntityTypeRequest")]
+pub struct UpdateEntityType {
+    pub params: UpdateEntityTypesParams<Vec<EntityTypeRelationAndSubject>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct UpdateEntityTypeRequest {
+    schema: serde_json::Value,
+    type_to_update: VersionedUrl,
+    #[serde(default)]
+    label_property: Option<BaseUrl>,
+    #[serde(default)]
+    icon: Option<String>,
+    relationships: Vec<EntityTypeRelationAndSubject>,
+    #[serde(default)]
+    provenance: ProvidedOntologyEditionProvenance,
+    #[serde(default)]
+    breaking_changes: BreakingChangeBehavior,
+}
+
+impl TryFrom<UpdateEntityTypeRequest> for UpdateEntityType {
+    type Error = Report<PatchAndParseError>;
+
+    fn try_from(request: UpdateEntityTypeRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            params: UpdateEntityTypesParams {
+                schema: parse_next_version(&request.type_to_update, request.schema)?,
+                label_property: request.label_property,
+                icon: request.icon,
+                relationships: request.relationships,
+                provenance: request.provenance,
+                breaking_changes: request.breaking_changes,
+            },
+        })
+    }
+}
+
+/// Payload of [`EntityTypeService::archive_entity_type`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct ArchiveEntityType {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: ArchiveEntityTypeParams<'static>,
+}
+
+/// Payload of [`EntityTypeService::unarchive_entity_type`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct UnarchiveEntityType {
+    pub params: UnarchiveEntityTypeParams<'static>,
+}
+
+/// Payload of [`EntityTypeService::update_entity_type_embeddings`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct UpdateEntityTypeEmbeddings {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: UpdateEntityTypeEmbeddingParams<'static>,
+}
+
+#[harpc_service::service(id = 0x03, version = "0.0")]
+pub trait EntityTypeService {
+    /// Gets a list of entity types that satisfy the given query.
+    #[procedure(id = 0x00)]
+    fn get_entity_types(
+        &self,
+        payload: GetEntityTypes,
+    ) -> Result<GetEntityTypesResponse, Report<RpcError>>;
+
+    /// Gets a subgraph rooted at all entity types that satisfy the given query.
+    #[procedure(id = 0x01)]
+    fn get_entity_type_subgraph(
+        &self,
+        payload: GetEntityTypeSubgraph,
+    ) -> Result<GetEntityTypeSubgraphResponse, Report<RpcError>>;
+
+    /// Creates the given entity types and returns their metadata in the same order.
+    #[procedure(id = 0x02)]
+    fn create_entity_types(
+        &self,
+        payload: CreateEntityTypes,
+    ) -> Result<Vec<EntityTypeMetadata>, Report<RpcError>>;
+
+    /// Creates a new version of an existing entity type.
+    #[procedure(id = 0x03)]
+    fn update_entity_type(
+        &self,
+        payload: UpdateEntityType,
+    ) -> Result<UpdateEntityTypesResponse, Report<RpcError>>;
+
+    /// Archives the given version of a entity type.
+    #[procedure(id = 0x04)]
+    fn archive_entity_type(
+        &self,
+        payload: ArchiveEntityType,
+    ) -> Result<OntologyTemporalMetadata, Report<RpcError>>;
+
+    /// Restores an archived version of a entity type.
+    #[procedure(id = 0x05)]
+    fn unarchive_entity_type(
+        &self,
+        payload: UnarchiveEntityType,
+    ) -> Result<OntologyTemporalMetadata, Report<RpcError>>;
+
+    /// Updates the embeddings of a entity type.
+    #[procedure(id = 0x06)]
+    fn update_entity_type_embeddings(
+        &self,
+        payload: UpdateEntityTypeEmbeddings,
+    ) -> Result<(), Report<RpcError>>;
+}
+
+impl<S, A> EntityTypeService<Server<GraphSession>> for GraphRpc<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    async fn get_entity_types(
+        &self,
+        session: &GraphSession,
+        payload: GetEntityTypes,
+    ) -> Result<GetEntityTypesResponse, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let store = self.store().await?;
+
+        store
+            .get_entity_types(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn get_entity_type_subgraph(
+        &self,
+        session: &GraphSession,
+        payload: GetEntityTypeSubgraph,
+    ) -> Result<GetEntityTypeSubgraphResponse, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let store = self.store().await?;
+
+        store
+            .get_entity_type_subgraph(actor_id, payload.params)
+            .await
+            .map(GetEntityTypeSubgraphResponse::from)
+            .change_context(RpcError::Store)
+    }
+
+    async fn create_entity_types(
+        &self,
+        session: &GraphSession,
+        payload: CreateEntityTypes,
+    ) -> Result<Vec<EntityTypeMetadata>, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+
+        let CreateEntityTypes {
+            schema,
+            owned_by_id,
+            label_property,
+            icon,
+            relationships,
+            provenance,
+        } = payload;
+
+        let params = schema
+            .into_iter()
+            .map(|schema| {
+                self.domain_validator
+                    .validate(&schema)
+                    .change_context(RpcError::InvalidRequest)?;
+
+                Ok(CreateEntityTypeParams {
+                    schema,
+                    classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+                    label_property: label_property.clone(),
+                    icon: icon.clone(),
+                    relationships: relationships.clone(),
+                    conflict_behavior: ConflictBehavior::Fail,
+                    provenance: provenance.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, Report<RpcError>>>()?;
+
+        let mut store = self.store().await?;
+
+        store
+            .create_entity_types(actor_id, params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn update_entity_type(
+        &self,
+        session: &GraphSession,
+        payload: UpdateEntityType,
+    ) -> Result<UpdateEntityTypesResponse, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .update_entity_type(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn archive_entity_type(
+        &self,
+        session: &GraphSession,
+        payload: ArchiveEntityType,
+    ) -> Result<OntologyTemporalMetadata, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .archive_entity_type(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn unarchive_entity_type(
+        &self,
+        session: &GraphSession,
+        payload: UnarchiveEntityType,
+    ) -> Result<OntologyTemporalMetadata, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .unarchive_entity_type(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn update_entity_type_embeddings(
+        &self,
+        session: &GraphSession,
+        payload: UpdateEntityTypeEmbeddings,
+    ) -> Result<(), Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .update_entity_type_embeddings(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+}
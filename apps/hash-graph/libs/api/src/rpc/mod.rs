@@ -0,0 +1,550 @@
+//! The harpc server for accessing the Graph API operations.
+//!
+//! Services are grouped the same way as the resources of the REST API and call into the same store
+//! operations. Values and errors are encoded as JSON, so requests and responses have the same shape
+//! as their REST counterparts. Payloads are the parameters of the store operations, a malformed
+//! request is therefore rejected when decoding it, before the procedure is called.
+//!
+//! Writes are checked the same way as in the REST API: created types have to pass the
+//! [`DomainValidator`] and updated types are parsed as the next version of the type to update.
+//! Creating types accepts a single schema or a list of schemas, but always responds with a list.
+//! Authorization relationships are only managed through the REST API.
+
+mod account;
+mod auth;
+mod data_type;
+mod entity;
+mod entity_type;
+mod property_type;
+
+use alloc::sync::Arc;
+use core::{error::Error, fmt, time::Duration};
+
+use authorization::AuthorizationApiPool;
+use error_stack::{Report, ResultExt, bail};
+use graph::{ontology::domain_validator::DomainValidator, store::StorePool};
+use graph_types::account::AccountId;
+use harpc_codec::json::JsonCodec;
+use harpc_net::{
+    session::server::{SessionConfig, SessionLayer},
+    transport::{TransportConfig, TransportKind, TransportLayer},
+};
+pub use harpc_server::auth::PreSharedTokenVerifier;
+use harpc_server::{
+    auth::{Authentication, PreSharedToken, Verifier},
+    router::RouterBuilder,
+    serve::serve,
+    session::Session,
+};
+use harpc_tower::layer::{
+    boxed::BoxedResponseLayer, concurrency::ConcurrencyLimitLayer, rate_limit::RateLimitLayer,
+    report::HandleReportLayer, timeout::TimeoutLayer, trace::TraceLayer,
+};
+use libp2p::Multiaddr;
+use serde::{Deserialize, Deserializer, de};
+use temporal_client::TemporalClient;
+use tokio_util::sync::CancellationToken;
+
+pub use self::{
+    account::{
+        AccountService, AccountServiceClient, AccountServiceDelegate, CreateAccount,
+        CreateAccountGroup,
+    },
+    auth::{
+        Authenticate, AuthenticationService, AuthenticationServiceClient,
+        AuthenticationServiceDelegate, Authenticator,
+    },
+    data_type::{
+        ArchiveDataType, CreateDataTypes, DataTypeService, DataTypeServiceClient,
+        DataTypeServiceDelegate, GetDataTypeSubgraph, GetDataTypes, UnarchiveDataType,
+        UpdateDataType, UpdateDataTypeEmbeddings,
+    },
+    entity::{
+        CountEntities, CreateEntities, CreateEntity, EntityService, EntityServiceClient,
+        EntityServiceDelegate, GetEntities, GetEntitySubgraph, PatchEntity, UpdateEntityEmbeddings,
+    },
+    entity_type::{
+        ArchiveEntityType, CreateEntityTypes, EntityTypeService, EntityTypeServiceClient,
+        EntityTypeServiceDelegate, GetEntityTypeSubgraph, GetEntityTypes, UnarchiveEntityType,
+        UpdateEntityType, UpdateEntityTypeEmbeddings,
+    },
+    property_type::{
+        ArchivePropertyType, CreatePropertyTypes, GetPropertyTypeSubgraph, GetPropertyTypes,
+        PropertyTypeService, PropertyTypeServiceClient, PropertyTypeServiceDelegate,
+        UnarchivePropertyType, UpdatePropertyType, UpdatePropertyTypeEmbeddings,
+    },
+};
+use crate::rest::ListOrValue;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RpcError {
+    StoreAcquisition,
+    InvalidRequest,
+    NotFound,
+    Store,
+    Unauthenticated,
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StoreAcquisition => fmt.write_str("could not acquire a store from the pool"),
+            Self::InvalidRequest => fmt.write_str("the request is invalid"),
+            Self::NotFound => fmt.write_str("the requested resource does not exist"),
+            Self::Store => fmt.write_str("the store operation failed"),
+            Self::Unauthenticated => fmt.write_str("the session is not authenticated"),
+        }
+    }
+}
+
+impl Error for RpcError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RpcServerError {
+    UnsupportedAddress,
+    Transport,
+}
+
+impl fmt::Display for RpcServerError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedAddress => fmt.write_str("no transport supports the RPC address"),
+            Self::Transport => fmt.write_str("the RPC server encountered an error"),
+        }
+    }
+}
+
+impl Error for RpcServerError {}
+
+/// Session of a client connected to the RPC server.
+///
+/// The identity of an authenticated session is the account on whose behalf requests are made.
+pub type GraphSession = Session<Authentication<AccountId>>;
+
+/// Returns the acting account of an authenticated session.
+fn actor_id(session: &GraphSession) -> Result<AccountId, Report<RpcError>> {
+    session
+        .get()
+        .require()
+        .copied()
+        .change_context(RpcError::Unauthenticated)
+}
+
+/// Deserializes store parameters, which may borrow from the input, as an owned payload.
+///
+/// Procedures decode their payloads without borrowing from the request, so the payload is read
+/// into a JSON value first, which the parameters then take ownership of.
+fn deserialize_owned<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'static>,
+{
+    serde_json::Value::deserialize(deserializer)
+        .and_then(|value| T::deserialize(value).map_err(de::Error::custom))
+}
+
+/// Deserializes either a single value or a list of values as a list.
+fn deserialize_list_or_value<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    ListOrValue::deserialize(deserializer).map(|values| values.into_iter().collect())
+}
+
+/// Implementation of all Graph API services, backed by a [`StorePool`].
+pub struct GraphRpc<S, A> {
+    store: Arc<S>,
+    authorization_api: Arc<A>,
+    temporal_client: Option<Arc<TemporalClient>>,
+    domain_validator: DomainValidator,
+}
+
+impl<S, A> Clone for GraphRpc<S, A> {
+    fn clone(&self) -> Self {
+        Self {
+            store: Arc::clone(&self.store),
+            authorization_api: Arc::clone(&self.authorization_api),
+            temporal_client: self.temporal_client.clone(),
+            domain_validator: self.domain_validator.clone(),
+        }
+    }
+}
+
+impl<S, A> GraphRpc<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    #[must_use]
+    pub const fn new(
+        store: Arc<S>,
+        authorization_api: Arc<A>,
+        temporal_client: Option<Arc<TemporalClient>>,
+        domain_validator: DomainValidator,
+    ) -> Self {
+        Self {
+            store,
+            authorization_api,
+            temporal_client,
+            domain_validator,
+        }
+    }
+
+    async fn store(&self) -> Result<S::Store<'_, A::Api<'_>>, Report<RpcError>> {
+        let authorization_api = self
+            .authorization_api
+            .acquire()
+            .await
+            .change_context(RpcError::StoreAcquisition)?;
+
+        self.store
+            .acquire(authorization_api, self.temporal_client.clone())
+            .await
+            .change_context(RpcError::StoreAcquisition)
+    }
+}
+
+/// Configuration of the RPC server started by [`serve_rpc`].
+#[derive(Debug, Clone)]
+pub struct RpcConfig {
+    /// The address the server is listening at.
+    pub address: Multiaddr,
+    /// Time a procedure has to respond to a request before it is aborted.
+    pub request_timeout: Duration,
+    /// Number of requests a single session can have in flight at the same time.
+    pub concurrency_limit: usize,
+    /// Number of requests a single session can send within [`rate_limit_period`].
+    ///
+    /// [`rate_limit_period`]: Self::rate_limit_period
+    pub rate_limit: u64,
+    pub rate_limit_period: Duration,
+}
+
+impl RpcConfig {
+    /// Creates a configuration listening at `address` with the default limits.
+    #[must_use]
+    pub const fn new(address: Multiaddr) -> Self {
+        Self {
+            address,
+            request_timeout: Duration::from_secs(60),
+            concurrency_limit: 64,
+            rate_limit: 1_000,
+            rate_limit_period: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Listens on the configured address and returns a future serving all Graph API services.
+///
+/// The transport is chosen based on the address, e.g. `/unix/<path>` listens on a Unix domain
+/// socket instead of a TCP port. The address is listened on before this function returns, so an
+/// invalid address is reported immediately, while the returned future serves requests until the
+/// transport layer shuts down.
+///
+/// Clients need to authenticate their session through the [`AuthenticationService`] before
+/// calling any other service, the credentials are verified by `verifier`.
+///
+/// # Errors
+///
+/// - [`UnsupportedAddress`] if no transport is able to listen on the address
+/// - [`Transport`] if the transport layer cannot be started or the address cannot be listened on
+///
+/// [`UnsupportedAddress`]: RpcServerError::UnsupportedAddress
+/// [`Transport`]: RpcServerError::Transport
+pub async fn serve_rpc<S, A, V>(
+    config: RpcConfig,
+    rpc: GraphRpc<S, A>,
+    verifier: V,
+) -> Result<impl Future<Output = ()> + Send, Report<RpcServerError>>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+    V: Verifier<PreSharedToken<AccountId>, Identity = AccountId>,
+{
+    let cancel = CancellationToken::new();
+    // Shuts down the transport layer if listening fails or once the server stops serving.
+    let guard = cancel.clone().drop_guard();
+
+    let Some(kind) = TransportKind::from_address(&config.address) else {
+        bail!(
+            Report::new(RpcServerError::UnsupportedAddress)
+                .attach_printable(format!("address: {}", config.address))
+        );
+    };
+
+    let transport = TransportLayer::start_from_config(
+        TransportConfig {
+            kind,
+            ..TransportConfig::default()
+        },
+        cancel,
+    )
+    .change_context(RpcServerError::Transport)?;
+
+    let session = SessionLayer::new(SessionConfig::default(), transport, JsonCodec);
+    let stream = session
+        .listen(config.address)
+        .await
+        .change_context(RpcServerError::Transport)?;
+
+    let router = RouterBuilder::new::<Authentication<AccountId>>(JsonCodec)
+        .with_builder(|builder| {
+            builder
+                .layer(BoxedResponseLayer::new())
+                .layer(TraceLayer::new())
+                .layer(RateLimitLayer::new(
+                    JsonCodec,
+                    config.rate_limit,
+                    config.rate_limit_period,
+                ))
+                .layer(ConcurrencyLimitLayer::new(
+                    JsonCodec,
+                    config.concurrency_limit,
+                ))
+                .layer(TimeoutLayer::new(JsonCodec, config.request_timeout))
+                .layer(HandleReportLayer::new(JsonCodec))
+        })
+        .register(AuthenticationServiceDelegate::new(Authenticator::new(
+            verifier,
+        )))
+        .register(AccountServiceDelegate::new(rpc.clone()))
+        .register(DataTypeServiceDelegate::new(rpc.clone()))
+        .register(PropertyTypeServiceDelegate::new(rpc.clone()))
+        .register(EntityTypeServiceDelegate::new(rpc.clone()))
+        .register(EntityServiceDelegate::new(rpc))
+        .build();
+
+    Ok(async move {
+        let _guard = guard;
+        serve(stream, router).await.wait().await;
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use authorization::NoAuthorization;
+    use graph::store::MemoryStorePool;
+    use regex::Regex;
+    use serde_json::json;
+
+    use super::*;
+
+    fn temporal_axes() -> serde_json::Value {
+        json!({
+            "pinned": {
+                "axis": "transactionTime",
+                "timestamp": null,
+            },
+            "variable": {
+                "axis": "decisionTime",
+                "interval": {
+                    "start": null,
+                    "end": null,
+                },
+            },
+        })
+    }
+
+    #[test]
+    fn decode_ontology_payload() {
+        let payload: GetDataTypes = serde_json::from_value(json!({
+            "filter": {
+                "equal": [
+                    { "path": ["title"] },
+                    { "parameter": "Text" },
+                ],
+            },
+            "temporalAxes": temporal_axes(),
+            "includeDrafts": false,
+            "limit": 10,
+        }))
+        .expect("payload should be decodable");
+
+        assert_eq!(payload.params.limit, Some(10));
+        assert!(!payload.params.include_drafts);
+        assert!(!payload.params.include_count);
+    }
+
+    #[test]
+    fn decode_entity_payload() {
+        let payload: GetEntities = serde_json::from_value(json!({
+            "filter": {
+                "equal": [
+                    { "path": ["uuid"] },
+                    { "parameter": "a6b1a1b5-0d5b-4d1c-8a8d-2e4c2c1d5c3e" },
+                ],
+            },
+            "temporalAxes": temporal_axes(),
+            "includeDrafts": true,
+            "limit": 5,
+            "includeCount": true,
+        }))
+        .expect("payload should be decodable");
+
+        assert_eq!(payload.params.limit, Some(5));
+        assert!(payload.params.include_drafts);
+        assert!(payload.params.include_count);
+        assert!(
+            !payload.params.sorting.paths.is_empty(),
+            "a limited query should be sorted"
+        );
+
+        let payload: CountEntities = serde_json::from_value(json!({
+            "filter": { "all": [] },
+            "temporalAxes": temporal_axes(),
+            "includeDrafts": false,
+        }))
+        .expect("payload should be decodable");
+
+        assert!(!payload.params.include_drafts);
+    }
+
+    #[test]
+    fn reject_malformed_payload() {
+        for (name, payload) in [
+            (
+                "unknown path",
+                json!({
+                    "filter": { "equal": [{ "path": ["unknown"] }, { "parameter": 1 }] },
+                    "temporalAxes": temporal_axes(),
+                    "includeDrafts": false,
+                }),
+            ),
+            (
+                "missing field",
+                json!({
+                    "filter": { "all": [] },
+                    "includeDrafts": false,
+                }),
+            ),
+            (
+                "unknown field",
+                json!({
+                    "query": {
+                        "filter": { "all": [] },
+                        "temporalAxes": temporal_axes(),
+                        "includeDrafts": false,
+                    },
+                }),
+            ),
+        ] {
+            assert!(
+                serde_json::from_value::<GetEntities>(payload.clone()).is_err(),
+                "entity payload with {name} should be rejected"
+            );
+            assert!(
+                serde_json::from_value::<GetPropertyTypes>(payload).is_err(),
+                "property type payload with {name} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn decode_create_payload() {
+        let schema = json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+            "kind": "propertyType",
+            "$id": "https://blockprotocol.org/@alice/types/property-type/text/v/1",
+            "title": "Text",
+            "oneOf": [
+                { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" },
+            ],
+        });
+
+        for (name, schema) in [
+            ("a single schema", schema.clone()),
+            ("a list of schemas", json!([schema])),
+        ] {
+            let payload: CreatePropertyTypes = serde_json::from_value(json!({
+                "schema": schema,
+                "ownedById": "a6b1a1b5-0d5b-4d1c-8a8d-2e4c2c1d5c3e",
+                "relationships": [],
+            }))
+            .unwrap_or_else(|error| panic!("payload with {name} should be decodable: {error}"));
+
+            assert_eq!(payload.schema.len(), 1, "payload with {name}");
+        }
+    }
+
+    #[test]
+    fn decode_update_payload() {
+        let payload: UpdatePropertyType = serde_json::from_value(json!({
+            "schema": {
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+                "kind": "propertyType",
+                "title": "Text",
+                "oneOf": [
+                    { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" },
+                ],
+            },
+            "typeToUpdate": "https://blockprotocol.org/@alice/types/property-type/text/v/1",
+            "relationships": [],
+        }))
+        .expect("payload should be decodable");
+
+        assert_eq!(
+            payload.params.schema.id.to_string(),
+            "https://blockprotocol.org/@alice/types/property-type/text/v/2",
+            "the schema should be parsed as the next version"
+        );
+    }
+
+    #[test]
+    fn reject_update_payload_with_id() {
+        let payload = json!({
+            "schema": {
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+                "kind": "propertyType",
+                "$id": "https://blockprotocol.org/@alice/types/property-type/text/v/2",
+                "title": "Text",
+                "oneOf": [
+                    { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" },
+                ],
+            },
+            "typeToUpdate": "https://blockprotocol.org/@alice/types/property-type/text/v/1",
+            "relationships": [],
+        });
+
+        assert!(
+            serde_json::from_value::<UpdatePropertyType>(payload).is_err(),
+            "the id of an updated schema is derived from the type to update"
+        );
+    }
+
+    async fn start_rpc(address: &str) -> Result<(), Report<RpcServerError>> {
+        let address = address.parse().expect("address should be valid");
+        let rpc = GraphRpc::new(
+            Arc::new(MemoryStorePool::new()),
+            Arc::new(NoAuthorization),
+            None,
+            DomainValidator::new(
+                Regex::new(
+                    r"https://blockprotocol.org/@(?P<shortname>[\w-]+)/types/(?P<kind>[\w-]+)/",
+                )
+                .expect("regex should be valid"),
+            ),
+        );
+
+        serve_rpc(RpcConfig::new(address), rpc, PreSharedTokenVerifier::new())
+            .await
+            .map(drop)
+    }
+
+    #[tokio::test]
+    async fn reject_unsupported_address() {
+        let error = start_rpc("/p2p-circuit")
+            .await
+            .expect_err("startup should fail without a transport for the address");
+        assert_eq!(*error.current_context(), RpcServerError::UnsupportedAddress);
+    }
+
+    #[tokio::test]
+    async fn reject_unlistenable_address() {
+        // DNS addresses are dialed over TCP, but a listener has to be bound to an IP address.
+        let error = start_rpc("/dns/localhost/tcp/0")
+            .await
+            .expect_err("startup should fail if the address cannot be listened on");
+        assert_eq!(*error.current_context(), RpcServerError::Transport);
+    }
+}
@@ -0,0 +1,317 @@
+//! RPC service for querying and modifying property types.
+
+use authorization::{AuthorizationApiPool, schema::PropertyTypeRelationAndSubject};
+use error_stack::{Report, ResultExt};
+use graph::{
+    ontology::{PatchAndParseError, domain_validator::ValidateOntologyType, parse_next_version},
+    store::{
+        PropertyTypeStore, StorePool,
+        ontology::{
+            ArchivePropertyTypeParams, BreakingChangeBehavior, CreatePropertyTypeParams,
+            GetPropertyTypeSubgraphParams, GetPropertyTypesParams, GetPropertyTypesResponse,
+            UnarchivePropertyTypeParams, UpdatePropertyTypeEmbeddingParams,
+            UpdatePropertyTypesParams, UpdatePropertyTypesResponse,
+        },
+    },
+};
+use graph_types::{
+    ontology::{
+        OntologyTemporalMetadata, OntologyTypeClassificationMetadata, PropertyTypeMetadata,
+        ProvidedOntologyEditionProvenance,
+    },
+    owned_by_id::OwnedById,
+};
+use harpc_service::role::Server;
+use hash_graph_store::ConflictBehavior;
+use serde::Deserialize;
+use type_system::{schema::PropertyType, url::VersionedUrl};
+
+use crate::{
+    rest::GetPropertyTypeSubgraphResponse,
+    rpc::{
+        GraphRpc, GraphSession, RpcError, actor_id, deserialize_list_or_value, deserialize_owned,
+    },
+};
+
+/// Payload of [`PropertyTypeService::get_property_types`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct GetPropertyTypes {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: GetPropertyTypesParams<'static>,
+}
+
+/// Payload of [`PropertyTypeService::get_property_type_subgraph`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct GetPropertyTypeSubgraph {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: GetPropertyTypeSubgraphParams<'static>,
+}
+
+/// Payload of [`PropertyTypeService::create_property_types`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreatePropertyTypes {
+    /// The property types to create, encoded either as a single schema or as a list of schemas.
+    #[serde(deserialize_with = "deserialize_list_or_value")]
+    pub schema: Vec<PropertyType>,
+    pub owned_by_id: OwnedById,
+    pub relationships: Vec<PropertyTypeRelationAndSubject>,
+    #[serde(default)]
+    pub provenance: ProvidedOntologyEditionProvenance,
+}
+
+/// Payload of [`PropertyTypeService::update_property_type`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint, the schema is
+/// parsed as the next version of `typeToUpdate`.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "UpdatePropertyTypeRequest")]
+pub struct UpdatePropertyType {
+    pub params: UpdatePropertyTypesParams<Vec<PropertyTypeRelationAndSubject>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct UpdatePropertyTypeRequest {
+    schema: serde_json::Value,
+    type_to_update: VersionedUrl,
+    relationships: Vec<PropertyTypeRelationAndSubject>,
+    #[serde(default)]
+    provenance: ProvidedOntologyEditionProvenance,
+    #[serde(default)]
+    breaking_changes: BreakingChangeBehavior,
+}
+
+impl TryFrom<UpdatePropertyTypeRequest> for UpdatePropertyType {
+    type Error = Report<PatchAndParseError>;
+
+    fn try_from(request: UpdatePropertyTypeRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            params: UpdatePropertyTypesParams {
+                schema: parse_next_version(&request.type_to_update, request.schema)?,
+                relationships: request.relationships,
+                provenance: request.provenance,
+                breaking_changes: request.breaking_changes,
+            },
+        })
+    }
+}
+
+/// Payload of [`PropertyTypeService::archive_property_type`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct ArchivePropertyType {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: ArchivePropertyTypeParams<'static>,
+}
+
+/// Payload of [`PropertyTypeService::unarchive_property_type`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct UnarchivePropertyType {
+    pub params: UnarchivePropertyTypeParams<'static>,
+}
+
+/// Payload of [`PropertyTypeService::update_property_type_embeddings`].
+///
+/// Encoded the same way as the request body of the corresponding REST endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct UpdatePropertyTypeEmbeddings {
+    #[serde(deserialize_with = "deserialize_owned")]
+    pub params: UpdatePropertyTypeEmbeddingParams<'static>,
+}
+
+#[harpc_service::service(id = 0x02, version = "0.0")]
+pub trait PropertyTypeService {
+    /// Gets a list of property types that satisfy the given query.
+    #[procedure(id = 0x00)]
+    fn get_property_types(
+        &self,
+        payload: GetPropertyTypes,
+    ) -> Result<GetPropertyTypesResponse, Report<RpcError>>;
+
+    /// Gets a subgraph rooted at all property types that satisfy the given query.
+    #[procedure(id = 0x01)]
+    fn get_property_type_subgraph(
+        &self,
+        payload: GetPropertyTypeSubgraph,
+    ) -> Result<GetPropertyTypeSubgraphResponse, Report<RpcError>>;
+
+    /// Creates the given property types and returns their metadata in the same order.
+    #[procedure(id = 0x02)]
+    fn create_property_types(
+        &self,
+        payload: CreatePropertyTypes,
+    ) -> Result<Vec<PropertyTypeMetadata>, Report<RpcError>>;
+
+    /// Creates a new version of an existing property type.
+    #[procedure(id = 0x03)]
+    fn update_property_type(
+        &self,
+        payload: UpdatePropertyType,
+    ) -> Result<UpdatePropertyTypesResponse, Report<RpcError>>;
+
+    /// Archives the given version of a property type.
+    #[procedure(id = 0x04)]
+    fn archive_property_type(
+        &self,
+        payload: ArchivePropertyType,
+    ) -> Result<OntologyTemporalMetadata, Report<RpcError>>;
+
+    /// Restores an archived version of a property type.
+    #[procedure(id = 0x05)]
+    fn unarchive_property_type(
+        &self,
+        payload: UnarchivePropertyType,
+    ) -> Result<OntologyTemporalMetadata, Report<RpcError>>;
+
+    /// Updates the embeddings of a property type.
+    #[procedure(id = 0x06)]
+    fn update_property_type_embeddings(
+        &self,
+        payload: UpdatePropertyTypeEmbeddings,
+    ) -> Result<(), Report<RpcError>>;
+}
+
+impl<S, A> PropertyTypeService<Server<GraphSession>> for GraphRpc<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    async fn get_property_types(
+        &self,
+        session: &GraphSession,
+        payload: GetPropertyTypes,
+    ) -> Result<GetPropertyTypesResponse, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let store = self.store().await?;
+
+        store
+            .get_property_types(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn get_property_type_subgraph(
+        &self,
+        session: &GraphSession,
+        payload: GetPropertyTypeSubgraph,
+    ) -> Result<GetPropertyTypeSubgraphResponse, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let store = self.store().await?;
+
+        store
+            .get_property_type_subgraph(actor_id, payload.params)
+            .await
+            .map(GetPropertyTypeSubgraphResponse::from)
+            .change_context(RpcError::Store)
+    }
+
+    async fn create_property_types(
+        &self,
+        session: &GraphSession,
+        payload: CreatePropertyTypes,
+    ) -> Result<Vec<PropertyTypeMetadata>, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+
+        let CreatePropertyTypes {
+            schema,
+            owned_by_id,
+            relationships,
+            provenance,
+        } = payload;
+
+        let params = schema
+            .into_iter()
+            .map(|schema| {
+                self.domain_validator
+                    .validate(&schema)
+                    .change_context(RpcError::InvalidRequest)?;
+
+                Ok(CreatePropertyTypeParams {
+                    schema,
+                    classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+                    relationships: relationships.clone(),
+                    conflict_behavior: ConflictBehavior::Fail,
+                    provenance: provenance.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, Report<RpcError>>>()?;
+
+        let mut store = self.store().await?;
+
+        store
+            .create_property_types(actor_id, params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn update_property_type(
+        &self,
+        session: &GraphSession,
+        payload: UpdatePropertyType,
+    ) -> Result<UpdatePropertyTypesResponse, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .update_property_type(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn archive_property_type(
+        &self,
+        session: &GraphSession,
+        payload: ArchivePropertyType,
+    ) -> Result<OntologyTemporalMetadata, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .archive_property_type(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn unarchive_property_type(
+        &self,
+        session: &GraphSession,
+        payload: UnarchivePropertyType,
+    ) -> Result<OntologyTemporalMetadata, Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .unarchive_property_type(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+
+    async fn update_property_type_embeddings(
+        &self,
+        session: &GraphSession,
+        payload: UpdatePropertyTypeEmbeddings,
+    ) -> Result<(), Report<RpcError>> {
+        let actor_id = actor_id(session)?;
+        let mut store = self.store().await?;
+
+        store
+            .update_property_type_embeddings(actor_id, payload.params)
+            .await
+            .change_context(RpcError::Store)
+    }
+}
@@ -0,0 +1,3 @@
+CREATE INDEX entity_editions_properties_full_text_idx
+    ON entity_editions
+        USING gin (jsonb_to_tsvector('english', properties, '["string"]'));
@@ -0,0 +1,21 @@
+CREATE TYPE change_kind AS ENUM (
+    'created',
+    'updated',
+    'archived',
+    'unarchived'
+);
+
+CREATE TABLE change_feed (
+    transaction_id BIGINT NOT NULL DEFAULT pg_current_xact_id()::TEXT::BIGINT,
+    sequence_id BIGINT GENERATED ALWAYS AS IDENTITY,
+    change_kind change_kind NOT NULL,
+    web_id UUID,
+    entity_uuid UUID,
+    draft_id UUID,
+    entity_edition_id UUID,
+    ontology_id UUID,
+    transaction_time TIMESTAMP WITH TIME ZONE NOT NULL,
+    actor_id UUID NOT NULL,
+    PRIMARY KEY (transaction_id, sequence_id),
+    CHECK (num_nonnulls(entity_edition_id, ontology_id) = 1)
+);
@@ -6,7 +6,7 @@ use core::fmt;
 
 use error_stack::{Context, Result, ResultExt};
 use serde::Deserialize;
-use type_system::url::VersionedUrl;
+use type_system::url::{OntologyTypeVersion, VersionedUrl};
 
 #[derive(Debug)]
 pub struct PatchAndParseError;
@@ -57,3 +57,27 @@ where
 
     serde_json::from_value(value).change_context(PatchAndParseError)
 }
+
+/// Parses the schema of the version following `type_to_update`.
+///
+/// Updates of ontology types are requested with the [`VersionedUrl`] of the current version and a
+/// schema without an "$id". The version is incremented and the schema is parsed as described in
+/// [`patch_id_and_parse`].
+///
+/// # Errors
+///
+/// - [`PatchAndParseError`] if the schema cannot be patched or parsed
+pub fn parse_next_version<T>(
+    type_to_update: &VersionedUrl,
+    schema: serde_json::Value,
+) -> Result<T, PatchAndParseError>
+where
+    for<'de> T: Deserialize<'de>,
+{
+    let id = VersionedUrl {
+        base_url: type_to_update.base_url.clone(),
+        version: OntologyTypeVersion::new(type_to_update.version.inner() + 1),
+    };
+
+    patch_id_and_parse(&id, schema)
+}
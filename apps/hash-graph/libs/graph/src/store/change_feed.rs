@@ -0,0 +1,227 @@
+use std::collections::{HashMap, HashSet};
+
+use authorization::{
+    AuthorizationApi,
+    schema::{DataTypePermission, EntityPermission, EntityTypePermission, PropertyTypePermission},
+    zanzibar::Consistency,
+};
+use error_stack::{Report, ResultExt};
+use graph_types::{
+    account::AccountId,
+    knowledge::entity::EntityRecordId,
+    ontology::{EntityTypeId, PropertyTypeId},
+};
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use temporal_versioning::{Timestamp, TransactionTime};
+use type_system::{schema::DataTypeId, url::VersionedUrl};
+
+use crate::store::QueryError;
+
+/// The position of a [`Change`] in the change feed.
+///
+/// Changes are ordered by the transaction which wrote them first and by the order in which they
+/// were written inside of that transaction second. A position is stable and can be used to resume
+/// reading the feed at any later point in time.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ChangeFeedPosition {
+    pub transaction_id: i64,
+    pub sequence_id: i64,
+}
+
+/// The kind of write which produced a [`Change`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSql, FromSql)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "change_kind", rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// The record was created.
+    Created,
+    /// A new edition of the record was written.
+    Updated,
+    /// The record was archived.
+    Archived,
+    /// A previously archived record was restored.
+    Unarchived,
+}
+
+/// The record which was written by a [`Change`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ChangedRecord {
+    Entity(EntityRecordId),
+    DataType(VersionedUrl),
+    PropertyType(VersionedUrl),
+    EntityType(VersionedUrl),
+}
+
+/// A single write to the graph as it appears in the change feed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+    pub position: ChangeFeedPosition,
+    pub kind: ChangeKind,
+    pub record: ChangedRecord,
+    /// The transaction time at which the change was written.
+    pub transaction_time: Timestamp<TransactionTime>,
+    /// The actor which wrote the change.
+    pub actor_id: AccountId,
+}
+
+#[derive(Debug, Copy, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ReadChangesParams {
+    /// Only changes after this position are read. If not specified, the feed is read from the
+    /// beginning.
+    #[serde(default)]
+    pub after: Option<ChangeFeedPosition>,
+    /// The maximum number of changes to read.
+    pub limit: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ReadChangesResponse {
+    /// The changes in the order they were written.
+    pub changes: Vec<Change>,
+    /// The position to continue reading from.
+    ///
+    /// This may be after the last returned change if changes of records the actor is not
+    /// permitted to view were skipped. It's only absent if the feed was read from the beginning
+    /// and no changes were written yet.
+    pub position: Option<ChangeFeedPosition>,
+}
+
+/// Describes the API of a store implementation providing a feed of all writes to the graph.
+///
+/// Every write of an entity or an ontology type, i.e. creating, updating, archiving, and
+/// unarchiving it, is recorded in the same transaction as the write itself, so a consumer of the
+/// feed cannot miss a change.
+pub trait ChangeFeedStore {
+    /// Reads the changes written after [`ReadChangesParams::after`].
+    ///
+    /// Changes are only returned once they are visible to every reader, so reading the feed from
+    /// the returned position never skips a change which is committed later. Changes of records
+    /// the actor is not permitted to view are omitted.
+    ///
+    /// # Errors
+    ///
+    /// - if the request to the database fails
+    /// - if the permissions of the actor cannot be checked
+    fn read_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadChangesParams,
+    ) -> impl Future<Output = Result<ReadChangesResponse, Report<QueryError>>> + Send;
+}
+
+/// Removes the changes of records the actor is not permitted to view.
+pub(crate) async fn filter_permitted_changes<A>(
+    authorization_api: &A,
+    actor_id: AccountId,
+    mut changes: Vec<Change>,
+) -> Result<Vec<Change>, Report<QueryError>>
+where
+    A: AuthorizationApi + Sync,
+{
+    let mut entity_ids = HashSet::new();
+    let mut data_type_ids = HashSet::new();
+    let mut property_type_ids = HashSet::new();
+    let mut entity_type_ids = HashSet::new();
+    for change in &changes {
+        match &change.record {
+            ChangedRecord::Entity(record_id) => {
+                entity_ids.insert(record_id.entity_id);
+            }
+            ChangedRecord::DataType(url) => {
+                data_type_ids.insert(DataTypeId::from_url(url));
+            }
+            ChangedRecord::PropertyType(url) => {
+                property_type_ids.insert(PropertyTypeId::from_url(url));
+            }
+            ChangedRecord::EntityType(url) => {
+                entity_type_ids.insert(EntityTypeId::from_url(url));
+            }
+        }
+    }
+
+    let entity_permissions = if entity_ids.is_empty() {
+        HashMap::new()
+    } else {
+        authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0
+    };
+    let data_type_permissions = if data_type_ids.is_empty() {
+        HashMap::new()
+    } else {
+        authorization_api
+            .check_data_types_permission(
+                actor_id,
+                DataTypePermission::View,
+                data_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0
+    };
+    let property_type_permissions = if property_type_ids.is_empty() {
+        HashMap::new()
+    } else {
+        authorization_api
+            .check_property_types_permission(
+                actor_id,
+                PropertyTypePermission::View,
+                property_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0
+    };
+    let entity_type_permissions = if entity_type_ids.is_empty() {
+        HashMap::new()
+    } else {
+        authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::View,
+                entity_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0
+    };
+
+    changes.retain(|change| {
+        match &change.record {
+            ChangedRecord::Entity(record_id) => {
+                entity_permissions.get(&record_id.entity_id.entity_uuid)
+            }
+            ChangedRecord::DataType(url) => data_type_permissions.get(&DataTypeId::from_url(url)),
+            ChangedRecord::PropertyType(url) => {
+                property_type_permissions.get(&PropertyTypeId::from_url(url))
+            }
+            ChangedRecord::EntityType(url) => {
+                entity_type_permissions.get(&EntityTypeId::from_url(url))
+            }
+        }
+        .copied()
+        .unwrap_or(false)
+    });
+    Ok(changes)
+}
@@ -7,6 +7,10 @@ use derive_where::derive_where;
 pub enum DatabaseType {
     #[default]
     Postgres,
+    /// A volatile store kept entirely in memory.
+    ///
+    /// No connection parameters are used and all data is lost when the process exits.
+    Memory,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -112,6 +116,7 @@ impl DatabaseConnectionInfo {
     pub fn url(&self) -> String {
         let db_type = match self.database_type {
             DatabaseType::Postgres => "postgres",
+            DatabaseType::Memory => "memory",
         };
         format!(
             "{}://{}:{}@{}:{}/{}",
@@ -157,6 +162,7 @@ impl fmt::Display for DatabaseConnectionInfo {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         let db_type = match self.database_type {
             DatabaseType::Postgres => "postgres",
+            DatabaseType::Memory => "memory",
         };
         write!(
             fmt,
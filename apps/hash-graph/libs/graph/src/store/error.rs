@@ -97,6 +97,18 @@ impl fmt::Display for RaceConditionOnUpdate {
 
 impl Context for RaceConditionOnUpdate {}
 
+#[derive(Debug)]
+#[must_use]
+pub struct EntityEditionConflict;
+
+impl fmt::Display for EntityEditionConflict {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The entity that should be updated is not at the expected edition")
+    }
+}
+
+impl Context for EntityEditionConflict {}
+
 #[derive(Debug)]
 #[must_use]
 pub struct VersionedUrlAlreadyExists;
@@ -133,6 +145,18 @@ impl fmt::Display for OntologyTypeIsNotOwned {
 
 impl Context for OntologyTypeIsNotOwned {}
 
+#[derive(Debug)]
+#[must_use]
+pub struct BreakingOntologyTypeChange;
+
+impl fmt::Display for BreakingOntologyTypeChange {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("the new version of the ontology type contains breaking changes")
+    }
+}
+
+impl Context for BreakingOntologyTypeChange {}
+
 #[derive(Debug)]
 pub struct MigrationError;
 
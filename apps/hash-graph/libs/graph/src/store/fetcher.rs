@@ -12,6 +12,7 @@ use authorization::{
     zanzibar::Consistency,
 };
 use error_stack::{Report, Result, ResultExt};
+use futures::Stream;
 use graph_types::{
     account::AccountId,
     knowledge::entity::{Entity, EntityId},
@@ -49,13 +50,16 @@ use type_system::{
 use crate::{
     ontology::domain_validator::DomainValidator,
     store::{
-        DataTypeStore, EntityStore, EntityTypeStore, InsertionError, PropertyTypeStore, QueryError,
-        StoreError, StorePool, UpdateError,
+        ChangeFeedStore, DataTypeStore, EntityStore, EntityTypeStore, InsertionError,
+        PropertyTypeStore, QueryError, StoreError, StorePool, UpdateError,
+        change_feed::{ReadChangesParams, ReadChangesResponse},
         crud::{QueryResult, Read, ReadPaginated, Sorting},
         knowledge::{
-            CountEntitiesParams, CreateEntityParams, GetEntitiesParams, GetEntitiesResponse,
-            GetEntitySubgraphParams, GetEntitySubgraphResponse, PatchEntityParams,
-            UpdateEntityEmbeddingsParams, ValidateEntityError, ValidateEntityParams,
+            AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+            CreateEntityParams, FindEntityPathsParams, FindEntityPathsResponse, GetEntitiesParams,
+            GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse,
+            PatchEntityParams, UpdateEntityEmbeddingsParams, ValidateEntityError,
+            ValidateEntityParams,
         },
         ontology::{
             ArchiveDataTypeParams, ArchiveEntityTypeParams, ArchivePropertyTypeParams,
@@ -66,8 +70,10 @@ use crate::{
             GetEntityTypesParams, GetEntityTypesResponse, GetPropertyTypeSubgraphParams,
             GetPropertyTypeSubgraphResponse, GetPropertyTypesParams, GetPropertyTypesResponse,
             UnarchiveDataTypeParams, UnarchiveEntityTypeParams, UnarchivePropertyTypeParams,
-            UpdateDataTypeEmbeddingParams, UpdateDataTypesParams, UpdateEntityTypeEmbeddingParams,
-            UpdateEntityTypesParams, UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
+            UpdateDataTypeEmbeddingParams, UpdateDataTypesParams, UpdateDataTypesResponse,
+            UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams, UpdateEntityTypesResponse,
+            UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
+            UpdatePropertyTypesResponse,
         },
     },
 };
@@ -803,6 +809,20 @@ where
     }
 }
 
+impl<S, A> ChangeFeedStore for FetchingStore<S, A>
+where
+    S: ChangeFeedStore + Send + Sync,
+    A: Send + Sync,
+{
+    async fn read_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadChangesParams,
+    ) -> Result<ReadChangesResponse, QueryError> {
+        self.store.read_changes(actor_id, params).await
+    }
+}
+
 impl<S, A> DataTypeStore for FetchingStore<S, A>
 where
     S: DataTypeStore + PropertyTypeStore + EntityTypeStore + Send + Sync,
@@ -872,7 +892,7 @@ where
         &mut self,
         actor_id: AccountId,
         params: UpdateDataTypesParams<R>,
-    ) -> Result<DataTypeMetadata, UpdateError>
+    ) -> Result<UpdateDataTypesResponse, UpdateError>
     where
         R: IntoIterator<Item = DataTypeRelationAndSubject> + Send + Sync,
     {
@@ -993,7 +1013,7 @@ where
         &mut self,
         actor_id: AccountId,
         params: UpdatePropertyTypesParams<R>,
-    ) -> Result<PropertyTypeMetadata, UpdateError>
+    ) -> Result<UpdatePropertyTypesResponse, UpdateError>
     where
         R: IntoIterator<Item = PropertyTypeRelationAndSubject> + Send + Sync,
     {
@@ -1108,7 +1128,7 @@ where
         &mut self,
         actor_id: AccountId,
         params: UpdateEntityTypesParams<R>,
-    ) -> Result<EntityTypeMetadata, UpdateError>
+    ) -> Result<UpdateEntityTypesResponse, UpdateError>
     where
         R: IntoIterator<Item = EntityTypeRelationAndSubject> + Send + Sync,
     {
@@ -1208,6 +1228,14 @@ where
         self.store.get_entities(actor_id, params).await
     }
 
+    async fn stream_entities<'s>(
+        &'s self,
+        actor_id: AccountId,
+        params: GetEntitiesParams<'s>,
+    ) -> Result<impl Stream<Item = Result<Entity, QueryError>> + Send + 's, QueryError> {
+        self.store.stream_entities(actor_id, params).await
+    }
+
     async fn get_entity_subgraph(
         &self,
         actor_id: AccountId,
@@ -1236,6 +1264,22 @@ where
         self.store.count_entities(actor_id, params).await
     }
 
+    async fn aggregate_entities(
+        &self,
+        actor_id: AccountId,
+        params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, QueryError> {
+        self.store.aggregate_entities(actor_id, params).await
+    }
+
+    async fn find_entity_paths(
+        &self,
+        actor_id: AccountId,
+        params: FindEntityPathsParams<'_>,
+    ) -> Result<FindEntityPathsResponse, QueryError> {
+        self.store.find_entity_paths(actor_id, params).await
+    }
+
     async fn patch_entity(
         &mut self,
         actor_id: AccountId,
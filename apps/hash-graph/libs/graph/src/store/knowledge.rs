@@ -4,15 +4,20 @@ use std::collections::{HashMap, HashSet};
 
 use authorization::{schema::EntityRelationAndSubject, zanzibar::Consistency};
 use error_stack::Report;
-use futures::TryFutureExt;
+use futures::{Stream, TryFutureExt};
 use graph_types::{
     account::{AccountId, CreatedById, EditionCreatedById},
     knowledge::{
         Confidence, EntityTypeIdDiff,
-        entity::{Entity, EntityEmbedding, EntityId, EntityUuid, ProvidedEntityEditionProvenance},
+        entity::{
+            Entity, EntityEditionId, EntityEmbedding, EntityId, EntityRecordId, EntityUuid,
+            ProvidedEntityEditionProvenance,
+        },
         link::LinkData,
         property::{
-            PropertyDiff, PropertyPatchOperation, PropertyPath, PropertyWithMetadataObject,
+            PropertyDiff, PropertyMetadataObject, PropertyObject, PropertyPatchOperation,
+            PropertyPath, PropertyPathElement, PropertyProvenance, PropertyWithMetadata,
+            PropertyWithMetadataObject,
         },
     },
     owned_by_id::OwnedById,
@@ -20,13 +25,21 @@ use graph_types::{
 use hash_graph_store::{
     entity::EntityQueryPath,
     filter::Filter,
-    subgraph::{Subgraph, edges::GraphResolveDepths, temporal_axes::QueryTemporalAxesUnresolved},
+    subgraph::{
+        Subgraph,
+        edges::GraphResolveDepths,
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
+    },
 };
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 use temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
 use type_system::{
     schema::{ClosedEntityType, EntityType},
-    url::VersionedUrl,
+    url::{BaseUrl, VersionedUrl},
 };
 #[cfg(feature = "utoipa")]
 use utoipa::{
@@ -36,8 +49,8 @@ use utoipa::{
 use validation::ValidateEntityComponents;
 
 use crate::store::{
-    InsertionError, NullOrdering, Ordering, QueryError, UpdateError, crud::Sorting,
-    postgres::CursorField,
+    AggregationFunction, InsertionError, NullOrdering, Ordering, QueryError, UpdateError,
+    crud::Sorting, postgres::CursorField,
 };
 
 #[derive(Debug, Clone, Deserialize)]
@@ -124,6 +137,12 @@ pub struct EntityQuerySorting<'s> {
     pub paths: Vec<EntityQuerySortingRecord<'s>>,
     #[serde(borrow)]
     pub cursor: Option<EntityQueryCursor<'s>>,
+    /// Full-text search query to sort the entities by relevance of their properties.
+    ///
+    /// The relevance takes precedence over the sorting `paths` and is the first value of the
+    /// cursor.
+    #[serde(borrow, default)]
+    pub relevance: Option<Cow<'s, str>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -225,6 +244,117 @@ pub struct QueryConversion<'a> {
     pub data_type_id: VersionedUrl,
 }
 
+/// Restricts which parts of an [`Entity`] are returned by a query.
+///
+/// The identifiers, temporal versioning, entity types, and provenance of an entity are always
+/// returned.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityProjection<'a> {
+    /// The property paths to return.
+    ///
+    /// If not specified, all properties are returned. Paths which don't exist on an entity are
+    /// omitted from its properties.
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub properties: Option<Vec<PropertyPath<'a>>>,
+    /// Whether the metadata of the properties is returned.
+    #[serde(default)]
+    pub property_metadata: bool,
+    /// Whether the confidence and provenance of the link ends are returned.
+    #[serde(default)]
+    pub link_metadata: bool,
+}
+
+impl EntityProjection<'_> {
+    /// Restricts an already loaded [`Entity`] to the projected parts.
+    pub fn apply(&self, entity: &mut Entity) {
+        if let Some(paths) = &self.properties {
+            let properties = serde_json::to_value(&entity.properties)
+                .expect("properties should be serializable to JSON");
+            entity.properties = project_properties(paths.iter().filter_map(|path| {
+                property_path_value(&properties, path).map(|value| (path, value.clone()))
+            }));
+        }
+        if !self.property_metadata {
+            entity.metadata.properties = PropertyMetadataObject::default();
+        }
+        if !self.link_metadata {
+            if let Some(link_data) = &mut entity.link_data {
+                link_data.left_entity_confidence = None;
+                link_data.left_entity_provenance = PropertyProvenance::default();
+                link_data.right_entity_confidence = None;
+                link_data.right_entity_provenance = PropertyProvenance::default();
+            }
+        }
+    }
+}
+
+/// Returns the value of `properties` located at `path`.
+fn property_path_value<'v>(
+    properties: &'v JsonValue,
+    path: &PropertyPath<'_>,
+) -> Option<&'v JsonValue> {
+    path.as_ref()
+        .iter()
+        .try_fold(properties, |value, element| match element {
+            PropertyPathElement::Property(key) => value.get(key.as_str()),
+            PropertyPathElement::Index(index) => value.get(*index),
+        })
+}
+
+fn insert_property_path_value(
+    target: &mut JsonValue,
+    path: &[PropertyPathElement<'_>],
+    value: JsonValue,
+) {
+    let Some((element, remaining)) = path.split_first() else {
+        *target = value;
+        return;
+    };
+
+    let child = match element {
+        PropertyPathElement::Property(key) => {
+            if !target.is_object() {
+                *target = JsonValue::Object(serde_json::Map::new());
+            }
+            let JsonValue::Object(object) = target else {
+                unreachable!("the target was just converted to an object");
+            };
+            object.entry(key.to_string()).or_insert(JsonValue::Null)
+        }
+        PropertyPathElement::Index(index) => {
+            if !target.is_array() {
+                *target = JsonValue::Array(Vec::new());
+            }
+            let JsonValue::Array(array) = target else {
+                unreachable!("the target was just converted to an array");
+            };
+            if array.len() <= *index {
+                array.resize(index + 1, JsonValue::Null);
+            }
+            &mut array[*index]
+        }
+    };
+    insert_property_path_value(child, remaining, value);
+}
+
+/// Assembles a [`PropertyObject`] from the values found at the projected property paths.
+///
+/// Array elements which precede a projected index but are not projected themselves are filled
+/// with `null`.
+pub(crate) fn project_properties<'p, 'k: 'p>(
+    values: impl IntoIterator<Item = (&'p PropertyPath<'k>, JsonValue)>,
+) -> PropertyObject {
+    let mut properties = JsonValue::Object(serde_json::Map::new());
+    for (path, value) in values {
+        insert_property_path_value(&mut properties, path.as_ref(), value);
+    }
+    serde_json::from_value(properties)
+        .expect("projected properties should be a subset of valid properties")
+}
+
 #[derive(Debug)]
 #[expect(clippy::struct_excessive_bools, reason = "Parameter struct")]
 pub struct GetEntitiesParams<'a> {
@@ -232,6 +362,7 @@ pub struct GetEntitiesParams<'a> {
     pub temporal_axes: QueryTemporalAxesUnresolved,
     pub sorting: EntityQuerySorting<'static>,
     pub conversions: Vec<QueryConversion<'a>>,
+    pub projection: Option<EntityProjection<'a>>,
     pub limit: Option<usize>,
     pub include_drafts: bool,
     pub include_count: bool,
@@ -262,15 +393,55 @@ pub struct GetEntitiesResponse<'r> {
     pub type_ids: Option<HashMap<VersionedUrl, usize>>,
 }
 
+/// The direction in which a link entity is followed during a traversal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum LinkTraversalDirection {
+    /// Follows links from their left entity to their right entity.
+    Outgoing,
+    /// Follows links from their right entity to their left entity.
+    Incoming,
+}
+
+/// A single step of an [`EntityTraversalPath`] from an entity over a link entity to the entity at
+/// the other end of the link.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityTraversalHop<'p> {
+    pub direction: LinkTraversalDirection,
+    /// The filter a link entity has to satisfy to be followed.
+    #[serde(borrow)]
+    pub link_filter: Filter<'p, Entity>,
+    /// The filter the entity at the other end of the link has to satisfy to be followed.
+    #[serde(borrow)]
+    pub endpoint_filter: Filter<'p, Entity>,
+}
+
+/// A chain of hops which is followed from the root entities of a subgraph.
+///
+/// Each hop starts at the entities reached by the previous hop. Link entities and endpoints which
+/// don't satisfy the filters of a hop are neither added to the subgraph nor traversed further.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityTraversalPath<'p> {
+    #[serde(borrow)]
+    pub hops: Vec<EntityTraversalHop<'p>>,
+}
+
 #[derive(Debug)]
 #[expect(clippy::struct_excessive_bools, reason = "Parameter struct")]
 pub struct GetEntitySubgraphParams<'a> {
     pub filter: Filter<'a, Entity>,
     pub temporal_axes: QueryTemporalAxesUnresolved,
     pub graph_resolve_depths: GraphResolveDepths,
+    pub traversal_paths: Vec<EntityTraversalPath<'a>>,
     pub sorting: EntityQuerySorting<'static>,
     pub limit: Option<usize>,
     pub conversions: Vec<QueryConversion<'a>>,
+    pub projection: Option<EntityProjection<'a>>,
     pub include_drafts: bool,
     pub include_count: bool,
     pub include_web_ids: bool,
@@ -300,11 +471,287 @@ pub struct CountEntitiesParams<'a> {
     pub include_drafts: bool,
 }
 
+/// An aggregate function applied to the entities of a group.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityAggregation<'p> {
+    pub function: AggregationFunction,
+    /// The path to aggregate over.
+    ///
+    /// Only [`AggregationFunction::Count`] may omit the path, in which case the number of entities
+    /// in the group is returned.
+    #[serde(borrow, default)]
+    pub path: Option<EntityQueryPath<'p>>,
+}
+
+#[cfg(feature = "utoipa")]
+impl ToSchema<'_> for EntityAggregation<'_> {
+    fn schema() -> (&'static str, RefOr<Schema>) {
+        (
+            "EntityAggregation",
+            Schema::Object(
+                schema::ObjectBuilder::new()
+                    .property("function", Ref::from_schema_name("AggregationFunction"))
+                    .required("function")
+                    .property("path", Ref::from_schema_name("EntityQueryPath"))
+                    .build(),
+            )
+            .into(),
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregateEntitiesParams<'a> {
+    #[serde(borrow)]
+    pub filter: Filter<'a, Entity>,
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    pub include_drafts: bool,
+    #[serde(borrow, default)]
+    pub group_by: Vec<EntityQueryPath<'a>>,
+    #[serde(borrow)]
+    pub aggregations: Vec<EntityAggregation<'a>>,
+}
+
+#[cfg(feature = "utoipa")]
+impl ToSchema<'_> for AggregateEntitiesParams<'_> {
+    fn schema() -> (&'static str, RefOr<Schema>) {
+        (
+            "AggregateEntitiesParams",
+            Schema::Object(
+                schema::ObjectBuilder::new()
+                    .property("filter", Ref::from_schema_name("Filter"))
+                    .required("filter")
+                    .property(
+                        "temporalAxes",
+                        Ref::from_schema_name("QueryTemporalAxesUnresolved"),
+                    )
+                    .required("temporalAxes")
+                    .property(
+                        "includeDrafts",
+                        schema::ObjectBuilder::new().schema_type(schema::SchemaType::Boolean),
+                    )
+                    .required("includeDrafts")
+                    .property(
+                        "groupBy",
+                        Ref::from_schema_name("EntityQueryPath").to_array_builder(),
+                    )
+                    .property(
+                        "aggregations",
+                        Ref::from_schema_name("EntityAggregation").to_array_builder(),
+                    )
+                    .required("aggregations")
+                    .build(),
+            )
+            .into(),
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityAggregationGroup {
+    /// The values of the `groupBy` paths shared by all entities in this group.
+    #[cfg_attr(feature = "utoipa", schema(value_type = Vec<Object>))]
+    pub key: Vec<serde_json::Value>,
+    /// The results of the `aggregations` in the order they were requested.
+    ///
+    /// A value is `null` if the group has no value to aggregate over.
+    pub values: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateEntitiesResponse {
+    pub groups: Vec<EntityAggregationGroup>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FindEntityPathsParams<'a> {
+    pub source_entity_id: EntityId,
+    pub target_entity_id: EntityId,
+    /// The maximum number of links in a returned path.
+    pub max_length: usize,
+    /// The filter a link entity has to satisfy to be part of a path.
+    ///
+    /// If not specified, all links are followed.
+    #[serde(borrow, default)]
+    pub link_filter: Option<Filter<'a, Entity>>,
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    pub include_drafts: bool,
+    /// The maximum number of paths to return.
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[cfg(feature = "utoipa")]
+impl ToSchema<'_> for FindEntityPathsParams<'_> {
+    fn schema() -> (&'static str, RefOr<Schema>) {
+        (
+            "FindEntityPathsParams",
+            Schema::Object(
+                schema::ObjectBuilder::new()
+                    .property("sourceEntityId", Ref::from_schema_name("EntityId"))
+                    .required("sourceEntityId")
+                    .property("targetEntityId", Ref::from_schema_name("EntityId"))
+                    .required("targetEntityId")
+                    .property(
+                        "maxLength",
+                        schema::ObjectBuilder::new().schema_type(schema::SchemaType::Integer),
+                    )
+                    .required("maxLength")
+                    .property("linkFilter", Ref::from_schema_name("Filter"))
+                    .property(
+                        "temporalAxes",
+                        Ref::from_schema_name("QueryTemporalAxesUnresolved"),
+                    )
+                    .required("temporalAxes")
+                    .property(
+                        "includeDrafts",
+                        schema::ObjectBuilder::new().schema_type(schema::SchemaType::Boolean),
+                    )
+                    .required("includeDrafts")
+                    .property(
+                        "limit",
+                        schema::ObjectBuilder::new()
+                            .schema_type(schema::SchemaType::Integer)
+                            .nullable(true),
+                    )
+                    .build(),
+            )
+            .into(),
+        )
+    }
+}
+
+/// A single step of an [`EntityPath`] over a link entity to the entity at the other end of the
+/// link.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityPathHop {
+    pub link_entity_id: EntityId,
+    pub direction: LinkTraversalDirection,
+    pub entity_id: EntityId,
+}
+
+/// A chain of links connecting the source entity with the target entity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityPath {
+    pub hops: Vec<EntityPathHop>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FindEntityPathsResponse {
+    /// The shortest paths between the source and the target entity.
+    ///
+    /// All paths have the same length. If the entities are not connected by a path of at most
+    /// the requested length, no path is returned.
+    pub paths: Vec<EntityPath>,
+}
+
+/// Keeps track of the entities discovered by a breadth-first search for the shortest paths
+/// between two entities.
+#[derive(Debug)]
+pub(crate) struct EntityPathSearch {
+    source_entity_id: EntityId,
+    target_entity_id: EntityId,
+    depths: HashMap<EntityId, usize>,
+    predecessors: HashMap<EntityId, Vec<(EntityId, EntityPathHop)>>,
+}
+
+impl EntityPathSearch {
+    pub(crate) fn new(source_entity_id: EntityId, target_entity_id: EntityId) -> Self {
+        Self {
+            source_entity_id,
+            target_entity_id,
+            depths: HashMap::from([(source_entity_id, 0)]),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.depths.contains_key(&self.target_entity_id)
+    }
+
+    /// Records that `hop` reaches its entity from `previous_entity_id` at `depth`.
+    ///
+    /// Returns `true` if the entity was not discovered before and has to be searched from in the
+    /// next step.
+    pub(crate) fn visit(
+        &mut self,
+        depth: usize,
+        previous_entity_id: EntityId,
+        hop: EntityPathHop,
+    ) -> bool {
+        let discovered = match self.depths.get(&hop.entity_id) {
+            Some(&known_depth) if known_depth < depth => return false,
+            Some(_) => false,
+            None => {
+                self.depths.insert(hop.entity_id, depth);
+                true
+            }
+        };
+
+        let predecessors = self.predecessors.entry(hop.entity_id).or_default();
+        let predecessor = (previous_entity_id, hop);
+        if !predecessors.contains(&predecessor) {
+            predecessors.push(predecessor);
+        }
+        discovered
+    }
+
+    /// Returns the shortest paths from the source to the target entity.
+    pub(crate) fn into_paths(self, limit: Option<usize>) -> Vec<EntityPath> {
+        let limit = limit.unwrap_or(usize::MAX);
+        let mut paths = Vec::new();
+        if !self.is_finished() {
+            return paths;
+        }
+
+        let mut stack = vec![(self.target_entity_id, Vec::new())];
+        while let Some((entity_id, mut hops)) = stack.pop() {
+            if paths.len() >= limit {
+                break;
+            }
+
+            if entity_id == self.source_entity_id {
+                hops.reverse();
+                paths.push(EntityPath { hops });
+                continue;
+            }
+
+            for (previous_entity_id, hop) in self.predecessors.get(&entity_id).into_iter().flatten()
+            {
+                let mut hops = hops.clone();
+                hops.push(hop.clone());
+                stack.push((*previous_entity_id, hops));
+            }
+        }
+
+        paths
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct PatchEntityParams {
     pub entity_id: EntityId,
+    /// The edition the patch is based on.
+    ///
+    /// If specified, the patch is rejected if the entity was updated since this edition was read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub expected_edition_id: Option<EntityEditionId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     pub decision_time: Option<Timestamp<DecisionTime>>,
@@ -326,6 +773,101 @@ pub struct PatchEntityParams {
     pub provenance: ProvidedEntityEditionProvenance,
 }
 
+/// Describes how a top-level property of an entity is changed when migrating the entity to another
+/// entity type.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "op")]
+pub enum PropertyMapping {
+    /// Moves the value of a property, including its metadata, to another property.
+    ///
+    /// Entities without a value for the property are left unchanged.
+    Rename { from: BaseUrl, to: BaseUrl },
+    /// Removes a property.
+    Remove { property: BaseUrl },
+    /// Sets a property to the given value if the entity does not have a value for it yet.
+    Default {
+        property: BaseUrl,
+        value: PropertyWithMetadata,
+    },
+}
+
+/// Returns the patch operations which apply the `mappings` in order to the given properties.
+fn property_mapping_operations(
+    mappings: &[PropertyMapping],
+    mut properties: HashMap<BaseUrl, PropertyWithMetadata>,
+) -> Vec<PropertyPatchOperation> {
+    let path = |base_url: &BaseUrl| -> PropertyPath<'static> {
+        [PropertyPathElement::from(base_url.clone())]
+            .into_iter()
+            .collect()
+    };
+
+    let mut operations = Vec::new();
+    for mapping in mappings {
+        match mapping {
+            PropertyMapping::Rename { from, to } => {
+                if let Some(property) = properties.remove(from) {
+                    operations.push(PropertyPatchOperation::Remove { path: path(from) });
+                    operations.push(PropertyPatchOperation::Add {
+                        path: path(to),
+                        property: property.clone(),
+                    });
+                    properties.insert(to.clone(), property);
+                }
+            }
+            PropertyMapping::Remove { property } => {
+                if properties.remove(property).is_some() {
+                    operations.push(PropertyPatchOperation::Remove {
+                        path: path(property),
+                    });
+                }
+            }
+            PropertyMapping::Default { property, value } => {
+                if !properties.contains_key(property) {
+                    operations.push(PropertyPatchOperation::Add {
+                        path: path(property),
+                        property: value.clone(),
+                    });
+                    properties.insert(property.clone(), value.clone());
+                }
+            }
+        }
+    }
+    operations
+}
+
+#[derive(Debug)]
+pub struct MigrateEntitiesParams<'a> {
+    /// Only entities matching this filter are migrated.
+    pub filter: Filter<'a, Entity>,
+    /// The entity type the entities are migrated from.
+    pub from_entity_type_id: VersionedUrl,
+    /// The entity type which replaces [`Self::from_entity_type_id`].
+    pub to_entity_type_id: VersionedUrl,
+    /// The mappings applied to the properties of every migrated entity.
+    pub property_mappings: Vec<PropertyMapping>,
+    pub include_drafts: bool,
+    /// The provenance of the editions created by the migration.
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityMigrationFailure {
+    pub entity_id: EntityId,
+    /// Describes why the entity could not be migrated.
+    pub reason: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrateEntitiesResponse {
+    /// The editions created for the migrated entities.
+    pub migrated: Vec<EntityRecordId>,
+    /// The entities which could not be migrated.
+    pub failures: Vec<EntityMigrationFailure>,
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -370,6 +912,9 @@ pub struct DiffEntityResult<'e> {
     pub draft_state: Option<bool>,
 }
 
+/// The number of entities which are read per page when migrating entities.
+const MIGRATION_CHUNK_SIZE: usize = 1000;
+
 /// Describes the API of a store implementation for [Entities].
 ///
 /// [Entities]: Entity
@@ -445,6 +990,28 @@ pub trait EntityStore {
         params: GetEntitiesParams<'_>,
     ) -> impl Future<Output = Result<GetEntitiesResponse<'static>, Report<QueryError>>> + Send;
 
+    /// Returns a stream of the entities specified by the [`GetEntitiesParams`].
+    ///
+    /// In contrast to [`get_entities`], the entities are read lazily while the stream is consumed.
+    /// The sorting, the cursor, and the limit are respected, while the requested counts are not
+    /// computed. Only entities the actor is permitted to view are returned.
+    ///
+    /// # Errors
+    ///
+    /// - if the requested [`Entities`][Entity] cannot be retrieved
+    ///
+    /// [`get_entities`]: Self::get_entities
+    fn stream_entities<'s>(
+        &'s self,
+        actor_id: AccountId,
+        params: GetEntitiesParams<'s>,
+    ) -> impl Future<
+        Output = Result<
+            impl Stream<Item = Result<Entity, Report<QueryError>>> + Send + 's,
+            Report<QueryError>,
+        >,
+    > + Send;
+
     /// Get the [`Subgraph`]s specified by the [`GetEntitySubgraphParams`].
     ///
     /// # Errors
@@ -469,6 +1036,36 @@ pub trait EntityStore {
         params: CountEntitiesParams<'_>,
     ) -> impl Future<Output = Result<usize, Report<QueryError>>> + Send;
 
+    /// Groups the entities matching the filter of [`AggregateEntitiesParams`] and computes the
+    /// requested aggregations for each group.
+    ///
+    /// Only entities the actor is permitted to view are taken into account.
+    ///
+    /// # Errors
+    ///
+    /// - if an aggregation other than a count does not specify a path
+    /// - if the request to the database fails
+    fn aggregate_entities(
+        &self,
+        actor_id: AccountId,
+        params: AggregateEntitiesParams<'_>,
+    ) -> impl Future<Output = Result<AggregateEntitiesResponse, Report<QueryError>>> + Send;
+
+    /// Finds the shortest paths of links between the source and the target entity of
+    /// [`FindEntityPathsParams`].
+    ///
+    /// Links may be followed in either direction. Only links and entities the actor is permitted
+    /// to view are part of the returned paths.
+    ///
+    /// # Errors
+    ///
+    /// - if the request to the database fails
+    fn find_entity_paths(
+        &self,
+        actor_id: AccountId,
+        params: FindEntityPathsParams<'_>,
+    ) -> impl Future<Output = Result<FindEntityPathsResponse, Report<QueryError>>> + Send;
+
     fn get_entity_by_id(
         &self,
         actor_id: AccountId,
@@ -483,6 +1080,134 @@ pub trait EntityStore {
         params: PatchEntityParams,
     ) -> impl Future<Output = Result<Entity, Report<UpdateError>>> + Send;
 
+    /// Migrates the entities matching the filter of [`MigrateEntitiesParams`] from one entity type
+    /// to another.
+    ///
+    /// The entities are read in pages of a fixed size and every entity is patched individually:
+    /// the old entity type is replaced by the new one and the property mappings are applied,
+    /// before the entity is validated against the new entity type and a new edition is created.
+    /// An entity which cannot be migrated, e.g. because it does not satisfy the new entity type or
+    /// it was updated concurrently, is reported as a failure and does not abort the migration of
+    /// the other entities.
+    ///
+    /// # Errors
+    ///
+    /// - if the entities to migrate cannot be read
+    fn migrate_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MigrateEntitiesParams<'_>,
+    ) -> impl Future<Output = Result<MigrateEntitiesResponse, Report<QueryError>>> + Send
+    where
+        Self: Send + Sync,
+    {
+        async move {
+            let MigrateEntitiesParams {
+                filter,
+                from_entity_type_id,
+                to_entity_type_id,
+                property_mappings,
+                include_drafts,
+                provenance,
+            } = params;
+
+            let filter = Filter::All(vec![
+                filter,
+                Filter::for_entity_by_type_id(&from_entity_type_id),
+            ]);
+            // Every page is read at the same transaction time, so the editions created while
+            // migrating a page don't affect the following pages.
+            let temporal_axes = QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(Some(Timestamp::now())),
+                variable: VariableTemporalAxisUnresolved::new(None, None),
+            };
+
+            let mut response = MigrateEntitiesResponse::default();
+            let mut cursor = None;
+            loop {
+                let page = self
+                    .get_entities(actor_id, GetEntitiesParams {
+                        filter: filter.clone(),
+                        temporal_axes: temporal_axes.clone(),
+                        sorting: EntityQuerySorting {
+                            paths: Vec::new(),
+                            cursor: cursor.take(),
+                            relevance: None,
+                        },
+                        conversions: Vec::new(),
+                        projection: None,
+                        limit: Some(MIGRATION_CHUNK_SIZE),
+                        include_drafts,
+                        include_count: false,
+                        include_web_ids: false,
+                        include_created_by_ids: false,
+                        include_edition_created_by_ids: false,
+                        include_type_ids: false,
+                    })
+                    .await?;
+
+                for entity in page.entities {
+                    let entity_id = entity.metadata.record_id.entity_id;
+                    // The base URL and the version of the type are filtered independently, so an
+                    // entity with multiple types may match without being of the old entity type.
+                    if !entity
+                        .metadata
+                        .entity_type_ids
+                        .contains(&from_entity_type_id)
+                    {
+                        continue;
+                    }
+
+                    let properties = match PropertyWithMetadataObject::from_parts(
+                        entity.properties,
+                        Some(entity.metadata.properties),
+                    ) {
+                        Ok(properties) => properties.value,
+                        Err(report) => {
+                            response.failures.push(EntityMigrationFailure {
+                                entity_id,
+                                reason: format!("{report:#}"),
+                            });
+                            continue;
+                        }
+                    };
+
+                    let mut entity_type_ids = entity.metadata.entity_type_ids;
+                    entity_type_ids.remove(&from_entity_type_id);
+                    entity_type_ids.insert(to_entity_type_id.clone());
+
+                    let patch = self
+                        .patch_entity(actor_id, PatchEntityParams {
+                            entity_id,
+                            expected_edition_id: Some(entity.metadata.record_id.edition_id),
+                            decision_time: None,
+                            entity_type_ids,
+                            properties: property_mapping_operations(&property_mappings, properties),
+                            draft: None,
+                            archived: None,
+                            confidence: entity.metadata.confidence,
+                            provenance: provenance.clone(),
+                        })
+                        .await;
+                    match patch {
+                        Ok(entity) => response.migrated.push(entity.metadata.record_id),
+                        Err(report) => response.failures.push(EntityMigrationFailure {
+                            entity_id,
+                            reason: format!("{report:#}"),
+                        }),
+                    }
+                }
+
+                cursor = page.cursor;
+                if cursor.is_none() {
+                    break;
+                }
+            }
+
+            Ok(response)
+        }
+    }
+
     fn diff_entity(
         &self,
         actor_id: AccountId,
@@ -0,0 +1,74 @@
+use authorization::AuthorizationApi;
+use error_stack::Result;
+use graph_types::account::AccountId;
+
+use super::{MemoryStore, state::ChangeRecordId};
+use crate::store::{
+    QueryError,
+    change_feed::{
+        Change, ChangeFeedPosition, ChangeFeedStore, ChangedRecord, ReadChangesParams,
+        ReadChangesResponse, filter_permitted_changes,
+    },
+};
+
+impl<A> ChangeFeedStore for MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn read_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadChangesParams,
+    ) -> Result<ReadChangesResponse, QueryError> {
+        // Writes are applied atomically, so every change is visible as soon as it's recorded. The
+        // position of a change is its index in the feed.
+        let changes = {
+            let state = self.state.read().await;
+            state
+                .changes
+                .iter()
+                .zip(1..)
+                .map(|(row, sequence_id)| {
+                    let position = ChangeFeedPosition {
+                        transaction_id: 0,
+                        sequence_id,
+                    };
+                    (row, position)
+                })
+                .skip_while(|(_, position)| params.after.is_some_and(|after| *position <= after))
+                .take(params.limit)
+                .map(|(row, position)| {
+                    let record = match &row.record_id {
+                        ChangeRecordId::Entity(record_id) => ChangedRecord::Entity(*record_id),
+                        ChangeRecordId::Ontology(url) if state.data_types.contains_key(url) => {
+                            ChangedRecord::DataType(url.clone())
+                        }
+                        ChangeRecordId::Ontology(url) if state.property_types.contains_key(url) => {
+                            ChangedRecord::PropertyType(url.clone())
+                        }
+                        ChangeRecordId::Ontology(url) => ChangedRecord::EntityType(url.clone()),
+                    };
+
+                    Change {
+                        position,
+                        kind: row.kind,
+                        record,
+                        transaction_time: row.transaction_time,
+                        actor_id: row.actor_id,
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let position = changes
+            .last()
+            .map(|change| change.position)
+            .or(params.after);
+
+        Ok(ReadChangesResponse {
+            changes: filter_permitted_changes(&self.authorization_api, actor_id, changes).await?,
+            position,
+        })
+    }
+}
@@ -0,0 +1,2545 @@
+use alloc::borrow::Cow;
+use core::{borrow::Borrow, iter::once, mem};
+use std::collections::{HashMap, HashSet, hash_map::Entry};
+
+use authorization::{
+    AuthorizationApi,
+    backend::ModifyRelationshipOperation,
+    schema::{
+        EntityOwnerSubject, EntityPermission, EntityRelationAndSubject, EntityTypePermission,
+        WebPermission,
+    },
+    zanzibar::{Consistency, Zookie},
+};
+use error_stack::{Report, ReportSink, Result, ResultExt, bail};
+use futures::{Stream, TryStreamExt, stream};
+use graph_types::{
+    account::{AccountId, CreatedById, EditionArchivedById, EditionCreatedById},
+    knowledge::{
+        Confidence,
+        entity::{
+            DraftId, Entity, EntityEditionId, EntityEditionProvenance, EntityId, EntityMetadata,
+            EntityProvenance, EntityRecordId, EntityTemporalMetadata, EntityUuid,
+            InferredEntityProvenance,
+        },
+        property::{
+            Property, PropertyMetadata, PropertyMetadataObject, PropertyObject, PropertyPath,
+            PropertyPathError, PropertyWithMetadata, PropertyWithMetadataObject,
+            PropertyWithMetadataValue, visitor::EntityVisitor,
+        },
+    },
+    ontology::{DataTypeProvider, EntityTypeId, EntityTypeProvider},
+    owned_by_id::OwnedById,
+};
+use hash_graph_store::{
+    entity::EntityQueryPath,
+    filter::{Filter, FilterExpression, ParameterList},
+    subgraph::{
+        Subgraph, SubgraphRecord,
+        edges::{EdgeDirection, GraphResolveDepths, KnowledgeGraphEdgeKind, SharedEdgeKind},
+        identifier::{EntityIdWithInterval, EntityTypeVertexId, EntityVertexId},
+        temporal_axes::{
+            PinnedAxis, PinnedTemporalAxisUnresolved, QueryTemporalAxes,
+            QueryTemporalAxesUnresolved, VariableAxis, VariableTemporalAxisUnresolved,
+        },
+    },
+};
+use hash_status::StatusCode;
+use serde_json::Value as JsonValue;
+use temporal_versioning::{
+    ClosedTemporalBound, DecisionTime, LeftClosedTemporalInterval, LimitedTemporalBound,
+    OpenTemporalBound, RightBoundedTemporalInterval, TemporalBound, TemporalInterval,
+    TemporalTagged, TimeAxis, Timestamp, TransactionTime,
+};
+use type_system::url::VersionedUrl;
+use uuid::Uuid;
+use validation::{EntityPreprocessor, Validate, ValidateEntityComponents};
+
+use super::{
+    MemoryStore,
+    query::{CompiledFilter, Node, QueryContext, RecordKind, aggregate_rows},
+    read::matching_nodes,
+    state::{
+        ChangeRecordId, EntityEditionRecord, EntityEmbeddingRecord, EntityIdRecord,
+        EntityTemporalRow, MemoryState,
+    },
+    transaction_time,
+};
+use crate::store::{
+    AggregationFunction, EntityStore, InsertionError, QueryError, StoreCache, StoreProvider,
+    UpdateError,
+    change_feed::ChangeKind,
+    crud::{QueryResult, Read, ReadPaginated, Sorting},
+    error::{EntityDoesNotExist, EntityEditionConflict, RaceConditionOnUpdate},
+    knowledge::{
+        AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+        CreateEntityParams, EntityPathHop, EntityPathSearch, EntityQuerySorting,
+        EntityTraversalPath, EntityValidationType, FindEntityPathsParams, FindEntityPathsResponse,
+        GetEntitiesParams, GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse,
+        LinkTraversalDirection, PatchEntityParams, QueryConversion, UpdateEntityEmbeddingsParams,
+        ValidateEntityError, ValidateEntityParams,
+    },
+    postgres::{ResponseCountMap, TraversalContext},
+};
+
+#[derive(Debug)]
+#[expect(clippy::struct_excessive_bools, reason = "Parameter struct")]
+struct GetEntitiesImplParams<'a> {
+    filter: Filter<'a, Entity>,
+    sorting: EntityQuerySorting<'static>,
+    limit: Option<usize>,
+    include_drafts: bool,
+    include_count: bool,
+    include_web_ids: bool,
+    include_created_by_ids: bool,
+    include_edition_created_by_ids: bool,
+    include_type_ids: bool,
+}
+
+/// An entity which has to be traversed, together with the depths and the interval used to
+/// traverse it.
+type EntityTraversalData = (
+    EntityVertexId,
+    GraphResolveDepths,
+    RightBoundedTemporalInterval<VariableAxis>,
+);
+
+/// The result of an entity-to-ontology edge traversal.
+struct SharedEdgeTraversal {
+    left_endpoint: EntityVertexId,
+    right_endpoint: VersionedUrl,
+    resolve_depths: GraphResolveDepths,
+    traversal_interval: RightBoundedTemporalInterval<VariableAxis>,
+}
+
+/// The result of an entity-to-entity edge traversal.
+struct KnowledgeEdgeTraversal {
+    left_endpoint: EntityVertexId,
+    right_endpoint: EntityVertexId,
+    right_endpoint_edition_id: EntityEditionId,
+    resolve_depths: GraphResolveDepths,
+    edge_interval: LeftClosedTemporalInterval<VariableAxis>,
+    traversal_interval: RightBoundedTemporalInterval<VariableAxis>,
+}
+
+/// Intersects the `traversal_interval` with the `interval` of a temporal row.
+///
+/// Returns `None` if both intervals don't overlap.
+fn intersect_traversal_interval(
+    traversal_interval: RightBoundedTemporalInterval<VariableAxis>,
+    interval: LeftClosedTemporalInterval<VariableAxis>,
+) -> Option<RightBoundedTemporalInterval<VariableAxis>> {
+    let traversal_interval: TemporalInterval<VariableAxis> = traversal_interval.convert();
+    traversal_interval
+        .intersect(interval.convert())
+        .map(|intersection| intersection.convert())
+}
+
+impl EntityTemporalRow {
+    fn variable_interval(
+        &self,
+        variable_axis: TimeAxis,
+    ) -> LeftClosedTemporalInterval<VariableAxis> {
+        match variable_axis {
+            TimeAxis::DecisionTime => self.decision_time.cast(),
+            TimeAxis::TransactionTime => self.transaction_time.cast(),
+        }
+    }
+
+    fn contains_pinned_timestamp(
+        &self,
+        variable_axis: TimeAxis,
+        pinned_timestamp: Timestamp<PinnedAxis>,
+    ) -> bool {
+        match variable_axis {
+            TimeAxis::DecisionTime => self
+                .transaction_time
+                .contains_point(&pinned_timestamp.cast()),
+            TimeAxis::TransactionTime => {
+                self.decision_time.contains_point(&pinned_timestamp.cast())
+            }
+        }
+    }
+
+    fn is_revision_of(
+        &self,
+        vertex_id: &EntityVertexId,
+        variable_axis: TimeAxis,
+        pinned_timestamp: Timestamp<PinnedAxis>,
+    ) -> bool {
+        let ClosedTemporalBound::Inclusive(revision_id) =
+            *self.variable_interval(variable_axis).start();
+        self.entity_id.owned_by_id == vertex_id.base_id.owned_by_id
+            && self.entity_id.entity_uuid == vertex_id.base_id.entity_uuid
+            && revision_id == vertex_id.revision_id
+            && self.contains_pinned_timestamp(variable_axis, pinned_timestamp)
+    }
+}
+
+impl MemoryState {
+    /// Returns the index of the temporal row of `entity_id` which is valid at the given times.
+    fn locked_entity_row(
+        &self,
+        entity_id: EntityId,
+        transaction_time: Timestamp<TransactionTime>,
+        decision_time: Timestamp<DecisionTime>,
+    ) -> Option<usize> {
+        self.entity_temporal_metadata.iter().position(|row| {
+            row.entity_id == entity_id
+                && row.transaction_time.contains_point(&transaction_time)
+                && row.decision_time.contains_point(&decision_time)
+        })
+    }
+
+    fn insert_entity_edition(
+        &mut self,
+        archived: bool,
+        entity_type_ids: &HashSet<VersionedUrl>,
+        properties: &PropertyObject,
+        confidence: Option<Confidence>,
+        provenance: &EntityEditionProvenance,
+        metadata: &PropertyMetadataObject,
+    ) -> EntityEditionId {
+        let edition_id = EntityEditionId::new(Uuid::new_v4());
+        self.entity_editions
+            .insert(edition_id, EntityEditionRecord {
+                properties: properties.clone(),
+                property_metadata: metadata.clone(),
+                archived,
+                confidence,
+                provenance: provenance.clone(),
+                entity_type_ids: entity_type_ids.clone(),
+            });
+        edition_id
+    }
+
+    /// Points the locked row to the new edition and keeps the history of the previous edition.
+    fn update_temporal_metadata(
+        &mut self,
+        locked_row: usize,
+        transaction_time: Timestamp<TransactionTime>,
+        decision_time: Timestamp<DecisionTime>,
+        entity_edition_id: EntityEditionId,
+        undraft: bool,
+    ) -> EntityTemporalMetadata {
+        let locked = self.entity_temporal_metadata[locked_row].clone();
+        let ClosedTemporalBound::Inclusive(locked_transaction_time) =
+            *locked.transaction_time.start();
+        let ClosedTemporalBound::Inclusive(locked_decision_time) = *locked.decision_time.start();
+
+        let row = &mut self.entity_temporal_metadata[locked_row];
+        row.transaction_time = LeftClosedTemporalInterval::new(
+            ClosedTemporalBound::Inclusive(transaction_time),
+            OpenTemporalBound::Unbounded,
+        );
+        row.decision_time = LeftClosedTemporalInterval::new(
+            ClosedTemporalBound::Inclusive(decision_time),
+            *locked.decision_time.end(),
+        );
+        row.edition_id = entity_edition_id;
+        if undraft {
+            row.entity_id.draft_id = None;
+        }
+        let temporal_versioning = EntityTemporalMetadata {
+            decision_time: row.decision_time,
+            transaction_time: row.transaction_time,
+        };
+
+        if locked_transaction_time < transaction_time {
+            self.entity_temporal_metadata.push(EntityTemporalRow {
+                transaction_time: LeftClosedTemporalInterval::new(
+                    ClosedTemporalBound::Inclusive(locked_transaction_time),
+                    OpenTemporalBound::Exclusive(transaction_time),
+                ),
+                ..locked.clone()
+            });
+        }
+        if locked_decision_time < decision_time {
+            self.entity_temporal_metadata.push(EntityTemporalRow {
+                transaction_time: LeftClosedTemporalInterval::new(
+                    ClosedTemporalBound::Inclusive(transaction_time),
+                    OpenTemporalBound::Unbounded,
+                ),
+                decision_time: LeftClosedTemporalInterval::new(
+                    ClosedTemporalBound::Inclusive(locked_decision_time),
+                    OpenTemporalBound::Exclusive(decision_time),
+                ),
+                ..locked
+            });
+        }
+
+        temporal_versioning
+    }
+
+    /// Ends the decision time of the locked row and keeps the history of the archived edition.
+    fn archive_entity(
+        &mut self,
+        actor_id: AccountId,
+        locked_row: usize,
+        transaction_time: Timestamp<TransactionTime>,
+        decision_time: Timestamp<DecisionTime>,
+    ) {
+        let locked = self.entity_temporal_metadata[locked_row].clone();
+        let archived_edition_id = locked.edition_id;
+        let ClosedTemporalBound::Inclusive(locked_transaction_time) =
+            *locked.transaction_time.start();
+        let ClosedTemporalBound::Inclusive(locked_decision_time) = *locked.decision_time.start();
+
+        if locked_decision_time < decision_time {
+            let row = &mut self.entity_temporal_metadata[locked_row];
+            row.transaction_time = LeftClosedTemporalInterval::new(
+                ClosedTemporalBound::Inclusive(transaction_time),
+                OpenTemporalBound::Unbounded,
+            );
+            row.decision_time = LeftClosedTemporalInterval::new(
+                ClosedTemporalBound::Inclusive(locked_decision_time),
+                OpenTemporalBound::Exclusive(decision_time),
+            );
+        } else {
+            // The archived edition was never valid at the current transaction time.
+            self.entity_temporal_metadata.remove(locked_row);
+        }
+
+        if locked_transaction_time < transaction_time {
+            self.entity_temporal_metadata.push(EntityTemporalRow {
+                transaction_time: LeftClosedTemporalInterval::new(
+                    ClosedTemporalBound::Inclusive(locked_transaction_time),
+                    OpenTemporalBound::Exclusive(transaction_time),
+                ),
+                ..locked
+            });
+        }
+
+        if let Some(edition) = self.entity_editions.get_mut(&archived_edition_id) {
+            edition.provenance.archived_by_id = Some(EditionArchivedById::new(actor_id));
+        }
+    }
+
+    /// Returns the entities on the other side of the link edge of `entity_id`.
+    fn knowledge_edge_targets(
+        &self,
+        owned_by_id: OwnedById,
+        entity_uuid: EntityUuid,
+        edge_kind: KnowledgeGraphEdgeKind,
+        edge_direction: EdgeDirection,
+    ) -> Vec<(OwnedById, EntityUuid)> {
+        let endpoint = |record: &EntityIdRecord| {
+            record.link_data.as_ref().map(|link_data| match edge_kind {
+                KnowledgeGraphEdgeKind::HasLeftEntity => link_data.left_entity_id,
+                KnowledgeGraphEdgeKind::HasRightEntity => link_data.right_entity_id,
+            })
+        };
+
+        match edge_direction {
+            EdgeDirection::Outgoing => self
+                .entity_ids
+                .get(&(owned_by_id, entity_uuid))
+                .and_then(endpoint)
+                .map(|target| (target.owned_by_id, target.entity_uuid))
+                .into_iter()
+                .collect(),
+            EdgeDirection::Incoming => self
+                .entity_ids
+                .iter()
+                .filter(|(_, record)| {
+                    endpoint(record).is_some_and(|source| {
+                        source.owned_by_id == owned_by_id && source.entity_uuid == entity_uuid
+                    })
+                })
+                .map(|(id, _)| *id)
+                .collect(),
+        }
+    }
+
+    /// Returns the entity types of the entities in `traversal_data`.
+    ///
+    /// Only the entity types the entity is directly of are returned, inherited entity types are not
+    /// considered.
+    fn read_shared_edges(
+        &self,
+        traversal_data: &[EntityTraversalData],
+        pinned_timestamp: Timestamp<PinnedAxis>,
+        variable_axis: TimeAxis,
+    ) -> Vec<SharedEdgeTraversal> {
+        let mut edges = Vec::new();
+        for (vertex_id, resolve_depths, traversal_interval) in traversal_data {
+            for source in self
+                .entity_temporal_metadata
+                .iter()
+                .filter(|row| row.is_revision_of(vertex_id, variable_axis, pinned_timestamp))
+            {
+                let Some(traversal_interval) = intersect_traversal_interval(
+                    *traversal_interval,
+                    source.variable_interval(variable_axis),
+                ) else {
+                    continue;
+                };
+                let Some(edition) = self.entity_editions.get(&source.edition_id) else {
+                    continue;
+                };
+
+                edges.extend(edition.entity_type_ids.iter().map(|entity_type_id| {
+                    SharedEdgeTraversal {
+                        left_endpoint: EntityVertexId {
+                            base_id: EntityId {
+                                draft_id: None,
+                                ..vertex_id.base_id
+                            },
+                            revision_id: vertex_id.revision_id,
+                        },
+                        right_endpoint: entity_type_id.clone(),
+                        resolve_depths: *resolve_depths,
+                        traversal_interval,
+                    }
+                }));
+            }
+        }
+        edges
+    }
+
+    /// Returns the entities connected to the entities in `traversal_data` by the given edge.
+    fn read_knowledge_edges(
+        &self,
+        traversal_data: &[EntityTraversalData],
+        edge_kind: KnowledgeGraphEdgeKind,
+        edge_direction: EdgeDirection,
+        pinned_timestamp: Timestamp<PinnedAxis>,
+        variable_axis: TimeAxis,
+    ) -> Vec<(EntityId, KnowledgeEdgeTraversal)> {
+        let mut edges = Vec::new();
+        for (vertex_id, resolve_depths, traversal_interval) in traversal_data {
+            let targets = self.knowledge_edge_targets(
+                vertex_id.base_id.owned_by_id,
+                vertex_id.base_id.entity_uuid,
+                edge_kind,
+                edge_direction,
+            );
+
+            for source in self
+                .entity_temporal_metadata
+                .iter()
+                .filter(|row| row.is_revision_of(vertex_id, variable_axis, pinned_timestamp))
+            {
+                let source_interval = source.variable_interval(variable_axis);
+
+                for target in self.entity_temporal_metadata.iter().filter(|row| {
+                    targets.contains(&(row.entity_id.owned_by_id, row.entity_id.entity_uuid))
+                        && row.contains_pinned_timestamp(variable_axis, pinned_timestamp)
+                }) {
+                    let target_interval = target.variable_interval(variable_axis);
+                    let Some(edge_interval) = source_interval.intersect(target_interval) else {
+                        continue;
+                    };
+                    let Some(traversal_interval) =
+                        intersect_traversal_interval(*traversal_interval, edge_interval)
+                    else {
+                        continue;
+                    };
+
+                    let ClosedTemporalBound::Inclusive(revision_id) = *target_interval.start();
+                    let right_endpoint_base_id = EntityId {
+                        owned_by_id: target.entity_id.owned_by_id,
+                        entity_uuid: target.entity_id.entity_uuid,
+                        draft_id: None,
+                    };
+                    edges.push((right_endpoint_base_id, KnowledgeEdgeTraversal {
+                        left_endpoint: EntityVertexId {
+                            base_id: EntityId {
+                                draft_id: None,
+                                ..vertex_id.base_id
+                            },
+                            revision_id: vertex_id.revision_id,
+                        },
+                        right_endpoint: EntityVertexId {
+                            base_id: right_endpoint_base_id,
+                            revision_id,
+                        },
+                        right_endpoint_edition_id: target.edition_id,
+                        resolve_depths: *resolve_depths,
+                        edge_interval,
+                        traversal_interval,
+                    }));
+                }
+            }
+        }
+        edges
+    }
+}
+
+async fn convert_entity_properties<P: DataTypeProvider + Sync>(
+    provider: &P,
+    entity: &mut PropertyWithMetadata,
+    path: &PropertyPath<'_>,
+    target_data_type_id: &VersionedUrl,
+) {
+    let Ok(PropertyWithMetadata::Value(PropertyWithMetadataValue { value, metadata })) =
+        entity.get_mut(path.as_ref())
+    else {
+        // If the property does not exist or is not a value, we can ignore it.
+        return;
+    };
+
+    let Some(source_data_type_id) = &mut metadata.data_type_id else {
+        // If the property does not have a data type, we can ignore it.
+        return;
+    };
+
+    let Ok(conversions) = provider
+        .find_conversion(source_data_type_id, target_data_type_id)
+        .await
+    else {
+        // If no conversion is found, we can ignore the property.
+        return;
+    };
+
+    let Some(mut value_number) = value.as_f64() else {
+        // If the value is not a number, we can ignore the property.
+        return;
+    };
+
+    for conversion in conversions.borrow() {
+        value_number = conversion.evaluate(value_number);
+    }
+    drop(conversions);
+
+    *value = JsonValue::from(value_number);
+
+    metadata.data_type_id = Some(target_data_type_id.clone());
+}
+
+async fn convert_entity<P: DataTypeProvider + Sync>(
+    provider: &P,
+    entity: &mut Entity,
+    conversions: &[QueryConversion<'_>],
+) -> Result<(), PropertyPathError> {
+    let mut property = PropertyWithMetadata::Object(PropertyWithMetadataObject::from_parts(
+        mem::take(&mut entity.properties),
+        Some(mem::take(&mut entity.metadata.properties)),
+    )?);
+    for conversion in conversions {
+        convert_entity_properties(
+            provider,
+            &mut property,
+            &conversion.path,
+            &conversion.data_type_id,
+        )
+        .await;
+    }
+    let PropertyWithMetadata::Object(property) = property else {
+        unreachable!("The property was just converted to an object");
+    };
+    let (properties, metadata) = property.into_parts();
+    entity.properties = properties;
+    entity.metadata.properties = metadata;
+    Ok(())
+}
+
+impl<A> MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    /// Traverses the entities in `entity_queue` and adds the edges to the `subgraph`.
+    ///
+    /// Entity types found while traversing are traversed afterwards.
+    #[tracing::instrument(level = "info", skip(self, traversal_context, subgraph, zookie))]
+    async fn traverse_entities(
+        &self,
+        mut entity_queue: Vec<EntityTraversalData>,
+        traversal_context: &mut TraversalContext,
+        actor_id: AccountId,
+        zookie: &Zookie<'static>,
+        subgraph: &mut Subgraph,
+    ) -> Result<(), QueryError> {
+        let variable_axis = subgraph.temporal_axes.resolved.variable_time_axis();
+        let pinned_timestamp = subgraph.temporal_axes.resolved.pinned_timestamp();
+
+        let mut entity_type_queue = Vec::new();
+
+        while !entity_queue.is_empty() {
+            let mut shared_edges_to_traverse = Vec::new();
+            let mut knowledge_edges_to_traverse =
+                HashMap::<(KnowledgeGraphEdgeKind, EdgeDirection), Vec<EntityTraversalData>>::new();
+
+            let entity_edges = [
+                (
+                    KnowledgeGraphEdgeKind::HasLeftEntity,
+                    EdgeDirection::Incoming,
+                ),
+                (
+                    KnowledgeGraphEdgeKind::HasRightEntity,
+                    EdgeDirection::Incoming,
+                ),
+                (
+                    KnowledgeGraphEdgeKind::HasLeftEntity,
+                    EdgeDirection::Outgoing,
+                ),
+                (
+                    KnowledgeGraphEdgeKind::HasRightEntity,
+                    EdgeDirection::Outgoing,
+                ),
+            ];
+
+            for (entity_vertex_id, graph_resolve_depths, traversal_interval) in
+                mem::take(&mut entity_queue)
+            {
+                if let Some(new_graph_resolve_depths) = graph_resolve_depths
+                    .decrement_depth_for_edge(SharedEdgeKind::IsOfType, EdgeDirection::Outgoing)
+                {
+                    shared_edges_to_traverse.push((
+                        entity_vertex_id,
+                        new_graph_resolve_depths,
+                        traversal_interval,
+                    ));
+                }
+
+                for (edge_kind, edge_direction) in entity_edges {
+                    if let Some(new_graph_resolve_depths) =
+                        graph_resolve_depths.decrement_depth_for_edge(edge_kind, edge_direction)
+                    {
+                        knowledge_edges_to_traverse
+                            .entry((edge_kind, edge_direction))
+                            .or_default()
+                            .push((
+                                entity_vertex_id,
+                                new_graph_resolve_depths,
+                                traversal_interval,
+                            ));
+                    }
+                }
+            }
+
+            if !shared_edges_to_traverse.is_empty() {
+                let edges = self.state.read().await.read_shared_edges(
+                    &shared_edges_to_traverse,
+                    pinned_timestamp,
+                    variable_axis,
+                );
+
+                let (permissions, _) = self
+                    .authorization_api
+                    .check_entity_types_permission(
+                        actor_id,
+                        EntityTypePermission::View,
+                        edges
+                            .iter()
+                            .map(|edge| EntityTypeId::from_url(&edge.right_endpoint)),
+                        Consistency::AtExactSnapshot(zookie),
+                    )
+                    .await
+                    .change_context(QueryError)?;
+
+                for edge in edges {
+                    let entity_type_id = EntityTypeId::from_url(&edge.right_endpoint);
+                    if !permissions.get(&entity_type_id).copied().unwrap_or(false) {
+                        continue;
+                    }
+
+                    subgraph.insert_edge(
+                        &edge.left_endpoint,
+                        SharedEdgeKind::IsOfType,
+                        EdgeDirection::Outgoing,
+                        EntityTypeVertexId::from(edge.right_endpoint.clone()),
+                    );
+
+                    entity_type_queue.extend(
+                        traversal_context
+                            .add_entity_type_id(
+                                entity_type_id,
+                                edge.resolve_depths,
+                                edge.traversal_interval,
+                            )
+                            .map(|(_, resolve_depths, traversal_interval)| {
+                                (
+                                    edge.right_endpoint.clone(),
+                                    resolve_depths,
+                                    traversal_interval,
+                                )
+                            }),
+                    );
+                }
+            }
+
+            for (edge_kind, edge_direction) in entity_edges {
+                let Some(traversal_data) =
+                    knowledge_edges_to_traverse.get(&(edge_kind, edge_direction))
+                else {
+                    continue;
+                };
+
+                let (entity_ids, knowledge_edges): (Vec<_>, Vec<_>) = self
+                    .state
+                    .read()
+                    .await
+                    .read_knowledge_edges(
+                        traversal_data,
+                        edge_kind,
+                        edge_direction,
+                        pinned_timestamp,
+                        variable_axis,
+                    )
+                    .into_iter()
+                    .unzip();
+
+                if knowledge_edges.is_empty() {
+                    continue;
+                }
+
+                let permissions = self
+                    .authorization_api
+                    .check_entities_permission(
+                        actor_id,
+                        EntityPermission::View,
+                        entity_ids.iter().copied(),
+                        Consistency::AtExactSnapshot(zookie),
+                    )
+                    .await
+                    .change_context(QueryError)?
+                    .0;
+
+                entity_queue.extend(
+                    knowledge_edges
+                        .into_iter()
+                        .zip(entity_ids)
+                        .filter_map(|(edge, entity_id)| {
+                            permissions
+                                .get(&entity_id.entity_uuid)
+                                .copied()
+                                .unwrap_or(true)
+                                .then_some(edge)
+                        })
+                        .flat_map(|edge| {
+                            subgraph.insert_edge(
+                                &edge.left_endpoint,
+                                edge_kind,
+                                edge_direction,
+                                EntityIdWithInterval {
+                                    entity_id: edge.right_endpoint.base_id,
+                                    interval: edge.edge_interval,
+                                },
+                            );
+
+                            traversal_context
+                                .add_entity_id(
+                                    edge.right_endpoint_edition_id,
+                                    edge.resolve_depths,
+                                    edge.traversal_interval,
+                                )
+                                .map(move |(_, resolve_depths, interval)| {
+                                    (edge.right_endpoint, resolve_depths, interval)
+                                })
+                        }),
+                );
+            }
+        }
+
+        self.traverse_entity_types(
+            entity_type_queue,
+            traversal_context,
+            actor_id,
+            zookie,
+            subgraph,
+        )
+        .await
+    }
+
+    /// Follows the `traversal_paths` starting at the `root_entities` and adds the traversed link
+    /// entities, their endpoints, and the edges between them to the `subgraph`.
+    ///
+    /// See [`PostgresStore::traverse_entity_paths`] for the semantics of a single hop.
+    ///
+    /// [`PostgresStore::traverse_entity_paths`]: crate::store::PostgresStore::traverse_entity_paths
+    #[tracing::instrument(level = "info", skip_all)]
+    async fn traverse_entity_paths(
+        &self,
+        root_entities: &[(EntityVertexId, RightBoundedTemporalInterval<VariableAxis>)],
+        traversal_paths: &[EntityTraversalPath<'_>],
+        actor_id: AccountId,
+        zookie: &Zookie<'static>,
+        include_drafts: bool,
+        subgraph: &mut Subgraph,
+    ) -> Result<(), QueryError> {
+        let variable_axis = subgraph.temporal_axes.resolved.variable_time_axis();
+        let pinned_timestamp = subgraph.temporal_axes.resolved.pinned_timestamp();
+
+        for traversal_path in traversal_paths {
+            let mut entities = root_entities.to_vec();
+
+            for hop in &traversal_path.hops {
+                let (link_edge_kind, endpoint_edge_kind) = match hop.direction {
+                    LinkTraversalDirection::Outgoing => (
+                        KnowledgeGraphEdgeKind::HasLeftEntity,
+                        KnowledgeGraphEdgeKind::HasRightEntity,
+                    ),
+                    LinkTraversalDirection::Incoming => (
+                        KnowledgeGraphEdgeKind::HasRightEntity,
+                        KnowledgeGraphEdgeKind::HasLeftEntity,
+                    ),
+                };
+
+                for (edge_kind, edge_direction, filter) in [
+                    (link_edge_kind, EdgeDirection::Incoming, &hop.link_filter),
+                    (
+                        endpoint_edge_kind,
+                        EdgeDirection::Outgoing,
+                        &hop.endpoint_filter,
+                    ),
+                ] {
+                    if entities.is_empty() {
+                        break;
+                    }
+
+                    let traversal_data = mem::take(&mut entities)
+                        .into_iter()
+                        .map(|(entity_vertex_id, traversal_interval)| {
+                            (
+                                entity_vertex_id,
+                                GraphResolveDepths::default(),
+                                traversal_interval,
+                            )
+                        })
+                        .collect::<Vec<_>>();
+
+                    let edges = self
+                        .state
+                        .read()
+                        .await
+                        .read_knowledge_edges(
+                            &traversal_data,
+                            edge_kind,
+                            edge_direction,
+                            pinned_timestamp,
+                            variable_axis,
+                        )
+                        .into_iter()
+                        .map(|(_, edge)| edge)
+                        .collect::<Vec<_>>();
+                    if edges.is_empty() {
+                        break;
+                    }
+
+                    let filter = Filter::All(vec![
+                        filter.clone(),
+                        Filter::In(
+                            FilterExpression::Path {
+                                path: EntityQueryPath::EditionId,
+                            },
+                            ParameterList::EntityEditionIds(Cow::Owned(
+                                edges
+                                    .iter()
+                                    .map(|edge| edge.right_endpoint_edition_id)
+                                    .collect(),
+                            )),
+                        ),
+                    ]);
+                    let matching_entities = Read::<Entity>::read_vec(
+                        self,
+                        &filter,
+                        Some(&subgraph.temporal_axes.resolved),
+                        include_drafts,
+                    )
+                    .await?;
+
+                    let permissions = self
+                        .authorization_api
+                        .check_entities_permission(
+                            actor_id,
+                            EntityPermission::View,
+                            matching_entities
+                                .iter()
+                                .map(|entity| entity.metadata.record_id.entity_id),
+                            Consistency::AtExactSnapshot(zookie),
+                        )
+                        .await
+                        .change_context(QueryError)?
+                        .0;
+
+                    let mut permitted_edition_ids = HashSet::new();
+                    for entity in matching_entities {
+                        if permissions
+                            .get(&entity.metadata.record_id.entity_id.entity_uuid)
+                            .copied()
+                            .unwrap_or(false)
+                        {
+                            permitted_edition_ids.insert(entity.metadata.record_id.edition_id);
+                            subgraph
+                                .vertices
+                                .entities
+                                .entry(entity.vertex_id(variable_axis))
+                                .or_insert(entity);
+                        }
+                    }
+
+                    entities.extend(
+                        edges
+                            .into_iter()
+                            .filter(|edge| {
+                                permitted_edition_ids.contains(&edge.right_endpoint_edition_id)
+                            })
+                            .map(|edge| {
+                                subgraph.insert_edge(
+                                    &edge.left_endpoint,
+                                    edge_kind,
+                                    edge_direction,
+                                    EntityIdWithInterval {
+                                        entity_id: edge.right_endpoint.base_id,
+                                        interval: edge.edge_interval,
+                                    },
+                                );
+                                (edge.right_endpoint, edge.traversal_interval)
+                            }),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the editions out of `edition_ids` which satisfy the `filter` and which the actor is
+    /// permitted to view.
+    #[tracing::instrument(level = "trace", skip_all)]
+    async fn filter_permitted_entity_editions(
+        &self,
+        actor_id: AccountId,
+        edition_ids: Vec<EntityEditionId>,
+        filter: Option<&Filter<'_, Entity>>,
+        temporal_axes: &QueryTemporalAxes,
+        include_drafts: bool,
+    ) -> Result<HashSet<EntityEditionId>, QueryError> {
+        let edition_filter = Filter::In(
+            FilterExpression::Path {
+                path: EntityQueryPath::EditionId,
+            },
+            ParameterList::EntityEditionIds(Cow::Owned(edition_ids)),
+        );
+        let filter = match filter {
+            Some(filter) => Filter::All(vec![filter.clone(), edition_filter]),
+            None => edition_filter,
+        };
+
+        let entities = Read::<Entity>::read(self, &filter, Some(temporal_axes), include_drafts)
+            .await?
+            .map_ok(|entity| {
+                (
+                    entity.metadata.record_id.entity_id,
+                    entity.metadata.record_id.edition_id,
+                )
+            })
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let permissions = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entities.iter().map(|(entity_id, _)| *entity_id),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0;
+
+        Ok(entities
+            .into_iter()
+            .filter(|(entity_id, _)| {
+                permissions
+                    .get(&entity_id.entity_uuid)
+                    .copied()
+                    .unwrap_or(false)
+            })
+            .map(|(_, edition_id)| edition_id)
+            .collect())
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn get_entities_impl(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntitiesImplParams<'_>,
+        temporal_axes: &QueryTemporalAxes,
+    ) -> Result<(GetEntitiesResponse<'static>, Zookie<'static>), QueryError> {
+        let mut root_entities = Vec::new();
+
+        let (permissions, count, web_ids, created_by_ids, edition_created_by_ids, type_ids) =
+            if params.include_count
+                || params.include_web_ids
+                || params.include_created_by_ids
+                || params.include_edition_created_by_ids
+                || params.include_type_ids
+            {
+                let mut web_ids = params.include_web_ids.then(ResponseCountMap::default);
+                let mut created_by_ids = params
+                    .include_created_by_ids
+                    .then(ResponseCountMap::default);
+                let mut edition_created_by_ids = params
+                    .include_edition_created_by_ids
+                    .then(ResponseCountMap::default);
+                let mut include_type_ids = params.include_type_ids.then(ResponseCountMap::default);
+
+                let entity_ids = Read::<Entity>::read(
+                    self,
+                    &params.filter,
+                    Some(temporal_axes),
+                    params.include_drafts,
+                )
+                .await?
+                .map_ok(|entity| {
+                    if let Some(web_ids) = &mut web_ids {
+                        web_ids.increment(&entity.metadata.record_id.entity_id.owned_by_id);
+                    }
+                    if let Some(created_by_ids) = &mut created_by_ids {
+                        created_by_ids
+                            .increment(&entity.metadata.provenance.inferred.created_by_id);
+                    }
+                    if let Some(edition_created_by_ids) = &mut edition_created_by_ids {
+                        edition_created_by_ids
+                            .increment(&entity.metadata.provenance.edition.created_by_id);
+                    }
+                    if let Some(include_type_ids) = &mut include_type_ids {
+                        for entity_type_id in &entity.metadata.entity_type_ids {
+                            include_type_ids.increment(entity_type_id);
+                        }
+                    }
+                    entity.metadata.record_id.entity_id
+                })
+                .try_collect::<Vec<_>>()
+                .await?;
+
+                let (permissions, zookie) = self
+                    .authorization_api
+                    .check_entities_permission(
+                        actor_id,
+                        EntityPermission::View,
+                        entity_ids.iter().copied(),
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(QueryError)?;
+
+                let permitted_ids = permissions
+                    .into_iter()
+                    .filter_map(|(entity_id, has_permission)| has_permission.then_some(entity_id))
+                    .collect::<HashSet<_>>();
+
+                let count = entity_ids
+                    .into_iter()
+                    .filter(|id| permitted_ids.contains(&id.entity_uuid))
+                    .count();
+                (
+                    Some((permitted_ids, zookie)),
+                    Some(count),
+                    web_ids.map(HashMap::from),
+                    created_by_ids.map(HashMap::from),
+                    edition_created_by_ids.map(HashMap::from),
+                    include_type_ids.map(HashMap::from),
+                )
+            } else {
+                (None, None, None, None, None, None)
+            };
+
+        let (latest_zookie, last) = loop {
+            let (rows, artifacts) =
+                ReadPaginated::<Entity, EntityQuerySorting>::read_paginated_vec(
+                    self,
+                    &params.filter,
+                    Some(temporal_axes),
+                    &params.sorting,
+                    params.limit,
+                    params.include_drafts,
+                )
+                .await?;
+            let entities = rows
+                .into_iter()
+                .map(|row| (row.decode_record(&artifacts), row))
+                .collect::<Vec<_>>();
+            if let Some((_, row)) = entities.last() {
+                params.sorting.set_cursor(row.decode_cursor(&artifacts));
+            }
+
+            let num_returned_entities = entities.len();
+
+            let (permitted_ids, zookie) = if let Some((permitted_ids, zookie)) = &permissions {
+                (
+                    Cow::<HashSet<EntityUuid>>::Borrowed(permitted_ids),
+                    Cow::<Zookie>::Borrowed(zookie),
+                )
+            } else {
+                let filtered_ids = entities
+                    .iter()
+                    .map(|(entity, _)| entity.metadata.record_id.entity_id)
+                    .collect::<HashSet<_>>();
+
+                let (permissions, zookie) = self
+                    .authorization_api
+                    .check_entities_permission(
+                        actor_id,
+                        EntityPermission::View,
+                        filtered_ids,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(QueryError)?;
+
+                (
+                    Cow::Owned(
+                        permissions
+                            .into_iter()
+                            .filter_map(|(entity_id, has_permission)| {
+                                has_permission.then_some(entity_id)
+                            })
+                            .collect::<HashSet<_>>(),
+                    ),
+                    Cow::Owned(zookie),
+                )
+            };
+
+            root_entities.extend(
+                entities
+                    .into_iter()
+                    .filter(|(entity, _)| {
+                        permitted_ids.contains(&entity.metadata.record_id.entity_id.entity_uuid)
+                    })
+                    .take(params.limit.unwrap_or(usize::MAX) - root_entities.len()),
+            );
+
+            if let Some(limit) = params.limit {
+                if num_returned_entities < limit {
+                    // When the returned entities are less than the requested amount we know
+                    // that there are no more entities to return.
+                    break (zookie, None);
+                }
+                if root_entities.len() == limit {
+                    // The requested limit is reached, so we can stop here.
+                    break (
+                        zookie,
+                        root_entities
+                            .last()
+                            .map(|(_, row)| row.decode_cursor(&artifacts)),
+                    );
+                }
+            } else {
+                // Without a limit all entities are returned.
+                break (zookie, None);
+            }
+        };
+
+        Ok((
+            GetEntitiesResponse {
+                entities: root_entities
+                    .into_iter()
+                    .map(|(entity, _)| entity)
+                    .collect(),
+                cursor: last,
+                count,
+                web_ids,
+                created_by_ids,
+                edition_created_by_ids,
+                type_ids,
+            },
+            latest_zookie.into_owned(),
+        ))
+    }
+}
+
+impl<A> EntityStore for MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn create_entities<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: Vec<CreateEntityParams<R>>,
+    ) -> Result<Vec<Entity>, InsertionError>
+    where
+        R: IntoIterator<Item = EntityRelationAndSubject> + Send,
+    {
+        let transaction_time = transaction_time();
+        let mut relationships = Vec::with_capacity(params.len());
+        let mut entity_type_ids = HashMap::new();
+        let mut checked_web_ids = HashSet::new();
+
+        let mut entities = Vec::with_capacity(params.len());
+        let mut validation_params = Vec::with_capacity(params.len());
+
+        let validator_provider = StoreProvider {
+            store: &*self,
+            cache: StoreCache::default(),
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+
+        for mut params in params {
+            let entity_type = validator_provider
+                .provide_closed_type(&params.entity_type_ids)
+                .await
+                .change_context(InsertionError)?;
+
+            let validation_components = if params.draft {
+                ValidateEntityComponents {
+                    num_items: false,
+                    required_properties: false,
+                    ..ValidateEntityComponents::full()
+                }
+            } else {
+                ValidateEntityComponents::full()
+            };
+            EntityPreprocessor {
+                components: validation_components,
+            }
+            .visit_object(&entity_type, &mut params.properties, &validator_provider)
+            .await
+            .attach(StatusCode::InvalidArgument)
+            .change_context(InsertionError)?;
+            validation_params.push((entity_type, validation_components));
+
+            let (properties, property_metadata) = params.properties.into_parts();
+
+            let decision_time = params
+                .decision_time
+                .map_or_else(|| transaction_time.cast(), Timestamp::remove_nanosecond);
+            let entity_id = EntityId {
+                owned_by_id: params.owned_by_id,
+                entity_uuid: params
+                    .entity_uuid
+                    .unwrap_or_else(|| EntityUuid::new(Uuid::new_v4())),
+                draft_id: params.draft.then(|| DraftId::new(Uuid::new_v4())),
+            };
+
+            if entity_id.entity_uuid.as_uuid() != entity_id.owned_by_id.as_uuid() {
+                checked_web_ids.insert(entity_id.owned_by_id);
+            }
+
+            let entity_provenance = EntityProvenance {
+                inferred: InferredEntityProvenance {
+                    created_by_id: CreatedById::new(actor_id),
+                    created_at_transaction_time: transaction_time,
+                    created_at_decision_time: decision_time,
+                    first_non_draft_created_at_transaction_time: entity_id
+                        .draft_id
+                        .is_none()
+                        .then_some(transaction_time),
+                    first_non_draft_created_at_decision_time: entity_id
+                        .draft_id
+                        .is_none()
+                        .then_some(decision_time),
+                },
+                edition: EntityEditionProvenance {
+                    created_by_id: EditionCreatedById::new(actor_id),
+                    archived_by_id: None,
+                    provided: params.provenance,
+                },
+            };
+
+            for entity_type_url in &params.entity_type_ids {
+                entity_type_ids.insert(
+                    EntityTypeId::from_url(entity_type_url),
+                    entity_type_url.clone(),
+                );
+            }
+
+            entities.push(Entity {
+                properties,
+                link_data: params.link_data,
+                metadata: EntityMetadata {
+                    record_id: EntityRecordId {
+                        entity_id,
+                        edition_id: EntityEditionId::new(Uuid::new_v4()),
+                    },
+                    temporal_versioning: EntityTemporalMetadata {
+                        decision_time: LeftClosedTemporalInterval::new(
+                            ClosedTemporalBound::Inclusive(decision_time),
+                            OpenTemporalBound::Unbounded,
+                        ),
+                        transaction_time: LeftClosedTemporalInterval::new(
+                            ClosedTemporalBound::Inclusive(transaction_time),
+                            OpenTemporalBound::Unbounded,
+                        ),
+                    },
+                    entity_type_ids: params.entity_type_ids,
+                    archived: false,
+                    provenance: entity_provenance,
+                    confidence: params.confidence,
+                    properties: property_metadata,
+                },
+            });
+
+            let current_num_relationships = relationships.len();
+            relationships.extend(
+                params
+                    .relationships
+                    .into_iter()
+                    .chain(once(EntityRelationAndSubject::Owner {
+                        subject: EntityOwnerSubject::Web {
+                            id: params.owned_by_id,
+                        },
+                        level: 0,
+                    }))
+                    .map(|relation_and_subject| (entity_id, relation_and_subject)),
+            );
+            if relationships.len() == current_num_relationships {
+                return Err(Report::new(InsertionError)
+                    .attach_printable("At least one relationship must be provided"));
+            }
+        }
+        let store_cache = validator_provider.cache;
+
+        let (instantiate_permissions, zookie) = self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::Instantiate,
+                entity_type_ids.keys().copied(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(InsertionError)?;
+        let forbidden_instantiations = instantiate_permissions
+            .iter()
+            .filter_map(|(entity_type_id, permission)| {
+                if *permission {
+                    None
+                } else {
+                    entity_type_ids.get(entity_type_id)
+                }
+            })
+            .collect::<Vec<_>>();
+        if !forbidden_instantiations.is_empty() {
+            return Err(Report::new(InsertionError)
+                .attach(StatusCode::PermissionDenied)
+                .attach_printable(
+                    "The actor does not have permission to instantiate one or more entity types",
+                )
+                .attach_printable(
+                    forbidden_instantiations
+                        .into_iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ));
+        }
+
+        if !checked_web_ids.is_empty() {
+            let (create_entity_permissions, _zookie) = self
+                .authorization_api
+                .check_webs_permission(
+                    actor_id,
+                    WebPermission::CreateEntity,
+                    checked_web_ids,
+                    Consistency::AtLeastAsFresh(&zookie),
+                )
+                .await
+                .change_context(InsertionError)?;
+            let forbidden_webs = create_entity_permissions
+                .iter()
+                .filter_map(
+                    |(web_id, permission)| {
+                        if *permission { None } else { Some(web_id) }
+                    },
+                )
+                .collect::<Vec<_>>();
+            if !forbidden_webs.is_empty() {
+                return Err(Report::new(InsertionError)
+                    .attach(StatusCode::PermissionDenied)
+                    .attach_printable(
+                        "The actor does not have permission to create entities for one or more \
+                         web ids",
+                    )
+                    .attach_printable(
+                        forbidden_webs
+                            .into_iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ));
+            }
+        }
+
+        let mut state = self.state.write().await;
+        let mut transaction = MemoryState::clone(&state);
+
+        for entity in &entities {
+            let entity_id = entity.metadata.record_id.entity_id;
+            if !transaction.webs.contains(&entity_id.owned_by_id) {
+                bail!(
+                    Report::new(InsertionError)
+                        .attach_printable("The web does not exist")
+                        .attach_printable(entity_id.owned_by_id)
+                );
+            }
+            if transaction
+                .entity_ids
+                .insert(
+                    (entity_id.owned_by_id, entity_id.entity_uuid),
+                    EntityIdRecord {
+                        provenance: entity.metadata.provenance.inferred.clone(),
+                        link_data: entity.link_data.clone(),
+                    },
+                )
+                .is_some()
+            {
+                bail!(
+                    Report::new(InsertionError)
+                        .attach_printable("The entity already exists")
+                        .attach_printable(entity_id)
+                );
+            }
+
+            transaction.entity_editions.insert(
+                entity.metadata.record_id.edition_id,
+                EntityEditionRecord {
+                    properties: entity.properties.clone(),
+                    property_metadata: entity.metadata.properties.clone(),
+                    archived: false,
+                    confidence: entity.metadata.confidence,
+                    provenance: entity.metadata.provenance.edition.clone(),
+                    entity_type_ids: entity.metadata.entity_type_ids.clone(),
+                },
+            );
+            transaction
+                .entity_temporal_metadata
+                .push(EntityTemporalRow {
+                    entity_id,
+                    edition_id: entity.metadata.record_id.edition_id,
+                    decision_time: entity.metadata.temporal_versioning.decision_time,
+                    transaction_time: entity.metadata.temporal_versioning.transaction_time,
+                });
+            transaction.record_change(
+                ChangeKind::Created,
+                ChangeRecordId::Entity(entity.metadata.record_id),
+                transaction_time,
+                actor_id,
+            );
+        }
+
+        for link_data in entities
+            .iter()
+            .filter_map(|entity| entity.link_data.as_ref())
+        {
+            for endpoint in [link_data.left_entity_id, link_data.right_entity_id] {
+                if !transaction
+                    .entity_ids
+                    .contains_key(&(endpoint.owned_by_id, endpoint.entity_uuid))
+                {
+                    bail!(
+                        Report::new(InsertionError)
+                            .attach_printable("The linked entity does not exist")
+                            .attach_printable(endpoint)
+                    );
+                }
+            }
+        }
+
+        let transaction = MemoryStore::detached(transaction, &mut self.authorization_api);
+
+        transaction
+            .authorization_api
+            .modify_entity_relations(relationships.iter().copied().map(
+                |(entity_id, relation_and_subject)| {
+                    (
+                        ModifyRelationshipOperation::Create,
+                        entity_id,
+                        relation_and_subject,
+                    )
+                },
+            ))
+            .await
+            .change_context(InsertionError)?;
+
+        let validator_provider = StoreProvider {
+            store: &transaction,
+            cache: store_cache,
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+
+        for (entity, (schema, components)) in entities.iter().zip(validation_params) {
+            entity
+                .validate(&schema, components, &validator_provider)
+                .await
+                .change_context(InsertionError)?;
+        }
+        drop(validator_provider);
+
+        *state = transaction.into_state();
+        drop(state);
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_entity_embeddings_workflow(actor_id, &entities)
+                .await
+                .change_context(InsertionError)?;
+        }
+
+        Ok(entities)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn validate_entities(
+        &self,
+        actor_id: AccountId,
+        consistency: Consistency<'_>,
+        params: Vec<ValidateEntityParams<'_>>,
+    ) -> Result<(), ValidateEntityError> {
+        let mut status = ReportSink::new();
+
+        let validator_provider = StoreProvider {
+            store: self,
+            cache: StoreCache::default(),
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+
+        for mut params in params {
+            let schema = match params.entity_types {
+                EntityValidationType::ClosedSchema(schema) => schema,
+                EntityValidationType::Schema(schemas) => Cow::Owned(schemas.into_iter().collect()),
+                EntityValidationType::Id(entity_type_urls) => Cow::Owned(
+                    validator_provider
+                        .provide_closed_type(entity_type_urls.as_ref())
+                        .await
+                        .change_context(ValidateEntityError)?,
+                ),
+            };
+
+            if schema.schemas.is_empty() {
+                let error = Report::new(validation::EntityValidationError::EmptyEntityTypes);
+                status.append(error);
+            };
+
+            let pre_process_result = EntityPreprocessor {
+                components: params.components,
+            }
+            .visit_object(
+                schema.as_ref(),
+                params.properties.to_mut(),
+                &validator_provider,
+            )
+            .await
+            .change_context(validation::EntityValidationError::InvalidProperties);
+            if let Err(error) = pre_process_result {
+                status.append(error);
+            }
+
+            if let Err(error) = params
+                .link_data
+                .as_deref()
+                .validate(&schema, params.components, &validator_provider)
+                .await
+            {
+                status.append(error);
+            }
+        }
+
+        status
+            .finish()
+            .change_context(ValidateEntityError)
+            .attach(StatusCode::InvalidArgument)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn get_entities(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntitiesParams<'_>,
+    ) -> Result<GetEntitiesResponse<'static>, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+
+        let mut response = self
+            .get_entities_impl(
+                actor_id,
+                GetEntitiesImplParams {
+                    filter: params.filter,
+                    sorting: params.sorting,
+                    limit: params.limit,
+                    include_drafts: params.include_drafts,
+                    include_count: params.include_count,
+                    include_web_ids: params.include_web_ids,
+                    include_created_by_ids: params.include_created_by_ids,
+                    include_edition_created_by_ids: params.include_edition_created_by_ids,
+                    include_type_ids: params.include_type_ids,
+                },
+                &temporal_axes,
+            )
+            .await
+            .map(|(response, _)| response)?;
+
+        if !params.conversions.is_empty() {
+            let provider = StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            };
+            for entity in &mut response.entities {
+                convert_entity(&provider, entity, &params.conversions)
+                    .await
+                    .change_context(QueryError)?;
+            }
+        }
+
+        if let Some(projection) = &params.projection {
+            for entity in &mut response.entities {
+                projection.apply(entity);
+            }
+        }
+
+        Ok(response)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn stream_entities<'s>(
+        &'s self,
+        actor_id: AccountId,
+        params: GetEntitiesParams<'s>,
+    ) -> Result<impl Stream<Item = Result<Entity, QueryError>> + Send + 's, QueryError> {
+        // All entities are held in memory already, so reading them lazily has no benefit.
+        let response = self
+            .get_entities(actor_id, GetEntitiesParams {
+                include_count: false,
+                include_web_ids: false,
+                include_created_by_ids: false,
+                include_edition_created_by_ids: false,
+                include_type_ids: false,
+                ..params
+            })
+            .await?;
+        Ok(stream::iter(response.entities.into_iter().map(Ok)))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn get_entity_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntitySubgraphParams<'_>,
+    ) -> Result<GetEntitySubgraphResponse<'static>, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        for hop in params
+            .traversal_paths
+            .iter_mut()
+            .flat_map(|traversal_path| &mut traversal_path.hops)
+        {
+            for filter in [&mut hop.link_filter, &mut hop.endpoint_filter] {
+                filter
+                    .convert_parameters(&StoreProvider {
+                        store: self,
+                        cache: StoreCache::default(),
+                        authorization: Some((actor_id, Consistency::FullyConsistent)),
+                    })
+                    .await
+                    .change_context(QueryError)?;
+            }
+        }
+
+        let unresolved_temporal_axes = params.temporal_axes;
+        let temporal_axes = unresolved_temporal_axes.clone().resolve();
+
+        let time_axis = temporal_axes.variable_time_axis();
+
+        let (
+            GetEntitiesResponse {
+                entities: root_entities,
+                cursor,
+                count,
+                web_ids,
+                created_by_ids,
+                edition_created_by_ids,
+                type_ids,
+            },
+            zookie,
+        ) = self
+            .get_entities_impl(
+                actor_id,
+                GetEntitiesImplParams {
+                    filter: params.filter,
+                    sorting: params.sorting,
+                    limit: params.limit,
+                    include_drafts: params.include_drafts,
+                    include_count: false,
+                    include_web_ids: params.include_web_ids,
+                    include_created_by_ids: params.include_created_by_ids,
+                    include_edition_created_by_ids: params.include_edition_created_by_ids,
+                    include_type_ids: params.include_type_ids,
+                },
+                &temporal_axes,
+            )
+            .await?;
+
+        let mut subgraph = Subgraph::new(
+            params.graph_resolve_depths,
+            unresolved_temporal_axes,
+            temporal_axes,
+        );
+
+        subgraph.roots.extend(
+            root_entities
+                .iter()
+                .map(|entity| entity.vertex_id(time_axis).into()),
+        );
+        subgraph.vertices.entities = root_entities
+            .into_iter()
+            .map(|entity| (entity.vertex_id(time_axis), entity))
+            .collect();
+
+        let mut traversal_context = TraversalContext::default();
+
+        let root_entities = subgraph
+            .vertices
+            .entities
+            .keys()
+            .map(|id| (*id, subgraph.temporal_axes.resolved.variable_interval()))
+            .collect::<Vec<_>>();
+
+        self.traverse_entities(
+            root_entities
+                .iter()
+                .map(|(id, interval)| (*id, subgraph.depths, *interval))
+                .collect(),
+            &mut traversal_context,
+            actor_id,
+            &zookie,
+            &mut subgraph,
+        )
+        .await?;
+
+        self.traverse_entity_paths(
+            &root_entities,
+            &params.traversal_paths,
+            actor_id,
+            &zookie,
+            params.include_drafts,
+            &mut subgraph,
+        )
+        .await?;
+
+        traversal_context
+            .read_traversed_vertices(self, &mut subgraph, params.include_drafts)
+            .await?;
+
+        if !params.conversions.is_empty() {
+            let provider = StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            };
+            for entity in subgraph.vertices.entities.values_mut() {
+                convert_entity(&provider, entity, &params.conversions)
+                    .await
+                    .change_context(QueryError)?;
+            }
+        }
+
+        if let Some(projection) = &params.projection {
+            for entity in subgraph.vertices.entities.values_mut() {
+                projection.apply(entity);
+            }
+        }
+
+        Ok(GetEntitySubgraphResponse {
+            subgraph,
+            cursor,
+            count,
+            web_ids,
+            created_by_ids,
+            edition_created_by_ids,
+            type_ids,
+        })
+    }
+
+    async fn count_entities(
+        &self,
+        actor_id: AccountId,
+        mut params: CountEntitiesParams<'_>,
+    ) -> Result<usize, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+
+        let entity_ids = Read::<Entity>::read(
+            self,
+            &params.filter,
+            Some(&temporal_axes),
+            params.include_drafts,
+        )
+        .await?
+        .map_ok(|entity| entity.metadata.record_id.entity_id)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+        let permitted_ids = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_ids.iter().copied(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0
+            .into_iter()
+            .filter_map(|(entity_id, has_permission)| has_permission.then_some(entity_id))
+            .collect::<HashSet<_>>();
+
+        Ok(entity_ids
+            .into_iter()
+            .filter(|id| permitted_ids.contains(&id.entity_uuid))
+            .count())
+    }
+
+    async fn aggregate_entities(
+        &self,
+        actor_id: AccountId,
+        mut params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, QueryError> {
+        if let Some(aggregation) = params.aggregations.iter().find(|aggregation| {
+            aggregation.path.is_none() && aggregation.function != AggregationFunction::Count
+        }) {
+            bail!(
+                Report::new(QueryError)
+                    .attach(StatusCode::InvalidArgument)
+                    .attach_printable(format!(
+                        "The aggregation `{:?}` requires a path",
+                        aggregation.function
+                    ))
+            );
+        }
+
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+        let state = self.state.read().await;
+        let context = QueryContext {
+            state: &state,
+            temporal_axes: Some(&temporal_axes),
+            include_drafts: params.include_drafts,
+            kind: RecordKind::Entity,
+        };
+        let nodes = matching_nodes(&context, &params.filter);
+
+        let permitted_ids = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                nodes.iter().filter_map(|node| match node {
+                    Node::Entity(row) => Some(row.entity_id),
+                    _ => None,
+                }),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0
+            .into_iter()
+            .filter_map(|(entity_id, has_permission)| has_permission.then_some(entity_id))
+            .collect::<HashSet<_>>();
+
+        // Counting the entity UUIDs counts the entities, as they are never missing.
+        let paths = CompiledFilter::from_paths(
+            params.group_by.iter().chain(
+                params
+                    .aggregations
+                    .iter()
+                    .map(|aggregation| aggregation.path.as_ref().unwrap_or(&EntityQueryPath::Uuid)),
+            ),
+        );
+        let rows = nodes
+            .into_iter()
+            .filter(|node| match node {
+                Node::Entity(row) => permitted_ids.contains(&row.entity_id.entity_uuid),
+                _ => false,
+            })
+            .map(|node| context.sorting_values(&paths, node));
+
+        Ok(AggregateEntitiesResponse {
+            groups: aggregate_rows(
+                rows,
+                params.group_by.len(),
+                &params
+                    .aggregations
+                    .iter()
+                    .map(|aggregation| aggregation.function)
+                    .collect::<Vec<_>>(),
+            ),
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn find_entity_paths(
+        &self,
+        actor_id: AccountId,
+        mut params: FindEntityPathsParams<'_>,
+    ) -> Result<FindEntityPathsResponse, QueryError> {
+        if let Some(link_filter) = &mut params.link_filter {
+            link_filter
+                .convert_parameters(&StoreProvider {
+                    store: self,
+                    cache: StoreCache::default(),
+                    authorization: Some((actor_id, Consistency::FullyConsistent)),
+                })
+                .await
+                .change_context(QueryError)?;
+        }
+
+        let temporal_axes = params.temporal_axes.resolve();
+        let variable_axis = temporal_axes.variable_time_axis();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+
+        let source_permitted = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                once(params.source_entity_id),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0
+            .get(&params.source_entity_id.entity_uuid)
+            .copied()
+            .unwrap_or(false);
+        if !source_permitted {
+            return Ok(FindEntityPathsResponse { paths: Vec::new() });
+        }
+
+        let mut entities = Read::<Entity>::read_vec(
+            self,
+            &Filter::for_entity_by_entity_id(params.source_entity_id),
+            Some(&temporal_axes),
+            params.include_drafts,
+        )
+        .await?
+        .into_iter()
+        .map(|entity| {
+            (
+                entity.vertex_id(variable_axis),
+                GraphResolveDepths::default(),
+                temporal_axes.variable_interval(),
+            )
+        })
+        .collect::<Vec<_>>();
+        if entities.is_empty() {
+            return Ok(FindEntityPathsResponse { paths: Vec::new() });
+        }
+
+        let mut search = EntityPathSearch::new(params.source_entity_id, params.target_entity_id);
+        let mut depth = 0;
+        while !entities.is_empty() && !search.is_finished() && depth < params.max_length {
+            depth += 1;
+            let mut next_entities = Vec::new();
+
+            for direction in [
+                LinkTraversalDirection::Outgoing,
+                LinkTraversalDirection::Incoming,
+            ] {
+                let (link_edge_kind, endpoint_edge_kind) = match direction {
+                    LinkTraversalDirection::Outgoing => (
+                        KnowledgeGraphEdgeKind::HasLeftEntity,
+                        KnowledgeGraphEdgeKind::HasRightEntity,
+                    ),
+                    LinkTraversalDirection::Incoming => (
+                        KnowledgeGraphEdgeKind::HasRightEntity,
+                        KnowledgeGraphEdgeKind::HasLeftEntity,
+                    ),
+                };
+
+                let link_edges = self.state.read().await.read_knowledge_edges(
+                    &entities,
+                    link_edge_kind,
+                    EdgeDirection::Incoming,
+                    pinned_timestamp,
+                    variable_axis,
+                );
+                if link_edges.is_empty() {
+                    continue;
+                }
+
+                let permitted_links = self
+                    .filter_permitted_entity_editions(
+                        actor_id,
+                        link_edges
+                            .iter()
+                            .map(|(_, edge)| edge.right_endpoint_edition_id)
+                            .collect(),
+                        params.link_filter.as_ref(),
+                        &temporal_axes,
+                        params.include_drafts,
+                    )
+                    .await?;
+
+                // A link may be reached from multiple entities, so the entities it was reached
+                // from are collected before the link is followed to its other end.
+                let mut link_origins = HashMap::<EntityId, Vec<EntityId>>::new();
+                let mut links = Vec::new();
+                for (_, edge) in link_edges {
+                    if !permitted_links.contains(&edge.right_endpoint_edition_id) {
+                        continue;
+                    }
+
+                    let origins = link_origins.entry(edge.right_endpoint.base_id).or_default();
+                    if origins.is_empty() {
+                        links.push((
+                            edge.right_endpoint,
+                            GraphResolveDepths::default(),
+                            edge.traversal_interval,
+                        ));
+                    }
+                    if !origins.contains(&edge.left_endpoint.base_id) {
+                        origins.push(edge.left_endpoint.base_id);
+                    }
+                }
+                if links.is_empty() {
+                    continue;
+                }
+
+                let endpoint_edges = self.state.read().await.read_knowledge_edges(
+                    &links,
+                    endpoint_edge_kind,
+                    EdgeDirection::Outgoing,
+                    pinned_timestamp,
+                    variable_axis,
+                );
+                if endpoint_edges.is_empty() {
+                    continue;
+                }
+
+                let permitted_endpoints = self
+                    .filter_permitted_entity_editions(
+                        actor_id,
+                        endpoint_edges
+                            .iter()
+                            .map(|(_, edge)| edge.right_endpoint_edition_id)
+                            .collect(),
+                        None,
+                        &temporal_axes,
+                        params.include_drafts,
+                    )
+                    .await?;
+
+                for (_, edge) in endpoint_edges {
+                    if !permitted_endpoints.contains(&edge.right_endpoint_edition_id) {
+                        continue;
+                    }
+
+                    let link_entity_id = edge.left_endpoint.base_id;
+                    for previous_entity_id in
+                        link_origins.get(&link_entity_id).into_iter().flatten()
+                    {
+                        let hop = EntityPathHop {
+                            link_entity_id,
+                            direction,
+                            entity_id: edge.right_endpoint.base_id,
+                        };
+                        if search.visit(depth, *previous_entity_id, hop) {
+                            next_entities.push((
+                                edge.right_endpoint,
+                                GraphResolveDepths::default(),
+                                edge.traversal_interval,
+                            ));
+                        }
+                    }
+                }
+            }
+
+            entities = next_entities;
+        }
+
+        Ok(FindEntityPathsResponse {
+            paths: search.into_paths(params.limit),
+        })
+    }
+
+    async fn get_entity_by_id(
+        &self,
+        actor_id: AccountId,
+        entity_id: EntityId,
+        transaction_time: Option<Timestamp<TransactionTime>>,
+        decision_time: Option<Timestamp<DecisionTime>>,
+    ) -> Result<Entity, QueryError> {
+        self.authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .assert_permission()
+            .change_context(QueryError)?;
+
+        let temporal_axes = QueryTemporalAxesUnresolved::TransactionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(decision_time),
+            variable: VariableTemporalAxisUnresolved::new(
+                transaction_time.map(TemporalBound::Inclusive),
+                transaction_time.map(LimitedTemporalBound::Inclusive),
+            ),
+        }
+        .resolve();
+
+        Read::<Entity>::read_one(
+            self,
+            &Filter::for_entity_by_entity_id(entity_id),
+            Some(&temporal_axes),
+            entity_id.draft_id.is_some(),
+        )
+        .await
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn patch_entity(
+        &mut self,
+        actor_id: AccountId,
+        mut params: PatchEntityParams,
+    ) -> Result<Entity, UpdateError> {
+        let transaction_time = transaction_time();
+        let decision_time = params
+            .decision_time
+            .map_or_else(|| transaction_time.cast(), Timestamp::remove_nanosecond);
+        let entity_type_ids = params
+            .entity_type_ids
+            .iter()
+            .map(EntityTypeId::from_url)
+            .collect::<Vec<_>>();
+
+        if !self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::Instantiate,
+                entity_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .0
+            .into_iter()
+            .all(|(_, permission)| permission)
+        {
+            bail!(Report::new(UpdateError).attach(StatusCode::PermissionDenied));
+        }
+
+        self.authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::Update,
+                params.entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let (locked_row, previous_entity) = {
+            let state = self.state.read().await;
+            let locked_row = state
+                .locked_entity_row(params.entity_id, transaction_time, decision_time)
+                .ok_or_else(|| {
+                    Report::new(EntityDoesNotExist)
+                        .attach(StatusCode::NotFound)
+                        .attach_printable(params.entity_id)
+                        .change_context(UpdateError)
+                })?;
+            let locked_row = state.entity_temporal_metadata[locked_row].clone();
+            let previous_entity = state.entity(&locked_row);
+            (locked_row, previous_entity)
+        };
+        if params
+            .expected_edition_id
+            .is_some_and(|edition_id| edition_id != locked_row.edition_id)
+        {
+            bail!(
+                Report::new(EntityEditionConflict)
+                    .attach_printable(params.entity_id)
+                    .change_context(UpdateError)
+            );
+        }
+
+        let mut first_non_draft_created_at_decision_time = previous_entity
+            .metadata
+            .provenance
+            .inferred
+            .first_non_draft_created_at_decision_time;
+        let mut first_non_draft_created_at_transaction_time = previous_entity
+            .metadata
+            .provenance
+            .inferred
+            .first_non_draft_created_at_transaction_time;
+
+        let was_draft_before = previous_entity
+            .metadata
+            .record_id
+            .entity_id
+            .draft_id
+            .is_some();
+        let draft = params.draft.unwrap_or(was_draft_before);
+        let archived = params.archived.unwrap_or(previous_entity.metadata.archived);
+        let (entity_type_ids, entity_types_updated) = if params.entity_type_ids.is_empty() {
+            (previous_entity.metadata.entity_type_ids, false)
+        } else {
+            let added_types = previous_entity
+                .metadata
+                .entity_type_ids
+                .difference(&params.entity_type_ids);
+            let removed_types = params
+                .entity_type_ids
+                .difference(&previous_entity.metadata.entity_type_ids);
+
+            let mut has_changed = false;
+            for entity_type_id in added_types.chain(removed_types) {
+                has_changed = true;
+
+                let entity_type_id = EntityTypeId::from_url(entity_type_id);
+                self.authorization_api
+                    .check_entity_type_permission(
+                        actor_id,
+                        EntityTypePermission::Instantiate,
+                        entity_type_id,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(UpdateError)?
+                    .assert_permission()
+                    .change_context(UpdateError)
+                    .attach(StatusCode::PermissionDenied)?;
+            }
+
+            (params.entity_type_ids, has_changed)
+        };
+
+        let previous_properties = previous_entity.properties.clone();
+        let previous_property_metadata = previous_entity.metadata.properties.clone();
+
+        let mut properties_with_metadata = PropertyWithMetadata::from_parts(
+            Property::Object(previous_entity.properties),
+            Some(PropertyMetadata::Object {
+                value: previous_entity.metadata.properties.value,
+                metadata: previous_entity.metadata.properties.metadata,
+            }),
+        )
+        .change_context(UpdateError)?;
+        properties_with_metadata
+            .patch(params.properties)
+            .change_context(UpdateError)?;
+
+        let validator_provider = StoreProvider {
+            store: &*self,
+            cache: StoreCache::default(),
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+        let entity_type = validator_provider
+            .provide_closed_type(&entity_type_ids)
+            .await
+            .change_context(UpdateError)?;
+
+        let validation_components = if draft {
+            ValidateEntityComponents::draft()
+        } else {
+            ValidateEntityComponents::full()
+        };
+
+        let (properties, property_metadata) =
+            if let PropertyWithMetadata::Object(mut object) = properties_with_metadata {
+                EntityPreprocessor {
+                    components: validation_components,
+                }
+                .visit_object(&entity_type, &mut object, &validator_provider)
+                .await
+                .attach(StatusCode::InvalidArgument)
+                .change_context(UpdateError)?;
+                let (properties, property_metadata) = object.into_parts();
+                (properties, property_metadata)
+            } else {
+                unreachable!("patching should not change the property type");
+            };
+        let store_cache = validator_provider.cache;
+
+        let has_property_changes = previous_properties
+            .diff(&properties, &mut PropertyPath::default())
+            .next()
+            .is_some();
+
+        if !has_property_changes
+            && was_draft_before == draft
+            && archived == previous_entity.metadata.archived
+            && !entity_types_updated
+            && previous_property_metadata == property_metadata
+            && params.confidence == previous_entity.metadata.confidence
+        {
+            // No changes were made to the entity.
+            return Ok(Entity {
+                properties: previous_properties,
+                link_data: previous_entity.link_data,
+                metadata: EntityMetadata {
+                    record_id: previous_entity.metadata.record_id,
+                    temporal_versioning: previous_entity.metadata.temporal_versioning,
+                    entity_type_ids,
+                    provenance: previous_entity.metadata.provenance,
+                    archived,
+                    confidence: previous_entity.metadata.confidence,
+                    properties: property_metadata,
+                },
+            });
+        }
+
+        let link_data = previous_entity.link_data;
+
+        let edition_provenance = EntityEditionProvenance {
+            created_by_id: EditionCreatedById::new(actor_id),
+            archived_by_id: None,
+            provided: params.provenance,
+        };
+
+        let mut state = self.state.write().await;
+        let mut transaction = MemoryState::clone(&state);
+
+        // The entity may have been updated since it was read.
+        let locked_entity_id = params.entity_id;
+        let ensure_locked_row = |transaction: &MemoryState| {
+            transaction
+                .entity_temporal_metadata
+                .iter()
+                .position(|row| {
+                    row.entity_id == locked_row.entity_id
+                        && row.edition_id == locked_row.edition_id
+                        && row.transaction_time == locked_row.transaction_time
+                        && row.decision_time == locked_row.decision_time
+                })
+                .ok_or_else(|| {
+                    Report::new(RaceConditionOnUpdate)
+                        .attach(locked_entity_id)
+                        .change_context(UpdateError)
+                })
+        };
+        ensure_locked_row(&transaction)?;
+
+        let edition_id = transaction.insert_entity_edition(
+            archived,
+            &entity_type_ids,
+            &properties,
+            params.confidence,
+            &edition_provenance,
+            &property_metadata,
+        );
+
+        let temporal_versioning = match (was_draft_before, draft) {
+            (true, true) | (false, false) => {
+                // regular update
+                let locked_row = ensure_locked_row(&transaction)?;
+                transaction.update_temporal_metadata(
+                    locked_row,
+                    transaction_time,
+                    decision_time,
+                    edition_id,
+                    false,
+                )
+            }
+            (false, true) => {
+                params.entity_id.draft_id = Some(DraftId::new(Uuid::new_v4()));
+                let temporal_versioning = EntityTemporalMetadata {
+                    decision_time: LeftClosedTemporalInterval::new(
+                        ClosedTemporalBound::Inclusive(decision_time),
+                        OpenTemporalBound::Unbounded,
+                    ),
+                    transaction_time: LeftClosedTemporalInterval::new(
+                        ClosedTemporalBound::Inclusive(transaction_time),
+                        OpenTemporalBound::Unbounded,
+                    ),
+                };
+                transaction
+                    .entity_temporal_metadata
+                    .push(EntityTemporalRow {
+                        entity_id: params.entity_id,
+                        edition_id,
+                        decision_time: temporal_versioning.decision_time,
+                        transaction_time: temporal_versioning.transaction_time,
+                    });
+                temporal_versioning
+            }
+            (true, false) => {
+                // Publish a draft
+                params.entity_id.draft_id = None;
+
+                if first_non_draft_created_at_decision_time.is_none() {
+                    if let Some(record) = transaction
+                        .entity_ids
+                        .get_mut(&(params.entity_id.owned_by_id, params.entity_id.entity_uuid))
+                    {
+                        record
+                            .provenance
+                            .first_non_draft_created_at_transaction_time = Some(transaction_time);
+                        record.provenance.first_non_draft_created_at_decision_time =
+                            Some(decision_time);
+                    }
+
+                    first_non_draft_created_at_transaction_time = Some(transaction_time);
+                    first_non_draft_created_at_decision_time = Some(decision_time);
+                }
+
+                if let Some(previous_live_entity) =
+                    transaction.locked_entity_row(params.entity_id, transaction_time, decision_time)
+                {
+                    transaction.archive_entity(
+                        actor_id,
+                        previous_live_entity,
+                        transaction_time,
+                        decision_time,
+                    );
+                }
+                let locked_row = ensure_locked_row(&transaction)?;
+                transaction.update_temporal_metadata(
+                    locked_row,
+                    transaction_time,
+                    decision_time,
+                    edition_id,
+                    true,
+                )
+            }
+        };
+
+        let change_kind = match (previous_entity.metadata.archived, archived) {
+            (false, true) => ChangeKind::Archived,
+            (true, false) => ChangeKind::Unarchived,
+            (false, false) | (true, true) => ChangeKind::Updated,
+        };
+        transaction.record_change(
+            change_kind,
+            ChangeRecordId::Entity(EntityRecordId {
+                entity_id: params.entity_id,
+                edition_id,
+            }),
+            transaction_time,
+            actor_id,
+        );
+
+        let entity_metadata = EntityMetadata {
+            record_id: EntityRecordId {
+                entity_id: params.entity_id,
+                edition_id,
+            },
+            temporal_versioning,
+            entity_type_ids,
+            provenance: EntityProvenance {
+                inferred: InferredEntityProvenance {
+                    first_non_draft_created_at_transaction_time,
+                    first_non_draft_created_at_decision_time,
+                    ..previous_entity.metadata.provenance.inferred
+                },
+                edition: edition_provenance,
+            },
+            confidence: params.confidence,
+            properties: property_metadata,
+            archived,
+        };
+        let entities = [Entity {
+            properties,
+            link_data,
+            metadata: entity_metadata,
+        }];
+
+        let transaction = MemoryStore::detached(transaction, &mut self.authorization_api);
+        let validator_provider = StoreProvider {
+            store: &transaction,
+            cache: store_cache,
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+        entities[0]
+            .validate(&entity_type, validation_components, &validator_provider)
+            .await
+            .change_context(UpdateError)?;
+        drop(validator_provider);
+
+        *state = transaction.into_state();
+        drop(state);
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_entity_embeddings_workflow(actor_id, &entities)
+                .await
+                .change_context(UpdateError)?;
+        }
+        let [entity] = entities;
+        Ok(entity)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_entity_embeddings(
+        &mut self,
+        _: AccountId,
+        params: UpdateEntityEmbeddingsParams<'_>,
+    ) -> Result<(), UpdateError> {
+        // TODO: Add permission to allow updating embeddings
+        //   see https://linear.app/hash/issue/H-1870
+
+        let mut state = self.state.write().await;
+
+        if params.reset {
+            state.entity_embeddings.retain(|(entity_id, _), embedding| {
+                *entity_id != params.entity_id
+                    || embedding.updated_at_transaction_time > params.updated_at_transaction_time
+                    || embedding.updated_at_decision_time > params.updated_at_decision_time
+            });
+        }
+
+        for embedding in params.embeddings {
+            let record = EntityEmbeddingRecord {
+                embedding: embedding.embedding.into_owned(),
+                updated_at_transaction_time: params.updated_at_transaction_time,
+                updated_at_decision_time: params.updated_at_decision_time,
+            };
+            match state
+                .entity_embeddings
+                .entry((params.entity_id, embedding.property))
+            {
+                Entry::Occupied(mut entry) => {
+                    let existing = entry.get();
+                    if existing.updated_at_transaction_time <= params.updated_at_transaction_time
+                        && existing.updated_at_decision_time <= params.updated_at_decision_time
+                    {
+                        entry.insert(record);
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(record);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
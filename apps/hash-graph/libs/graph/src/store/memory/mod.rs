@@ -0,0 +1,570 @@
+//! A volatile [`Store`] implementation keeping all data in memory.
+//!
+//! The in-memory store follows the semantics of the [`PostgresStore`] as closely as possible,
+//! including bitemporal versioning, drafts, and permission checks. It's primarily intended for
+//! tests and local development where spinning up a database is not desired.
+//!
+//! [`Store`]: crate::store::Store
+//! [`PostgresStore`]: crate::store::PostgresStore
+
+mod change_feed;
+mod knowledge;
+mod ontology;
+mod query;
+mod read;
+mod state;
+
+use alloc::sync::Arc;
+
+use authorization::{
+    AuthorizationApi,
+    backend::ModifyRelationshipOperation,
+    schema::{
+        AccountGroupAdministratorSubject, AccountGroupRelationAndSubject, WebDataTypeViewerSubject,
+        WebEntityCreatorSubject, WebEntityEditorSubject, WebEntityTypeViewerSubject,
+        WebOwnerSubject, WebPropertyTypeViewerSubject, WebRelationAndSubject, WebSubjectSet,
+    },
+};
+use error_stack::{Report, Result, ResultExt};
+use graph_types::{
+    account::{AccountGroupId, AccountId, EditionArchivedById},
+    ontology::{
+        OntologyEditionProvenance, OntologyProvenance, OntologyTemporalMetadata,
+        OntologyTypeClassificationMetadata, OntologyTypeRecordId,
+    },
+    owned_by_id::OwnedById,
+};
+use hash_graph_store::{
+    ConflictBehavior,
+    account::{
+        AccountGroupInsertionError, AccountInsertionError, AccountStore,
+        InsertAccountGroupIdParams, InsertAccountIdParams, InsertWebIdParams, QueryWebError,
+        WebInsertionError,
+    },
+};
+use temporal_client::TemporalClient;
+use temporal_versioning::{
+    ClosedTemporalBound, LeftClosedTemporalInterval, OpenTemporalBound, Timestamp, TransactionTime,
+};
+use tokio::sync::RwLock;
+use type_system::url::{OntologyTypeVersion, VersionedUrl};
+
+use self::state::{ChangeRecordId, MemoryState, OntologyIdRecord, OntologyTemporalRow};
+use crate::store::{
+    BaseUrlAlreadyExists, InsertionError, StoreError, StorePool, UpdateError,
+    change_feed::ChangeKind,
+    error::{
+        DeletionError, OntologyTypeIsNotOwned, OntologyVersionDoesNotExist,
+        VersionedUrlAlreadyExists,
+    },
+};
+
+/// A store keeping all records in memory.
+///
+/// All stores acquired from the same [`MemoryStorePool`] share the same underlying data.
+pub struct MemoryStore<A> {
+    state: Arc<RwLock<MemoryState>>,
+    pub authorization_api: A,
+    pub temporal_client: Option<Arc<TemporalClient>>,
+}
+
+/// Pool handing out [`MemoryStore`]s which all share the same data.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryStorePool {
+    state: Arc<RwLock<MemoryState>>,
+}
+
+impl MemoryStorePool {
+    /// Creates a new, empty `MemoryStorePool`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl StorePool for MemoryStorePool {
+    type Error = StoreError;
+    type Store<'pool, A: AuthorizationApi> = MemoryStore<A>;
+
+    async fn acquire<A: AuthorizationApi>(
+        &self,
+        authorization_api: A,
+        temporal_client: Option<Arc<TemporalClient>>,
+    ) -> Result<Self::Store<'_, A>, Self::Error> {
+        Ok(MemoryStore {
+            state: Arc::clone(&self.state),
+            authorization_api,
+            temporal_client,
+        })
+    }
+
+    async fn acquire_owned<A: AuthorizationApi>(
+        &self,
+        authorization_api: A,
+        temporal_client: Option<Arc<TemporalClient>>,
+    ) -> Result<Self::Store<'static, A>, Self::Error> {
+        Ok(MemoryStore {
+            state: Arc::clone(&self.state),
+            authorization_api,
+            temporal_client,
+        })
+    }
+}
+
+impl<A> MemoryStore<A> {
+    /// Creates a new, empty `MemoryStore`.
+    #[must_use]
+    pub fn new(authorization_api: A, temporal_client: Option<Arc<TemporalClient>>) -> Self {
+        Self {
+            state: Arc::default(),
+            authorization_api,
+            temporal_client,
+        }
+    }
+
+    /// Removes all accounts, account groups, and webs from the store.
+    ///
+    /// # Errors
+    ///
+    /// This function currently does not fail but returns a [`Result`] to match the
+    /// [`PostgresStore`] API.
+    ///
+    /// [`PostgresStore`]: crate::store::PostgresStore
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub async fn delete_accounts(&mut self, _actor_id: AccountId) -> Result<(), DeletionError> {
+        let mut state = self.state.write().await;
+        state.webs.clear();
+        state.accounts.clear();
+        state.account_groups.clear();
+        Ok(())
+    }
+
+    /// Creates a store operating on `state` which is not shared with any other store.
+    ///
+    /// This is used to validate the uncommitted state of a transaction.
+    fn detached(state: MemoryState, authorization_api: A) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(state)),
+            authorization_api,
+            temporal_client: None,
+        }
+    }
+
+    /// Returns the state of a store created by [`Self::detached`].
+    fn into_state(self) -> MemoryState {
+        Arc::into_inner(self.state)
+            .expect("detached store should not be shared")
+            .into_inner()
+    }
+}
+
+/// Returns the current transaction time.
+///
+/// Nanoseconds are removed to match the precision of the [`PostgresStore`].
+///
+/// [`PostgresStore`]: crate::store::PostgresStore
+fn transaction_time() -> Timestamp<TransactionTime> {
+    Timestamp::now().remove_nanosecond()
+}
+
+impl MemoryState {
+    fn create_base_url(
+        &mut self,
+        record_id: &OntologyTypeRecordId,
+        on_conflict: ConflictBehavior,
+        owned: bool,
+    ) -> Result<(), InsertionError> {
+        if self.base_urls.insert(record_id.base_url.clone()) {
+            return Ok(());
+        }
+
+        let exists_in_specified_location = on_conflict == ConflictBehavior::Skip
+            && self.ontology_ids.iter().any(|(url, record)| {
+                url.base_url == record_id.base_url
+                    && matches!(
+                        (owned, &record.classification),
+                        (true, OntologyTypeClassificationMetadata::Owned { .. })
+                            | (false, OntologyTypeClassificationMetadata::External { .. })
+                    )
+            });
+
+        if exists_in_specified_location {
+            Ok(())
+        } else {
+            Err(Report::new(BaseUrlAlreadyExists)
+                .attach_printable(record_id.base_url.clone())
+                .change_context(InsertionError))
+        }
+    }
+
+    /// Inserts the metadata for a new ontology type.
+    ///
+    /// Returns `None` if the type already exists and `on_conflict` is
+    /// [`ConflictBehavior::Skip`].
+    ///
+    /// # Errors
+    ///
+    /// - If the [`BaseUrl`] already exists and `on_conflict` is [`ConflictBehavior::Fail`]
+    /// - If the [`VersionedUrl`] already exists and `on_conflict` is [`ConflictBehavior::Fail`]
+    /// - If the owning web does not exist
+    ///
+    /// [`BaseUrl`]: type_system::url::BaseUrl
+    fn create_ontology_metadata(
+        &mut self,
+        record_id: &OntologyTypeRecordId,
+        classification: &OntologyTypeClassificationMetadata,
+        on_conflict: ConflictBehavior,
+        provenance: &OntologyProvenance,
+    ) -> Result<Option<OntologyTemporalMetadata>, InsertionError> {
+        match classification {
+            OntologyTypeClassificationMetadata::Owned { owned_by_id } => {
+                if !self.webs.contains(owned_by_id) {
+                    return Err(Report::new(InsertionError)
+                        .attach_printable("The web does not exist")
+                        .attach_printable(*owned_by_id));
+                }
+                self.create_base_url(record_id, on_conflict, true)?;
+            }
+            OntologyTypeClassificationMetadata::External { .. } => {
+                self.create_base_url(record_id, ConflictBehavior::Skip, false)?;
+            }
+        }
+
+        let url = VersionedUrl::from(record_id.clone());
+        if self.ontology_ids.contains_key(&url) {
+            return match on_conflict {
+                ConflictBehavior::Skip => Ok(None),
+                ConflictBehavior::Fail => Err(Report::new(VersionedUrlAlreadyExists)
+                    .attach_printable(url)
+                    .change_context(InsertionError)),
+            };
+        }
+
+        let now = transaction_time();
+        let transaction_time = LeftClosedTemporalInterval::new(
+            ClosedTemporalBound::Inclusive(now),
+            OpenTemporalBound::Unbounded,
+        );
+        self.ontology_ids.insert(url.clone(), OntologyIdRecord {
+            classification: classification.clone(),
+            temporal_metadata: vec![OntologyTemporalRow {
+                transaction_time,
+                provenance: provenance.edition.clone(),
+            }],
+        });
+        self.record_change(
+            ChangeKind::Created,
+            ChangeRecordId::Ontology(url),
+            now,
+            provenance.edition.created_by_id.as_account_id(),
+        );
+
+        Ok(Some(OntologyTemporalMetadata { transaction_time }))
+    }
+
+    /// Updates the latest version of [`VersionedUrl::base_url`] and creates the metadata for the
+    /// new version.
+    ///
+    /// # Errors
+    ///
+    /// - [`VersionedUrlAlreadyExists`] if [`VersionedUrl`] does already exist in the store
+    /// - [`OntologyVersionDoesNotExist`] if the previous version does not exist
+    /// - [`OntologyTypeIsNotOwned`] if ontology type is an external ontology type
+    fn update_owned_ontology_id(
+        &mut self,
+        url: &VersionedUrl,
+        provenance: &OntologyEditionProvenance,
+    ) -> Result<(OwnedById, OntologyTemporalMetadata), UpdateError> {
+        let previous_url = VersionedUrl {
+            base_url: url.base_url.clone(),
+            version: OntologyTypeVersion::new(
+                url.version
+                    .inner()
+                    .checked_sub(1)
+                    .ok_or(UpdateError)
+                    .attach_printable(
+                        "The version of the data type is already at the lowest possible value",
+                    )?,
+            ),
+        };
+
+        let owned_by_id = match self.ontology_ids.get(&previous_url) {
+            Some(OntologyIdRecord {
+                classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+                ..
+            }) => *owned_by_id,
+            Some(_) => {
+                return Err(Report::new(OntologyTypeIsNotOwned)
+                    .attach_printable(url.clone())
+                    .change_context(UpdateError));
+            }
+            None => {
+                return Err(Report::new(OntologyVersionDoesNotExist)
+                    .attach_printable(url.clone())
+                    .change_context(UpdateError));
+            }
+        };
+
+        if self.ontology_ids.contains_key(url) {
+            return Err(Report::new(VersionedUrlAlreadyExists)
+                .attach_printable(url.clone())
+                .change_context(UpdateError));
+        }
+
+        let now = transaction_time();
+        let transaction_time = LeftClosedTemporalInterval::new(
+            ClosedTemporalBound::Inclusive(now),
+            OpenTemporalBound::Unbounded,
+        );
+        self.ontology_ids.insert(url.clone(), OntologyIdRecord {
+            classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+            temporal_metadata: vec![OntologyTemporalRow {
+                transaction_time,
+                provenance: provenance.clone(),
+            }],
+        });
+        self.record_change(
+            ChangeKind::Updated,
+            ChangeRecordId::Ontology(url.clone()),
+            now,
+            provenance.created_by_id.as_account_id(),
+        );
+
+        Ok((owned_by_id, OntologyTemporalMetadata { transaction_time }))
+    }
+
+    fn archive_ontology_type(
+        &mut self,
+        id: &VersionedUrl,
+        archived_by_id: EditionArchivedById,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        let Some(record) = self.ontology_ids.get_mut(id) else {
+            return Err(Report::new(OntologyVersionDoesNotExist)
+                .attach_printable(id.clone())
+                .change_context(UpdateError));
+        };
+
+        let now = transaction_time();
+        let Some(row) = record
+            .temporal_metadata
+            .iter_mut()
+            .find(|row| row.transaction_time.contains_point(&now))
+        else {
+            return Err(Report::new(VersionedUrlAlreadyExists)
+                .attach_printable(id.clone())
+                .change_context(UpdateError));
+        };
+
+        row.transaction_time = LeftClosedTemporalInterval::new(
+            *row.transaction_time.start(),
+            OpenTemporalBound::Exclusive(now),
+        );
+        row.provenance.archived_by_id = Some(archived_by_id);
+        let transaction_time = row.transaction_time;
+
+        self.record_change(
+            ChangeKind::Archived,
+            ChangeRecordId::Ontology(id.clone()),
+            now,
+            archived_by_id.as_account_id(),
+        );
+
+        Ok(OntologyTemporalMetadata { transaction_time })
+    }
+
+    fn unarchive_ontology_type(
+        &mut self,
+        id: &VersionedUrl,
+        provenance: &OntologyEditionProvenance,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        let Some(record) = self.ontology_ids.get_mut(id) else {
+            return Err(Report::new(OntologyVersionDoesNotExist)
+                .attach_printable(id.clone())
+                .change_context(UpdateError));
+        };
+
+        let now = transaction_time();
+        let transaction_time = LeftClosedTemporalInterval::new(
+            ClosedTemporalBound::Inclusive(now),
+            OpenTemporalBound::Unbounded,
+        );
+        if record
+            .temporal_metadata
+            .iter()
+            .any(|row| row.transaction_time.overlaps(&transaction_time))
+        {
+            return Err(Report::new(VersionedUrlAlreadyExists)
+                .attach_printable(id.clone())
+                .change_context(UpdateError));
+        }
+
+        record.temporal_metadata.push(OntologyTemporalRow {
+            transaction_time,
+            provenance: provenance.clone(),
+        });
+        self.record_change(
+            ChangeKind::Unarchived,
+            ChangeRecordId::Ontology(id.clone()),
+            now,
+            provenance.created_by_id.as_account_id(),
+        );
+
+        Ok(OntologyTemporalMetadata { transaction_time })
+    }
+}
+
+impl<A: AuthorizationApi> AccountStore for MemoryStore<A> {
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn insert_account_id(
+        &mut self,
+        _actor_id: AccountId,
+        params: InsertAccountIdParams,
+    ) -> Result<(), AccountInsertionError> {
+        if self.state.write().await.accounts.insert(params.account_id) {
+            Ok(())
+        } else {
+            Err(Report::new(AccountInsertionError)
+                .attach_printable("The account already exists")
+                .attach_printable(params.account_id))
+        }
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn insert_account_group_id(
+        &mut self,
+        actor_id: AccountId,
+        params: InsertAccountGroupIdParams,
+    ) -> Result<(), AccountGroupInsertionError> {
+        if !self
+            .state
+            .write()
+            .await
+            .account_groups
+            .insert(params.account_group_id)
+        {
+            return Err(Report::new(AccountGroupInsertionError)
+                .attach_printable("The account group already exists")
+                .attach_printable(params.account_group_id));
+        }
+
+        if let Err(error) = self
+            .authorization_api
+            .modify_account_group_relations([(
+                ModifyRelationshipOperation::Create,
+                params.account_group_id,
+                AccountGroupRelationAndSubject::Administrator {
+                    subject: AccountGroupAdministratorSubject::Account { id: actor_id },
+                    level: 0,
+                },
+            )])
+            .await
+            .change_context(AccountGroupInsertionError)
+        {
+            self.state
+                .write()
+                .await
+                .account_groups
+                .remove(&params.account_group_id);
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn insert_web_id(
+        &mut self,
+        _actor_id: AccountId,
+        params: InsertWebIdParams,
+    ) -> Result<(), WebInsertionError> {
+        if !self.state.write().await.webs.insert(params.owned_by_id) {
+            return Err(Report::new(WebInsertionError)
+                .attach_printable("The web already exists")
+                .attach_printable(params.owned_by_id));
+        }
+
+        let mut relationships = vec![
+            WebRelationAndSubject::Owner {
+                subject: params.owner,
+                level: 0,
+            },
+            WebRelationAndSubject::EntityTypeViewer {
+                subject: WebEntityTypeViewerSubject::Public,
+                level: 0,
+            },
+            WebRelationAndSubject::PropertyTypeViewer {
+                subject: WebPropertyTypeViewerSubject::Public,
+                level: 0,
+            },
+            WebRelationAndSubject::DataTypeViewer {
+                subject: WebDataTypeViewerSubject::Public,
+                level: 0,
+            },
+        ];
+        if let WebOwnerSubject::AccountGroup { id } = params.owner {
+            relationships.extend([
+                WebRelationAndSubject::EntityCreator {
+                    subject: WebEntityCreatorSubject::AccountGroup {
+                        id,
+                        set: WebSubjectSet::Member,
+                    },
+                    level: 0,
+                },
+                WebRelationAndSubject::EntityEditor {
+                    subject: WebEntityEditorSubject::AccountGroup {
+                        id,
+                        set: WebSubjectSet::Member,
+                    },
+                    level: 0,
+                },
+            ]);
+        }
+
+        if let Err(error) = self
+            .authorization_api
+            .modify_web_relations(relationships.into_iter().map(|relation_and_subject| {
+                (
+                    ModifyRelationshipOperation::Create,
+                    params.owned_by_id,
+                    relation_and_subject,
+                )
+            }))
+            .await
+            .change_context(WebInsertionError)
+        {
+            self.state.write().await.webs.remove(&params.owned_by_id);
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn identify_owned_by_id(
+        &self,
+        owned_by_id: OwnedById,
+    ) -> Result<WebOwnerSubject, QueryWebError> {
+        let state = self.state.read().await;
+        let is_account = state
+            .accounts
+            .contains(&AccountId::new(owned_by_id.into_uuid()));
+        let is_account_group = state
+            .account_groups
+            .contains(&AccountGroupId::new(owned_by_id.into_uuid()));
+
+        match (is_account, is_account_group) {
+            (false, false) => Err(Report::new(QueryWebError)
+                .attach_printable("Record does not exist")
+                .attach_printable(owned_by_id)),
+            (true, false) => Ok(WebOwnerSubject::Account {
+                id: AccountId::new(owned_by_id.into_uuid()),
+            }),
+            (false, true) => Ok(WebOwnerSubject::AccountGroup {
+                id: AccountGroupId::new(owned_by_id.into_uuid()),
+            }),
+            (true, true) => Err(Report::new(QueryWebError)
+                .attach_printable("Record exists in both accounts and account_groups")
+                .attach_printable(owned_by_id)),
+        }
+    }
+}
@@ -0,0 +1,811 @@
+use alloc::sync::Arc;
+use core::iter::once;
+use std::collections::HashSet;
+
+use authorization::{
+    AuthorizationApi,
+    backend::ModifyRelationshipOperation,
+    schema::{DataTypeOwnerSubject, DataTypePermission, DataTypeRelationAndSubject, WebPermission},
+    zanzibar::{Consistency, Zookie},
+};
+use error_stack::{Report, Result, ResultExt};
+use futures::StreamExt;
+use graph_types::{
+    account::{AccountId, EditionArchivedById, EditionCreatedById},
+    ontology::{
+        DataTypeMetadata, DataTypeWithMetadata, OntologyEditionProvenance, OntologyProvenance,
+        OntologyTemporalMetadata, OntologyTypeClassificationMetadata, OntologyTypeRecordId,
+    },
+};
+use hash_graph_store::subgraph::{
+    Subgraph, SubgraphRecord,
+    edges::{EdgeDirection, OntologyEdgeKind},
+    identifier::{DataTypeVertexId, GraphElementVertexId},
+    temporal_axes::QueryTemporalAxes,
+};
+use hash_status::StatusCode;
+use type_system::{
+    Validator,
+    schema::{
+        ConversionDefinition, ConversionExpression, DataType, DataTypeId, DataTypeValidator,
+        OntologyTypeResolver,
+    },
+    url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+};
+
+use super::{OntologyTraversalData, split_traversal_queue, update_ontology_embedding};
+use crate::store::{
+    DataTypeStore, InsertionError, QueryError, StoreCache, StoreProvider, UpdateError,
+    crud::{QueryResult, Read, ReadPaginated, VersionedUrlSorting},
+    memory::{
+        MemoryStore,
+        state::{DataTypeRecord, MemoryState},
+    },
+    ontology::{
+        ArchiveDataTypeParams, BreakingChangeBehavior, CountDataTypesParams, CreateDataTypeParams,
+        GetDataTypeSubgraphParams, GetDataTypeSubgraphResponse, GetDataTypesParams,
+        GetDataTypesResponse, UnarchiveDataTypeParams, UpdateDataTypeEmbeddingParams,
+        UpdateDataTypesParams, UpdateDataTypesResponse,
+    },
+    postgres::TraversalContext,
+};
+
+impl MemoryState {
+    /// Creates an [`OntologyTypeResolver`] containing the data types referenced by `data_types`.
+    ///
+    /// Data types contained in `data_types` are added as open data types, all other referenced
+    /// data types are added together with their stored inheritance data.
+    fn data_type_resolver<'d>(
+        &self,
+        data_types: impl IntoIterator<Item = &'d DataType> + Clone,
+    ) -> OntologyTypeResolver {
+        let mut ontology_type_resolver = OntologyTypeResolver::default();
+
+        let inserted_ids = data_types
+            .clone()
+            .into_iter()
+            .map(|data_type| DataTypeId::from_url(&data_type.id))
+            .collect::<HashSet<_>>();
+
+        for parent_id in data_types
+            .clone()
+            .into_iter()
+            .flat_map(DataType::data_type_references)
+            .map(|(reference, _)| DataTypeId::from_url(&reference.url))
+            .filter(|parent_id| !inserted_ids.contains(parent_id))
+        {
+            if let Some(parent) = self.data_type_by_id(parent_id) {
+                ontology_type_resolver.add_closed(
+                    parent_id,
+                    Arc::new(parent.schema.clone()),
+                    Arc::new(parent.inheritance_data.clone()),
+                );
+            }
+        }
+
+        for data_type in data_types {
+            ontology_type_resolver.add_open(
+                DataTypeId::from_url(&data_type.id),
+                Arc::new(data_type.clone()),
+            );
+        }
+
+        ontology_type_resolver
+    }
+}
+
+impl<A> MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    async fn get_data_types_impl(
+        &self,
+        actor_id: AccountId,
+        params: GetDataTypesParams<'_>,
+        temporal_axes: &QueryTemporalAxes,
+    ) -> Result<(GetDataTypesResponse, Zookie<'static>), QueryError> {
+        #[expect(clippy::if_then_some_else_none, reason = "Function is async")]
+        let count = if params.include_count {
+            Some(
+                self.count_data_types(actor_id, CountDataTypesParams {
+                    filter: params.filter.clone(),
+                    temporal_axes: params.temporal_axes.clone(),
+                    include_drafts: params.include_drafts,
+                })
+                .await?,
+            )
+        } else {
+            None
+        };
+
+        let mut visited_ontology_ids = HashSet::new();
+
+        let (data, artifacts) =
+            ReadPaginated::<DataTypeWithMetadata, VersionedUrlSorting>::read_paginated_vec(
+                self,
+                &params.filter,
+                Some(temporal_axes),
+                &VersionedUrlSorting {
+                    cursor: params.after,
+                },
+                params.limit,
+                params.include_drafts,
+            )
+            .await?;
+        let data_types = data
+            .into_iter()
+            .filter_map(|row| {
+                let data_type = row.decode_record(&artifacts);
+                let id = DataTypeId::from_url(&data_type.schema.id);
+                visited_ontology_ids.insert(id).then_some((id, data_type))
+            })
+            .collect::<Vec<_>>();
+
+        let filtered_ids = data_types
+            .iter()
+            .map(|(data_type_id, _)| *data_type_id)
+            .collect::<Vec<_>>();
+
+        let (permissions, zookie) = self
+            .authorization_api
+            .check_data_types_permission(
+                actor_id,
+                DataTypePermission::View,
+                filtered_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let data_types = data_types
+            .into_iter()
+            .filter_map(|(id, data_type)| {
+                permissions
+                    .get(&id)
+                    .copied()
+                    .unwrap_or(false)
+                    .then_some(data_type)
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            GetDataTypesResponse {
+                cursor: if params.limit.is_some() {
+                    data_types
+                        .last()
+                        .map(|data_type| data_type.schema.id.clone())
+                } else {
+                    None
+                },
+                data_types,
+                count,
+            },
+            zookie,
+        ))
+    }
+
+    /// Traverses the data types in `data_type_queue` and adds the edges to the `subgraph`.
+    pub(in crate::store::memory) async fn traverse_data_types(
+        &self,
+        mut data_type_queue: Vec<OntologyTraversalData>,
+        traversal_context: &mut TraversalContext,
+        actor_id: AccountId,
+        zookie: &Zookie<'static>,
+        subgraph: &mut Subgraph,
+    ) -> Result<(), QueryError> {
+        while !data_type_queue.is_empty() {
+            let edges_to_traverse =
+                split_traversal_queue(core::mem::take(&mut data_type_queue), &[
+                    OntologyEdgeKind::InheritsFrom,
+                    OntologyEdgeKind::ConstrainsValuesOn,
+                ]);
+
+            for (edge_kind, traversal_data) in edges_to_traverse {
+                let edges = self.read_ontology_edges(&traversal_data, edge_kind).await;
+                let (permissions, _) = self
+                    .authorization_api
+                    .check_data_types_permission(
+                        actor_id,
+                        DataTypePermission::View,
+                        edges
+                            .iter()
+                            .map(|edge| DataTypeId::from_url(&edge.right_endpoint)),
+                        Consistency::AtExactSnapshot(zookie),
+                    )
+                    .await
+                    .change_context(QueryError)?;
+
+                for edge in edges {
+                    let data_type_id = DataTypeId::from_url(&edge.right_endpoint);
+                    if !permissions.get(&data_type_id).copied().unwrap_or(false) {
+                        continue;
+                    }
+
+                    subgraph.insert_edge(
+                        &DataTypeVertexId::from(edge.left_endpoint),
+                        edge_kind,
+                        EdgeDirection::Outgoing,
+                        DataTypeVertexId::from(edge.right_endpoint.clone()),
+                    );
+
+                    data_type_queue.extend(
+                        traversal_context
+                            .add_data_type_id(
+                                data_type_id,
+                                edge.resolve_depths,
+                                edge.traversal_interval,
+                            )
+                            .map(|(_, resolve_depths, traversal_interval)| {
+                                (
+                                    edge.right_endpoint.clone(),
+                                    resolve_depths,
+                                    traversal_interval,
+                                )
+                            }),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if `parent` is a direct or indirect parent of the data type `child`.
+    pub(in crate::store) async fn data_type_is_parent_of(
+        &self,
+        child: &VersionedUrl,
+        parent: &BaseUrl,
+    ) -> bool {
+        self.state
+            .read()
+            .await
+            .data_type_parents(child)
+            .iter()
+            .any(|(parent_id, _)| parent_id.base_url == *parent)
+    }
+
+    /// Returns the conversion expressions required to convert a value from `source` to `target`.
+    ///
+    /// # Errors
+    ///
+    /// - If none or more than one conversion paths between both data types exist
+    pub(in crate::store) async fn find_data_type_conversion(
+        &self,
+        source: &VersionedUrl,
+        target: &VersionedUrl,
+    ) -> Result<Vec<ConversionExpression>, QueryError> {
+        let state = self.state.read().await;
+        let source_conversions = state
+            .data_types
+            .get(source)
+            .map(|data_type| &data_type.conversions);
+        let target_conversions = state
+            .data_types
+            .get(target)
+            .map(|data_type| &data_type.conversions);
+
+        let mut candidates = Vec::<Vec<&ConversionDefinition>>::new();
+        let mut add_candidate = |candidate| {
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        };
+        if let (Some(source_conversions), Some(target_conversions)) =
+            (source_conversions, target_conversions)
+        {
+            for (base_url, source_conversion) in source_conversions {
+                if let Some(target_conversion) = target_conversions.get(base_url) {
+                    add_candidate(vec![&source_conversion.to, &target_conversion.from]);
+                }
+            }
+        }
+        if let Some(conversion) =
+            source_conversions.and_then(|conversions| conversions.get(&target.base_url))
+        {
+            add_candidate(vec![&conversion.to]);
+        }
+        if let Some(conversion) =
+            target_conversions.and_then(|conversions| conversions.get(&source.base_url))
+        {
+            add_candidate(vec![&conversion.from]);
+        }
+        match candidates.as_slice() {
+            [conversions] => Ok(conversions
+                .iter()
+                .map(|conversion| conversion.expression.clone())
+                .collect()),
+            _ => Err(Report::new(QueryError).attach_printable(format!(
+                "Found none or more than one conversions between `{source}` and `{target}`"
+            ))),
+        }
+    }
+}
+
+impl<A> DataTypeStore for MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn create_data_types<P, R>(
+        &mut self,
+        actor_id: AccountId,
+        params: P,
+    ) -> Result<Vec<DataTypeMetadata>, InsertionError>
+    where
+        P: IntoIterator<Item = CreateDataTypeParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = DataTypeRelationAndSubject> + Send + Sync,
+    {
+        let mut relationships = HashSet::new();
+        let mut parameters = Vec::new();
+
+        for params in params {
+            let data_type_id = DataTypeId::from_url(&params.schema.id);
+            if let OntologyTypeClassificationMetadata::Owned { owned_by_id } =
+                &params.classification
+            {
+                self.authorization_api
+                    .check_web_permission(
+                        actor_id,
+                        WebPermission::CreateDataType,
+                        *owned_by_id,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(InsertionError)?
+                    .assert_permission()
+                    .change_context(InsertionError)?;
+
+                relationships.insert((data_type_id, DataTypeRelationAndSubject::Owner {
+                    subject: DataTypeOwnerSubject::Web { id: *owned_by_id },
+                    level: 0,
+                }));
+            }
+
+            relationships.extend(
+                params
+                    .relationships
+                    .into_iter()
+                    .map(|relation_and_subject| (data_type_id, relation_and_subject)),
+            );
+
+            DataTypeValidator
+                .validate_ref(&params.schema)
+                .await
+                .attach(StatusCode::InvalidArgument)
+                .change_context(InsertionError)?;
+
+            parameters.push((
+                params.schema,
+                params.classification,
+                params.conflict_behavior,
+                params.conversions,
+                OntologyProvenance {
+                    edition: OntologyEditionProvenance {
+                        created_by_id: EditionCreatedById::new(actor_id),
+                        archived_by_id: None,
+                        user_defined: params.provenance,
+                    },
+                },
+            ));
+        }
+
+        let mut state = self.state.write().await;
+        let mut transaction = MemoryState::clone(&state);
+
+        let mut inserted_data_types = Vec::new();
+        for (schema, classification, conflict_behavior, conversions, provenance) in parameters {
+            let record_id = OntologyTypeRecordId::from(schema.id.clone());
+            if let Some(temporal_versioning) = transaction.create_ontology_metadata(
+                &record_id,
+                &classification,
+                conflict_behavior,
+                &provenance,
+            )? {
+                inserted_data_types.push(DataTypeWithMetadata {
+                    schema,
+                    metadata: DataTypeMetadata {
+                        record_id,
+                        classification,
+                        temporal_versioning,
+                        provenance,
+                        conversions,
+                    },
+                });
+            }
+        }
+
+        let mut ontology_type_resolver = transaction.data_type_resolver(
+            inserted_data_types
+                .iter()
+                .map(|data_type| &data_type.schema),
+        );
+        for data_type in &inserted_data_types {
+            let inheritance_data = ontology_type_resolver
+                .resolve_data_type_metadata(DataTypeId::from_url(&data_type.schema.id))
+                .change_context(InsertionError)?;
+            transaction
+                .data_types
+                .insert(data_type.schema.id.clone(), DataTypeRecord {
+                    schema: data_type.schema.clone(),
+                    inheritance_data: Arc::unwrap_or_clone(inheritance_data),
+                    conversions: data_type.metadata.conversions.clone(),
+                    embedding: None,
+                });
+        }
+
+        self.authorization_api
+            .modify_data_type_relations(relationships.iter().map(
+                |(resource, relation_and_subject)| {
+                    (
+                        ModifyRelationshipOperation::Create,
+                        *resource,
+                        *relation_and_subject,
+                    )
+                },
+            ))
+            .await
+            .change_context(InsertionError)?;
+
+        *state = transaction;
+        drop(state);
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_data_type_embeddings_workflow(actor_id, &inserted_data_types)
+                .await
+                .change_context(InsertionError)?;
+        }
+
+        Ok(inserted_data_types
+            .into_iter()
+            .map(|data_type| data_type.metadata)
+            .collect())
+    }
+
+    async fn get_data_types(
+        &self,
+        actor_id: AccountId,
+        mut params: GetDataTypesParams<'_>,
+    ) -> Result<GetDataTypesResponse, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.clone().resolve();
+        self.get_data_types_impl(actor_id, params, &temporal_axes)
+            .await
+            .map(|(response, _)| response)
+    }
+
+    async fn count_data_types(
+        &self,
+        actor_id: AccountId,
+        mut params: CountDataTypesParams<'_>,
+    ) -> Result<usize, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        Ok(self
+            .read(
+                &params.filter,
+                Some(&params.temporal_axes.resolve()),
+                params.include_drafts,
+            )
+            .await?
+            .count()
+            .await)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_data_type_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetDataTypeSubgraphParams<'_>,
+    ) -> Result<GetDataTypeSubgraphResponse, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.clone().resolve();
+        let time_axis = temporal_axes.variable_time_axis();
+
+        let (
+            GetDataTypesResponse {
+                data_types,
+                cursor,
+                count,
+            },
+            zookie,
+        ) = self
+            .get_data_types_impl(
+                actor_id,
+                GetDataTypesParams {
+                    filter: params.filter,
+                    temporal_axes: params.temporal_axes.clone(),
+                    after: params.after,
+                    limit: params.limit,
+                    include_drafts: params.include_drafts,
+                    include_count: params.include_count,
+                },
+                &temporal_axes,
+            )
+            .await?;
+
+        let mut subgraph = Subgraph::new(
+            params.graph_resolve_depths,
+            params.temporal_axes,
+            temporal_axes.clone(),
+        );
+
+        let (data_type_urls, data_type_vertex_ids): (Vec<_>, Vec<_>) = data_types
+            .iter()
+            .map(|data_type| {
+                (
+                    data_type.schema.id.clone(),
+                    GraphElementVertexId::from(data_type.vertex_id(time_axis)),
+                )
+            })
+            .unzip();
+        subgraph.roots.extend(data_type_vertex_ids);
+        subgraph.vertices.data_types = data_types
+            .into_iter()
+            .map(|data_type| (data_type.vertex_id(time_axis), data_type))
+            .collect();
+
+        let mut traversal_context = TraversalContext::default();
+
+        self.traverse_data_types(
+            data_type_urls
+                .into_iter()
+                .map(|url| {
+                    (
+                        url,
+                        subgraph.depths,
+                        subgraph.temporal_axes.resolved.variable_interval(),
+                    )
+                })
+                .collect(),
+            &mut traversal_context,
+            actor_id,
+            &zookie,
+            &mut subgraph,
+        )
+        .await?;
+
+        traversal_context
+            .read_traversed_vertices(self, &mut subgraph, params.include_drafts)
+            .await?;
+
+        Ok(GetDataTypeSubgraphResponse {
+            subgraph,
+            cursor,
+            count,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_data_type<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateDataTypesParams<R>,
+    ) -> Result<UpdateDataTypesResponse, UpdateError>
+    where
+        R: IntoIterator<Item = DataTypeRelationAndSubject> + Send + Sync,
+    {
+        let previous_url = VersionedUrl {
+            base_url: params.schema.id.base_url.clone(),
+            version: OntologyTypeVersion::new(
+                params
+                    .schema
+                    .id
+                    .version
+                    .inner()
+                    .checked_sub(1)
+                    .ok_or(UpdateError)
+                    .attach_printable(
+                        "The version of the data type is already at the lowest possible value",
+                    )?,
+            ),
+        };
+        let old_ontology_id = DataTypeId::from_url(&previous_url);
+        let data_type_id = DataTypeId::from_url(&params.schema.id);
+        self.authorization_api
+            .check_data_type_permission(
+                actor_id,
+                DataTypePermission::Update,
+                old_ontology_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let provenance = OntologyProvenance {
+            edition: OntologyEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                user_defined: params.provenance,
+            },
+        };
+
+        DataTypeValidator
+            .validate_ref(&params.schema)
+            .await
+            .change_context(UpdateError)?;
+
+        let mut state = self.state.write().await;
+        let mut transaction = MemoryState::clone(&state);
+
+        let inheritance_data = transaction
+            .data_type_resolver(once(&params.schema))
+            .resolve_data_type_metadata(data_type_id)
+            .change_context(UpdateError)?;
+
+        let (owned_by_id, temporal_versioning) =
+            transaction.update_owned_ontology_id(&params.schema.id, &provenance.edition)?;
+
+        let changes = if params.breaking_changes == BreakingChangeBehavior::Allow {
+            Vec::new()
+        } else {
+            let previous = transaction.data_types.get(&previous_url).ok_or_else(|| {
+                Report::new(UpdateError).attach_printable("previous data type not found")
+            })?;
+            params
+                .breaking_changes
+                .handle_changes(previous.schema.changes_to(&params.schema))
+                .change_context(UpdateError)?
+        };
+
+        transaction
+            .data_types
+            .insert(params.schema.id.clone(), DataTypeRecord {
+                schema: params.schema.clone(),
+                inheritance_data: Arc::unwrap_or_clone(inheritance_data),
+                conversions: params.conversions.clone(),
+                embedding: None,
+            });
+
+        let relationships = params
+            .relationships
+            .into_iter()
+            .chain(once(DataTypeRelationAndSubject::Owner {
+                subject: DataTypeOwnerSubject::Web { id: owned_by_id },
+                level: 0,
+            }))
+            .collect::<Vec<_>>();
+
+        self.authorization_api
+            .modify_data_type_relations(relationships.into_iter().map(|relation_and_subject| {
+                (
+                    ModifyRelationshipOperation::Create,
+                    data_type_id,
+                    relation_and_subject,
+                )
+            }))
+            .await
+            .change_context(UpdateError)?;
+
+        *state = transaction;
+        drop(state);
+
+        let metadata = DataTypeMetadata {
+            record_id: OntologyTypeRecordId::from(params.schema.id.clone()),
+            classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+            temporal_versioning,
+            provenance,
+            conversions: params.conversions,
+        };
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_data_type_embeddings_workflow(actor_id, &[DataTypeWithMetadata {
+                    schema: params.schema,
+                    metadata: metadata.clone(),
+                }])
+                .await
+                .change_context(UpdateError)?;
+        }
+
+        Ok(UpdateDataTypesResponse { metadata, changes })
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn archive_data_type(
+        &mut self,
+        actor_id: AccountId,
+        params: ArchiveDataTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        self.state
+            .write()
+            .await
+            .archive_ontology_type(&params.data_type_id, EditionArchivedById::new(actor_id))
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn unarchive_data_type(
+        &mut self,
+        actor_id: AccountId,
+        params: UnarchiveDataTypeParams,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        self.state.write().await.unarchive_ontology_type(
+            &params.data_type_id,
+            &OntologyEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                user_defined: params.provenance,
+            },
+        )
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_data_type_embeddings(
+        &mut self,
+        _: AccountId,
+        params: UpdateDataTypeEmbeddingParams<'_>,
+    ) -> Result<(), UpdateError> {
+        // TODO: Add permission to allow updating embeddings
+        //   see https://linear.app/hash/issue/H-1870
+
+        update_ontology_embedding(
+            &mut self.state.write().await.data_types,
+            &params.data_type_id,
+            params.embedding,
+            params.updated_at_transaction_time,
+        );
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn reindex_cache(&mut self) -> Result<(), UpdateError> {
+        tracing::info!("Reindexing data type cache");
+        let mut state = self.state.write().await;
+
+        let mut ontology_type_resolver = OntologyTypeResolver::default();
+        for (url, data_type) in &state.data_types {
+            ontology_type_resolver.add_open(
+                DataTypeId::from_url(url),
+                Arc::new(data_type.schema.clone()),
+            );
+        }
+
+        let inheritance_data = state
+            .data_types
+            .keys()
+            .map(|url| {
+                ontology_type_resolver
+                    .resolve_data_type_metadata(DataTypeId::from_url(url))
+                    .map(|inheritance_data| (url.clone(), inheritance_data))
+                    .change_context(UpdateError)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (url, inheritance_data) in inheritance_data {
+            if let Some(data_type) = state.data_types.get_mut(&url) {
+                data_type.inheritance_data = Arc::unwrap_or_clone(inheritance_data);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,968 @@
+use core::iter::once;
+use std::collections::{HashMap, HashSet};
+
+use authorization::{
+    AuthorizationApi,
+    backend::ModifyRelationshipOperation,
+    schema::{
+        EntityTypeOwnerSubject, EntityTypePermission, EntityTypeRelationAndSubject,
+        PropertyTypePermission, WebPermission,
+    },
+    zanzibar::{Consistency, Zookie},
+};
+use error_stack::{Report, Result, ResultExt, ensure};
+use futures::{StreamExt, TryStreamExt};
+use graph_types::{
+    account::{AccountId, EditionArchivedById, EditionCreatedById},
+    ontology::{
+        EntityTypeId, EntityTypeMetadata, EntityTypeWithMetadata, OntologyEditionProvenance,
+        OntologyProvenance, OntologyTemporalMetadata, OntologyTypeClassificationMetadata,
+        OntologyTypeRecordId, PropertyTypeId,
+    },
+};
+use hash_graph_store::subgraph::{
+    Subgraph, SubgraphRecord,
+    edges::{EdgeDirection, OntologyEdgeKind},
+    identifier::{EntityTypeVertexId, GraphElementVertexId, PropertyTypeVertexId},
+    temporal_axes::QueryTemporalAxes,
+};
+use type_system::{
+    Validator,
+    schema::{ClosedEntityType, EntityType, EntityTypeValidator},
+    url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+};
+
+use super::{OntologyTraversalData, split_traversal_queue, update_ontology_embedding};
+use crate::store::{
+    EntityTypeStore, InsertionError, QueryError, StoreCache, StoreProvider, UpdateError,
+    crud::{QueryResult, Read, ReadPaginated, VersionedUrlSorting},
+    memory::{
+        MemoryStore,
+        state::{EntityTypeRecord, MemoryState},
+        transaction_time,
+    },
+    ontology::{
+        ArchiveEntityTypeParams, BreakingChangeBehavior, CountEntityTypesParams,
+        CreateEntityTypeParams, GetEntityTypeSubgraphParams, GetEntityTypeSubgraphResponse,
+        GetEntityTypesParams, GetEntityTypesResponse, UnarchiveEntityTypeParams,
+        UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams, UpdateEntityTypesResponse,
+    },
+    postgres::{ResponseCountMap, TraversalContext},
+};
+
+fn create_closed_entity_type(
+    entity_type_id: EntityTypeId,
+    available_types: &mut HashMap<EntityTypeId, ClosedEntityType>,
+) -> Result<ClosedEntityType, QueryError> {
+    let mut current_type = available_types
+        .remove(&entity_type_id)
+        .ok_or_else(|| Report::new(QueryError))
+        .attach_printable("entity type not available")?;
+    let mut visited_ids = HashSet::from([entity_type_id]);
+
+    loop {
+        for parent in current_type.all_of.clone() {
+            let parent_id = EntityTypeId::from_url(&parent.url);
+
+            ensure!(
+                parent_id != entity_type_id,
+                Report::new(QueryError).attach_printable("inheritance cycle detected")
+            );
+
+            if visited_ids.contains(&parent_id) {
+                // This may happen in case of multiple inheritance or cycles. Cycles are
+                // already checked above, so we can just skip this parent.
+                current_type.all_of.remove(&parent);
+                break;
+            }
+
+            current_type.extend_one(
+                available_types
+                    .get(&parent_id)
+                    .ok_or_else(|| Report::new(QueryError))
+                    .attach_printable("entity type not available")
+                    .attach_printable_lazy(|| parent.url.clone())?
+                    .clone(),
+            );
+
+            visited_ids.insert(parent_id);
+        }
+
+        if current_type.all_of.is_empty() {
+            break;
+        }
+    }
+
+    available_types.insert(entity_type_id, current_type.clone());
+    Ok(current_type)
+}
+
+impl MemoryState {
+    /// Creates the closed schemas for the provided entity types.
+    ///
+    /// Parents are either taken from the provided entity types or from the store.
+    fn resolve_entity_types(
+        &self,
+        entity_types: &[&EntityType],
+    ) -> Result<Vec<ClosedEntityType>, QueryError> {
+        let parent_schemas = entity_types
+            .iter()
+            .flat_map(|schema| &schema.all_of)
+            .filter_map(|reference| {
+                self.entity_types.get(&reference.url).map(|parent| {
+                    (
+                        EntityTypeId::from_url(&reference.url),
+                        parent.closed_schema.clone(),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut available_schemas: HashMap<_, _> = entity_types
+            .iter()
+            .map(|schema| {
+                (
+                    EntityTypeId::from_url(&schema.id),
+                    ClosedEntityType::from((*schema).clone()),
+                )
+            })
+            .chain(parent_schemas)
+            .collect();
+
+        entity_types
+            .iter()
+            .map(|schema| {
+                create_closed_entity_type(
+                    EntityTypeId::from_url(&schema.id),
+                    &mut available_schemas,
+                )
+            })
+            .collect()
+    }
+
+    /// Ensures that all property types and entity types referenced by the entity type exist.
+    fn ensure_entity_type_references_exist(
+        &self,
+        entity_type: &EntityType,
+    ) -> Result<(), InsertionError> {
+        let link_mappings = entity_type.link_mappings();
+        self.ensure_ontology_references_exist(
+            entity_type
+                .property_type_references()
+                .into_iter()
+                .map(|reference| &reference.url)
+                .chain(entity_type.all_of.iter().map(|reference| &reference.url))
+                .chain(link_mappings.keys().map(|reference| &reference.url))
+                .chain(
+                    link_mappings
+                        .values()
+                        .flatten()
+                        .flat_map(|destinations| destinations.iter())
+                        .map(|reference| &reference.url),
+                ),
+        )
+        .attach_printable_lazy(|| {
+            format!(
+                "could not insert references for entity type: {}",
+                entity_type.id
+            )
+        })
+    }
+}
+
+impl<A> MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    async fn get_entity_types_impl(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityTypesParams<'_>,
+        temporal_axes: &QueryTemporalAxes,
+    ) -> Result<(GetEntityTypesResponse, Zookie<'static>), QueryError> {
+        let (count, web_ids, edition_created_by_ids) = if params.include_count
+            || params.include_web_ids
+            || params.include_edition_created_by_ids
+        {
+            let mut web_ids = params.include_web_ids.then(ResponseCountMap::default);
+            let mut edition_created_by_ids = params
+                .include_edition_created_by_ids
+                .then(ResponseCountMap::default);
+
+            let entity_type_ids = Read::<EntityTypeWithMetadata>::read(
+                self,
+                &params.filter,
+                Some(temporal_axes),
+                params.include_drafts,
+            )
+            .await?
+            .map_ok(|entity_type| {
+                if let (Some(web_ids), OntologyTypeClassificationMetadata::Owned { owned_by_id }) =
+                    (&mut web_ids, &entity_type.metadata.classification)
+                {
+                    web_ids.increment(owned_by_id);
+                }
+                if let Some(edition_created_by_ids) = &mut edition_created_by_ids {
+                    edition_created_by_ids
+                        .increment(&entity_type.metadata.provenance.edition.created_by_id);
+                }
+                EntityTypeId::from_record_id(&entity_type.metadata.record_id)
+            })
+            .try_collect::<Vec<_>>()
+            .await?;
+
+            let (permissions, _zookie) = self
+                .authorization_api
+                .check_entity_types_permission(
+                    actor_id,
+                    EntityTypePermission::View,
+                    entity_type_ids.iter().copied(),
+                    Consistency::FullyConsistent,
+                )
+                .await
+                .change_context(QueryError)?;
+
+            let count = entity_type_ids
+                .into_iter()
+                .filter(|id| permissions.get(id).copied().unwrap_or(false))
+                .count();
+            (
+                Some(count),
+                web_ids.map(HashMap::from),
+                edition_created_by_ids.map(HashMap::from),
+            )
+        } else {
+            (None, None, None)
+        };
+
+        let mut visited_ontology_ids = HashSet::new();
+
+        let (data, artifacts) =
+            ReadPaginated::<EntityTypeWithMetadata, VersionedUrlSorting>::read_paginated_vec(
+                self,
+                &params.filter,
+                Some(temporal_axes),
+                &VersionedUrlSorting {
+                    cursor: params.after,
+                },
+                params.limit,
+                params.include_drafts,
+            )
+            .await?;
+        let entity_types = data
+            .into_iter()
+            .filter_map(|row| {
+                let entity_type = row.decode_record(&artifacts);
+                let id = EntityTypeId::from_url(&entity_type.schema.id);
+                visited_ontology_ids.insert(id).then_some((id, entity_type))
+            })
+            .collect::<Vec<_>>();
+
+        let filtered_ids = entity_types
+            .iter()
+            .map(|(entity_type_id, _)| *entity_type_id)
+            .collect::<Vec<_>>();
+
+        let (permissions, zookie) = self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::View,
+                filtered_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let entity_types = entity_types
+            .into_iter()
+            .filter_map(|(id, entity_type)| {
+                permissions
+                    .get(&id)
+                    .copied()
+                    .unwrap_or(false)
+                    .then_some(entity_type)
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            GetEntityTypesResponse {
+                cursor: if params.limit.is_some() {
+                    entity_types
+                        .last()
+                        .map(|entity_type| entity_type.schema.id.clone())
+                } else {
+                    None
+                },
+                entity_types,
+                count,
+                web_ids,
+                edition_created_by_ids,
+            },
+            zookie,
+        ))
+    }
+
+    /// Traverses the entity types in `entity_type_queue` and adds the edges to the `subgraph`.
+    ///
+    /// Property types found while traversing are traversed afterwards.
+    pub(in crate::store::memory) async fn traverse_entity_types(
+        &self,
+        mut entity_type_queue: Vec<OntologyTraversalData>,
+        traversal_context: &mut TraversalContext,
+        actor_id: AccountId,
+        zookie: &Zookie<'static>,
+        subgraph: &mut Subgraph,
+    ) -> Result<(), QueryError> {
+        let mut property_type_queue = Vec::new();
+
+        while !entity_type_queue.is_empty() {
+            let edges_to_traverse =
+                split_traversal_queue(core::mem::take(&mut entity_type_queue), &[
+                    OntologyEdgeKind::ConstrainsPropertiesOn,
+                    OntologyEdgeKind::InheritsFrom,
+                    OntologyEdgeKind::ConstrainsLinksOn,
+                    OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                ]);
+
+            if let Some(traversal_data) =
+                edges_to_traverse.get(&OntologyEdgeKind::ConstrainsPropertiesOn)
+            {
+                let edges = self
+                    .read_ontology_edges(traversal_data, OntologyEdgeKind::ConstrainsPropertiesOn)
+                    .await;
+                let (permissions, _) = self
+                    .authorization_api
+                    .check_property_types_permission(
+                        actor_id,
+                        PropertyTypePermission::View,
+                        edges
+                            .iter()
+                            .map(|edge| PropertyTypeId::from_url(&edge.right_endpoint)),
+                        Consistency::AtExactSnapshot(zookie),
+                    )
+                    .await
+                    .change_context(QueryError)?;
+
+                for edge in edges {
+                    let property_type_id = PropertyTypeId::from_url(&edge.right_endpoint);
+                    if !permissions.get(&property_type_id).copied().unwrap_or(false) {
+                        continue;
+                    }
+
+                    subgraph.insert_edge(
+                        &EntityTypeVertexId::from(edge.left_endpoint),
+                        OntologyEdgeKind::ConstrainsPropertiesOn,
+                        EdgeDirection::Outgoing,
+                        PropertyTypeVertexId::from(edge.right_endpoint.clone()),
+                    );
+
+                    property_type_queue.extend(
+                        traversal_context
+                            .add_property_type_id(
+                                property_type_id,
+                                edge.resolve_depths,
+                                edge.traversal_interval,
+                            )
+                            .map(|(_, resolve_depths, traversal_interval)| {
+                                (
+                                    edge.right_endpoint.clone(),
+                                    resolve_depths,
+                                    traversal_interval,
+                                )
+                            }),
+                    );
+                }
+            }
+
+            for edge_kind in [
+                OntologyEdgeKind::InheritsFrom,
+                OntologyEdgeKind::ConstrainsLinksOn,
+                OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+            ] {
+                let Some(traversal_data) = edges_to_traverse.get(&edge_kind) else {
+                    continue;
+                };
+
+                let edges = self.read_ontology_edges(traversal_data, edge_kind).await;
+                let (permissions, _) = self
+                    .authorization_api
+                    .check_entity_types_permission(
+                        actor_id,
+                        EntityTypePermission::View,
+                        edges
+                            .iter()
+                            .map(|edge| EntityTypeId::from_url(&edge.right_endpoint)),
+                        Consistency::AtExactSnapshot(zookie),
+                    )
+                    .await
+                    .change_context(QueryError)?;
+
+                for edge in edges {
+                    let entity_type_id = EntityTypeId::from_url(&edge.right_endpoint);
+                    if !permissions.get(&entity_type_id).copied().unwrap_or(false) {
+                        continue;
+                    }
+
+                    subgraph.insert_edge(
+                        &EntityTypeVertexId::from(edge.left_endpoint),
+                        edge_kind,
+                        EdgeDirection::Outgoing,
+                        EntityTypeVertexId::from(edge.right_endpoint.clone()),
+                    );
+
+                    entity_type_queue.extend(
+                        traversal_context
+                            .add_entity_type_id(
+                                entity_type_id,
+                                edge.resolve_depths,
+                                edge.traversal_interval,
+                            )
+                            .map(|(_, resolve_depths, traversal_interval)| {
+                                (
+                                    edge.right_endpoint.clone(),
+                                    resolve_depths,
+                                    traversal_interval,
+                                )
+                            }),
+                    );
+                }
+            }
+        }
+
+        self.traverse_property_types(
+            property_type_queue,
+            traversal_context,
+            actor_id,
+            zookie,
+            subgraph,
+        )
+        .await
+    }
+
+    /// Returns `true` if `parent` is a direct or indirect parent of the entity type `child`.
+    pub(in crate::store) async fn entity_type_is_parent_of(
+        &self,
+        child: &VersionedUrl,
+        parent: &BaseUrl,
+    ) -> bool {
+        self.state
+            .read()
+            .await
+            .entity_type_ancestors(child)
+            .keys()
+            .any(|ancestor| ancestor.base_url == *parent)
+    }
+
+    /// Returns the closed schema of the entity type which is currently not archived.
+    ///
+    /// # Errors
+    ///
+    /// - If the entity type does not exist or is archived
+    pub(in crate::store) async fn closed_entity_type(
+        &self,
+        entity_type_id: &VersionedUrl,
+    ) -> Result<ClosedEntityType, QueryError> {
+        let now = transaction_time();
+        let state = self.state.read().await;
+        let is_visible = state
+            .ontology_ids
+            .get(entity_type_id)
+            .is_some_and(|record| {
+                record
+                    .temporal_metadata
+                    .iter()
+                    .any(|row| row.transaction_time.contains_point(&now))
+            });
+
+        state
+            .entity_types
+            .get(entity_type_id)
+            .filter(|_| is_visible)
+            .map(|entity_type| entity_type.closed_schema.clone())
+            .ok_or_else(|| {
+                Report::new(QueryError).attach_printable(
+                    "Expected exactly one closed schema to be returned from the query but none \
+                     was returned",
+                )
+            })
+    }
+}
+
+impl<A> EntityTypeStore for MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn create_entity_types<P, R>(
+        &mut self,
+        actor_id: AccountId,
+        params: P,
+    ) -> Result<Vec<EntityTypeMetadata>, InsertionError>
+    where
+        P: IntoIterator<Item = CreateEntityTypeParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = EntityTypeRelationAndSubject> + Send + Sync,
+    {
+        let mut relationships = HashSet::new();
+        let mut parameters = Vec::new();
+
+        for params in params {
+            let entity_type_id = EntityTypeId::from_url(&params.schema.id);
+            if let OntologyTypeClassificationMetadata::Owned { owned_by_id } =
+                &params.classification
+            {
+                self.authorization_api
+                    .check_web_permission(
+                        actor_id,
+                        WebPermission::CreateEntityType,
+                        *owned_by_id,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(InsertionError)?
+                    .assert_permission()
+                    .change_context(InsertionError)?;
+
+                relationships.insert((entity_type_id, EntityTypeRelationAndSubject::Owner {
+                    subject: EntityTypeOwnerSubject::Web { id: *owned_by_id },
+                    level: 0,
+                }));
+            }
+
+            relationships.extend(
+                params
+                    .relationships
+                    .into_iter()
+                    .map(|relation_and_subject| (entity_type_id, relation_and_subject)),
+            );
+
+            parameters.push(params);
+        }
+
+        let mut state = self.state.write().await;
+        let mut transaction = MemoryState::clone(&state);
+
+        let closed_schemas = transaction
+            .resolve_entity_types(
+                &parameters
+                    .iter()
+                    .map(|params| &params.schema)
+                    .collect::<Vec<_>>(),
+            )
+            .change_context(InsertionError)?;
+
+        let mut inserted_entity_types = Vec::new();
+        for (params, closed_schema) in parameters.into_iter().zip(closed_schemas) {
+            EntityTypeValidator
+                .validate_ref(&params.schema)
+                .await
+                .change_context(InsertionError)?;
+            EntityTypeValidator
+                .validate_ref(&closed_schema)
+                .await
+                .change_context(InsertionError)?;
+
+            let record_id = OntologyTypeRecordId::from(params.schema.id.clone());
+            let provenance = OntologyProvenance {
+                edition: OntologyEditionProvenance {
+                    created_by_id: EditionCreatedById::new(actor_id),
+                    archived_by_id: None,
+                    user_defined: params.provenance,
+                },
+            };
+
+            if let Some(temporal_versioning) = transaction.create_ontology_metadata(
+                &record_id,
+                &params.classification,
+                params.conflict_behavior,
+                &provenance,
+            )? {
+                transaction
+                    .entity_types
+                    .insert(params.schema.id.clone(), EntityTypeRecord {
+                        schema: params.schema.clone(),
+                        closed_schema,
+                        label_property: params.label_property.clone(),
+                        icon: params.icon.clone(),
+                        embedding: None,
+                    });
+                inserted_entity_types.push(EntityTypeWithMetadata {
+                    schema: params.schema,
+                    metadata: EntityTypeMetadata {
+                        record_id,
+                        classification: params.classification,
+                        temporal_versioning,
+                        provenance,
+                        label_property: params.label_property,
+                        icon: params.icon,
+                    },
+                });
+            }
+        }
+
+        for entity_type in &inserted_entity_types {
+            transaction
+                .ensure_entity_type_references_exist(&entity_type.schema)
+                .attach_lazy(|| entity_type.schema.clone())?;
+        }
+
+        self.authorization_api
+            .modify_entity_type_relations(relationships.into_iter().map(
+                |(resource, relation_and_subject)| {
+                    (
+                        ModifyRelationshipOperation::Create,
+                        resource,
+                        relation_and_subject,
+                    )
+                },
+            ))
+            .await
+            .change_context(InsertionError)?;
+
+        *state = transaction;
+        drop(state);
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_entity_type_embeddings_workflow(actor_id, &inserted_entity_types)
+                .await
+                .change_context(InsertionError)?;
+        }
+
+        Ok(inserted_entity_types
+            .into_iter()
+            .map(|entity_type| entity_type.metadata)
+            .collect())
+    }
+
+    async fn count_entity_types(
+        &self,
+        actor_id: AccountId,
+        mut params: CountEntityTypesParams<'_>,
+    ) -> Result<usize, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        Ok(self
+            .read(
+                &params.filter,
+                Some(&params.temporal_axes.resolve()),
+                params.include_drafts,
+            )
+            .await?
+            .count()
+            .await)
+    }
+
+    async fn get_entity_types(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntityTypesParams<'_>,
+    ) -> Result<GetEntityTypesResponse, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.clone().resolve();
+        self.get_entity_types_impl(actor_id, params, &temporal_axes)
+            .await
+            .map(|(response, _)| response)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_entity_type_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntityTypeSubgraphParams<'_>,
+    ) -> Result<GetEntityTypeSubgraphResponse, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.clone().resolve();
+        let time_axis = temporal_axes.variable_time_axis();
+
+        let (
+            GetEntityTypesResponse {
+                entity_types,
+                cursor,
+                count,
+                web_ids,
+                edition_created_by_ids,
+            },
+            zookie,
+        ) = self
+            .get_entity_types_impl(
+                actor_id,
+                GetEntityTypesParams {
+                    filter: params.filter,
+                    temporal_axes: params.temporal_axes.clone(),
+                    after: params.after,
+                    limit: params.limit,
+                    include_drafts: params.include_drafts,
+                    include_count: params.include_count,
+                    include_web_ids: params.include_web_ids,
+                    include_edition_created_by_ids: params.include_edition_created_by_ids,
+                },
+                &temporal_axes,
+            )
+            .await?;
+
+        let mut subgraph = Subgraph::new(
+            params.graph_resolve_depths,
+            params.temporal_axes,
+            temporal_axes.clone(),
+        );
+
+        let (entity_type_urls, entity_type_vertex_ids): (Vec<_>, Vec<_>) = entity_types
+            .iter()
+            .map(|entity_type| {
+                (
+                    entity_type.schema.id.clone(),
+                    GraphElementVertexId::from(entity_type.vertex_id(time_axis)),
+                )
+            })
+            .unzip();
+        subgraph.roots.extend(entity_type_vertex_ids);
+        subgraph.vertices.entity_types = entity_types
+            .into_iter()
+            .map(|entity_type| (entity_type.vertex_id(time_axis), entity_type))
+            .collect();
+
+        let mut traversal_context = TraversalContext::default();
+
+        self.traverse_entity_types(
+            entity_type_urls
+                .into_iter()
+                .map(|url| {
+                    (
+                        url,
+                        subgraph.depths,
+                        subgraph.temporal_axes.resolved.variable_interval(),
+                    )
+                })
+                .collect(),
+            &mut traversal_context,
+            actor_id,
+            &zookie,
+            &mut subgraph,
+        )
+        .await?;
+
+        traversal_context
+            .read_traversed_vertices(self, &mut subgraph, params.include_drafts)
+            .await?;
+
+        Ok(GetEntityTypeSubgraphResponse {
+            subgraph,
+            cursor,
+            count,
+            web_ids,
+            edition_created_by_ids,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_entity_type<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateEntityTypesParams<R>,
+    ) -> Result<UpdateEntityTypesResponse, UpdateError>
+    where
+        R: IntoIterator<Item = EntityTypeRelationAndSubject> + Send + Sync,
+    {
+        let previous_url = VersionedUrl {
+            base_url: params.schema.id.base_url.clone(),
+            version: OntologyTypeVersion::new(
+                params
+                    .schema
+                    .id
+                    .version
+                    .inner()
+                    .checked_sub(1)
+                    .ok_or(UpdateError)
+                    .attach_printable(
+                        "The version of the data type is already at the lowest possible value",
+                    )?,
+            ),
+        };
+        let old_ontology_id = EntityTypeId::from_url(&previous_url);
+        self.authorization_api
+            .check_entity_type_permission(
+                actor_id,
+                EntityTypePermission::Update,
+                old_ontology_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let provenance = OntologyProvenance {
+            edition: OntologyEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                user_defined: params.provenance,
+            },
+        };
+
+        let mut state = self.state.write().await;
+        let mut transaction = MemoryState::clone(&state);
+
+        let (owned_by_id, temporal_versioning) =
+            transaction.update_owned_ontology_id(&params.schema.id, &provenance.edition)?;
+
+        let changes = if params.breaking_changes == BreakingChangeBehavior::Allow {
+            Vec::new()
+        } else {
+            let previous = transaction.entity_types.get(&previous_url).ok_or_else(|| {
+                Report::new(UpdateError).attach_printable("previous entity type not found")
+            })?;
+            params
+                .breaking_changes
+                .handle_changes(previous.schema.changes_to(&params.schema))
+                .change_context(UpdateError)?
+        };
+
+        let closed_schema = transaction
+            .resolve_entity_types(&[&params.schema])
+            .change_context(UpdateError)?
+            .pop()
+            .ok_or_else(|| Report::new(UpdateError).attach_printable("entity type not found"))?;
+
+        EntityTypeValidator
+            .validate_ref(&params.schema)
+            .await
+            .change_context(UpdateError)?;
+        EntityTypeValidator
+            .validate_ref(&closed_schema)
+            .await
+            .change_context(UpdateError)?;
+
+        transaction
+            .ensure_entity_type_references_exist(&params.schema)
+            .change_context(UpdateError)
+            .attach_lazy(|| params.schema.clone())?;
+        transaction
+            .entity_types
+            .insert(params.schema.id.clone(), EntityTypeRecord {
+                schema: params.schema.clone(),
+                closed_schema,
+                label_property: params.label_property.clone(),
+                icon: params.icon.clone(),
+                embedding: None,
+            });
+
+        let entity_type_id = EntityTypeId::from_url(&params.schema.id);
+        let relationships = params
+            .relationships
+            .into_iter()
+            .chain(once(EntityTypeRelationAndSubject::Owner {
+                subject: EntityTypeOwnerSubject::Web { id: owned_by_id },
+                level: 0,
+            }))
+            .collect::<Vec<_>>();
+
+        self.authorization_api
+            .modify_entity_type_relations(relationships.into_iter().map(|relation_and_subject| {
+                (
+                    ModifyRelationshipOperation::Create,
+                    entity_type_id,
+                    relation_and_subject,
+                )
+            }))
+            .await
+            .change_context(UpdateError)?;
+
+        *state = transaction;
+        drop(state);
+
+        let metadata = EntityTypeMetadata {
+            record_id: OntologyTypeRecordId::from(params.schema.id.clone()),
+            classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+            temporal_versioning,
+            provenance,
+            label_property: params.label_property,
+            icon: params.icon,
+        };
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_entity_type_embeddings_workflow(actor_id, &[EntityTypeWithMetadata {
+                    schema: params.schema,
+                    metadata: metadata.clone(),
+                }])
+                .await
+                .change_context(UpdateError)?;
+        }
+
+        Ok(UpdateEntityTypesResponse { metadata, changes })
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn archive_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: ArchiveEntityTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        self.state
+            .write()
+            .await
+            .archive_ontology_type(&params.entity_type_id, EditionArchivedById::new(actor_id))
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn unarchive_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: UnarchiveEntityTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        self.state.write().await.unarchive_ontology_type(
+            &params.entity_type_id,
+            &OntologyEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                user_defined: params.provenance,
+            },
+        )
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_entity_type_embeddings(
+        &mut self,
+        _: AccountId,
+        params: UpdateEntityTypeEmbeddingParams<'_>,
+    ) -> Result<(), UpdateError> {
+        // TODO: Add permission to allow updating embeddings
+        //   see https://linear.app/hash/issue/H-1870
+
+        update_ontology_embedding(
+            &mut self.state.write().await.entity_types,
+            &params.entity_type_id,
+            params.embedding,
+            params.updated_at_transaction_time,
+        );
+
+        Ok(())
+    }
+}
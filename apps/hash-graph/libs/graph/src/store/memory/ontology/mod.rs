@@ -0,0 +1,242 @@
+mod data_type;
+mod entity_type;
+mod property_type;
+
+use std::collections::HashMap;
+
+use error_stack::{Report, Result, ensure};
+use graph_types::Embedding;
+use hash_graph_store::subgraph::{
+    edges::{EdgeDirection, GraphResolveDepths, OntologyEdgeKind},
+    temporal_axes::VariableAxis,
+};
+use temporal_versioning::{RightBoundedTemporalInterval, Timestamp, TransactionTime};
+use type_system::url::VersionedUrl;
+
+use super::{
+    InsertionError, MemoryStore,
+    state::{
+        DataTypeRecord, EntityTypeRecord, MemoryState, OntologyEmbeddingRecord, PropertyTypeRecord,
+    },
+};
+
+/// An ontology type which has to be traversed, together with the depths and the interval used
+/// to traverse it.
+type OntologyTraversalData = (
+    VersionedUrl,
+    GraphResolveDepths,
+    RightBoundedTemporalInterval<VariableAxis>,
+);
+
+/// An outgoing edge between two ontology types found while traversing the graph.
+struct OntologyEdgeTraversal {
+    left_endpoint: VersionedUrl,
+    right_endpoint: VersionedUrl,
+    resolve_depths: GraphResolveDepths,
+    traversal_interval: RightBoundedTemporalInterval<VariableAxis>,
+}
+
+impl MemoryState {
+    /// Ensures that all referenced ontology types exist in the store.
+    ///
+    /// # Errors
+    ///
+    /// - [`InsertionError`] if any of the referenced ontology types does not exist
+    fn ensure_ontology_references_exist<'u>(
+        &self,
+        references: impl IntoIterator<Item = &'u VersionedUrl>,
+    ) -> Result<(), InsertionError> {
+        for reference in references {
+            ensure!(
+                self.ontology_ids.contains_key(reference),
+                Report::new(InsertionError)
+                    .attach_printable("The referenced ontology type does not exist")
+                    .attach_printable(reference.clone())
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the direct targets of the outgoing edge of `edge_kind` for the ontology type.
+    ///
+    /// Only the edges stored for the type itself are returned, inherited edges are not
+    /// considered, which matches an inheritance depth of `0` in the [`PostgresStore`].
+    ///
+    /// [`PostgresStore`]: crate::store::PostgresStore
+    fn ontology_edge_targets(
+        &self,
+        url: &VersionedUrl,
+        edge_kind: OntologyEdgeKind,
+    ) -> Vec<VersionedUrl> {
+        if self.data_types.contains_key(url) {
+            match edge_kind {
+                OntologyEdgeKind::InheritsFrom => self
+                    .data_type_parents(url)
+                    .into_iter()
+                    .filter_map(|(parent, depth)| (depth == 0).then_some(parent))
+                    .collect(),
+                _ => Vec::new(),
+            }
+        } else if let Some(property_type) = self.property_types.get(url) {
+            match edge_kind {
+                OntologyEdgeKind::ConstrainsValuesOn => property_type
+                    .schema
+                    .data_type_references()
+                    .into_iter()
+                    .map(|reference| reference.url.clone())
+                    .collect(),
+                OntologyEdgeKind::ConstrainsPropertiesOn => property_type
+                    .schema
+                    .property_type_references()
+                    .into_iter()
+                    .map(|reference| reference.url.clone())
+                    .collect(),
+                _ => Vec::new(),
+            }
+        } else if let Some(entity_type) = self.entity_types.get(url) {
+            match edge_kind {
+                OntologyEdgeKind::ConstrainsPropertiesOn => entity_type
+                    .schema
+                    .property_type_references()
+                    .into_iter()
+                    .map(|reference| reference.url.clone())
+                    .collect(),
+                OntologyEdgeKind::InheritsFrom => entity_type
+                    .schema
+                    .all_of
+                    .iter()
+                    .map(|reference| reference.url.clone())
+                    .collect(),
+                OntologyEdgeKind::ConstrainsLinksOn => entity_type
+                    .schema
+                    .link_mappings()
+                    .into_keys()
+                    .map(|reference| reference.url.clone())
+                    .collect(),
+                OntologyEdgeKind::ConstrainsLinkDestinationsOn => entity_type
+                    .schema
+                    .link_mappings()
+                    .into_values()
+                    .flatten()
+                    .flatten()
+                    .map(|reference| reference.url.clone())
+                    .collect(),
+                OntologyEdgeKind::ConstrainsValuesOn => Vec::new(),
+            }
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl<A> MemoryStore<A> {
+    /// Reads the outgoing edges of `edge_kind` for all ontology types in `traversal_data`.
+    ///
+    /// Ontology types are not versioned on the variable axis, so the traversal interval is passed
+    /// through unchanged.
+    async fn read_ontology_edges(
+        &self,
+        traversal_data: &[OntologyTraversalData],
+        edge_kind: OntologyEdgeKind,
+    ) -> Vec<OntologyEdgeTraversal> {
+        let state = self.state.read().await;
+        traversal_data
+            .iter()
+            .flat_map(|(url, resolve_depths, traversal_interval)| {
+                state
+                    .ontology_edge_targets(url, edge_kind)
+                    .into_iter()
+                    .map(|right_endpoint| OntologyEdgeTraversal {
+                        left_endpoint: url.clone(),
+                        right_endpoint,
+                        resolve_depths: *resolve_depths,
+                        traversal_interval: *traversal_interval,
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Splits the traversal queue into the ontology types which have to be traversed for each edge
+/// kind.
+fn split_traversal_queue(
+    queue: Vec<OntologyTraversalData>,
+    edge_kinds: &[OntologyEdgeKind],
+) -> HashMap<OntologyEdgeKind, Vec<OntologyTraversalData>> {
+    let mut edges_to_traverse = HashMap::<_, Vec<_>>::new();
+    for (url, graph_resolve_depths, traversal_interval) in queue {
+        for &edge_kind in edge_kinds {
+            if let Some(new_graph_resolve_depths) =
+                graph_resolve_depths.decrement_depth_for_edge(edge_kind, EdgeDirection::Outgoing)
+            {
+                edges_to_traverse.entry(edge_kind).or_default().push((
+                    url.clone(),
+                    new_graph_resolve_depths,
+                    traversal_interval,
+                ));
+            }
+        }
+    }
+    edges_to_traverse
+}
+
+/// An ontology record which can store an embedding.
+trait OntologyEmbeddingRecordSlot {
+    fn embedding_mut(&mut self) -> &mut Option<OntologyEmbeddingRecord>;
+}
+
+impl OntologyEmbeddingRecordSlot for DataTypeRecord {
+    fn embedding_mut(&mut self) -> &mut Option<OntologyEmbeddingRecord> {
+        &mut self.embedding
+    }
+}
+
+impl OntologyEmbeddingRecordSlot for PropertyTypeRecord {
+    fn embedding_mut(&mut self) -> &mut Option<OntologyEmbeddingRecord> {
+        &mut self.embedding
+    }
+}
+
+impl OntologyEmbeddingRecordSlot for EntityTypeRecord {
+    fn embedding_mut(&mut self) -> &mut Option<OntologyEmbeddingRecord> {
+        &mut self.embedding
+    }
+}
+
+/// Stores the embedding for the ontology type.
+///
+/// Only the latest version of an ontology type keeps an embedding, embeddings of previous
+/// versions are removed. An existing embedding is only replaced if it was not updated after
+/// `updated_at_transaction_time`.
+fn update_ontology_embedding<R: OntologyEmbeddingRecordSlot>(
+    records: &mut HashMap<VersionedUrl, R>,
+    url: &VersionedUrl,
+    embedding: Embedding<'_>,
+    updated_at_transaction_time: Timestamp<TransactionTime>,
+) {
+    let is_latest_version = records
+        .keys()
+        .filter(|other| other.base_url == url.base_url)
+        .all(|other| other.version <= url.version);
+    if !is_latest_version || !records.contains_key(url) {
+        return;
+    }
+
+    for (other, record) in records.iter_mut() {
+        if other.base_url != url.base_url {
+            continue;
+        }
+
+        let slot = record.embedding_mut();
+        if other.version < url.version {
+            *slot = None;
+        } else if slot.as_ref().is_none_or(|existing| {
+            existing.updated_at_transaction_time <= updated_at_transaction_time
+        }) {
+            *slot = Some(OntologyEmbeddingRecord {
+                embedding: embedding.to_owned(),
+                updated_at_transaction_time,
+            });
+        }
+    }
+}
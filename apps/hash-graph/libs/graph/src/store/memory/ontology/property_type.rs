@@ -0,0 +1,748 @@
+use core::iter::once;
+use std::collections::HashSet;
+
+use authorization::{
+    AuthorizationApi,
+    backend::ModifyRelationshipOperation,
+    schema::{
+        DataTypePermission, PropertyTypeOwnerSubject, PropertyTypePermission,
+        PropertyTypeRelationAndSubject, WebPermission,
+    },
+    zanzibar::{Consistency, Zookie},
+};
+use error_stack::{Report, Result, ResultExt};
+use futures::StreamExt;
+use graph_types::{
+    account::{AccountId, EditionArchivedById, EditionCreatedById},
+    ontology::{
+        OntologyEditionProvenance, OntologyProvenance, OntologyTemporalMetadata,
+        OntologyTypeClassificationMetadata, OntologyTypeRecordId, PropertyTypeId,
+        PropertyTypeMetadata, PropertyTypeWithMetadata,
+    },
+};
+use hash_graph_store::subgraph::{
+    Subgraph, SubgraphRecord,
+    edges::{EdgeDirection, OntologyEdgeKind},
+    identifier::{DataTypeVertexId, GraphElementVertexId, PropertyTypeVertexId},
+    temporal_axes::QueryTemporalAxes,
+};
+use type_system::{
+    Validator,
+    schema::{DataTypeId, PropertyType, PropertyTypeValidator},
+    url::{OntologyTypeVersion, VersionedUrl},
+};
+
+use super::{OntologyTraversalData, split_traversal_queue, update_ontology_embedding};
+use crate::store::{
+    InsertionError, PropertyTypeStore, QueryError, StoreCache, StoreProvider, UpdateError,
+    crud::{QueryResult, Read, ReadPaginated, VersionedUrlSorting},
+    memory::{
+        MemoryStore,
+        state::{MemoryState, PropertyTypeRecord},
+    },
+    ontology::{
+        ArchivePropertyTypeParams, BreakingChangeBehavior, CountPropertyTypesParams,
+        CreatePropertyTypeParams, GetPropertyTypeSubgraphParams, GetPropertyTypeSubgraphResponse,
+        GetPropertyTypesParams, GetPropertyTypesResponse, UnarchivePropertyTypeParams,
+        UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams, UpdatePropertyTypesResponse,
+    },
+    postgres::TraversalContext,
+};
+
+impl MemoryState {
+    /// Ensures that all data types and property types referenced by the property type exist.
+    fn ensure_property_type_references_exist(
+        &self,
+        property_type: &PropertyType,
+    ) -> Result<(), InsertionError> {
+        self.ensure_ontology_references_exist(
+            property_type
+                .property_type_references()
+                .into_iter()
+                .map(|reference| &reference.url)
+                .chain(
+                    property_type
+                        .data_type_references()
+                        .into_iter()
+                        .map(|reference| &reference.url),
+                ),
+        )
+        .attach_printable_lazy(|| {
+            format!(
+                "could not insert references for property type: {}",
+                property_type.id
+            )
+        })
+    }
+}
+
+impl<A> MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    async fn get_property_types_impl(
+        &self,
+        actor_id: AccountId,
+        params: GetPropertyTypesParams<'_>,
+        temporal_axes: &QueryTemporalAxes,
+    ) -> Result<(GetPropertyTypesResponse, Zookie<'static>), QueryError> {
+        #[expect(clippy::if_then_some_else_none, reason = "Function is async")]
+        let count = if params.include_count {
+            Some(
+                self.count_property_types(actor_id, CountPropertyTypesParams {
+                    filter: params.filter.clone(),
+                    temporal_axes: params.temporal_axes.clone(),
+                    include_drafts: params.include_drafts,
+                })
+                .await?,
+            )
+        } else {
+            None
+        };
+
+        let mut visited_ontology_ids = HashSet::new();
+
+        let (data, artifacts) =
+            ReadPaginated::<PropertyTypeWithMetadata, VersionedUrlSorting>::read_paginated_vec(
+                self,
+                &params.filter,
+                Some(temporal_axes),
+                &VersionedUrlSorting {
+                    cursor: params.after,
+                },
+                params.limit,
+                params.include_drafts,
+            )
+            .await?;
+        let property_types = data
+            .into_iter()
+            .filter_map(|row| {
+                let property_type = row.decode_record(&artifacts);
+                let id = PropertyTypeId::from_url(&property_type.schema.id);
+                visited_ontology_ids
+                    .insert(id)
+                    .then_some((id, property_type))
+            })
+            .collect::<Vec<_>>();
+
+        let filtered_ids = property_types
+            .iter()
+            .map(|(property_type_id, _)| *property_type_id)
+            .collect::<Vec<_>>();
+
+        let (permissions, zookie) = self
+            .authorization_api
+            .check_property_types_permission(
+                actor_id,
+                PropertyTypePermission::View,
+                filtered_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let property_types = property_types
+            .into_iter()
+            .filter_map(|(id, property_type)| {
+                permissions
+                    .get(&id)
+                    .copied()
+                    .unwrap_or(false)
+                    .then_some(property_type)
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            GetPropertyTypesResponse {
+                cursor: if params.limit.is_some() {
+                    property_types
+                        .last()
+                        .map(|property_type| property_type.schema.id.clone())
+                } else {
+                    None
+                },
+                property_types,
+                count,
+            },
+            zookie,
+        ))
+    }
+
+    /// Traverses the property types in `property_type_queue` and adds the edges to the
+    /// `subgraph`.
+    ///
+    /// Data types found while traversing are traversed afterwards.
+    pub(in crate::store::memory) async fn traverse_property_types(
+        &self,
+        mut property_type_queue: Vec<OntologyTraversalData>,
+        traversal_context: &mut TraversalContext,
+        actor_id: AccountId,
+        zookie: &Zookie<'static>,
+        subgraph: &mut Subgraph,
+    ) -> Result<(), QueryError> {
+        let mut data_type_queue = Vec::new();
+
+        while !property_type_queue.is_empty() {
+            let edges_to_traverse =
+                split_traversal_queue(core::mem::take(&mut property_type_queue), &[
+                    OntologyEdgeKind::ConstrainsValuesOn,
+                    OntologyEdgeKind::ConstrainsPropertiesOn,
+                ]);
+
+            if let Some(traversal_data) =
+                edges_to_traverse.get(&OntologyEdgeKind::ConstrainsValuesOn)
+            {
+                let edges = self
+                    .read_ontology_edges(traversal_data, OntologyEdgeKind::ConstrainsValuesOn)
+                    .await;
+                let (permissions, _) = self
+                    .authorization_api
+                    .check_data_types_permission(
+                        actor_id,
+                        DataTypePermission::View,
+                        edges
+                            .iter()
+                            .map(|edge| DataTypeId::from_url(&edge.right_endpoint)),
+                        Consistency::AtExactSnapshot(zookie),
+                    )
+                    .await
+                    .change_context(QueryError)?;
+
+                for edge in edges {
+                    let data_type_id = DataTypeId::from_url(&edge.right_endpoint);
+                    if !permissions.get(&data_type_id).copied().unwrap_or(false) {
+                        continue;
+                    }
+
+                    subgraph.insert_edge(
+                        &PropertyTypeVertexId::from(edge.left_endpoint),
+                        OntologyEdgeKind::ConstrainsValuesOn,
+                        EdgeDirection::Outgoing,
+                        DataTypeVertexId::from(edge.right_endpoint.clone()),
+                    );
+
+                    data_type_queue.extend(
+                        traversal_context
+                            .add_data_type_id(
+                                data_type_id,
+                                edge.resolve_depths,
+                                edge.traversal_interval,
+                            )
+                            .map(|(_, resolve_depths, traversal_interval)| {
+                                (
+                                    edge.right_endpoint.clone(),
+                                    resolve_depths,
+                                    traversal_interval,
+                                )
+                            }),
+                    );
+                }
+            }
+
+            if let Some(traversal_data) =
+                edges_to_traverse.get(&OntologyEdgeKind::ConstrainsPropertiesOn)
+            {
+                let edges = self
+                    .read_ontology_edges(traversal_data, OntologyEdgeKind::ConstrainsPropertiesOn)
+                    .await;
+                let (permissions, _) = self
+                    .authorization_api
+                    .check_property_types_permission(
+                        actor_id,
+                        PropertyTypePermission::View,
+                        edges
+                            .iter()
+                            .map(|edge| PropertyTypeId::from_url(&edge.right_endpoint)),
+                        Consistency::AtExactSnapshot(zookie),
+                    )
+                    .await
+                    .change_context(QueryError)?;
+
+                for edge in edges {
+                    let property_type_id = PropertyTypeId::from_url(&edge.right_endpoint);
+                    if !permissions.get(&property_type_id).copied().unwrap_or(false) {
+                        continue;
+                    }
+
+                    subgraph.insert_edge(
+                        &PropertyTypeVertexId::from(edge.left_endpoint),
+                        OntologyEdgeKind::ConstrainsPropertiesOn,
+                        EdgeDirection::Outgoing,
+                        PropertyTypeVertexId::from(edge.right_endpoint.clone()),
+                    );
+
+                    property_type_queue.extend(
+                        traversal_context
+                            .add_property_type_id(
+                                property_type_id,
+                                edge.resolve_depths,
+                                edge.traversal_interval,
+                            )
+                            .map(|(_, resolve_depths, traversal_interval)| {
+                                (
+                                    edge.right_endpoint.clone(),
+                                    resolve_depths,
+                                    traversal_interval,
+                                )
+                            }),
+                    );
+                }
+            }
+        }
+
+        self.traverse_data_types(
+            data_type_queue,
+            traversal_context,
+            actor_id,
+            zookie,
+            subgraph,
+        )
+        .await
+    }
+}
+
+impl<A> PropertyTypeStore for MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn create_property_types<P, R>(
+        &mut self,
+        actor_id: AccountId,
+        params: P,
+    ) -> Result<Vec<PropertyTypeMetadata>, InsertionError>
+    where
+        P: IntoIterator<Item = CreatePropertyTypeParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = PropertyTypeRelationAndSubject> + Send + Sync,
+    {
+        let mut relationships = HashSet::new();
+        let mut parameters = Vec::new();
+
+        for params in params {
+            let property_type_id = PropertyTypeId::from_url(&params.schema.id);
+            if let OntologyTypeClassificationMetadata::Owned { owned_by_id } =
+                &params.classification
+            {
+                self.authorization_api
+                    .check_web_permission(
+                        actor_id,
+                        WebPermission::CreatePropertyType,
+                        *owned_by_id,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(InsertionError)?
+                    .assert_permission()
+                    .change_context(InsertionError)?;
+
+                relationships.insert((property_type_id, PropertyTypeRelationAndSubject::Owner {
+                    subject: PropertyTypeOwnerSubject::Web { id: *owned_by_id },
+                    level: 0,
+                }));
+            }
+
+            relationships.extend(
+                params
+                    .relationships
+                    .into_iter()
+                    .map(|relation_and_subject| (property_type_id, relation_and_subject)),
+            );
+
+            PropertyTypeValidator
+                .validate_ref(&params.schema)
+                .await
+                .change_context(InsertionError)?;
+
+            parameters.push((
+                params.schema,
+                params.classification,
+                params.conflict_behavior,
+                OntologyProvenance {
+                    edition: OntologyEditionProvenance {
+                        created_by_id: EditionCreatedById::new(actor_id),
+                        archived_by_id: None,
+                        user_defined: params.provenance,
+                    },
+                },
+            ));
+        }
+
+        let mut state = self.state.write().await;
+        let mut transaction = MemoryState::clone(&state);
+
+        let mut inserted_property_types = Vec::new();
+        for (schema, classification, conflict_behavior, provenance) in parameters {
+            let record_id = OntologyTypeRecordId::from(schema.id.clone());
+            if let Some(temporal_versioning) = transaction.create_ontology_metadata(
+                &record_id,
+                &classification,
+                conflict_behavior,
+                &provenance,
+            )? {
+                transaction
+                    .property_types
+                    .insert(schema.id.clone(), PropertyTypeRecord {
+                        schema: schema.clone(),
+                        embedding: None,
+                    });
+                inserted_property_types.push(PropertyTypeWithMetadata {
+                    schema,
+                    metadata: PropertyTypeMetadata {
+                        record_id,
+                        classification,
+                        temporal_versioning,
+                        provenance,
+                    },
+                });
+            }
+        }
+
+        for property_type in &inserted_property_types {
+            transaction.ensure_property_type_references_exist(&property_type.schema)?;
+        }
+
+        self.authorization_api
+            .modify_property_type_relations(relationships.into_iter().map(
+                |(resource, relation_and_subject)| {
+                    (
+                        ModifyRelationshipOperation::Create,
+                        resource,
+                        relation_and_subject,
+                    )
+                },
+            ))
+            .await
+            .change_context(InsertionError)?;
+
+        *state = transaction;
+        drop(state);
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_property_type_embeddings_workflow(actor_id, &inserted_property_types)
+                .await
+                .change_context(InsertionError)?;
+        }
+
+        Ok(inserted_property_types
+            .into_iter()
+            .map(|property_type| property_type.metadata)
+            .collect())
+    }
+
+    async fn count_property_types(
+        &self,
+        actor_id: AccountId,
+        mut params: CountPropertyTypesParams<'_>,
+    ) -> Result<usize, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        Ok(self
+            .read(
+                &params.filter,
+                Some(&params.temporal_axes.resolve()),
+                params.include_drafts,
+            )
+            .await?
+            .count()
+            .await)
+    }
+
+    async fn get_property_types(
+        &self,
+        actor_id: AccountId,
+        mut params: GetPropertyTypesParams<'_>,
+    ) -> Result<GetPropertyTypesResponse, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.clone().resolve();
+        self.get_property_types_impl(actor_id, params, &temporal_axes)
+            .await
+            .map(|(response, _)| response)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_property_type_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetPropertyTypeSubgraphParams<'_>,
+    ) -> Result<GetPropertyTypeSubgraphResponse, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.clone().resolve();
+        let time_axis = temporal_axes.variable_time_axis();
+
+        let (
+            GetPropertyTypesResponse {
+                property_types,
+                cursor,
+                count,
+            },
+            zookie,
+        ) = self
+            .get_property_types_impl(
+                actor_id,
+                GetPropertyTypesParams {
+                    filter: params.filter,
+                    temporal_axes: params.temporal_axes.clone(),
+                    after: params.after,
+                    limit: params.limit,
+                    include_drafts: params.include_drafts,
+                    include_count: params.include_count,
+                },
+                &temporal_axes,
+            )
+            .await?;
+
+        let mut subgraph = Subgraph::new(
+            params.graph_resolve_depths,
+            params.temporal_axes,
+            temporal_axes.clone(),
+        );
+
+        let (property_type_urls, property_type_vertex_ids): (Vec<_>, Vec<_>) = property_types
+            .iter()
+            .map(|property_type| {
+                (
+                    property_type.schema.id.clone(),
+                    GraphElementVertexId::from(property_type.vertex_id(time_axis)),
+                )
+            })
+            .unzip();
+        subgraph.roots.extend(property_type_vertex_ids);
+        subgraph.vertices.property_types = property_types
+            .into_iter()
+            .map(|property_type| (property_type.vertex_id(time_axis), property_type))
+            .collect();
+
+        let mut traversal_context = TraversalContext::default();
+
+        self.traverse_property_types(
+            property_type_urls
+                .into_iter()
+                .map(|url| {
+                    (
+                        url,
+                        subgraph.depths,
+                        subgraph.temporal_axes.resolved.variable_interval(),
+                    )
+                })
+                .collect(),
+            &mut traversal_context,
+            actor_id,
+            &zookie,
+            &mut subgraph,
+        )
+        .await?;
+
+        traversal_context
+            .read_traversed_vertices(self, &mut subgraph, params.include_drafts)
+            .await?;
+
+        Ok(GetPropertyTypeSubgraphResponse {
+            subgraph,
+            cursor,
+            count,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_property_type<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdatePropertyTypesParams<R>,
+    ) -> Result<UpdatePropertyTypesResponse, UpdateError>
+    where
+        R: IntoIterator<Item = PropertyTypeRelationAndSubject> + Send + Sync,
+    {
+        let previous_url = VersionedUrl {
+            base_url: params.schema.id.base_url.clone(),
+            version: OntologyTypeVersion::new(
+                params
+                    .schema
+                    .id
+                    .version
+                    .inner()
+                    .checked_sub(1)
+                    .ok_or(UpdateError)
+                    .attach_printable(
+                        "The version of the data type is already at the lowest possible value",
+                    )?,
+            ),
+        };
+        let old_ontology_id = PropertyTypeId::from_url(&previous_url);
+        self.authorization_api
+            .check_property_type_permission(
+                actor_id,
+                PropertyTypePermission::Update,
+                old_ontology_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)
+            .attach_printable(old_ontology_id.into_uuid())?;
+
+        let provenance = OntologyProvenance {
+            edition: OntologyEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                user_defined: params.provenance,
+            },
+        };
+
+        PropertyTypeValidator
+            .validate_ref(&params.schema)
+            .await
+            .change_context(UpdateError)?;
+
+        let mut state = self.state.write().await;
+        let mut transaction = MemoryState::clone(&state);
+
+        let (owned_by_id, temporal_versioning) =
+            transaction.update_owned_ontology_id(&params.schema.id, &provenance.edition)?;
+
+        let changes = if params.breaking_changes == BreakingChangeBehavior::Allow {
+            Vec::new()
+        } else {
+            let previous = transaction
+                .property_types
+                .get(&previous_url)
+                .ok_or_else(|| {
+                    Report::new(UpdateError).attach_printable("previous property type not found")
+                })?;
+            params
+                .breaking_changes
+                .handle_changes(previous.schema.changes_to(&params.schema))
+                .change_context(UpdateError)?
+        };
+
+        transaction
+            .ensure_property_type_references_exist(&params.schema)
+            .change_context(UpdateError)
+            .attach_lazy(|| params.schema.clone())?;
+        transaction
+            .property_types
+            .insert(params.schema.id.clone(), PropertyTypeRecord {
+                schema: params.schema.clone(),
+                embedding: None,
+            });
+
+        let property_type_id = PropertyTypeId::from_url(&params.schema.id);
+        let relationships = params
+            .relationships
+            .into_iter()
+            .chain(once(PropertyTypeRelationAndSubject::Owner {
+                subject: PropertyTypeOwnerSubject::Web { id: owned_by_id },
+                level: 0,
+            }))
+            .collect::<Vec<_>>();
+
+        self.authorization_api
+            .modify_property_type_relations(relationships.into_iter().map(|relation_and_subject| {
+                (
+                    ModifyRelationshipOperation::Create,
+                    property_type_id,
+                    relation_and_subject,
+                )
+            }))
+            .await
+            .change_context(UpdateError)?;
+
+        *state = transaction;
+        drop(state);
+
+        let metadata = PropertyTypeMetadata {
+            record_id: OntologyTypeRecordId::from(params.schema.id.clone()),
+            classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+            temporal_versioning,
+            provenance,
+        };
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_property_type_embeddings_workflow(actor_id, &[
+                    PropertyTypeWithMetadata {
+                        schema: params.schema,
+                        metadata: metadata.clone(),
+                    },
+                ])
+                .await
+                .change_context(UpdateError)?;
+        }
+
+        Ok(UpdatePropertyTypesResponse { metadata, changes })
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn archive_property_type(
+        &mut self,
+        actor_id: AccountId,
+        params: ArchivePropertyTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        self.state
+            .write()
+            .await
+            .archive_ontology_type(&params.property_type_id, EditionArchivedById::new(actor_id))
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn unarchive_property_type(
+        &mut self,
+        actor_id: AccountId,
+        params: UnarchivePropertyTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        self.state.write().await.unarchive_ontology_type(
+            &params.property_type_id,
+            &OntologyEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                user_defined: params.provenance,
+            },
+        )
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_property_type_embeddings(
+        &mut self,
+        _: AccountId,
+        params: UpdatePropertyTypeEmbeddingParams<'_>,
+    ) -> Result<(), UpdateError> {
+        // TODO: Add permission to allow updating embeddings
+        //   see https://linear.app/hash/issue/H-1870
+
+        update_ontology_embedding(
+            &mut self.state.write().await.property_types,
+            &params.property_type_id,
+            params.embedding,
+            params.updated_at_transaction_time,
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,2273 @@
+//! Evaluation of [`Filter`]s against the [`MemoryState`].
+//!
+//! A [`Filter`] is first compiled into a [`CompiledFilter`]. Every path used in the filter is
+//! split into a chain of [`Hop`]s, which describe how to get from the queried record to the record
+//! holding the value, and a [`Leaf`], which describes how to read the value from that record.
+//! Equal chains are shared between paths, which mirrors the way the Postgres store deduplicates
+//! joins: a record matches the filter if there exists one record for every chain which makes the
+//! condition evaluate to `true`.
+
+use core::cmp::Ordering;
+use std::collections::{HashMap, hash_map::Entry};
+
+use graph_types::{
+    knowledge::entity::{EntityId, EntityUuid},
+    ontology::OntologyTypeClassificationMetadata,
+    owned_by_id::OwnedById,
+};
+use hash_graph_store::{
+    data_type::DataTypeQueryPath,
+    entity::EntityQueryPath,
+    entity_type::EntityTypeQueryPath,
+    filter::{
+        Filter, FilterExpression, JsonPath, Parameter, ParameterList, PathToken, QueryRecord,
+    },
+    property_type::PropertyTypeQueryPath,
+    subgraph::{
+        edges::{EdgeDirection, KnowledgeGraphEdgeKind, OntologyEdgeKind, SharedEdgeKind},
+        temporal_axes::QueryTemporalAxes,
+    },
+};
+use regex::RegexBuilder;
+use serde_json::{Number as JsonNumber, Value as JsonValue, json};
+use temporal_versioning::{
+    LeftClosedTemporalInterval, TemporalBound, TemporalInterval, TemporalTagged, Timestamp,
+};
+use type_system::{schema::DataTypeId, url::VersionedUrl};
+use uuid::Uuid;
+
+use super::state::{EntityEmbeddingRecord, EntityTemporalRow, MemoryState, OntologyTemporalRow};
+use crate::store::{
+    AggregationFunction, NullOrdering, Ordering as SortingOrdering,
+    knowledge::{EntityAggregationGroup, EntityQuerySorting},
+    postgres::CursorField,
+};
+
+/// The kind of record a query is issued for.
+///
+/// Temporal and draft restrictions are only applied to records of the queried kind, the same way
+/// as the Postgres store only restricts the tables of the queried record.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(super) enum RecordKind {
+    DataType,
+    PropertyType,
+    EntityType,
+    Entity,
+}
+
+/// A step from one record to a related record.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub(super) enum Hop {
+    DataTypeInheritsFrom {
+        direction: EdgeDirection,
+        inheritance_depth: Option<u32>,
+    },
+    PropertyTypeConstrainsValuesOn {
+        direction: EdgeDirection,
+    },
+    PropertyTypeConstrainsPropertiesOn {
+        direction: EdgeDirection,
+    },
+    EntityTypeConstrainsPropertiesOn {
+        direction: EdgeDirection,
+        inheritance_depth: Option<u32>,
+    },
+    EntityTypeInheritsFrom {
+        direction: EdgeDirection,
+        inheritance_depth: Option<u32>,
+    },
+    EntityTypeConstrainsLinksOn {
+        direction: EdgeDirection,
+        inheritance_depth: Option<u32>,
+    },
+    EntityTypeConstrainsLinkDestinationsOn {
+        direction: EdgeDirection,
+        inheritance_depth: Option<u32>,
+    },
+    EntityIsOfType {
+        direction: EdgeDirection,
+        inheritance_depth: Option<u32>,
+    },
+    EntityHasLeftEntity {
+        direction: EdgeDirection,
+    },
+    EntityHasRightEntity {
+        direction: EdgeDirection,
+    },
+    EntityEmbeddings,
+}
+
+impl Hop {
+    /// Returns `true` if a record without any related record is still considered.
+    ///
+    /// This corresponds to a `LEFT OUTER JOIN` in the Postgres store.
+    const fn is_optional(self) -> bool {
+        matches!(self, Self::EntityEmbeddings)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(super) enum LinkSide {
+    Left,
+    Right,
+}
+
+/// Describes how to read a value from a record.
+///
+/// Depending on the record a leaf is evaluated on, the same leaf may read different values, e.g.
+/// [`Leaf::OwnedById`] reads the web of an ontology type or of an entity.
+#[derive(Debug, Copy, Clone)]
+pub(super) enum Leaf<'f> {
+    BaseUrl,
+    Version,
+    LatestVersion,
+    VersionedUrl,
+    TransactionTime,
+    OwnedById,
+    Title,
+    Description,
+    OntologyId,
+    Schema(Option<&'f JsonPath<'f>>),
+    AdditionalMetadata,
+    EditionProvenance(Option<&'f JsonPath<'f>>),
+    Embedding,
+
+    Type,
+    TargetConversionBaseUrls,
+    FromConversions,
+    IntoConversions,
+
+    Examples,
+    Required,
+    LabelProperty,
+    Icon,
+    ClosedSchema(Option<&'f JsonPath<'f>>),
+
+    Uuid,
+    DraftId,
+    EditionId,
+    DecisionTime,
+    TypeBaseUrls,
+    TypeVersions,
+    EntityConfidence,
+    LinkConfidence(LinkSide),
+    LinkProvenance(LinkSide),
+    LinkEntityUuid(LinkSide),
+    LinkOwnedById(LinkSide),
+    Archived,
+    Properties(Option<&'f JsonPath<'f>>),
+    Label,
+    Provenance(Option<&'f JsonPath<'f>>),
+    PropertyMetadata(Option<&'f JsonPath<'f>>),
+}
+
+/// A query path which can be evaluated by the [`MemoryStore`].
+///
+/// [`MemoryStore`]: super::MemoryStore
+pub(super) trait MemoryQueryPath {
+    /// Pushes the [`Hop`]s required to reach the record holding the value and returns the
+    /// [`Leaf`] to read the value.
+    fn compile<'f>(&'f self, hops: &mut Vec<Hop>) -> Leaf<'f>;
+}
+
+impl MemoryQueryPath for DataTypeQueryPath<'_> {
+    fn compile<'f>(&'f self, hops: &mut Vec<Hop>) -> Leaf<'f> {
+        match self {
+            Self::BaseUrl => Leaf::BaseUrl,
+            Self::Version => Leaf::Version,
+            Self::VersionedUrl => Leaf::VersionedUrl,
+            Self::TransactionTime => Leaf::TransactionTime,
+            Self::OwnedById => Leaf::OwnedById,
+            Self::Title => Leaf::Title,
+            Self::Description => Leaf::Description,
+            Self::Type => Leaf::Type,
+            Self::OntologyId => Leaf::OntologyId,
+            Self::Schema(path) => Leaf::Schema(path.as_ref()),
+            Self::AdditionalMetadata => Leaf::AdditionalMetadata,
+            Self::Embedding => Leaf::Embedding,
+            Self::EditionProvenance(path) => Leaf::EditionProvenance(path.as_ref()),
+            Self::TargetConversionBaseUrls => Leaf::TargetConversionBaseUrls,
+            Self::FromConversions => Leaf::FromConversions,
+            Self::IntoConversions => Leaf::IntoConversions,
+            Self::DataTypeEdge {
+                edge_kind: OntologyEdgeKind::InheritsFrom,
+                path,
+                direction,
+                inheritance_depth,
+            } => {
+                hops.push(Hop::DataTypeInheritsFrom {
+                    direction: *direction,
+                    inheritance_depth: *inheritance_depth,
+                });
+                path.compile(hops)
+            }
+            Self::PropertyTypeEdge {
+                edge_kind: OntologyEdgeKind::ConstrainsValuesOn,
+                path,
+            } => {
+                hops.push(Hop::PropertyTypeConstrainsValuesOn {
+                    direction: EdgeDirection::Incoming,
+                });
+                path.compile(hops)
+            }
+            Self::DataTypeEdge { .. } | Self::PropertyTypeEdge { .. } => {
+                unreachable!("Invalid path: {self}")
+            }
+        }
+    }
+}
+
+impl MemoryQueryPath for PropertyTypeQueryPath<'_> {
+    fn compile<'f>(&'f self, hops: &mut Vec<Hop>) -> Leaf<'f> {
+        match self {
+            Self::BaseUrl => Leaf::BaseUrl,
+            Self::Version => Leaf::Version,
+            Self::VersionedUrl => Leaf::VersionedUrl,
+            Self::TransactionTime => Leaf::TransactionTime,
+            Self::OwnedById => Leaf::OwnedById,
+            Self::Title => Leaf::Title,
+            Self::Description => Leaf::Description,
+            Self::OntologyId => Leaf::OntologyId,
+            Self::Schema(path) => Leaf::Schema(path.as_ref()),
+            Self::AdditionalMetadata => Leaf::AdditionalMetadata,
+            Self::EditionProvenance(path) => Leaf::EditionProvenance(path.as_ref()),
+            Self::Embedding => Leaf::Embedding,
+            Self::DataTypeEdge {
+                edge_kind: OntologyEdgeKind::ConstrainsValuesOn,
+                path,
+            } => {
+                hops.push(Hop::PropertyTypeConstrainsValuesOn {
+                    direction: EdgeDirection::Outgoing,
+                });
+                path.compile(hops)
+            }
+            Self::PropertyTypeEdge {
+                edge_kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                path,
+                direction,
+            } => {
+                hops.push(Hop::PropertyTypeConstrainsPropertiesOn {
+                    direction: *direction,
+                });
+                path.compile(hops)
+            }
+            Self::EntityTypeEdge {
+                edge_kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                path,
+                inheritance_depth,
+            } => {
+                hops.push(Hop::EntityTypeConstrainsPropertiesOn {
+                    direction: EdgeDirection::Incoming,
+                    inheritance_depth: *inheritance_depth,
+                });
+                path.compile(hops)
+            }
+            Self::DataTypeEdge { .. }
+            | Self::PropertyTypeEdge { .. }
+            | Self::EntityTypeEdge { .. } => unreachable!("Invalid path: {self}"),
+        }
+    }
+}
+
+impl MemoryQueryPath for EntityTypeQueryPath<'_> {
+    fn compile<'f>(&'f self, hops: &mut Vec<Hop>) -> Leaf<'f> {
+        match self {
+            Self::BaseUrl => Leaf::BaseUrl,
+            Self::Version => Leaf::Version,
+            Self::VersionedUrl => Leaf::VersionedUrl,
+            Self::TransactionTime => Leaf::TransactionTime,
+            Self::OwnedById => Leaf::OwnedById,
+            Self::Title => Leaf::Title,
+            Self::Description => Leaf::Description,
+            Self::Examples => Leaf::Examples,
+            Self::Required => Leaf::Required,
+            Self::LabelProperty => Leaf::LabelProperty,
+            Self::Icon => Leaf::Icon,
+            Self::OntologyId => Leaf::OntologyId,
+            Self::Schema(path) => Leaf::Schema(path.as_ref()),
+            Self::ClosedSchema(path) => Leaf::ClosedSchema(path.as_ref()),
+            Self::AdditionalMetadata => Leaf::AdditionalMetadata,
+            Self::EditionProvenance(path) => Leaf::EditionProvenance(path.as_ref()),
+            Self::Embedding => Leaf::Embedding,
+            Self::PropertyTypeEdge {
+                edge_kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                path,
+                inheritance_depth,
+            } => {
+                hops.push(Hop::EntityTypeConstrainsPropertiesOn {
+                    direction: EdgeDirection::Outgoing,
+                    inheritance_depth: *inheritance_depth,
+                });
+                path.compile(hops)
+            }
+            Self::EntityTypeEdge {
+                edge_kind,
+                path,
+                direction,
+                inheritance_depth,
+            } => {
+                let direction = *direction;
+                let inheritance_depth = *inheritance_depth;
+                hops.push(match edge_kind {
+                    OntologyEdgeKind::InheritsFrom => Hop::EntityTypeInheritsFrom {
+                        direction,
+                        inheritance_depth,
+                    },
+                    OntologyEdgeKind::ConstrainsLinksOn => Hop::EntityTypeConstrainsLinksOn {
+                        direction,
+                        inheritance_depth,
+                    },
+                    OntologyEdgeKind::ConstrainsLinkDestinationsOn => {
+                        Hop::EntityTypeConstrainsLinkDestinationsOn {
+                            direction,
+                            inheritance_depth,
+                        }
+                    }
+                    OntologyEdgeKind::ConstrainsValuesOn
+                    | OntologyEdgeKind::ConstrainsPropertiesOn => {
+                        unreachable!("Invalid path: {self}")
+                    }
+                });
+                path.compile(hops)
+            }
+            Self::EntityEdge {
+                edge_kind: SharedEdgeKind::IsOfType,
+                path,
+                inheritance_depth,
+            } => {
+                hops.push(Hop::EntityIsOfType {
+                    direction: EdgeDirection::Incoming,
+                    inheritance_depth: *inheritance_depth,
+                });
+                path.compile(hops)
+            }
+            Self::PropertyTypeEdge { .. } => unreachable!("Invalid path: {self}"),
+        }
+    }
+}
+
+impl MemoryQueryPath for EntityQueryPath<'_> {
+    fn compile<'f>(&'f self, hops: &mut Vec<Hop>) -> Leaf<'f> {
+        match self {
+            Self::Uuid => Leaf::Uuid,
+            Self::OwnedById => Leaf::OwnedById,
+            Self::DraftId => Leaf::DraftId,
+            Self::EditionId => Leaf::EditionId,
+            Self::DecisionTime => Leaf::DecisionTime,
+            Self::TransactionTime => Leaf::TransactionTime,
+            Self::TypeBaseUrls => Leaf::TypeBaseUrls,
+            Self::TypeVersions => Leaf::TypeVersions,
+            Self::EntityConfidence => Leaf::EntityConfidence,
+            Self::LeftEntityConfidence => Leaf::LinkConfidence(LinkSide::Left),
+            Self::LeftEntityProvenance => Leaf::LinkProvenance(LinkSide::Left),
+            Self::RightEntityConfidence => Leaf::LinkConfidence(LinkSide::Right),
+            Self::RightEntityProvenance => Leaf::LinkProvenance(LinkSide::Right),
+            Self::Archived => Leaf::Archived,
+            Self::Properties(path) => Leaf::Properties(path.as_ref()),
+            Self::Provenance(path) => Leaf::Provenance(path.as_ref()),
+            Self::EditionProvenance(path) => Leaf::EditionProvenance(path.as_ref()),
+            Self::PropertyMetadata(path) => Leaf::PropertyMetadata(path.as_ref()),
+            Self::Embedding => {
+                hops.push(Hop::EntityEmbeddings);
+                Leaf::Embedding
+            }
+            Self::Label { inheritance_depth } => {
+                hops.push(Hop::EntityIsOfType {
+                    direction: EdgeDirection::Outgoing,
+                    inheritance_depth: *inheritance_depth,
+                });
+                Leaf::Label
+            }
+            Self::EntityTypeEdge {
+                edge_kind: SharedEdgeKind::IsOfType,
+                path,
+                inheritance_depth,
+            } => {
+                hops.push(Hop::EntityIsOfType {
+                    direction: EdgeDirection::Outgoing,
+                    inheritance_depth: *inheritance_depth,
+                });
+                path.compile(hops)
+            }
+            Self::EntityEdge {
+                edge_kind,
+                path,
+                direction,
+            } => {
+                let side = match edge_kind {
+                    KnowledgeGraphEdgeKind::HasLeftEntity => LinkSide::Left,
+                    KnowledgeGraphEdgeKind::HasRightEntity => LinkSide::Right,
+                };
+                // The endpoints of a link are stored on the link itself, so they are read
+                // directly without requiring the endpoint to exist at the queried time.
+                match (direction, &**path) {
+                    (EdgeDirection::Outgoing, Self::Uuid) => return Leaf::LinkEntityUuid(side),
+                    (EdgeDirection::Outgoing, Self::OwnedById) => {
+                        return Leaf::LinkOwnedById(side);
+                    }
+                    _ => {}
+                }
+                hops.push(match side {
+                    LinkSide::Left => Hop::EntityHasLeftEntity {
+                        direction: *direction,
+                    },
+                    LinkSide::Right => Hop::EntityHasRightEntity {
+                        direction: *direction,
+                    },
+                });
+                path.compile(hops)
+            }
+        }
+    }
+}
+
+/// A value read from a record or passed as parameter.
+///
+/// [`Value::Null`] has the same semantics as `NULL` in SQL.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Text(String),
+    Uuid(Uuid),
+    Version(u32),
+    Timestamp(Timestamp<()>),
+    Interval(TemporalInterval<()>),
+    Json(JsonValue),
+    Vector(Vec<f32>),
+    Array(Vec<Self>),
+}
+
+impl From<&Parameter<'_>> for Value {
+    fn from(parameter: &Parameter<'_>) -> Self {
+        match parameter {
+            Parameter::Boolean(value) => Self::Bool(*value),
+            Parameter::I32(value) => Self::Number(f64::from(*value)),
+            Parameter::F64(value) => Self::Number(*value),
+            Parameter::Text(value) => Self::Text(value.to_string()),
+            Parameter::Vector(value) => Self::Vector(value.iter().collect()),
+            Parameter::Any(value) => Self::Json(value.clone()),
+            Parameter::Uuid(value) => Self::Uuid(*value),
+            Parameter::OntologyTypeVersion(value) => Self::Version(value.inner()),
+            Parameter::Timestamp(value) => Self::Timestamp(*value),
+        }
+    }
+}
+
+impl From<&CursorField<'_>> for Value {
+    fn from(field: &CursorField<'_>) -> Self {
+        match field {
+            CursorField::Bool(value) => Self::Bool(*value),
+            CursorField::I32(value) => Self::Number(f64::from(*value)),
+            CursorField::F64(value) => Self::Number(*value),
+            CursorField::String(value) => Self::Text(value.to_string()),
+            CursorField::Timestamp(value) => Self::Timestamp(*value),
+            CursorField::TimeInterval(value) => Self::Interval(*value),
+            CursorField::Json(JsonValue::Null) => Self::Null,
+            CursorField::Json(value) => Self::Json(value.clone()),
+            CursorField::Uuid(value) => Self::Uuid(*value),
+        }
+    }
+}
+
+impl Value {
+    fn interval<A>(interval: LeftClosedTemporalInterval<A>) -> Self {
+        Self::Interval(
+            interval
+                .convert::<TemporalBound<A>, TemporalBound<A>>()
+                .cast(),
+        )
+    }
+
+    fn json_path(value: JsonValue, path: Option<&JsonPath<'_>>) -> Self {
+        let Some(path) = path else {
+            return Self::Json(value);
+        };
+
+        let mut current = &value;
+        for token in path.path_tokens() {
+            let next = match token {
+                PathToken::Field(field) => current.get(field.as_ref()),
+                PathToken::Index(index) => current.get(*index),
+            };
+            match next {
+                Some(next) => current = next,
+                None => return Self::Null,
+            }
+        }
+        Self::Json(current.clone())
+    }
+
+    fn json_field(value: &JsonValue, field: &str) -> Self {
+        match value.get(field) {
+            None | Some(JsonValue::Null) => Self::Null,
+            Some(JsonValue::String(string)) => Self::Text(string.clone()),
+            Some(value) => Self::Json(value.clone()),
+        }
+    }
+
+    pub(super) fn into_cursor_field(self) -> CursorField<'static> {
+        match self {
+            Self::Null => CursorField::Json(JsonValue::Null),
+            Self::Bool(value) => CursorField::Bool(value),
+            Self::Number(value) => CursorField::F64(value),
+            Self::Text(value) => CursorField::String(value.into()),
+            Self::Uuid(value) => CursorField::Uuid(value),
+            Self::Version(value) => CursorField::I32(i32::try_from(value).unwrap_or(i32::MAX)),
+            Self::Timestamp(value) => CursorField::Timestamp(value),
+            Self::Interval(value) => CursorField::TimeInterval(value),
+            value @ (Self::Json(_) | Self::Vector(_) | Self::Array(_)) => {
+                CursorField::Json(value.into_json())
+            }
+        }
+    }
+
+    fn into_json(self) -> JsonValue {
+        match self {
+            Self::Null => JsonValue::Null,
+            Self::Bool(value) => JsonValue::Bool(value),
+            Self::Number(value) => {
+                JsonNumber::from_f64(value).map_or(JsonValue::Null, JsonValue::Number)
+            }
+            Self::Text(value) => JsonValue::String(value),
+            Self::Uuid(value) => JsonValue::String(value.to_string()),
+            Self::Version(value) => JsonValue::from(value),
+            Self::Timestamp(value) => json!(value),
+            Self::Interval(value) => json!(value),
+            Self::Json(value) => value,
+            Self::Vector(value) => value.into_iter().map(JsonValue::from).collect(),
+            Self::Array(value) => value.into_iter().map(Self::into_json).collect(),
+        }
+    }
+
+    /// Returns the textual representation as used for pattern matching.
+    fn as_text(&self) -> Option<String> {
+        match self {
+            Self::Null => None,
+            Self::Text(value) | Self::Json(JsonValue::String(value)) => Some(value.clone()),
+            Self::Bool(value) => Some(value.to_string()),
+            Self::Number(value) => Some(value.to_string()),
+            Self::Uuid(value) => Some(value.to_string()),
+            Self::Version(value) => Some(value.to_string()),
+            Self::Json(value) => Some(value.to_string()),
+            value @ (Self::Timestamp(_) | Self::Interval(_) | Self::Vector(_) | Self::Array(_)) => {
+                Some(value.clone().into_json().to_string())
+            }
+        }
+    }
+
+    /// Returns the elements of a JSON array.
+    ///
+    /// Like in Postgres, a JSON scalar is treated as an array containing only the scalar.
+    fn as_json_array(&self) -> Option<Vec<JsonValue>> {
+        match self {
+            Self::Null | Self::Json(JsonValue::Null) => None,
+            Self::Json(JsonValue::Array(array)) => Some(array.clone()),
+            value => Some(vec![value.clone().into_json()]),
+        }
+    }
+
+    /// Returns the numeric representation as used for aggregations.
+    ///
+    /// Like a cast to `float8` in Postgres, numeric strings are parsed.
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(*value),
+            Self::Version(value) => Some(f64::from(*value)),
+            Self::Json(JsonValue::Number(value)) => value.as_f64(),
+            Self::Text(value) | Self::Json(JsonValue::String(value)) => value.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Compares two values.
+    ///
+    /// Returns [`None`] if any value is [`Value::Null`] or the values are not comparable.
+    pub(super) fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Null, _) | (_, Self::Null) => None,
+            (Self::Bool(lhs), Self::Bool(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Number(lhs), Self::Number(rhs)) => lhs.partial_cmp(rhs),
+            (Self::Number(lhs), Self::Version(rhs)) => lhs.partial_cmp(&f64::from(*rhs)),
+            (Self::Version(lhs), Self::Number(rhs)) => f64::from(*lhs).partial_cmp(rhs),
+            (Self::Version(lhs), Self::Version(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Text(lhs), Self::Text(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Uuid(lhs), Self::Uuid(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Text(lhs), Self::Uuid(rhs)) => Some(lhs.as_str().cmp(&rhs.to_string())),
+            (Self::Uuid(lhs), Self::Text(rhs)) => Some(lhs.to_string().as_str().cmp(rhs)),
+            (Self::Timestamp(lhs), Self::Timestamp(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Interval(lhs), Self::Interval(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Json(lhs), Self::Json(rhs)) => Some(compare_json(lhs, rhs)),
+            (Self::Json(lhs), rhs) => Some(compare_json(lhs, &rhs.clone().into_json())),
+            (lhs, Self::Json(rhs)) => Some(compare_json(&lhs.clone().into_json(), rhs)),
+            (Self::Vector(lhs), Self::Vector(rhs)) => lhs.partial_cmp(rhs),
+            (Self::Array(lhs), Self::Array(rhs)) => {
+                for (lhs, rhs) in lhs.iter().zip(rhs) {
+                    match lhs.compare(rhs)? {
+                        Ordering::Equal => {}
+                        ordering => return Some(ordering),
+                    }
+                }
+                Some(lhs.len().cmp(&rhs.len()))
+            }
+            _ => None,
+        }
+    }
+
+    fn equals(&self, other: &Self) -> Option<bool> {
+        match (self, other) {
+            (Self::Null, _) | (_, Self::Null) => None,
+            (Self::Interval(interval), Self::Timestamp(timestamp))
+            | (Self::Timestamp(timestamp), Self::Interval(interval)) => {
+                Some(interval.contains_point(timestamp))
+            }
+            (lhs, rhs) => Some(lhs.compare(rhs) == Some(Ordering::Equal)),
+        }
+    }
+}
+
+/// Compares two JSON values the same way as `jsonb` values are compared in Postgres.
+fn compare_json(lhs: &JsonValue, rhs: &JsonValue) -> Ordering {
+    const fn rank(value: &JsonValue) -> u8 {
+        match value {
+            JsonValue::Null => 0,
+            JsonValue::String(_) => 1,
+            JsonValue::Number(_) => 2,
+            JsonValue::Bool(_) => 3,
+            JsonValue::Array(_) => 4,
+            JsonValue::Object(_) => 5,
+        }
+    }
+
+    match (lhs, rhs) {
+        (JsonValue::String(lhs), JsonValue::String(rhs)) => lhs.cmp(rhs),
+        (JsonValue::Number(lhs), JsonValue::Number(rhs)) => lhs
+            .as_f64()
+            .partial_cmp(&rhs.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (JsonValue::Bool(lhs), JsonValue::Bool(rhs)) => lhs.cmp(rhs),
+        (JsonValue::Array(lhs), JsonValue::Array(rhs)) => {
+            lhs.len().cmp(&rhs.len()).then_with(|| {
+                lhs.iter()
+                    .zip(rhs)
+                    .map(|(lhs, rhs)| compare_json(lhs, rhs))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            })
+        }
+        (JsonValue::Object(lhs), JsonValue::Object(rhs)) => {
+            lhs.len().cmp(&rhs.len()).then_with(|| {
+                let mut lhs = lhs.iter().collect::<Vec<_>>();
+                let mut rhs = rhs.iter().collect::<Vec<_>>();
+                lhs.sort_by_key(|(key, _)| *key);
+                rhs.sort_by_key(|(key, _)| *key);
+                lhs.into_iter()
+                    .zip(rhs)
+                    .map(|((lhs_key, lhs), (rhs_key, rhs))| {
+                        lhs_key.cmp(rhs_key).then_with(|| compare_json(lhs, rhs))
+                    })
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            })
+        }
+        (lhs, rhs) => rank(lhs).cmp(&rank(rhs)),
+    }
+}
+
+fn cosine_distance(lhs: &[f32], rhs: &[f32]) -> Option<f64> {
+    if lhs.len() != rhs.len() {
+        return None;
+    }
+    let (mut dot, mut lhs_norm, mut rhs_norm) = (0.0_f64, 0.0_f64, 0.0_f64);
+    for (lhs, rhs) in lhs.iter().zip(rhs) {
+        let (lhs, rhs) = (f64::from(*lhs), f64::from(*rhs));
+        dot += lhs * rhs;
+        lhs_norm += lhs * lhs;
+        rhs_norm += rhs * rhs;
+    }
+    let norm = lhs_norm.sqrt() * rhs_norm.sqrt();
+    (norm > 0.0).then(|| 1.0 - dot / norm)
+}
+
+/// Splits `text` into lower-cased words.
+fn search_words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|char: char| !char.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Collects the words of all text values in `value`, the same values Postgres indexes for a
+/// full-text search.
+fn document_words(value: &Value) -> Option<Vec<String>> {
+    fn collect_json(value: &JsonValue, words: &mut Vec<String>) {
+        match value {
+            JsonValue::String(text) => words.extend(search_words(text)),
+            JsonValue::Array(values) => {
+                for value in values {
+                    collect_json(value, words);
+                }
+            }
+            JsonValue::Object(object) => {
+                for value in object.values() {
+                    collect_json(value, words);
+                }
+            }
+            JsonValue::Null | JsonValue::Bool(_) | JsonValue::Number(_) => {}
+        }
+    }
+
+    match value {
+        Value::Null => None,
+        Value::Json(json) => {
+            let mut words = Vec::new();
+            collect_json(json, &mut words);
+            Some(words)
+        }
+        value => Some(search_words(&value.as_text()?).collect()),
+    }
+}
+
+/// A parsed full-text search query.
+///
+/// This is a simplified version of the web search syntax supported by Postgres: all words have to
+/// occur in the document, words prefixed with `-` must not occur. Unlike Postgres, words are
+/// neither stemmed nor are stop words removed.
+struct SearchQuery {
+    included: Vec<String>,
+    excluded: Vec<String>,
+}
+
+impl SearchQuery {
+    fn parse(query: &str) -> Self {
+        let mut included = Vec::new();
+        let mut excluded = Vec::new();
+        for term in query.split_whitespace() {
+            match term.strip_prefix('-') {
+                Some(term) => excluded.extend(search_words(term)),
+                None => included.extend(search_words(term)),
+            }
+        }
+        Self { included, excluded }
+    }
+
+    fn matches(&self, words: &[String]) -> bool {
+        !self.included.is_empty()
+            && self.included.iter().all(|word| words.contains(word))
+            && !self.excluded.iter().any(|word| words.contains(word))
+    }
+
+    /// The share of words in the document which are part of the query.
+    fn rank(&self, words: &[String]) -> f64 {
+        let count = |count: usize| f64::from(u32::try_from(count).unwrap_or(u32::MAX));
+
+        if !self.matches(words) {
+            return 0.0;
+        }
+        let matching = words
+            .iter()
+            .filter(|word| self.included.contains(word))
+            .count();
+        count(matching) / count(words.len())
+    }
+}
+
+/// Returns the full-text search relevance of `document` for `query`.
+pub(super) fn search_relevance(document: &Value, query: &str) -> Value {
+    document_words(document).map_or(Value::Null, |words| {
+        Value::Number(SearchQuery::parse(query).rank(&words))
+    })
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Path(usize),
+    Value(Value),
+}
+
+#[derive(Debug, Copy, Clone)]
+enum ComparisonOperator {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum PatternOperator {
+    Equal,
+    StartsWith,
+    EndsWith,
+    ContainsSegment,
+    Matches,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum CaseSensitivity {
+    Sensitive,
+    Insensitive,
+}
+
+#[derive(Debug, Clone)]
+enum Condition {
+    All(Vec<Self>),
+    Any(Vec<Self>),
+    Not(Box<Self>),
+    Equal(Option<Operand>, Option<Operand>),
+    NotEqual(Option<Operand>, Option<Operand>),
+    Compare(Operand, Operand, ComparisonOperator),
+    CosineDistance(Operand, Operand, Operand),
+    In(Operand, Vec<Value>),
+    Pattern(Operand, Operand, PatternOperator, CaseSensitivity),
+    FullTextSearch(Operand, Operand),
+    IsNull(Operand),
+    ArrayContains(Operand, Operand),
+    ArrayOverlaps(Operand, Operand),
+}
+
+/// A [`Filter`] compiled for evaluation against the [`MemoryState`].
+#[derive(Debug, Default)]
+pub(super) struct CompiledFilter<'f> {
+    /// The chains of hops, the chain at index `i` is referred to as `i + 1`, `0` is the queried
+    /// record itself. Parents are always stored before their children.
+    chains: Vec<(usize, Hop)>,
+    paths: Vec<(usize, Leaf<'f>)>,
+    condition: Option<Condition>,
+}
+
+impl<'f> CompiledFilter<'f> {
+    /// Compiles the `filter`.
+    pub(super) fn new<'p: 'f, R>(filter: &'f Filter<'p, R>) -> Self
+    where
+        R: QueryRecord<QueryPath<'p>: MemoryQueryPath>,
+    {
+        let mut compiled = Self::default();
+        compiled.condition = Some(compiled.compile_filter(filter));
+        compiled
+    }
+
+    /// Compiles the sorting of an entity query.
+    ///
+    /// If the sorting is by relevance, the properties are the first path, followed by the sorting
+    /// `paths` in order, as returned by [`QueryContext::sorting_values`].
+    pub(super) fn from_sorting(sorting: &'f EntityQuerySorting<'_>) -> Self {
+        let mut compiled = Self::default();
+        if sorting.relevance.is_some() {
+            compiled.add_path(&EntityQueryPath::Properties(None), |leaf| leaf);
+        }
+        for record in &sorting.paths {
+            compiled.add_path(&record.path, |leaf| leaf);
+        }
+        compiled
+    }
+
+    /// Compiles the `paths`, their values are returned in order by
+    /// [`QueryContext::sorting_values`].
+    pub(super) fn from_paths(paths: impl IntoIterator<Item = &'f EntityQueryPath<'f>>) -> Self {
+        let mut compiled = Self::default();
+        for path in paths {
+            compiled.add_path(path, |leaf| leaf);
+        }
+        compiled
+    }
+
+    fn chain(&mut self, parent: usize, hop: Hop) -> usize {
+        self.chains
+            .iter()
+            .position(|chain| *chain == (parent, hop))
+            .unwrap_or_else(|| {
+                self.chains.push((parent, hop));
+                self.chains.len() - 1
+            })
+            + 1
+    }
+
+    fn add_path<P>(&mut self, path: &'f P, map_leaf: impl FnOnce(Leaf<'f>) -> Leaf<'f>) -> usize
+    where
+        P: MemoryQueryPath + ?Sized,
+    {
+        let mut hops = Vec::new();
+        let leaf = path.compile(&mut hops);
+        let chain = hops
+            .into_iter()
+            .fold(0, |parent, hop| self.chain(parent, hop));
+        self.paths.push((chain, map_leaf(leaf)));
+        self.paths.len() - 1
+    }
+
+    fn compile_expression<'p: 'f, R>(&mut self, expression: &'f FilterExpression<'p, R>) -> Operand
+    where
+        R: QueryRecord<QueryPath<'p>: MemoryQueryPath>,
+    {
+        match expression {
+            FilterExpression::Path { path } => Operand::Path(self.add_path(path, |leaf| leaf)),
+            FilterExpression::Parameter { parameter, .. } => Operand::Value(parameter.into()),
+        }
+    }
+
+    /// Compiles `lhs == rhs` or `lhs != rhs`.
+    ///
+    /// Comparing the version with `"latest"` is special cased and compares against the latest
+    /// version of the ontology type instead.
+    fn compile_equality<'p: 'f, R>(
+        &mut self,
+        lhs: Option<&'f FilterExpression<'p, R>>,
+        rhs: Option<&'f FilterExpression<'p, R>>,
+    ) -> (Option<Operand>, Option<Operand>)
+    where
+        R: QueryRecord<QueryPath<'p>: MemoryQueryPath>,
+    {
+        match (lhs, rhs) {
+            (
+                Some(FilterExpression::Path { path }),
+                Some(FilterExpression::Parameter {
+                    parameter: Parameter::Text(text),
+                    convert: None,
+                }),
+            )
+            | (
+                Some(FilterExpression::Parameter {
+                    parameter: Parameter::Text(text),
+                    convert: None,
+                }),
+                Some(FilterExpression::Path { path }),
+            ) if text == "latest" => {
+                let mut is_version = false;
+                let index = self.add_path(path, |leaf| {
+                    if matches!(leaf, Leaf::Version) {
+                        is_version = true;
+                        Leaf::LatestVersion
+                    } else {
+                        leaf
+                    }
+                });
+                let value = if is_version {
+                    Value::Bool(true)
+                } else {
+                    Value::Text(text.to_string())
+                };
+                (Some(Operand::Path(index)), Some(Operand::Value(value)))
+            }
+            (lhs, rhs) => (
+                lhs.map(|lhs| self.compile_expression(lhs)),
+                rhs.map(|rhs| self.compile_expression(rhs)),
+            ),
+        }
+    }
+
+    fn compile_filter<'p: 'f, R>(&mut self, filter: &'f Filter<'p, R>) -> Condition
+    where
+        R: QueryRecord<QueryPath<'p>: MemoryQueryPath>,
+    {
+        match filter {
+            Filter::All(filters) => Condition::All(
+                filters
+                    .iter()
+                    .map(|filter| self.compile_filter(filter))
+                    .collect(),
+            ),
+            Filter::Any(filters) => Condition::Any(
+                filters
+                    .iter()
+                    .map(|filter| self.compile_filter(filter))
+                    .collect(),
+            ),
+            Filter::Not(filter) => Condition::Not(Box::new(self.compile_filter(filter))),
+            Filter::Equal(lhs, rhs) => {
+                let (lhs, rhs) = self.compile_equality(lhs.as_ref(), rhs.as_ref());
+                Condition::Equal(lhs, rhs)
+            }
+            Filter::NotEqual(lhs, rhs) => {
+                let (lhs, rhs) = self.compile_equality(lhs.as_ref(), rhs.as_ref());
+                Condition::NotEqual(lhs, rhs)
+            }
+            Filter::Greater(lhs, rhs) => {
+                self.compile_comparison(lhs, rhs, ComparisonOperator::Greater)
+            }
+            Filter::GreaterOrEqual(lhs, rhs) => {
+                self.compile_comparison(lhs, rhs, ComparisonOperator::GreaterOrEqual)
+            }
+            Filter::Less(lhs, rhs) => self.compile_comparison(lhs, rhs, ComparisonOperator::Less),
+            Filter::LessOrEqual(lhs, rhs) => {
+                self.compile_comparison(lhs, rhs, ComparisonOperator::LessOrEqual)
+            }
+            Filter::CosineDistance(lhs, rhs, max) => Condition::CosineDistance(
+                self.compile_expression(lhs),
+                self.compile_expression(rhs),
+                self.compile_expression(max),
+            ),
+            Filter::In(lhs, list) => {
+                let values = match list {
+                    ParameterList::DataTypeIds(ids) => {
+                        ids.iter().map(|id| Value::Uuid(id.into_uuid())).collect()
+                    }
+                    ParameterList::PropertyTypeIds(ids) => {
+                        ids.iter().map(|id| Value::Uuid(id.into_uuid())).collect()
+                    }
+                    ParameterList::EntityTypeIds(ids) => {
+                        ids.iter().map(|id| Value::Uuid(id.into_uuid())).collect()
+                    }
+                    ParameterList::EntityEditionIds(ids) => {
+                        ids.iter().map(|id| Value::Uuid(id.into_uuid())).collect()
+                    }
+                    ParameterList::Parameters(parameters) => {
+                        parameters.iter().map(Value::from).collect()
+                    }
+                };
+                Condition::In(self.compile_expression(lhs), values)
+            }
+            Filter::StartsWith(lhs, rhs) => self.compile_pattern(
+                lhs,
+                rhs,
+                PatternOperator::StartsWith,
+                CaseSensitivity::Sensitive,
+            ),
+            Filter::EndsWith(lhs, rhs) => self.compile_pattern(
+                lhs,
+                rhs,
+                PatternOperator::EndsWith,
+                CaseSensitivity::Sensitive,
+            ),
+            Filter::ContainsSegment(lhs, rhs) => self.compile_pattern(
+                lhs,
+                rhs,
+                PatternOperator::ContainsSegment,
+                CaseSensitivity::Sensitive,
+            ),
+            Filter::Matches(lhs, rhs) => self.compile_pattern(
+                lhs,
+                rhs,
+                PatternOperator::Matches,
+                CaseSensitivity::Sensitive,
+            ),
+            Filter::MatchesIgnoreCase(lhs, rhs) => self.compile_pattern(
+                lhs,
+                rhs,
+                PatternOperator::Matches,
+                CaseSensitivity::Insensitive,
+            ),
+            Filter::EqualIgnoreCase(lhs, rhs) => self.compile_pattern(
+                lhs,
+                rhs,
+                PatternOperator::Equal,
+                CaseSensitivity::Insensitive,
+            ),
+            Filter::StartsWithIgnoreCase(lhs, rhs) => self.compile_pattern(
+                lhs,
+                rhs,
+                PatternOperator::StartsWith,
+                CaseSensitivity::Insensitive,
+            ),
+            Filter::EndsWithIgnoreCase(lhs, rhs) => self.compile_pattern(
+                lhs,
+                rhs,
+                PatternOperator::EndsWith,
+                CaseSensitivity::Insensitive,
+            ),
+            Filter::ContainsSegmentIgnoreCase(lhs, rhs) => self.compile_pattern(
+                lhs,
+                rhs,
+                PatternOperator::ContainsSegment,
+                CaseSensitivity::Insensitive,
+            ),
+            Filter::FullTextSearch(lhs, rhs) => Condition::FullTextSearch(
+                self.compile_expression(lhs),
+                self.compile_expression(rhs),
+            ),
+            Filter::Exists(expression) => {
+                Condition::NotEqual(Some(self.compile_expression(expression)), None)
+            }
+            Filter::IsNull(expression) => Condition::IsNull(self.compile_expression(expression)),
+            Filter::ArrayContains(lhs, rhs) => {
+                Condition::ArrayContains(self.compile_expression(lhs), self.compile_expression(rhs))
+            }
+            Filter::ArrayOverlaps(lhs, rhs) => {
+                Condition::ArrayOverlaps(self.compile_expression(lhs), self.compile_expression(rhs))
+            }
+        }
+    }
+
+    fn compile_pattern<'p: 'f, R>(
+        &mut self,
+        lhs: &'f FilterExpression<'p, R>,
+        rhs: &'f FilterExpression<'p, R>,
+        operator: PatternOperator,
+        case: CaseSensitivity,
+    ) -> Condition
+    where
+        R: QueryRecord<QueryPath<'p>: MemoryQueryPath>,
+    {
+        Condition::Pattern(
+            self.compile_expression(lhs),
+            self.compile_expression(rhs),
+            operator,
+            case,
+        )
+    }
+
+    fn compile_comparison<'p: 'f, R>(
+        &mut self,
+        lhs: &'f FilterExpression<'p, R>,
+        rhs: &'f FilterExpression<'p, R>,
+        operator: ComparisonOperator,
+    ) -> Condition
+    where
+        R: QueryRecord<QueryPath<'p>: MemoryQueryPath>,
+    {
+        Condition::Compare(
+            self.compile_expression(lhs),
+            self.compile_expression(rhs),
+            operator,
+        )
+    }
+}
+
+/// A record or a related record a [`Leaf`] is evaluated on.
+#[derive(Debug, Copy, Clone)]
+pub(super) enum Node<'s> {
+    DataType(&'s VersionedUrl, usize),
+    PropertyType(&'s VersionedUrl, usize),
+    EntityType(&'s VersionedUrl, usize),
+    Entity(&'s EntityTemporalRow),
+    EntityEmbedding(&'s EntityEmbeddingRecord),
+}
+
+/// Returns `true` if `depth` is within the requested `inheritance_depth`.
+fn depth_matches(inheritance_depth: Option<u32>, depth: u32) -> bool {
+    inheritance_depth.is_none_or(|max_depth| depth <= max_depth)
+}
+
+impl MemoryState {
+    /// Returns all entity types the entity type with the given `url` inherits from, together with
+    /// the inheritance depth. Direct parents have a depth of `0`.
+    pub(super) fn entity_type_ancestors(&self, url: &VersionedUrl) -> HashMap<VersionedUrl, u32> {
+        let mut ancestors = HashMap::new();
+        let mut queue = vec![(url.clone(), 0)];
+        while let Some((current, depth)) = queue.pop() {
+            let Some(entity_type) = self.entity_types.get(&current) else {
+                continue;
+            };
+            for parent in &entity_type.schema.all_of {
+                match ancestors.entry(parent.url.clone()) {
+                    Entry::Occupied(mut entry) => {
+                        if *entry.get() <= depth {
+                            continue;
+                        }
+                        entry.insert(depth);
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(depth);
+                    }
+                }
+                queue.push((parent.url.clone(), depth + 1));
+            }
+        }
+        ancestors
+    }
+
+    /// Returns the entity type itself with a depth of `0` and all of its ancestors with their
+    /// depth increased by one.
+    fn entity_type_closure(&self, url: &VersionedUrl) -> Vec<(VersionedUrl, u32)> {
+        core::iter::once((url.clone(), 0))
+            .chain(
+                self.entity_type_ancestors(url)
+                    .into_iter()
+                    .map(|(ancestor, depth)| (ancestor, depth + 1)),
+            )
+            .collect()
+    }
+
+    /// Returns the property types constrained by the entity type including inherited ones.
+    fn entity_type_properties(&self, url: &VersionedUrl) -> Vec<(VersionedUrl, u32)> {
+        self.entity_type_closure(url)
+            .into_iter()
+            .filter_map(|(entity_type, depth)| {
+                let entity_type = self.entity_types.get(&entity_type)?;
+                Some(
+                    entity_type
+                        .schema
+                        .property_type_references()
+                        .into_iter()
+                        .map(move |reference| (reference.url.clone(), depth))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Returns the link entity types constrained by the entity type including inherited ones.
+    fn entity_type_links(&self, url: &VersionedUrl) -> Vec<(VersionedUrl, u32)> {
+        self.entity_type_closure(url)
+            .into_iter()
+            .filter_map(|(entity_type, depth)| {
+                let entity_type = self.entity_types.get(&entity_type)?;
+                Some(
+                    entity_type
+                        .schema
+                        .link_mappings()
+                        .into_keys()
+                        .map(move |reference| (reference.url.clone(), depth))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Returns the link destinations constrained by the entity type including inherited ones.
+    fn entity_type_link_destinations(&self, url: &VersionedUrl) -> Vec<(VersionedUrl, u32)> {
+        self.entity_type_closure(url)
+            .into_iter()
+            .filter_map(|(entity_type, depth)| {
+                let entity_type = self.entity_types.get(&entity_type)?;
+                Some(
+                    entity_type
+                        .schema
+                        .link_mappings()
+                        .into_values()
+                        .flatten()
+                        .flatten()
+                        .map(move |reference| (reference.url.clone(), depth))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Returns the entity types of the entity edition including inherited ones.
+    pub(super) fn entity_is_of_type(&self, row: &EntityTemporalRow) -> Vec<(VersionedUrl, u32)> {
+        self.entity_editions
+            .get(&row.edition_id)
+            .into_iter()
+            .flat_map(|edition| &edition.entity_type_ids)
+            .flat_map(|entity_type| self.entity_type_closure(entity_type))
+            .collect()
+    }
+
+    /// Returns the data types the data type inherits from together with their depth.
+    pub(super) fn data_type_parents(&self, url: &VersionedUrl) -> Vec<(VersionedUrl, u32)> {
+        let Some(data_type) = self.data_types.get(url) else {
+            return Vec::new();
+        };
+        data_type
+            .inheritance_data
+            .inheritance_depths
+            .iter()
+            .filter_map(|(id, depth)| {
+                let (parent, _) = self
+                    .data_types
+                    .iter()
+                    .find(|(parent, _)| DataTypeId::from_url(parent) == *id)?;
+                Some((parent.clone(), u32::from(depth.inner())))
+            })
+            .collect()
+    }
+
+    /// Returns the latest version of the ontology type with the same base URL.
+    fn latest_version(&self, url: &VersionedUrl) -> u32 {
+        self.ontology_ids
+            .keys()
+            .filter(|other| other.base_url == url.base_url)
+            .map(|other| other.version.inner())
+            .max()
+            .unwrap_or_else(|| url.version.inner())
+    }
+}
+
+/// The context used to evaluate a [`CompiledFilter`].
+pub(super) struct QueryContext<'s> {
+    pub state: &'s MemoryState,
+    pub temporal_axes: Option<&'s QueryTemporalAxes>,
+    pub include_drafts: bool,
+    pub kind: RecordKind,
+}
+
+impl<'s> QueryContext<'s> {
+    /// Returns `true` if the ontology temporal row is visible at the queried temporal axes.
+    pub(super) fn ontology_row_is_visible(&self, row: &OntologyTemporalRow) -> bool {
+        match self.temporal_axes {
+            None => true,
+            Some(QueryTemporalAxes::DecisionTime { pinned, .. }) => {
+                row.transaction_time.contains_point(&pinned.timestamp)
+            }
+            Some(QueryTemporalAxes::TransactionTime { variable, .. }) => {
+                row.transaction_time.overlaps(&variable.interval)
+            }
+        }
+    }
+
+    /// Returns `true` if the entity temporal row is visible at the queried temporal axes.
+    pub(super) fn entity_row_is_visible(&self, row: &EntityTemporalRow) -> bool {
+        if !self.include_drafts && row.entity_id.draft_id.is_some() {
+            return false;
+        }
+
+        match self.temporal_axes {
+            None => true,
+            Some(QueryTemporalAxes::DecisionTime { pinned, variable }) => {
+                row.transaction_time.contains_point(&pinned.timestamp)
+                    && row.decision_time.overlaps(&variable.interval)
+            }
+            Some(QueryTemporalAxes::TransactionTime { pinned, variable }) => {
+                row.decision_time.contains_point(&pinned.timestamp)
+                    && row.transaction_time.overlaps(&variable.interval)
+            }
+        }
+    }
+
+    /// Returns all records of the queried kind which are visible at the queried temporal axes.
+    ///
+    /// Ontology types are ordered by their base URL and descending version, entities are
+    /// returned in insertion order.
+    pub(super) fn root_nodes(&self) -> Vec<Node<'s>> {
+        let state = self.state;
+        let mut urls = match self.kind {
+            RecordKind::DataType => state.data_types.keys().collect::<Vec<_>>(),
+            RecordKind::PropertyType => state.property_types.keys().collect(),
+            RecordKind::EntityType => state.entity_types.keys().collect(),
+            RecordKind::Entity => {
+                return state
+                    .entity_temporal_metadata
+                    .iter()
+                    .filter(|row| self.entity_row_is_visible(row))
+                    .map(Node::Entity)
+                    .collect();
+            }
+        };
+        urls.sort_by(|lhs, rhs| {
+            lhs.base_url
+                .cmp(&rhs.base_url)
+                .then_with(|| rhs.version.cmp(&lhs.version))
+        });
+
+        let constructor: fn(&'s VersionedUrl, usize) -> Node<'s> = match self.kind {
+            RecordKind::DataType => Node::DataType,
+            RecordKind::PropertyType => Node::PropertyType,
+            RecordKind::EntityType | RecordKind::Entity => Node::EntityType,
+        };
+        urls.into_iter()
+            .flat_map(|url| self.ontology_nodes(url, self.kind, constructor))
+            .collect()
+    }
+
+    fn ontology_nodes(
+        &self,
+        url: &VersionedUrl,
+        kind: RecordKind,
+        constructor: fn(&'s VersionedUrl, usize) -> Node<'s>,
+    ) -> impl Iterator<Item = Node<'s>> + '_ {
+        self.state
+            .ontology_ids
+            .get_key_value(url)
+            .filter(|(url, _)| match kind {
+                RecordKind::DataType => self.state.data_types.contains_key(*url),
+                RecordKind::PropertyType => self.state.property_types.contains_key(*url),
+                RecordKind::EntityType => self.state.entity_types.contains_key(*url),
+                RecordKind::Entity => false,
+            })
+            .into_iter()
+            .flat_map(move |(url, record)| {
+                record
+                    .temporal_metadata
+                    .iter()
+                    .enumerate()
+                    .filter(move |(_, row)| kind != self.kind || self.ontology_row_is_visible(row))
+                    .map(move |(index, _)| constructor(url, index))
+            })
+    }
+
+    fn entity_nodes(
+        &self,
+        owned_by_id: OwnedById,
+        entity_uuid: EntityUuid,
+    ) -> impl Iterator<Item = Node<'s>> + '_ {
+        self.state
+            .entity_temporal_metadata
+            .iter()
+            .filter(move |row| {
+                row.entity_id.owned_by_id == owned_by_id
+                    && row.entity_id.entity_uuid == entity_uuid
+                    && (self.kind != RecordKind::Entity || self.entity_row_is_visible(row))
+            })
+            .map(Node::Entity)
+    }
+
+    /// Returns all links pointing to the entity at the given side.
+    fn link_nodes(&self, entity_id: EntityId, side: LinkSide) -> Vec<Node<'s>> {
+        self.state
+            .entity_ids
+            .iter()
+            .filter_map(|((owned_by_id, entity_uuid), record)| {
+                let link_data = record.link_data.as_ref()?;
+                let endpoint = match side {
+                    LinkSide::Left => link_data.left_entity_id,
+                    LinkSide::Right => link_data.right_entity_id,
+                };
+                (endpoint.owned_by_id == entity_id.owned_by_id
+                    && endpoint.entity_uuid == entity_id.entity_uuid)
+                    .then_some((*owned_by_id, *entity_uuid))
+            })
+            .flat_map(|(owned_by_id, entity_uuid)| self.entity_nodes(owned_by_id, entity_uuid))
+            .collect()
+    }
+
+    #[expect(clippy::too_many_lines)]
+    fn candidates(&self, hop: Hop, node: Node<'s>) -> Vec<Node<'s>> {
+        let state = self.state;
+        match (hop, node) {
+            (
+                Hop::DataTypeInheritsFrom {
+                    direction: EdgeDirection::Outgoing,
+                    inheritance_depth,
+                },
+                Node::DataType(url, _),
+            ) => state
+                .data_type_parents(url)
+                .into_iter()
+                .filter(|(_, depth)| depth_matches(inheritance_depth, *depth))
+                .flat_map(|(parent, _)| {
+                    self.ontology_nodes(&parent, RecordKind::DataType, Node::DataType)
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+            (
+                Hop::DataTypeInheritsFrom {
+                    direction: EdgeDirection::Incoming,
+                    inheritance_depth,
+                },
+                Node::DataType(url, _),
+            ) => {
+                let id = DataTypeId::from_url(url);
+                state
+                    .data_types
+                    .iter()
+                    .filter(|(_, child)| {
+                        child
+                            .inheritance_data
+                            .inheritance_depths
+                            .get(&id)
+                            .is_some_and(|depth| {
+                                depth_matches(inheritance_depth, u32::from(depth.inner()))
+                            })
+                    })
+                    .flat_map(|(child, _)| {
+                        self.ontology_nodes(child, RecordKind::DataType, Node::DataType)
+                    })
+                    .collect()
+            }
+            (
+                Hop::PropertyTypeConstrainsValuesOn {
+                    direction: EdgeDirection::Outgoing,
+                },
+                Node::PropertyType(url, _),
+            ) => state
+                .property_types
+                .get(url)
+                .into_iter()
+                .flat_map(|property_type| property_type.schema.data_type_references())
+                .flat_map(|reference| {
+                    self.ontology_nodes(&reference.url, RecordKind::DataType, Node::DataType)
+                })
+                .collect(),
+            (
+                Hop::PropertyTypeConstrainsValuesOn {
+                    direction: EdgeDirection::Incoming,
+                },
+                Node::DataType(url, _),
+            ) => state
+                .property_types
+                .iter()
+                .filter(|(_, property_type)| {
+                    property_type
+                        .schema
+                        .data_type_references()
+                        .iter()
+                        .any(|reference| reference.url == *url)
+                })
+                .flat_map(|(property_type, _)| {
+                    self.ontology_nodes(property_type, RecordKind::PropertyType, Node::PropertyType)
+                })
+                .collect(),
+            (
+                Hop::PropertyTypeConstrainsPropertiesOn {
+                    direction: EdgeDirection::Outgoing,
+                },
+                Node::PropertyType(url, _),
+            ) => state
+                .property_types
+                .get(url)
+                .into_iter()
+                .flat_map(|property_type| property_type.schema.property_type_references())
+                .flat_map(|reference| {
+                    self.ontology_nodes(
+                        &reference.url,
+                        RecordKind::PropertyType,
+                        Node::PropertyType,
+                    )
+                })
+                .collect(),
+            (
+                Hop::PropertyTypeConstrainsPropertiesOn {
+                    direction: EdgeDirection::Incoming,
+                },
+                Node::PropertyType(url, _),
+            ) => state
+                .property_types
+                .iter()
+                .filter(|(_, property_type)| {
+                    property_type
+                        .schema
+                        .property_type_references()
+                        .iter()
+                        .any(|reference| reference.url == *url)
+                })
+                .flat_map(|(property_type, _)| {
+                    self.ontology_nodes(property_type, RecordKind::PropertyType, Node::PropertyType)
+                })
+                .collect(),
+            (
+                Hop::EntityTypeConstrainsPropertiesOn {
+                    direction: EdgeDirection::Outgoing,
+                    inheritance_depth,
+                },
+                Node::EntityType(url, _),
+            ) => state
+                .entity_type_properties(url)
+                .into_iter()
+                .filter(|(_, depth)| depth_matches(inheritance_depth, *depth))
+                .flat_map(|(property_type, _)| {
+                    self.ontology_nodes(
+                        &property_type,
+                        RecordKind::PropertyType,
+                        Node::PropertyType,
+                    )
+                    .collect::<Vec<_>>()
+                })
+                .collect(),
+            (
+                Hop::EntityTypeConstrainsPropertiesOn {
+                    direction: EdgeDirection::Incoming,
+                    inheritance_depth,
+                },
+                Node::PropertyType(url, _),
+            ) => self.incoming_entity_types(|entity_type| {
+                state.entity_type_properties(entity_type).into_iter().any(
+                    |(property_type, depth)| {
+                        property_type == *url && depth_matches(inheritance_depth, depth)
+                    },
+                )
+            }),
+            (
+                Hop::EntityTypeInheritsFrom {
+                    direction: EdgeDirection::Outgoing,
+                    inheritance_depth,
+                },
+                Node::EntityType(url, _),
+            ) => self.outgoing_entity_types(
+                state.entity_type_ancestors(url).into_iter().collect(),
+                inheritance_depth,
+            ),
+            (
+                Hop::EntityTypeInheritsFrom {
+                    direction: EdgeDirection::Incoming,
+                    inheritance_depth,
+                },
+                Node::EntityType(url, _),
+            ) => self.incoming_entity_types(|entity_type| {
+                state
+                    .entity_type_ancestors(entity_type)
+                    .get(url)
+                    .is_some_and(|depth| depth_matches(inheritance_depth, *depth))
+            }),
+            (
+                Hop::EntityTypeConstrainsLinksOn {
+                    direction: EdgeDirection::Outgoing,
+                    inheritance_depth,
+                },
+                Node::EntityType(url, _),
+            ) => self.outgoing_entity_types(state.entity_type_links(url), inheritance_depth),
+            (
+                Hop::EntityTypeConstrainsLinksOn {
+                    direction: EdgeDirection::Incoming,
+                    inheritance_depth,
+                },
+                Node::EntityType(url, _),
+            ) => self.incoming_entity_types(|entity_type| {
+                state
+                    .entity_type_links(entity_type)
+                    .into_iter()
+                    .any(|(link, depth)| link == *url && depth_matches(inheritance_depth, depth))
+            }),
+            (
+                Hop::EntityTypeConstrainsLinkDestinationsOn {
+                    direction: EdgeDirection::Outgoing,
+                    inheritance_depth,
+                },
+                Node::EntityType(url, _),
+            ) => self
+                .outgoing_entity_types(state.entity_type_link_destinations(url), inheritance_depth),
+            (
+                Hop::EntityTypeConstrainsLinkDestinationsOn {
+                    direction: EdgeDirection::Incoming,
+                    inheritance_depth,
+                },
+                Node::EntityType(url, _),
+            ) => self.incoming_entity_types(|entity_type| {
+                state
+                    .entity_type_link_destinations(entity_type)
+                    .into_iter()
+                    .any(|(destination, depth)| {
+                        destination == *url && depth_matches(inheritance_depth, depth)
+                    })
+            }),
+            (
+                Hop::EntityIsOfType {
+                    direction: EdgeDirection::Outgoing,
+                    inheritance_depth,
+                },
+                Node::Entity(row),
+            ) => self.outgoing_entity_types(state.entity_is_of_type(row), inheritance_depth),
+            (
+                Hop::EntityIsOfType {
+                    direction: EdgeDirection::Incoming,
+                    inheritance_depth,
+                },
+                Node::EntityType(url, _),
+            ) => state
+                .entity_temporal_metadata
+                .iter()
+                .filter(|row| self.kind != RecordKind::Entity || self.entity_row_is_visible(row))
+                .filter(|row| {
+                    state
+                        .entity_is_of_type(row)
+                        .into_iter()
+                        .any(|(entity_type, depth)| {
+                            entity_type == *url && depth_matches(inheritance_depth, depth)
+                        })
+                })
+                .map(Node::Entity)
+                .collect(),
+            (Hop::EntityHasLeftEntity { direction }, Node::Entity(row)) => {
+                self.link_candidates(row, LinkSide::Left, direction)
+            }
+            (Hop::EntityHasRightEntity { direction }, Node::Entity(row)) => {
+                self.link_candidates(row, LinkSide::Right, direction)
+            }
+            (Hop::EntityEmbeddings, Node::Entity(row)) => state
+                .entity_embeddings
+                .iter()
+                .filter(|((entity_id, _), _)| {
+                    entity_id.owned_by_id == row.entity_id.owned_by_id
+                        && entity_id.entity_uuid == row.entity_id.entity_uuid
+                })
+                .map(|(_, embedding)| Node::EntityEmbedding(embedding))
+                .collect(),
+            (hop, node) => unreachable!("Cannot apply {hop:?} to {node:?}"),
+        }
+    }
+
+    fn outgoing_entity_types(
+        &self,
+        entity_types: Vec<(VersionedUrl, u32)>,
+        inheritance_depth: Option<u32>,
+    ) -> Vec<Node<'s>> {
+        entity_types
+            .into_iter()
+            .filter(|(_, depth)| depth_matches(inheritance_depth, *depth))
+            .flat_map(|(entity_type, _)| {
+                self.ontology_nodes(&entity_type, RecordKind::EntityType, Node::EntityType)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn incoming_entity_types(&self, predicate: impl Fn(&VersionedUrl) -> bool) -> Vec<Node<'s>> {
+        self.state
+            .entity_types
+            .keys()
+            .filter(|entity_type| predicate(entity_type))
+            .flat_map(|entity_type| {
+                self.ontology_nodes(entity_type, RecordKind::EntityType, Node::EntityType)
+            })
+            .collect()
+    }
+
+    fn link_candidates(
+        &self,
+        row: &EntityTemporalRow,
+        side: LinkSide,
+        direction: EdgeDirection,
+    ) -> Vec<Node<'s>> {
+        match direction {
+            EdgeDirection::Outgoing => self
+                .state
+                .entity_ids
+                .get(&(row.entity_id.owned_by_id, row.entity_id.entity_uuid))
+                .and_then(|record| record.link_data.as_ref())
+                .map(|link_data| match side {
+                    LinkSide::Left => link_data.left_entity_id,
+                    LinkSide::Right => link_data.right_entity_id,
+                })
+                .map(|endpoint| {
+                    self.entity_nodes(endpoint.owned_by_id, endpoint.entity_uuid)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            EdgeDirection::Incoming => self.link_nodes(row.entity_id, side),
+        }
+    }
+
+    /// Reads the value described by `leaf` from `node`.
+    ///
+    /// `parent` is the node `node` was reached from. Returns [`None`] if the record does not
+    /// provide the value at all, e.g. the web of an external ontology type.
+    #[expect(clippy::too_many_lines)]
+    fn leaf_value(
+        &self,
+        leaf: Leaf<'_>,
+        node: Option<Node<'s>>,
+        parent: Option<Node<'s>>,
+    ) -> Option<Value> {
+        let state = self.state;
+        let Some(node) = node else {
+            return Some(Value::Null);
+        };
+
+        match node {
+            Node::DataType(url, row)
+            | Node::PropertyType(url, row)
+            | Node::EntityType(url, row) => {
+                let (record, temporal_row) = state.ontology_row(url, row)?;
+                let schema = || {
+                    match node {
+                        Node::DataType(..) => serde_json::to_value(&state.data_types[url].schema),
+                        Node::PropertyType(..) => {
+                            serde_json::to_value(&state.property_types[url].schema)
+                        }
+                        _ => serde_json::to_value(&state.entity_types[url].schema),
+                    }
+                    .expect("ontology type should be serializable")
+                };
+                let embedding = || match node {
+                    Node::DataType(..) => state.data_types[url].embedding.as_ref(),
+                    Node::PropertyType(..) => state.property_types[url].embedding.as_ref(),
+                    _ => state.entity_types[url].embedding.as_ref(),
+                };
+
+                Some(match leaf {
+                    Leaf::BaseUrl => Value::Text(url.base_url.to_string()),
+                    Leaf::Version => Value::Version(url.version.inner()),
+                    Leaf::LatestVersion => {
+                        Value::Bool(url.version.inner() == state.latest_version(url))
+                    }
+                    Leaf::VersionedUrl => Value::Text(url.to_string()),
+                    Leaf::TransactionTime => Value::interval(temporal_row.transaction_time),
+                    Leaf::OwnedById => match &record.classification {
+                        OntologyTypeClassificationMetadata::Owned { owned_by_id } => {
+                            Value::Uuid(owned_by_id.into_uuid())
+                        }
+                        OntologyTypeClassificationMetadata::External { .. } => return None,
+                    },
+                    Leaf::Title => Value::json_field(&schema(), "title"),
+                    Leaf::Description => Value::json_field(&schema(), "description"),
+                    Leaf::Type => Value::json_field(&schema(), "type"),
+                    Leaf::Examples => Value::json_field(&schema(), "examples"),
+                    Leaf::Required => Value::json_field(&schema(), "required"),
+                    Leaf::OntologyId => Value::Uuid(DataTypeId::from_url(url).into_uuid()),
+                    Leaf::Schema(path) => Value::json_path(schema(), path),
+                    Leaf::ClosedSchema(path) => Value::json_path(
+                        serde_json::to_value(&state.entity_types.get(url)?.closed_schema)
+                            .expect("closed schema should be serializable"),
+                        path,
+                    ),
+                    Leaf::AdditionalMetadata => Value::Json(match &record.classification {
+                        OntologyTypeClassificationMetadata::Owned { owned_by_id } => {
+                            json!({ "web_id": owned_by_id })
+                        }
+                        classification @ OntologyTypeClassificationMetadata::External { .. } => {
+                            json!({
+                                "fetched_at": serde_json::to_value(classification)
+                                    .expect("classification should be serializable")
+                                    .get("fetchedAt")
+                            })
+                        }
+                    }),
+                    Leaf::EditionProvenance(path) => Value::json_path(
+                        serde_json::to_value(&temporal_row.provenance)
+                            .expect("provenance should be serializable"),
+                        path,
+                    ),
+                    Leaf::Embedding => embedding().map_or(Value::Null, |embedding| {
+                        Value::Vector(embedding.embedding.iter().collect())
+                    }),
+                    Leaf::TargetConversionBaseUrls
+                    | Leaf::FromConversions
+                    | Leaf::IntoConversions => {
+                        let conversions = &state.data_types.get(url)?.conversions;
+                        if conversions.is_empty() {
+                            Value::Null
+                        } else {
+                            Value::Array(
+                                conversions
+                                    .iter()
+                                    .map(|(base_url, conversions)| match leaf {
+                                        Leaf::TargetConversionBaseUrls => {
+                                            Value::Text(base_url.to_string())
+                                        }
+                                        Leaf::FromConversions => Value::Json(
+                                            serde_json::to_value(&conversions.from)
+                                                .expect("conversion should be serializable"),
+                                        ),
+                                        _ => Value::Json(
+                                            serde_json::to_value(&conversions.to)
+                                                .expect("conversion should be serializable"),
+                                        ),
+                                    })
+                                    .collect(),
+                            )
+                        }
+                    }
+                    Leaf::LabelProperty => state
+                        .entity_types
+                        .get(url)?
+                        .label_property
+                        .as_ref()
+                        .map_or(Value::Null, |label| Value::Text(label.to_string())),
+                    Leaf::Icon => state
+                        .entity_types
+                        .get(url)?
+                        .icon
+                        .clone()
+                        .map_or(Value::Null, Value::Text),
+                    Leaf::Label => {
+                        let Some(Node::Entity(entity)) = parent else {
+                            return None;
+                        };
+                        let label_property = state.entity_types.get(url)?.label_property.as_ref();
+                        label_property
+                            .and_then(|label_property| {
+                                state.entity_editions[&entity.edition_id]
+                                    .properties
+                                    .iter()
+                                    .find_map(|(key, property)| {
+                                        (key == label_property).then_some(property)
+                                    })
+                            })
+                            .map_or(Value::Null, |property| {
+                                Value::Json(
+                                    serde_json::to_value(property)
+                                        .expect("property should be serializable"),
+                                )
+                            })
+                    }
+                    _ => unreachable!("Cannot read {leaf:?} from an ontology type"),
+                })
+            }
+            Node::Entity(row) => {
+                let id_record = state
+                    .entity_ids
+                    .get(&(row.entity_id.owned_by_id, row.entity_id.entity_uuid))?;
+                let edition = state.entity_editions.get(&row.edition_id)?;
+                let link_data = id_record.link_data.as_ref();
+
+                Some(match leaf {
+                    Leaf::Uuid => Value::Uuid(row.entity_id.entity_uuid.into_uuid()),
+                    Leaf::OwnedById => Value::Uuid(row.entity_id.owned_by_id.into_uuid()),
+                    Leaf::DraftId => row
+                        .entity_id
+                        .draft_id
+                        .map_or(Value::Null, |draft_id| Value::Uuid(draft_id.into_uuid())),
+                    Leaf::EditionId => Value::Uuid(row.edition_id.into_uuid()),
+                    Leaf::DecisionTime => Value::interval(row.decision_time),
+                    Leaf::TransactionTime => Value::interval(row.transaction_time),
+                    Leaf::TypeBaseUrls | Leaf::TypeVersions => {
+                        let mut entity_types = edition.entity_type_ids.iter().collect::<Vec<_>>();
+                        entity_types.sort();
+                        Value::Array(
+                            entity_types
+                                .into_iter()
+                                .map(|entity_type| match leaf {
+                                    Leaf::TypeBaseUrls => {
+                                        Value::Text(entity_type.base_url.to_string())
+                                    }
+                                    _ => Value::Version(entity_type.version.inner()),
+                                })
+                                .collect(),
+                        )
+                    }
+                    Leaf::EntityConfidence => edition
+                        .confidence
+                        .map_or(Value::Null, |confidence| Value::Number(confidence.value())),
+                    Leaf::LinkConfidence(side) => link_data
+                        .and_then(|link_data| match side {
+                            LinkSide::Left => link_data.left_entity_confidence,
+                            LinkSide::Right => link_data.right_entity_confidence,
+                        })
+                        .map_or(Value::Null, |confidence| Value::Number(confidence.value())),
+                    Leaf::LinkProvenance(side) => link_data.map_or(Value::Null, |link_data| {
+                        Value::Json(
+                            serde_json::to_value(match side {
+                                LinkSide::Left => &link_data.left_entity_provenance,
+                                LinkSide::Right => &link_data.right_entity_provenance,
+                            })
+                            .expect("provenance should be serializable"),
+                        )
+                    }),
+                    Leaf::LinkEntityUuid(side) | Leaf::LinkOwnedById(side) => {
+                        link_data.map_or(Value::Null, |link_data| {
+                            let endpoint = match side {
+                                LinkSide::Left => link_data.left_entity_id,
+                                LinkSide::Right => link_data.right_entity_id,
+                            };
+                            Value::Uuid(if matches!(leaf, Leaf::LinkEntityUuid(_)) {
+                                endpoint.entity_uuid.into_uuid()
+                            } else {
+                                endpoint.owned_by_id.into_uuid()
+                            })
+                        })
+                    }
+                    Leaf::Archived => Value::Bool(edition.archived),
+                    Leaf::Properties(path) => Value::json_path(
+                        serde_json::to_value(&edition.properties)
+                            .expect("properties should be serializable"),
+                        path,
+                    ),
+                    Leaf::Provenance(path) => Value::json_path(
+                        serde_json::to_value(&id_record.provenance)
+                            .expect("provenance should be serializable"),
+                        path,
+                    ),
+                    Leaf::EditionProvenance(path) => Value::json_path(
+                        serde_json::to_value(&edition.provenance)
+                            .expect("provenance should be serializable"),
+                        path,
+                    ),
+                    Leaf::PropertyMetadata(path) => Value::json_path(
+                        serde_json::to_value(&edition.property_metadata)
+                            .expect("property metadata should be serializable"),
+                        path,
+                    ),
+                    _ => unreachable!("Cannot read {leaf:?} from an entity"),
+                })
+            }
+            Node::EntityEmbedding(record) => Some(match leaf {
+                Leaf::Embedding => Value::Vector(record.embedding.iter().collect()),
+                _ => unreachable!("Cannot read {leaf:?} from an entity embedding"),
+            }),
+        }
+    }
+
+    /// Assigns a node to every chain starting at `bindings.len()` and calls `visit` for every
+    /// complete assignment until `visit` returns `true`.
+    fn assign(
+        &self,
+        filter: &CompiledFilter<'_>,
+        bindings: &mut Vec<Option<Node<'s>>>,
+        visit: &mut impl FnMut(&[Option<Node<'s>>]) -> bool,
+    ) -> bool {
+        let index = bindings.len();
+        let Some(&(parent, hop)) = filter.chains.get(index - 1) else {
+            return visit(bindings);
+        };
+
+        let mut assign_node = |node: Option<Node<'s>>, bindings: &mut Vec<_>| {
+            bindings.push(node);
+            let found = self.assign(filter, bindings, visit);
+            bindings.pop();
+            found
+        };
+
+        let Some(parent) = bindings[parent] else {
+            return assign_node(None, bindings);
+        };
+
+        let candidates = self.candidates(hop, parent);
+        if candidates.is_empty() {
+            hop.is_optional() && assign_node(None, bindings)
+        } else {
+            candidates
+                .into_iter()
+                .any(|candidate| assign_node(Some(candidate), bindings))
+        }
+    }
+
+    fn path_values(
+        &self,
+        filter: &CompiledFilter<'_>,
+        bindings: &[Option<Node<'s>>],
+    ) -> Option<Vec<Value>> {
+        filter
+            .paths
+            .iter()
+            .map(|&(chain, leaf)| {
+                let parent = chain
+                    .checked_sub(1)
+                    .and_then(|index| bindings[filter.chains[index].0]);
+                self.leaf_value(leaf, bindings[chain], parent)
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the record represented by `root` matches the `filter`.
+    pub(super) fn matches(&self, filter: &CompiledFilter<'_>, root: Node<'s>) -> bool {
+        let Some(condition) = &filter.condition else {
+            return true;
+        };
+
+        self.assign(filter, &mut vec![Some(root)], &mut |bindings| {
+            self.path_values(filter, bindings)
+                .is_some_and(|values| evaluate(condition, &values) == Some(true))
+        })
+    }
+
+    /// Returns the values of all paths in `filter` for the record represented by `root`.
+    ///
+    /// If a path is related to multiple records, the first one is used. If there is no related
+    /// record, [`Value::Null`] is returned.
+    pub(super) fn sorting_values(&self, filter: &CompiledFilter<'_>, root: Node<'s>) -> Vec<Value> {
+        let mut values = None;
+        self.assign(filter, &mut vec![Some(root)], &mut |bindings| {
+            values = self.path_values(filter, bindings);
+            values.is_some()
+        });
+        values.unwrap_or_else(|| vec![Value::Null; filter.paths.len()])
+    }
+}
+
+fn operand_value<'v>(operand: &'v Operand, values: &'v [Value]) -> &'v Value {
+    match operand {
+        Operand::Path(index) => &values[*index],
+        Operand::Value(value) => value,
+    }
+}
+
+/// Evaluates the `condition` using three-valued logic, [`None`] represents `NULL`.
+fn evaluate(condition: &Condition, values: &[Value]) -> Option<bool> {
+    match condition {
+        Condition::All(conditions) => {
+            let mut result = Some(true);
+            for condition in conditions {
+                match evaluate(condition, values) {
+                    Some(false) => return Some(false),
+                    None => result = None,
+                    Some(true) => {}
+                }
+            }
+            result
+        }
+        Condition::Any(conditions) => {
+            let mut result = Some(false);
+            for condition in conditions {
+                match evaluate(condition, values) {
+                    Some(true) => return Some(true),
+                    None => result = None,
+                    Some(false) => {}
+                }
+            }
+            result
+        }
+        Condition::Not(condition) => evaluate(condition, values).map(|result| !result),
+        Condition::Equal(lhs, rhs) => match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => operand_value(lhs, values).equals(operand_value(rhs, values)),
+            (Some(operand), None) | (None, Some(operand)) => {
+                Some(*operand_value(operand, values) == Value::Null)
+            }
+            (None, None) => Some(true),
+        },
+        Condition::NotEqual(lhs, rhs) => match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => operand_value(lhs, values)
+                .equals(operand_value(rhs, values))
+                .map(|equal| !equal),
+            (Some(operand), None) | (None, Some(operand)) => {
+                Some(*operand_value(operand, values) != Value::Null)
+            }
+            (None, None) => Some(false),
+        },
+        Condition::Compare(lhs, rhs, operator) => {
+            let ordering = operand_value(lhs, values).compare(operand_value(rhs, values))?;
+            Some(match operator {
+                ComparisonOperator::Greater => ordering.is_gt(),
+                ComparisonOperator::GreaterOrEqual => ordering.is_ge(),
+                ComparisonOperator::Less => ordering.is_lt(),
+                ComparisonOperator::LessOrEqual => ordering.is_le(),
+            })
+        }
+        Condition::CosineDistance(lhs, rhs, max) => {
+            let (Value::Vector(lhs), Value::Vector(rhs)) =
+                (operand_value(lhs, values), operand_value(rhs, values))
+            else {
+                return None;
+            };
+            let Value::Number(max) = operand_value(max, values) else {
+                return None;
+            };
+            Some(cosine_distance(lhs, rhs)? <= *max)
+        }
+        Condition::In(operand, list) => {
+            let value = operand_value(operand, values);
+            if *value == Value::Null {
+                return None;
+            }
+            Some(list.iter().any(|item| value.equals(item) == Some(true)))
+        }
+        Condition::Pattern(lhs, rhs, operator, case) => {
+            let lhs = operand_value(lhs, values).as_text()?;
+            let rhs = operand_value(rhs, values).as_text()?;
+            let fold = |text: &str| match case {
+                CaseSensitivity::Sensitive => text.to_owned(),
+                CaseSensitivity::Insensitive => text.to_lowercase(),
+            };
+            Some(match operator {
+                PatternOperator::Equal => fold(&lhs) == fold(&rhs),
+                PatternOperator::StartsWith => fold(&lhs).starts_with(&fold(&rhs)),
+                PatternOperator::EndsWith => fold(&lhs).ends_with(&fold(&rhs)),
+                PatternOperator::ContainsSegment => fold(&lhs).contains(&fold(&rhs)),
+                PatternOperator::Matches => RegexBuilder::new(&rhs)
+                    .case_insensitive(*case == CaseSensitivity::Insensitive)
+                    .build()
+                    .ok()?
+                    .is_match(&lhs),
+            })
+        }
+        Condition::FullTextSearch(lhs, rhs) => {
+            let words = document_words(operand_value(lhs, values))?;
+            let query = operand_value(rhs, values).as_text()?;
+            Some(SearchQuery::parse(&query).matches(&words))
+        }
+        Condition::IsNull(operand) => Some(matches!(
+            operand_value(operand, values),
+            Value::Null | Value::Json(JsonValue::Null)
+        )),
+        Condition::ArrayContains(lhs, rhs) => {
+            let value = operand_value(rhs, values).clone().into_json();
+            match operand_value(lhs, values) {
+                Value::Null => None,
+                // Only scalars are compared, the same way as the JSON path comparison in Postgres
+                Value::Json(JsonValue::Array(array)) => Some(array.iter().any(|item| {
+                    !item.is_array() && !item.is_object() && compare_json(item, &value).is_eq()
+                })),
+                _ => Some(false),
+            }
+        }
+        Condition::ArrayOverlaps(lhs, rhs) => {
+            let lhs = operand_value(lhs, values).as_json_array()?;
+            let rhs = operand_value(rhs, values).as_json_array()?;
+            // Only scalars are compared, the same way as the JSON path comparison in Postgres
+            Some(lhs.iter().any(|lhs| {
+                !lhs.is_array()
+                    && !lhs.is_object()
+                    && rhs.iter().any(|rhs| compare_json(lhs, rhs).is_eq())
+            }))
+        }
+    }
+}
+
+/// Compares two sorting values according to the requested ordering.
+///
+/// Without an explicit [`NullOrdering`], `NULL`s are sorted as if they were larger than any other
+/// value, which matches the behavior of Postgres.
+pub(super) fn compare_sorting_values(
+    lhs: &Value,
+    rhs: &Value,
+    ordering: SortingOrdering,
+    nulls: Option<NullOrdering>,
+) -> Ordering {
+    let nulls = nulls.unwrap_or(match ordering {
+        SortingOrdering::Ascending => NullOrdering::Last,
+        SortingOrdering::Descending => NullOrdering::First,
+    });
+
+    match (lhs, rhs) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => match nulls {
+            NullOrdering::First => Ordering::Less,
+            NullOrdering::Last => Ordering::Greater,
+        },
+        (_, Value::Null) => match nulls {
+            NullOrdering::First => Ordering::Greater,
+            NullOrdering::Last => Ordering::Less,
+        },
+        (lhs, rhs) => {
+            let ordering_result = lhs.compare(rhs).unwrap_or(Ordering::Equal);
+            match ordering {
+                SortingOrdering::Ascending => ordering_result,
+                SortingOrdering::Descending => ordering_result.reverse(),
+            }
+        }
+    }
+}
+
+/// Groups `rows` by their first `group_size` values and applies the `functions` to the remaining
+/// values in order.
+///
+/// This mirrors the aggregation in Postgres: missing values and JSON `null`s are ignored, the
+/// groups are ordered by their key and without any grouping, exactly one group is returned.
+pub(super) fn aggregate_rows(
+    rows: impl IntoIterator<Item = Vec<Value>>,
+    group_size: usize,
+    functions: &[AggregationFunction],
+) -> Vec<EntityAggregationGroup> {
+    let mut groups = Vec::<(Vec<Value>, Vec<Vec<Value>>)>::new();
+    for mut row in rows {
+        let values = row.split_off(group_size);
+        let index = groups
+            .iter()
+            .position(|(key, _)| {
+                key.iter().zip(&row).all(|(lhs, rhs)| {
+                    compare_sorting_values(lhs, rhs, SortingOrdering::Ascending, None).is_eq()
+                })
+            })
+            .unwrap_or_else(|| {
+                groups.push((row, vec![Vec::new(); functions.len()]));
+                groups.len() - 1
+            });
+
+        for (aggregated, value) in groups[index].1.iter_mut().zip(values) {
+            if !matches!(value, Value::Null | Value::Json(JsonValue::Null)) {
+                aggregated.push(value);
+            }
+        }
+    }
+    if group_size == 0 && groups.is_empty() {
+        groups.push((Vec::new(), vec![Vec::new(); functions.len()]));
+    }
+
+    groups.sort_by(|(lhs, _), (rhs, _)| {
+        lhs.iter()
+            .zip(rhs)
+            .map(|(lhs, rhs)| compare_sorting_values(lhs, rhs, SortingOrdering::Ascending, None))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+
+    groups
+        .into_iter()
+        .map(|(key, values)| EntityAggregationGroup {
+            key: key.into_iter().map(Value::into_json).collect(),
+            values: functions
+                .iter()
+                .zip(values)
+                .map(|(function, values)| {
+                    let numbers = values.iter().filter_map(Value::as_number);
+                    match function {
+                        AggregationFunction::Count => {
+                            Some(f64::from(u32::try_from(values.len()).unwrap_or(u32::MAX)))
+                        }
+                        AggregationFunction::Sum => numbers.reduce(|lhs, rhs| lhs + rhs),
+                        AggregationFunction::Min => numbers.reduce(f64::min),
+                        AggregationFunction::Max => numbers.reduce(f64::max),
+                        AggregationFunction::Avg => {
+                            let (sum, count) = numbers
+                                .fold((0.0, 0_u32), |(sum, count), number| {
+                                    (sum + number, count + 1)
+                                });
+                            (count > 0).then(|| sum / f64::from(count))
+                        }
+                    }
+                })
+                .collect(),
+        })
+        .collect()
+}
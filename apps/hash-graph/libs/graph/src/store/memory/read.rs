@@ -0,0 +1,338 @@
+use core::cmp::Ordering;
+use std::vec;
+
+use error_stack::Report;
+use futures::stream;
+use graph_types::{
+    knowledge::entity::Entity,
+    ontology::{DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata},
+};
+use hash_graph_store::{
+    filter::{Filter, QueryRecord},
+    subgraph::temporal_axes::QueryTemporalAxes,
+};
+use type_system::url::VersionedUrl;
+
+use super::{
+    MemoryStore,
+    query::{
+        CompiledFilter, MemoryQueryPath, Node, QueryContext, RecordKind, Value,
+        compare_sorting_values, search_relevance,
+    },
+    state::MemoryState,
+};
+use crate::store::{
+    Ordering as SortingOrdering, QueryError,
+    crud::{QueryResult, Read, ReadPaginated, VersionedUrlSorting},
+    knowledge::{EntityQueryCursor, EntityQuerySorting},
+};
+
+/// A record which can be read from the [`MemoryStore`].
+pub(super) trait MemoryRecord: QueryRecord {
+    const KIND: RecordKind;
+
+    fn decode(state: &MemoryState, node: Node<'_>) -> Self;
+}
+
+impl MemoryRecord for DataTypeWithMetadata {
+    const KIND: RecordKind = RecordKind::DataType;
+
+    fn decode(state: &MemoryState, node: Node<'_>) -> Self {
+        let Node::DataType(url, row) = node else {
+            unreachable!("Expected a data type node, got {node:?}");
+        };
+        state.data_type_with_metadata(url, row)
+    }
+}
+
+impl MemoryRecord for PropertyTypeWithMetadata {
+    const KIND: RecordKind = RecordKind::PropertyType;
+
+    fn decode(state: &MemoryState, node: Node<'_>) -> Self {
+        let Node::PropertyType(url, row) = node else {
+            unreachable!("Expected a property type node, got {node:?}");
+        };
+        state.property_type_with_metadata(url, row)
+    }
+}
+
+impl MemoryRecord for EntityTypeWithMetadata {
+    const KIND: RecordKind = RecordKind::EntityType;
+
+    fn decode(state: &MemoryState, node: Node<'_>) -> Self {
+        let Node::EntityType(url, row) = node else {
+            unreachable!("Expected an entity type node, got {node:?}");
+        };
+        state.entity_type_with_metadata(url, row)
+    }
+}
+
+impl MemoryRecord for Entity {
+    const KIND: RecordKind = RecordKind::Entity;
+
+    fn decode(state: &MemoryState, node: Node<'_>) -> Self {
+        let Node::Entity(row) = node else {
+            unreachable!("Expected an entity node, got {node:?}");
+        };
+        state.entity(row)
+    }
+}
+
+/// Returns all records matching `filter` at the given temporal axes.
+pub(super) fn matching_nodes<'s, 'p, R>(
+    context: &QueryContext<'s>,
+    filter: &Filter<'p, R>,
+) -> Vec<Node<'s>>
+where
+    R: MemoryRecord<QueryPath<'p>: MemoryQueryPath>,
+{
+    let filter = CompiledFilter::new(filter);
+    context
+        .root_nodes()
+        .into_iter()
+        .filter(|node| context.matches(&filter, *node))
+        .collect()
+}
+
+impl<A, R> Read<R> for MemoryStore<A>
+where
+    for<'p> R: MemoryRecord<QueryPath<'p>: MemoryQueryPath> + Send + Sync,
+    A: Send + Sync,
+{
+    type ReadStream = stream::Iter<vec::IntoIter<Result<R, Report<QueryError>>>>;
+
+    #[tracing::instrument(level = "info", skip(self, filter))]
+    async fn read(
+        &self,
+        filter: &Filter<'_, R>,
+        temporal_axes: Option<&QueryTemporalAxes>,
+        include_drafts: bool,
+    ) -> Result<Self::ReadStream, Report<QueryError>> {
+        let state = self.state.read().await;
+        let context = QueryContext {
+            state: &state,
+            temporal_axes,
+            include_drafts,
+            kind: R::KIND,
+        };
+
+        Ok(stream::iter(
+            matching_nodes(&context, filter)
+                .into_iter()
+                .map(|node| Ok(R::decode(&state, node)))
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, filter))]
+    async fn read_one(
+        &self,
+        filter: &Filter<'_, R>,
+        temporal_axes: Option<&QueryTemporalAxes>,
+        include_drafts: bool,
+    ) -> Result<R, Report<QueryError>> {
+        let state = self.state.read().await;
+        let context = QueryContext {
+            state: &state,
+            temporal_axes,
+            include_drafts,
+            kind: R::KIND,
+        };
+
+        match matching_nodes(&context, filter).as_slice() {
+            [node] => Ok(R::decode(&state, *node)),
+            nodes => Err(Report::new(QueryError)
+                .attach_printable(format!("Expected 1 result, got {}", nodes.len()))),
+        }
+    }
+}
+
+/// A record returned from a paginated read together with its cursor.
+pub struct MemoryQueryResult<R, C> {
+    record: R,
+    cursor: C,
+}
+
+impl<'s> QueryResult<Entity, EntityQuerySorting<'s>>
+    for MemoryQueryResult<Entity, EntityQueryCursor<'static>>
+{
+    type Indices = ();
+
+    fn decode_record(&self, (): &Self::Indices) -> Entity {
+        self.record.clone()
+    }
+
+    fn decode_cursor(&self, (): &Self::Indices) -> EntityQueryCursor<'s> {
+        self.cursor.clone()
+    }
+}
+
+impl<R: Clone> QueryResult<R, VersionedUrlSorting> for MemoryQueryResult<R, VersionedUrl> {
+    type Indices = ();
+
+    fn decode_record(&self, (): &Self::Indices) -> R {
+        self.record.clone()
+    }
+
+    fn decode_cursor(&self, (): &Self::Indices) -> VersionedUrl {
+        self.cursor.clone()
+    }
+}
+
+/// Compares the sorting values of two entities using the requested sorting.
+///
+/// If the sorting is by relevance, the first value is the relevance, which is sorted in descending
+/// order.
+fn compare_sorting_tuples(
+    lhs: &[Value],
+    rhs: &[Value],
+    sorting: &EntityQuerySorting<'_>,
+) -> Ordering {
+    let relevance = sorting
+        .relevance
+        .as_ref()
+        .map(|_| (SortingOrdering::Descending, None));
+
+    lhs.iter()
+        .zip(rhs)
+        .zip(
+            relevance.into_iter().chain(
+                sorting
+                    .paths
+                    .iter()
+                    .map(|record| (record.ordering, record.nulls)),
+            ),
+        )
+        .map(|((lhs, rhs), (ordering, nulls))| compare_sorting_values(lhs, rhs, ordering, nulls))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+impl<'s, A> ReadPaginated<Entity, EntityQuerySorting<'s>> for MemoryStore<A>
+where
+    A: Send + Sync,
+{
+    type QueryResult = MemoryQueryResult<Entity, EntityQueryCursor<'static>>;
+    type ReadPaginatedStream =
+        stream::Iter<vec::IntoIter<Result<Self::QueryResult, Report<QueryError>>>>;
+
+    #[tracing::instrument(level = "info", skip(self, filter, sorting))]
+    async fn read_paginated(
+        &self,
+        filter: &Filter<'_, Entity>,
+        temporal_axes: Option<&QueryTemporalAxes>,
+        sorting: &EntityQuerySorting<'s>,
+        limit: Option<usize>,
+        include_drafts: bool,
+    ) -> Result<(Self::ReadPaginatedStream, ()), Report<QueryError>> {
+        let state = self.state.read().await;
+        let context = QueryContext {
+            state: &state,
+            temporal_axes,
+            include_drafts,
+            kind: RecordKind::Entity,
+        };
+
+        let sorting_paths = CompiledFilter::from_sorting(sorting);
+        let cursor = sorting
+            .cursor
+            .as_ref()
+            .map(|cursor| cursor.values.iter().map(Value::from).collect::<Vec<_>>());
+
+        let mut records = matching_nodes(&context, filter)
+            .into_iter()
+            .map(|node| {
+                let mut values = context.sorting_values(&sorting_paths, node);
+                if let Some(query) = &sorting.relevance {
+                    values[0] = search_relevance(&values[0], query);
+                }
+                (node, values)
+            })
+            .filter(|(_, values)| {
+                cursor
+                    .as_ref()
+                    .is_none_or(|cursor| compare_sorting_tuples(values, cursor, sorting).is_gt())
+            })
+            .collect::<Vec<_>>();
+        records.sort_by(|(_, lhs), (_, rhs)| compare_sorting_tuples(lhs, rhs, sorting));
+        if let Some(limit) = limit {
+            records.truncate(limit);
+        }
+
+        Ok((
+            stream::iter(
+                records
+                    .into_iter()
+                    .map(|(node, values)| {
+                        Ok(MemoryQueryResult {
+                            record: Entity::decode(&state, node),
+                            cursor: EntityQueryCursor {
+                                values: values.into_iter().map(Value::into_cursor_field).collect(),
+                            },
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            (),
+        ))
+    }
+}
+
+macro_rules! impl_ontology_read_paginated {
+    ($ty:ty) => {
+        impl<A> ReadPaginated<$ty, VersionedUrlSorting> for MemoryStore<A>
+        where
+            A: Send + Sync,
+        {
+            type QueryResult = MemoryQueryResult<$ty, VersionedUrl>;
+            type ReadPaginatedStream =
+                stream::Iter<vec::IntoIter<Result<Self::QueryResult, Report<QueryError>>>>;
+
+            #[tracing::instrument(level = "info", skip(self, filter, sorting))]
+            async fn read_paginated(
+                &self,
+                filter: &Filter<'_, $ty>,
+                temporal_axes: Option<&QueryTemporalAxes>,
+                sorting: &VersionedUrlSorting,
+                limit: Option<usize>,
+                include_drafts: bool,
+            ) -> Result<(Self::ReadPaginatedStream, ()), Report<QueryError>> {
+                let state = self.state.read().await;
+                let context = QueryContext {
+                    state: &state,
+                    temporal_axes,
+                    include_drafts,
+                    kind: <$ty as MemoryRecord>::KIND,
+                };
+
+                // The root nodes are already ordered by base URL and descending version.
+                let records = matching_nodes(&context, filter)
+                    .into_iter()
+                    .map(|node| <$ty as MemoryRecord>::decode(&state, node))
+                    .filter(|record| {
+                        sorting.cursor.as_ref().is_none_or(|cursor| {
+                            let url = &record.schema.id;
+                            url.base_url
+                                .cmp(&cursor.base_url)
+                                .then_with(|| cursor.version.cmp(&url.version))
+                                .is_gt()
+                        })
+                    })
+                    .take(limit.unwrap_or(usize::MAX))
+                    .map(|record| {
+                        Ok(MemoryQueryResult {
+                            cursor: record.schema.id.clone(),
+                            record,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                Ok((stream::iter(records), ()))
+            }
+        }
+    };
+}
+
+impl_ontology_read_paginated!(DataTypeWithMetadata);
+impl_ontology_read_paginated!(PropertyTypeWithMetadata);
+impl_ontology_read_paginated!(EntityTypeWithMetadata);
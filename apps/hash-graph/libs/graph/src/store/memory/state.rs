@@ -0,0 +1,293 @@
+use std::collections::{HashMap, HashSet};
+
+use graph_types::{
+    Embedding,
+    account::{AccountGroupId, AccountId},
+    knowledge::{
+        Confidence,
+        entity::{
+            Entity, EntityEditionId, EntityEditionProvenance, EntityId, EntityMetadata,
+            EntityProvenance, EntityRecordId, EntityTemporalMetadata, EntityUuid,
+            InferredEntityProvenance,
+        },
+        link::LinkData,
+        property::{PropertyMetadataObject, PropertyObject},
+    },
+    ontology::{
+        DataTypeMetadata, DataTypeWithMetadata, EntityTypeMetadata, EntityTypeWithMetadata,
+        OntologyEditionProvenance, OntologyProvenance, OntologyTemporalMetadata,
+        OntologyTypeClassificationMetadata, OntologyTypeRecordId, PropertyTypeMetadata,
+        PropertyTypeWithMetadata,
+    },
+    owned_by_id::OwnedById,
+};
+use temporal_versioning::{DecisionTime, LeftClosedTemporalInterval, Timestamp, TransactionTime};
+use type_system::{
+    schema::{
+        ClosedEntityType, Conversions, DataType, DataTypeId, DataTypeInheritanceData, EntityType,
+        PropertyType,
+    },
+    url::{BaseUrl, VersionedUrl},
+};
+
+use crate::store::change_feed::ChangeKind;
+
+/// The complete content of a [`MemoryStore`].
+///
+/// The layout mirrors the tables used by the Postgres store: every record is split into its
+/// identifier, its editions, and the temporal rows referencing an edition.
+///
+/// [`MemoryStore`]: super::MemoryStore
+#[derive(Debug, Default, Clone)]
+pub(super) struct MemoryState {
+    pub accounts: HashSet<AccountId>,
+    pub account_groups: HashSet<AccountGroupId>,
+    pub webs: HashSet<OwnedById>,
+
+    pub base_urls: HashSet<BaseUrl>,
+    pub ontology_ids: HashMap<VersionedUrl, OntologyIdRecord>,
+    pub data_types: HashMap<VersionedUrl, DataTypeRecord>,
+    pub property_types: HashMap<VersionedUrl, PropertyTypeRecord>,
+    pub entity_types: HashMap<VersionedUrl, EntityTypeRecord>,
+
+    pub entity_ids: HashMap<(OwnedById, EntityUuid), EntityIdRecord>,
+    pub entity_editions: HashMap<EntityEditionId, EntityEditionRecord>,
+    pub entity_temporal_metadata: Vec<EntityTemporalRow>,
+    pub entity_embeddings: HashMap<(EntityId, Option<BaseUrl>), EntityEmbeddingRecord>,
+
+    pub changes: Vec<ChangeRow>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct OntologyIdRecord {
+    pub classification: OntologyTypeClassificationMetadata,
+    pub temporal_metadata: Vec<OntologyTemporalRow>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct OntologyTemporalRow {
+    pub transaction_time: LeftClosedTemporalInterval<TransactionTime>,
+    pub provenance: OntologyEditionProvenance,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct OntologyEmbeddingRecord {
+    pub embedding: Embedding<'static>,
+    pub updated_at_transaction_time: Timestamp<TransactionTime>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct DataTypeRecord {
+    pub schema: DataType,
+    pub inheritance_data: DataTypeInheritanceData,
+    pub conversions: HashMap<BaseUrl, Conversions>,
+    pub embedding: Option<OntologyEmbeddingRecord>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct PropertyTypeRecord {
+    pub schema: PropertyType,
+    pub embedding: Option<OntologyEmbeddingRecord>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct EntityTypeRecord {
+    pub schema: EntityType,
+    pub closed_schema: ClosedEntityType,
+    pub label_property: Option<BaseUrl>,
+    pub icon: Option<String>,
+    pub embedding: Option<OntologyEmbeddingRecord>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct EntityIdRecord {
+    pub provenance: InferredEntityProvenance,
+    pub link_data: Option<LinkData>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct EntityEditionRecord {
+    pub properties: PropertyObject,
+    pub property_metadata: PropertyMetadataObject,
+    pub archived: bool,
+    pub confidence: Option<Confidence>,
+    pub provenance: EntityEditionProvenance,
+    pub entity_type_ids: HashSet<VersionedUrl>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct EntityTemporalRow {
+    pub entity_id: EntityId,
+    pub edition_id: EntityEditionId,
+    pub decision_time: LeftClosedTemporalInterval<DecisionTime>,
+    pub transaction_time: LeftClosedTemporalInterval<TransactionTime>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct EntityEmbeddingRecord {
+    pub embedding: Embedding<'static>,
+    pub updated_at_transaction_time: Timestamp<TransactionTime>,
+    pub updated_at_decision_time: Timestamp<DecisionTime>,
+}
+
+/// The record written by a [`ChangeRow`].
+#[derive(Debug, Clone)]
+pub(super) enum ChangeRecordId {
+    Entity(EntityRecordId),
+    Ontology(VersionedUrl),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct ChangeRow {
+    pub kind: ChangeKind,
+    pub record_id: ChangeRecordId,
+    pub transaction_time: Timestamp<TransactionTime>,
+    pub actor_id: AccountId,
+}
+
+impl MemoryState {
+    /// Appends a write to the change feed.
+    pub(super) fn record_change(
+        &mut self,
+        kind: ChangeKind,
+        record_id: ChangeRecordId,
+        transaction_time: Timestamp<TransactionTime>,
+        actor_id: AccountId,
+    ) {
+        self.changes.push(ChangeRow {
+            kind,
+            record_id,
+            transaction_time,
+            actor_id,
+        });
+    }
+
+    /// Returns the ontology id record and the temporal row at `row` for the given type.
+    pub(super) fn ontology_row(
+        &self,
+        url: &VersionedUrl,
+        row: usize,
+    ) -> Option<(&OntologyIdRecord, &OntologyTemporalRow)> {
+        let record = self.ontology_ids.get(url)?;
+        Some((record, record.temporal_metadata.get(row)?))
+    }
+
+    fn ontology_metadata(
+        &self,
+        url: &VersionedUrl,
+        row: usize,
+    ) -> (
+        OntologyTypeRecordId,
+        OntologyTypeClassificationMetadata,
+        OntologyTemporalMetadata,
+        OntologyProvenance,
+    ) {
+        let (record, temporal_row) = self
+            .ontology_row(url, row)
+            .expect("ontology type should have a temporal row");
+        (
+            OntologyTypeRecordId::from(url.clone()),
+            record.classification.clone(),
+            OntologyTemporalMetadata {
+                transaction_time: temporal_row.transaction_time,
+            },
+            OntologyProvenance {
+                edition: temporal_row.provenance.clone(),
+            },
+        )
+    }
+
+    pub(super) fn data_type_with_metadata(
+        &self,
+        url: &VersionedUrl,
+        row: usize,
+    ) -> DataTypeWithMetadata {
+        let data_type = &self.data_types[url];
+        let (record_id, classification, temporal_versioning, provenance) =
+            self.ontology_metadata(url, row);
+        DataTypeWithMetadata {
+            schema: data_type.schema.clone(),
+            metadata: DataTypeMetadata {
+                record_id,
+                classification,
+                temporal_versioning,
+                provenance,
+                conversions: data_type.conversions.clone(),
+            },
+        }
+    }
+
+    pub(super) fn property_type_with_metadata(
+        &self,
+        url: &VersionedUrl,
+        row: usize,
+    ) -> PropertyTypeWithMetadata {
+        let property_type = &self.property_types[url];
+        let (record_id, classification, temporal_versioning, provenance) =
+            self.ontology_metadata(url, row);
+        PropertyTypeWithMetadata {
+            schema: property_type.schema.clone(),
+            metadata: PropertyTypeMetadata {
+                record_id,
+                classification,
+                temporal_versioning,
+                provenance,
+            },
+        }
+    }
+
+    pub(super) fn entity_type_with_metadata(
+        &self,
+        url: &VersionedUrl,
+        row: usize,
+    ) -> EntityTypeWithMetadata {
+        let entity_type = &self.entity_types[url];
+        let (record_id, classification, temporal_versioning, provenance) =
+            self.ontology_metadata(url, row);
+        EntityTypeWithMetadata {
+            schema: entity_type.schema.clone(),
+            metadata: EntityTypeMetadata {
+                record_id,
+                classification,
+                temporal_versioning,
+                provenance,
+                label_property: entity_type.label_property.clone(),
+                icon: entity_type.icon.clone(),
+            },
+        }
+    }
+
+    /// Returns the data type with the given id.
+    pub(super) fn data_type_by_id(&self, id: DataTypeId) -> Option<&DataTypeRecord> {
+        self.data_types
+            .iter()
+            .find_map(|(url, record)| (DataTypeId::from_url(url) == id).then_some(record))
+    }
+
+    pub(super) fn entity(&self, row: &EntityTemporalRow) -> Entity {
+        let id_record = &self.entity_ids[&(row.entity_id.owned_by_id, row.entity_id.entity_uuid)];
+        let edition = &self.entity_editions[&row.edition_id];
+        Entity {
+            properties: edition.properties.clone(),
+            link_data: id_record.link_data.clone(),
+            metadata: EntityMetadata {
+                record_id: EntityRecordId {
+                    entity_id: row.entity_id,
+                    edition_id: row.edition_id,
+                },
+                temporal_versioning: EntityTemporalMetadata {
+                    decision_time: row.decision_time,
+                    transaction_time: row.transaction_time,
+                },
+                entity_type_ids: edition.entity_type_ids.clone(),
+                archived: edition.archived,
+                provenance: EntityProvenance {
+                    inferred: id_record.provenance.clone(),
+                    edition: edition.provenance.clone(),
+                },
+                confidence: edition.confidence,
+                properties: edition.property_metadata.clone(),
+            },
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod change_feed;
 pub mod crud;
 pub mod error;
 
@@ -9,6 +10,7 @@ mod pool;
 mod validation;
 
 mod fetcher;
+mod memory;
 pub(crate) mod postgres;
 
 use hash_graph_store::account::AccountStore;
@@ -17,6 +19,7 @@ use serde::Deserialize;
 use utoipa::ToSchema;
 
 pub use self::{
+    change_feed::ChangeFeedStore,
     config::{DatabaseConnectionInfo, DatabasePoolConfig, DatabaseType},
     error::{
         BaseUrlAlreadyExists, InsertionError, OntologyVersionDoesNotExist, QueryError, StoreError,
@@ -27,10 +30,11 @@ pub use self::{
         EntityQueryCursor, EntityQuerySorting, EntityQuerySortingRecord, EntityStore,
         EntityValidationType,
     },
+    memory::{MemoryStore, MemoryStorePool},
     migration::{Migration, MigrationState, StoreMigration},
     ontology::{DataTypeStore, EntityTypeStore, PropertyTypeStore},
     pool::StorePool,
-    postgres::{AsClient, PostgresStore, PostgresStorePool},
+    postgres::{AsClient, CursorField, PostgresStore, PostgresStorePool},
     validation::{StoreCache, StoreProvider},
 };
 
@@ -41,12 +45,17 @@ pub use self::{
 /// In addition to the errors described in the methods of this trait, further errors might also be
 /// raised depending on the implementation, e.g. connection issues.
 pub trait Store:
-    AccountStore + DataTypeStore + PropertyTypeStore + EntityTypeStore + EntityStore
+    AccountStore + DataTypeStore + PropertyTypeStore + EntityTypeStore + EntityStore + ChangeFeedStore
 {
 }
 
 impl<S> Store for S where
-    S: AccountStore + DataTypeStore + PropertyTypeStore + EntityTypeStore + EntityStore
+    S: AccountStore
+        + DataTypeStore
+        + PropertyTypeStore
+        + EntityTypeStore
+        + EntityStore
+        + ChangeFeedStore
 {
 }
 
@@ -65,3 +74,16 @@ pub enum NullOrdering {
     First,
     Last,
 }
+
+/// An aggregate function computed over the values of a group of records.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum AggregationFunction {
+    /// The number of non-null values.
+    Count,
+    Sum,
+    Min,
+    Max,
+    Avg,
+}
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 use authorization::schema::{
     DataTypeRelationAndSubject, EntityTypeRelationAndSubject, PropertyTypeRelationAndSubject,
 };
-use error_stack::Result;
+use error_stack::{Report, Result};
 use graph_types::{
     Embedding,
     account::{AccountId, EditionCreatedById},
@@ -24,11 +24,74 @@ use hash_graph_store::{
 use serde::{Deserialize, Serialize};
 use temporal_versioning::{Timestamp, TransactionTime};
 use type_system::{
-    schema::{Conversions, DataType, EntityType, PropertyType},
+    schema::{Conversions, DataType, EntityType, PropertyType, TypeChange},
     url::{BaseUrl, VersionedUrl},
 };
 
-use crate::store::{InsertionError, QueryError, UpdateError};
+use crate::store::{InsertionError, QueryError, UpdateError, error::BreakingOntologyTypeChange};
+
+/// Specifies how breaking changes of a new ontology type version are handled.
+///
+/// A change is breaking if data which is valid against the previous version may be invalid
+/// against the new version, e.g. if a required property is added or a constraint is narrowed.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum BreakingChangeBehavior {
+    /// The new version is created regardless of breaking changes.
+    #[default]
+    Allow,
+    /// The update fails and reports every breaking change if the new version contains any.
+    Reject,
+    /// The new version is created regardless of breaking changes and every detected change is
+    /// returned alongside its metadata.
+    Report,
+}
+
+impl BreakingChangeBehavior {
+    /// Handles the `changes` of a new ontology type version according to this behavior.
+    ///
+    /// Returns the changes which are passed back to the caller, these are only non-empty if the
+    /// changes are [`Report`]ed.
+    ///
+    /// # Errors
+    ///
+    /// - [`BreakingOntologyTypeChange`] if breaking changes are [`Reject`]ed and any of the
+    ///   `changes` is breaking.
+    ///
+    /// [`Report`]: Self::Report
+    /// [`Reject`]: Self::Reject
+    pub(crate) fn handle_changes(
+        self,
+        changes: Vec<TypeChange>,
+    ) -> Result<Vec<TypeChange>, BreakingOntologyTypeChange> {
+        match self {
+            Self::Allow => Ok(Vec::new()),
+            Self::Reject => reject_breaking_changes(changes).map(|()| Vec::new()),
+            Self::Report => Ok(changes),
+        }
+    }
+}
+
+/// Fails if any of the `changes` of a new ontology type version is breaking.
+///
+/// Every breaking change is attached to the returned report.
+fn reject_breaking_changes(
+    changes: impl IntoIterator<Item = TypeChange>,
+) -> Result<(), BreakingOntologyTypeChange> {
+    let mut breaking_changes = changes
+        .into_iter()
+        .filter(TypeChange::is_breaking)
+        .peekable();
+    if breaking_changes.peek().is_none() {
+        return Ok(());
+    }
+
+    Err(breaking_changes.fold(
+        Report::new(BreakingOntologyTypeChange),
+        Report::attach_printable,
+    ))
+}
 
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
@@ -115,10 +178,23 @@ pub struct UpdateDataTypesParams<R> {
     pub relationships: R,
     #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
     pub provenance: ProvidedOntologyEditionProvenance,
+    #[serde(default)]
+    pub breaking_changes: BreakingChangeBehavior,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub conversions: HashMap<BaseUrl, Conversions>,
 }
 
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDataTypesResponse {
+    #[serde(flatten)]
+    pub metadata: DataTypeMetadata,
+    /// The changes to the previous version, only populated if changes are reported.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<TypeChange>,
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -233,11 +309,13 @@ pub trait DataTypeStore {
     /// # Errors
     ///
     /// - if the [`DataType`] doesn't exist.
+    /// - if breaking changes are rejected and the [`DataType`] contains any, see
+    ///   [`BreakingChangeBehavior`].
     fn update_data_type<R>(
         &mut self,
         actor_id: AccountId,
         params: UpdateDataTypesParams<R>,
-    ) -> impl Future<Output = Result<DataTypeMetadata, UpdateError>> + Send
+    ) -> impl Future<Output = Result<UpdateDataTypesResponse, UpdateError>> + Send
     where
         R: IntoIterator<Item = DataTypeRelationAndSubject> + Send + Sync;
 
@@ -365,6 +443,19 @@ pub struct UpdatePropertyTypesParams<R> {
     pub relationships: R,
     #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
     pub provenance: ProvidedOntologyEditionProvenance,
+    #[serde(default)]
+    pub breaking_changes: BreakingChangeBehavior,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePropertyTypesResponse {
+    #[serde(flatten)]
+    pub metadata: PropertyTypeMetadata,
+    /// The changes to the previous version, only populated if changes are reported.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<TypeChange>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -481,11 +572,13 @@ pub trait PropertyTypeStore {
     /// # Errors
     ///
     /// - if the [`PropertyType`] doesn't exist.
+    /// - if breaking changes are rejected and the [`PropertyType`] contains any, see
+    ///   [`BreakingChangeBehavior`].
     fn update_property_type<R>(
         &mut self,
         actor_id: AccountId,
         params: UpdatePropertyTypesParams<R>,
-    ) -> impl Future<Output = Result<PropertyTypeMetadata, UpdateError>> + Send
+    ) -> impl Future<Output = Result<UpdatePropertyTypesResponse, UpdateError>> + Send
     where
         R: IntoIterator<Item = PropertyTypeRelationAndSubject> + Send + Sync;
 
@@ -624,6 +717,19 @@ pub struct UpdateEntityTypesParams<R> {
     pub relationships: R,
     #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
     pub provenance: ProvidedOntologyEditionProvenance,
+    #[serde(default)]
+    pub breaking_changes: BreakingChangeBehavior,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEntityTypesResponse {
+    #[serde(flatten)]
+    pub metadata: EntityTypeMetadata,
+    /// The changes to the previous version, only populated if changes are reported.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<TypeChange>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -740,11 +846,13 @@ pub trait EntityTypeStore {
     /// # Errors
     ///
     /// - if the [`EntityType`] doesn't exist.
+    /// - if breaking changes are rejected and the [`EntityType`] contains any, see
+    ///   [`BreakingChangeBehavior`].
     fn update_entity_type<R>(
         &mut self,
         actor_id: AccountId,
         params: UpdateEntityTypesParams<R>,
-    ) -> impl Future<Output = Result<EntityTypeMetadata, UpdateError>> + Send
+    ) -> impl Future<Output = Result<UpdateEntityTypesResponse, UpdateError>> + Send
     where
         R: IntoIterator<Item = EntityTypeRelationAndSubject> + Send + Sync;
 
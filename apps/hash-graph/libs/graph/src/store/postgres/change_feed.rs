@@ -0,0 +1,198 @@
+use authorization::AuthorizationApi;
+use error_stack::{Report, ResultExt};
+use graph_types::{
+    account::AccountId,
+    knowledge::entity::{EntityEditionId, EntityId, EntityRecordId},
+};
+use temporal_versioning::{Timestamp, TransactionTime};
+use type_system::url::VersionedUrl;
+
+use crate::store::{
+    AsClient, InsertionError, PostgresStore, QueryError,
+    change_feed::{
+        Change, ChangeFeedPosition, ChangeFeedStore, ChangeKind, ChangedRecord, ReadChangesParams,
+        ReadChangesResponse, filter_permitted_changes,
+    },
+};
+
+impl<C, A> PostgresStore<C, A>
+where
+    C: AsClient,
+    A: Send + Sync,
+{
+    /// Records the writes of the given entity editions in the change feed.
+    ///
+    /// This has to be called in the same transaction in which the editions are written.
+    ///
+    /// # Errors
+    ///
+    /// - if the changes could not be inserted
+    pub(crate) async fn insert_entity_changes(
+        &self,
+        change_kind: ChangeKind,
+        record_ids: impl IntoIterator<Item = EntityRecordId> + Send,
+        transaction_time: Timestamp<TransactionTime>,
+        actor_id: AccountId,
+    ) -> Result<(), Report<InsertionError>> {
+        let mut web_ids = Vec::new();
+        let mut entity_uuids = Vec::new();
+        let mut draft_ids = Vec::new();
+        let mut edition_ids = Vec::new();
+        for record_id in record_ids {
+            web_ids.push(record_id.entity_id.owned_by_id);
+            entity_uuids.push(record_id.entity_id.entity_uuid);
+            draft_ids.push(record_id.entity_id.draft_id);
+            edition_ids.push(record_id.edition_id);
+        }
+
+        self.as_client()
+            .query(
+                "
+                    INSERT INTO change_feed (
+                        change_kind,
+                        web_id,
+                        entity_uuid,
+                        draft_id,
+                        entity_edition_id,
+                        transaction_time,
+                        actor_id
+                    )
+                    SELECT $1, web_id, entity_uuid, draft_id, entity_edition_id, $6, $7
+                    FROM UNNEST($2::UUID[], $3::UUID[], $4::UUID[], $5::UUID[])
+                        AS changes(web_id, entity_uuid, draft_id, entity_edition_id);
+                ",
+                &[
+                    &change_kind,
+                    &web_ids,
+                    &entity_uuids,
+                    &draft_ids,
+                    &edition_ids,
+                    &transaction_time,
+                    &actor_id,
+                ],
+            )
+            .await
+            .change_context(InsertionError)?;
+
+        Ok(())
+    }
+}
+
+impl<C, A> ChangeFeedStore for PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn read_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadChangesParams,
+    ) -> Result<ReadChangesResponse, Report<QueryError>> {
+        let limit = i64::try_from(params.limit).change_context(QueryError)?;
+
+        // A change is only returned once no transaction which started before it is still running.
+        // Transactions are ordered by their id but may commit in a different order, so this
+        // ensures that no change with a smaller position becomes visible after a reader already
+        // moved past it. Changes written by the current transaction are always visible to itself.
+        let rows = self
+            .as_client()
+            .query(
+                "
+                    SELECT
+                        change_feed.transaction_id,
+                        change_feed.sequence_id,
+                        change_feed.change_kind,
+                        change_feed.web_id,
+                        change_feed.entity_uuid,
+                        change_feed.draft_id,
+                        change_feed.entity_edition_id,
+                        ontology_ids.base_url,
+                        ontology_ids.version,
+                        EXISTS (
+                            SELECT 1
+                            FROM data_types
+                            WHERE data_types.ontology_id = change_feed.ontology_id
+                        ),
+                        EXISTS (
+                            SELECT 1
+                            FROM property_types
+                            WHERE property_types.ontology_id = change_feed.ontology_id
+                        ),
+                        change_feed.transaction_time,
+                        change_feed.actor_id
+                    FROM change_feed
+                    LEFT JOIN ontology_ids
+                        ON ontology_ids.ontology_id = change_feed.ontology_id
+                    WHERE (
+                        $1::BIGINT IS NULL
+                        OR (change_feed.transaction_id, change_feed.sequence_id) > ($1, $2::BIGINT)
+                    )
+                    AND (
+                        change_feed.transaction_id
+                            < pg_snapshot_xmin(pg_current_snapshot())::TEXT::BIGINT
+                        OR change_feed.transaction_id
+                            = pg_current_xact_id_if_assigned()::TEXT::BIGINT
+                    )
+                    ORDER BY change_feed.transaction_id, change_feed.sequence_id
+                    LIMIT $3;
+                ",
+                &[
+                    &params.after.map(|position| position.transaction_id),
+                    &params.after.map(|position| position.sequence_id),
+                    &limit,
+                ],
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let changes = rows
+            .into_iter()
+            .map(|row| {
+                let record = if let Some(edition_id) = row.get::<_, Option<EntityEditionId>>(6) {
+                    ChangedRecord::Entity(EntityRecordId {
+                        entity_id: EntityId {
+                            owned_by_id: row.get(3),
+                            entity_uuid: row.get(4),
+                            draft_id: row.get(5),
+                        },
+                        edition_id,
+                    })
+                } else {
+                    let url = VersionedUrl {
+                        base_url: row.get(7),
+                        version: row.get(8),
+                    };
+                    if row.get(9) {
+                        ChangedRecord::DataType(url)
+                    } else if row.get(10) {
+                        ChangedRecord::PropertyType(url)
+                    } else {
+                        ChangedRecord::EntityType(url)
+                    }
+                };
+
+                Change {
+                    position: ChangeFeedPosition {
+                        transaction_id: row.get(0),
+                        sequence_id: row.get(1),
+                    },
+                    kind: row.get(2),
+                    record,
+                    transaction_time: row.get(11),
+                    actor_id: row.get(12),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let position = changes
+            .last()
+            .map(|change| change.position)
+            .or(params.after);
+
+        Ok(ReadChangesResponse {
+            changes: filter_permitted_changes(&self.authorization_api, actor_id, changes).await?,
+            position,
+        })
+    }
+}
@@ -1,7 +1,7 @@
 use error_stack::{Report, ResultExt};
 use futures::{Stream, StreamExt, TryStreamExt};
 use hash_graph_store::{filter::Filter, subgraph::temporal_axes::QueryTemporalAxes};
-use tokio_postgres::{GenericClient, Row};
+use tokio_postgres::{GenericClient, Row, RowStream};
 use tracing::Instrument;
 
 use crate::store::{
@@ -63,6 +63,46 @@ where
         limit: Option<usize>,
         include_drafts: bool,
     ) -> Result<(Self::ReadPaginatedStream, QueryIndices<R, S>), Report<QueryError>> {
+        let (stream, indices) = self
+            .query_paginated(
+                filter,
+                temporal_axes,
+                sorting,
+                limit,
+                include_drafts,
+                &R::parameters(),
+            )
+            .await?;
+        Ok((stream.map(|row| row.change_context(QueryError)), indices))
+    }
+}
+
+impl<Cl, A> PostgresStore<Cl, A>
+where
+    Cl: AsClient,
+    A: Send + Sync,
+{
+    /// Queries the records matching `filter` ordered by `sorting` and returns the resulting rows as
+    /// a stream together with the indices to decode them.
+    ///
+    /// The record selection is compiled from `record_parameters`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be executed.
+    pub(crate) async fn query_paginated<R, S>(
+        &self,
+        filter: &Filter<'_, R>,
+        temporal_axes: Option<&QueryTemporalAxes>,
+        sorting: &S,
+        limit: Option<usize>,
+        include_drafts: bool,
+        record_parameters: &R::CompilationParameters,
+    ) -> Result<(RowStream, QueryIndices<R, S>), Report<QueryError>>
+    where
+        for<'c> R: PostgresRecord<QueryPath<'c>: PostgresQueryPath>,
+        for<'s> S: PostgresSorting<'s, R> + Sync,
+    {
         let cursor_parameters = sorting.encode().change_context(QueryError)?;
 
         let mut compiler = SelectCompiler::new(temporal_axes, include_drafts);
@@ -76,8 +116,7 @@ where
             temporal_axes.expect("To use a cursor, temporal axes has to be specified"),
         );
 
-        let record_artifacts = R::parameters();
-        let record_indices = R::compile(&mut compiler, &record_artifacts);
+        let record_indices = R::compile(&mut compiler, record_parameters);
 
         compiler.add_filter(filter);
         let (statement, parameters) = compiler.compile();
@@ -88,12 +127,45 @@ where
             .await
             .change_context(QueryError)?;
 
+        Ok((stream, QueryIndices {
+            record_indices,
+            cursor_indices,
+        }))
+    }
+
+    /// Reads a page of records the same way as [`ReadPaginated::read_paginated_vec`] but compiles
+    /// the record selection from `record_parameters` instead of [`PostgresRecord::parameters`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the records fails.
+    #[tracing::instrument(level = "info", skip(self, filter, sorting, record_parameters))]
+    pub(crate) async fn read_paginated_vec_with_parameters<R, S>(
+        &self,
+        filter: &Filter<'_, R>,
+        temporal_axes: Option<&QueryTemporalAxes>,
+        sorting: &S,
+        limit: Option<usize>,
+        include_drafts: bool,
+        record_parameters: &R::CompilationParameters,
+    ) -> Result<(Vec<Row>, QueryIndices<R, S>), Report<QueryError>>
+    where
+        for<'c> R: PostgresRecord<QueryPath<'c>: PostgresQueryPath>,
+        for<'s> S: PostgresSorting<'s, R> + Sync,
+    {
+        let (stream, indices) = self
+            .query_paginated(
+                filter,
+                temporal_axes,
+                sorting,
+                limit,
+                include_drafts,
+                record_parameters,
+            )
+            .await?;
         Ok((
-            stream.map(|row| row.change_context(QueryError)),
-            QueryIndices {
-                record_indices,
-                cursor_indices,
-            },
+            stream.try_collect().await.change_context(QueryError)?,
+            indices,
         ))
     }
 }
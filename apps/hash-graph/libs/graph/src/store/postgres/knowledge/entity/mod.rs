@@ -14,7 +14,7 @@ use authorization::{
     zanzibar::{Consistency, Zookie},
 };
 use error_stack::{Report, ReportSink, Result, ResultExt, bail};
-use futures::TryStreamExt;
+use futures::{Stream, StreamExt, TryStreamExt, stream};
 use graph_types::{
     Embedding,
     account::{AccountId, CreatedById, EditionArchivedById, EditionCreatedById},
@@ -36,7 +36,7 @@ use graph_types::{
 };
 use hash_graph_store::{
     entity::EntityQueryPath,
-    filter::{Filter, FilterExpression, Parameter},
+    filter::{Filter, FilterExpression, Parameter, ParameterList},
     subgraph::{
         Subgraph, SubgraphRecord,
         edges::{EdgeDirection, GraphResolveDepths, KnowledgeGraphEdgeKind, SharedEdgeKind},
@@ -62,21 +62,26 @@ use uuid::Uuid;
 use validation::{EntityPreprocessor, Validate, ValidateEntityComponents};
 
 use crate::store::{
-    AsClient, EntityStore, InsertionError, PostgresStore, QueryError, StoreCache, UpdateError,
-    crud::{QueryResult, Read, ReadPaginated, Sorting},
-    error::{DeletionError, EntityDoesNotExist, RaceConditionOnUpdate},
+    AggregationFunction, AsClient, EntityStore, InsertionError, PostgresStore, QueryError,
+    StoreCache, UpdateError,
+    change_feed::ChangeKind,
+    crud::{QueryResult, Read, Sorting},
+    error::{DeletionError, EntityDoesNotExist, EntityEditionConflict, RaceConditionOnUpdate},
     knowledge::{
-        CountEntitiesParams, CreateEntityParams, EntityQuerySorting, EntityValidationType,
-        GetEntitiesParams, GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse,
+        AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+        CreateEntityParams, EntityAggregationGroup, EntityPathHop, EntityPathSearch,
+        EntityProjection, EntityQuerySorting, EntityTraversalPath, EntityValidationType,
+        FindEntityPathsParams, FindEntityPathsResponse, GetEntitiesParams, GetEntitiesResponse,
+        GetEntitySubgraphParams, GetEntitySubgraphResponse, LinkTraversalDirection,
         PatchEntityParams, QueryConversion, UpdateEntityEmbeddingsParams, ValidateEntityError,
         ValidateEntityParams,
     },
     postgres::{
         ResponseCountMap, TraversalContext,
-        knowledge::entity::read::EntityEdgeTraversalData,
+        knowledge::entity::{query::EntityRecordPaths, read::EntityEdgeTraversalData},
         ontology::OntologyId,
         query::{
-            InsertStatementBuilder, ReferenceTable, Table,
+            Distinctness, InsertStatementBuilder, ReferenceTable, SelectCompiler, Table,
             rows::{
                 EntityDraftRow, EntityEditionRow, EntityHasLeftEntityRow, EntityHasRightEntityRow,
                 EntityIdRow, EntityIsOfTypeRow, EntityTemporalMetadataRow,
@@ -91,6 +96,7 @@ use crate::store::{
 struct GetEntitiesImplParams<'a> {
     filter: Filter<'a, Entity>,
     sorting: EntityQuerySorting<'static>,
+    record_paths: EntityRecordPaths<'static>,
     limit: Option<usize>,
     include_drafts: bool,
     include_count: bool,
@@ -100,6 +106,24 @@ struct GetEntitiesImplParams<'a> {
     include_type_ids: bool,
 }
 
+/// The number of entities for which the permissions are checked at once when streaming entities.
+const ENTITY_STREAM_CHUNK_SIZE: usize = 1_000;
+
+/// Returns the paths to read the root entities of a query with the given projection.
+///
+/// Converting properties requires their metadata, so it's read regardless of the projection if
+/// any conversion is requested.
+fn projected_record_paths(
+    projection: Option<&EntityProjection<'_>>,
+    conversions: &[QueryConversion<'_>],
+) -> EntityRecordPaths<'static> {
+    projection.map_or_else(EntityRecordPaths::default, |projection| {
+        let mut record_paths = EntityRecordPaths::projected(projection);
+        record_paths.property_metadata |= !conversions.is_empty();
+        record_paths
+    })
+}
+
 impl<C, A> PostgresStore<C, A>
 where
     C: AsClient,
@@ -305,6 +329,215 @@ where
         Ok(())
     }
 
+    /// Follows the `traversal_paths` starting at the `root_entities` and adds the traversed link
+    /// entities, their endpoints, and the edges between them to the `subgraph`.
+    ///
+    /// Each hop first follows the edges to the link entities satisfying the link filter and then
+    /// the edges from these links to the endpoints satisfying the endpoint filter.
+    #[tracing::instrument(level = "info", skip_all)]
+    pub(crate) async fn traverse_entity_paths(
+        &self,
+        root_entities: &[(EntityVertexId, RightBoundedTemporalInterval<VariableAxis>)],
+        traversal_paths: &[EntityTraversalPath<'_>],
+        actor_id: AccountId,
+        zookie: &Zookie<'static>,
+        include_drafts: bool,
+        subgraph: &mut Subgraph,
+    ) -> Result<(), QueryError> {
+        let variable_axis = subgraph.temporal_axes.resolved.variable_time_axis();
+
+        for traversal_path in traversal_paths {
+            let mut entities = root_entities.to_vec();
+
+            for hop in &traversal_path.hops {
+                let (link_edge, endpoint_edge) = match hop.direction {
+                    LinkTraversalDirection::Outgoing => (
+                        (
+                            KnowledgeGraphEdgeKind::HasLeftEntity,
+                            ReferenceTable::EntityHasLeftEntity,
+                        ),
+                        (
+                            KnowledgeGraphEdgeKind::HasRightEntity,
+                            ReferenceTable::EntityHasRightEntity,
+                        ),
+                    ),
+                    LinkTraversalDirection::Incoming => (
+                        (
+                            KnowledgeGraphEdgeKind::HasRightEntity,
+                            ReferenceTable::EntityHasRightEntity,
+                        ),
+                        (
+                            KnowledgeGraphEdgeKind::HasLeftEntity,
+                            ReferenceTable::EntityHasLeftEntity,
+                        ),
+                    ),
+                };
+
+                for ((edge_kind, reference_table), edge_direction, filter) in [
+                    (link_edge, EdgeDirection::Incoming, &hop.link_filter),
+                    (endpoint_edge, EdgeDirection::Outgoing, &hop.endpoint_filter),
+                ] {
+                    if entities.is_empty() {
+                        break;
+                    }
+
+                    let mut traversal_data = EntityEdgeTraversalData::new(
+                        subgraph.temporal_axes.resolved.pinned_timestamp(),
+                        variable_axis,
+                    );
+                    #[expect(clippy::iter_with_drain, reason = "false positive, vector is reused")]
+                    for (entity_vertex_id, traversal_interval) in entities.drain(..) {
+                        traversal_data.push(
+                            entity_vertex_id,
+                            traversal_interval,
+                            GraphResolveDepths::default(),
+                        );
+                    }
+
+                    let edges = self
+                        .read_knowledge_edges(&traversal_data, reference_table, edge_direction)
+                        .await?
+                        .map(|(_, edge)| edge)
+                        .collect::<Vec<_>>();
+                    if edges.is_empty() {
+                        break;
+                    }
+
+                    let filter = Filter::All(vec![
+                        filter.clone(),
+                        Filter::In(
+                            FilterExpression::Path {
+                                path: EntityQueryPath::EditionId,
+                            },
+                            ParameterList::EntityEditionIds(Cow::Owned(
+                                edges
+                                    .iter()
+                                    .map(|edge| edge.right_endpoint_edition_id)
+                                    .collect(),
+                            )),
+                        ),
+                    ]);
+                    let matching_entities = Read::<Entity>::read_vec(
+                        self,
+                        &filter,
+                        Some(&subgraph.temporal_axes.resolved),
+                        include_drafts,
+                    )
+                    .await?;
+
+                    let permissions = self
+                        .authorization_api
+                        .check_entities_permission(
+                            actor_id,
+                            EntityPermission::View,
+                            matching_entities
+                                .iter()
+                                .map(|entity| entity.metadata.record_id.entity_id),
+                            Consistency::AtExactSnapshot(zookie),
+                        )
+                        .await
+                        .change_context(QueryError)?
+                        .0;
+
+                    let mut permitted_edition_ids = HashSet::new();
+                    for entity in matching_entities {
+                        if permissions
+                            .get(&entity.metadata.record_id.entity_id.entity_uuid)
+                            .copied()
+                            .unwrap_or(false)
+                        {
+                            permitted_edition_ids.insert(entity.metadata.record_id.edition_id);
+                            subgraph
+                                .vertices
+                                .entities
+                                .entry(entity.vertex_id(variable_axis))
+                                .or_insert(entity);
+                        }
+                    }
+
+                    entities.extend(
+                        edges
+                            .into_iter()
+                            .filter(|edge| {
+                                permitted_edition_ids.contains(&edge.right_endpoint_edition_id)
+                            })
+                            .map(|edge| {
+                                subgraph.insert_edge(
+                                    &edge.left_endpoint,
+                                    edge_kind,
+                                    edge_direction,
+                                    EntityIdWithInterval {
+                                        entity_id: edge.right_endpoint.base_id,
+                                        interval: edge.edge_interval,
+                                    },
+                                );
+                                (edge.right_endpoint, edge.traversal_interval)
+                            }),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the editions out of `edition_ids` which satisfy the `filter` and which the actor is
+    /// permitted to view.
+    #[tracing::instrument(level = "trace", skip_all)]
+    async fn filter_permitted_entity_editions(
+        &self,
+        actor_id: AccountId,
+        edition_ids: Vec<EntityEditionId>,
+        filter: Option<&Filter<'_, Entity>>,
+        temporal_axes: &QueryTemporalAxes,
+        include_drafts: bool,
+    ) -> Result<HashSet<EntityEditionId>, QueryError> {
+        let edition_filter = Filter::In(
+            FilterExpression::Path {
+                path: EntityQueryPath::EditionId,
+            },
+            ParameterList::EntityEditionIds(Cow::Owned(edition_ids)),
+        );
+        let filter = match filter {
+            Some(filter) => Filter::All(vec![filter.clone(), edition_filter]),
+            None => edition_filter,
+        };
+
+        let entities = Read::<Entity>::read(self, &filter, Some(temporal_axes), include_drafts)
+            .await?
+            .map_ok(|entity| {
+                (
+                    entity.metadata.record_id.entity_id,
+                    entity.metadata.record_id.edition_id,
+                )
+            })
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let permissions = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entities.iter().map(|(entity_id, _)| *entity_id),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0;
+
+        Ok(entities
+            .into_iter()
+            .filter(|(entity_id, _)| {
+                permissions
+                    .get(&entity_id.entity_uuid)
+                    .copied()
+                    .unwrap_or(false)
+            })
+            .map(|(_, edition_id)| edition_id)
+            .collect())
+    }
+
     #[tracing::instrument(level = "info", skip(self))]
     pub async fn delete_entities(&mut self) -> Result<(), DeletionError> {
         tracing::debug!("Deleting all entities");
@@ -320,6 +553,7 @@ where
                     DELETE FROM entity_embeddings;
                     DELETE FROM entity_drafts;
                     DELETE FROM entity_ids;
+                    DELETE FROM change_feed WHERE entity_edition_id IS NOT NULL;
                 ",
             )
             .await
@@ -489,14 +723,14 @@ where
 
         let (latest_zookie, last) = loop {
             // We query one more than requested to determine if there are more entities to return.
-            let (rows, artifacts) =
-                ReadPaginated::<Entity, EntityQuerySorting>::read_paginated_vec(
-                    self,
+            let (rows, artifacts) = self
+                .read_paginated_vec_with_parameters::<Entity, EntityQuerySorting>(
                     &params.filter,
                     Some(temporal_axes),
                     &params.sorting,
                     params.limit,
                     params.include_drafts,
+                    &params.record_paths,
                 )
                 .await?;
             let entities = rows
@@ -908,6 +1142,15 @@ where
                 .change_context(InsertionError)?;
         }
 
+        transaction
+            .insert_entity_changes(
+                ChangeKind::Created,
+                entities.iter().map(|entity| entity.metadata.record_id),
+                transaction_time,
+                actor_id,
+            )
+            .await?;
+
         transaction
             .authorization_api
             .modify_entity_relations(relationships.iter().copied().map(
@@ -1052,6 +1295,7 @@ where
             .change_context(QueryError)?;
 
         let temporal_axes = params.temporal_axes.resolve();
+        let record_paths = projected_record_paths(params.projection.as_ref(), &params.conversions);
 
         let mut response = self
             .get_entities_impl(
@@ -1059,6 +1303,7 @@ where
                 GetEntitiesImplParams {
                     filter: params.filter,
                     sorting: params.sorting,
+                    record_paths,
                     limit: params.limit,
                     include_drafts: params.include_drafts,
                     include_count: params.include_count,
@@ -1082,12 +1327,110 @@ where
                 self.convert_entity(&provider, entity, &params.conversions)
                     .await
                     .change_context(QueryError)?;
+                if let Some(projection) = &params.projection {
+                    // The conversion requires the property metadata, which may not be projected.
+                    projection.apply(entity);
+                }
             }
         }
 
         Ok(response)
     }
 
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn stream_entities<'s>(
+        &'s self,
+        actor_id: AccountId,
+        mut params: GetEntitiesParams<'s>,
+    ) -> Result<impl Stream<Item = Result<Entity, QueryError>> + Send + 's, QueryError> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+        let record_paths = projected_record_paths(params.projection.as_ref(), &params.conversions);
+
+        // The limit is applied after the permissions are checked, so it's not passed to the query.
+        let (rows, indices) = self
+            .query_paginated::<Entity, EntityQuerySorting>(
+                &params.filter,
+                Some(&temporal_axes),
+                &params.sorting,
+                None,
+                params.include_drafts,
+                &record_paths,
+            )
+            .await?;
+        let chunks = rows
+            .map(move |row| {
+                row.map(|row| {
+                    QueryResult::<Entity, EntityQuerySorting>::decode_record(&row, &indices)
+                })
+                .change_context(QueryError)
+            })
+            .chunks(ENTITY_STREAM_CHUNK_SIZE);
+
+        let limit = params.limit.unwrap_or(usize::MAX);
+        Ok(stream::try_unfold(
+            (chunks, params.conversions, params.projection),
+            move |(mut chunks, conversions, projection)| async move {
+                let Some(chunk) = chunks.next().await else {
+                    return Ok(None);
+                };
+                let mut entities = chunk.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+                let permissions = self
+                    .authorization_api
+                    .check_entities_permission(
+                        actor_id,
+                        EntityPermission::View,
+                        entities
+                            .iter()
+                            .map(|entity| entity.metadata.record_id.entity_id),
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(QueryError)?
+                    .0;
+                entities.retain(|entity| {
+                    permissions
+                        .get(&entity.metadata.record_id.entity_id.entity_uuid)
+                        .copied()
+                        .unwrap_or(false)
+                });
+
+                if !conversions.is_empty() {
+                    let provider = StoreProvider {
+                        store: self,
+                        cache: StoreCache::default(),
+                        authorization: Some((actor_id, Consistency::FullyConsistent)),
+                    };
+                    for entity in &mut entities {
+                        self.convert_entity(&provider, entity, &conversions)
+                            .await
+                            .change_context(QueryError)?;
+                        if let Some(projection) = &projection {
+                            // The conversion requires the property metadata, which may not be
+                            // projected.
+                            projection.apply(entity);
+                        }
+                    }
+                }
+
+                Ok::<_, Report<QueryError>>(Some((entities, (chunks, conversions, projection))))
+            },
+        )
+        .map_ok(|entities| stream::iter(entities.into_iter().map(Ok)))
+        .try_flatten()
+        .take(limit))
+    }
+
     #[tracing::instrument(level = "info", skip(self, params))]
     async fn get_entity_subgraph(
         &self,
@@ -1104,10 +1447,28 @@ where
             .await
             .change_context(QueryError)?;
 
+        for hop in params
+            .traversal_paths
+            .iter_mut()
+            .flat_map(|traversal_path| &mut traversal_path.hops)
+        {
+            for filter in [&mut hop.link_filter, &mut hop.endpoint_filter] {
+                filter
+                    .convert_parameters(&StoreProvider {
+                        store: self,
+                        cache: StoreCache::default(),
+                        authorization: Some((actor_id, Consistency::FullyConsistent)),
+                    })
+                    .await
+                    .change_context(QueryError)?;
+            }
+        }
+
         let unresolved_temporal_axes = params.temporal_axes;
         let temporal_axes = unresolved_temporal_axes.clone().resolve();
 
         let time_axis = temporal_axes.variable_time_axis();
+        let record_paths = projected_record_paths(params.projection.as_ref(), &params.conversions);
 
         let (
             GetEntitiesResponse {
@@ -1126,6 +1487,7 @@ where
                 GetEntitiesImplParams {
                     filter: params.filter,
                     sorting: params.sorting,
+                    record_paths,
                     limit: params.limit,
                     include_drafts: params.include_drafts,
                     include_count: false,
@@ -1159,20 +1521,19 @@ where
 
         let mut traversal_context = TraversalContext::default();
 
+        let root_entities = subgraph
+            .vertices
+            .entities
+            .keys()
+            .map(|id| (*id, subgraph.temporal_axes.resolved.variable_interval()))
+            .collect::<Vec<_>>();
+
         // TODO: We currently pass in the subgraph as mutable reference, thus we cannot borrow the
         //       vertices and have to `.collect()` the keys.
         self.traverse_entities(
-            subgraph
-                .vertices
-                .entities
-                .keys()
-                .map(|id| {
-                    (
-                        *id,
-                        subgraph.depths,
-                        subgraph.temporal_axes.resolved.variable_interval(),
-                    )
-                })
+            root_entities
+                .iter()
+                .map(|(id, interval)| (*id, subgraph.depths, *interval))
                 .collect(),
             &mut traversal_context,
             actor_id,
@@ -1181,6 +1542,16 @@ where
         )
         .await?;
 
+        self.traverse_entity_paths(
+            &root_entities,
+            &params.traversal_paths,
+            actor_id,
+            &zookie,
+            params.include_drafts,
+            &mut subgraph,
+        )
+        .await?;
+
         traversal_context
             .read_traversed_vertices(self, &mut subgraph, params.include_drafts)
             .await?;
@@ -1198,6 +1569,14 @@ where
             }
         }
 
+        if let Some(projection) = &params.projection {
+            // Only the root entities are projected when reading them, the traversed entities are
+            // read in full.
+            for entity in subgraph.vertices.entities.values_mut() {
+                projection.apply(entity);
+            }
+        }
+
         Ok(GetEntitySubgraphResponse {
             subgraph,
             cursor,
@@ -1261,6 +1640,337 @@ where
             .count())
     }
 
+    async fn aggregate_entities(
+        &self,
+        actor_id: AccountId,
+        mut params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, QueryError> {
+        if let Some(aggregation) = params.aggregations.iter().find(|aggregation| {
+            aggregation.path.is_none() && aggregation.function != AggregationFunction::Count
+        }) {
+            bail!(
+                Report::new(QueryError)
+                    .attach(StatusCode::InvalidArgument)
+                    .attach_printable(format!(
+                        "The aggregation `{:?}` requires a path",
+                        aggregation.function
+                    ))
+            );
+        }
+
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+
+        // Only the identifiers required for the permission check are selected, the entities
+        // themselves are not read.
+        let mut compiler = SelectCompiler::new(Some(&temporal_axes), params.include_drafts);
+        let owned_by_id_index = compiler.add_distinct_selection_with_ordering(
+            &EntityQueryPath::OwnedById,
+            Distinctness::Distinct,
+            None,
+        );
+        let entity_uuid_index = compiler.add_distinct_selection_with_ordering(
+            &EntityQueryPath::Uuid,
+            Distinctness::Distinct,
+            None,
+        );
+        let draft_id_index = compiler.add_distinct_selection_with_ordering(
+            &EntityQueryPath::DraftId,
+            Distinctness::Distinct,
+            None,
+        );
+        let edition_id_index = compiler.add_distinct_selection_with_ordering(
+            &EntityQueryPath::EditionId,
+            Distinctness::Distinct,
+            None,
+        );
+        compiler.add_filter(&params.filter);
+
+        let (statement, parameters) = compiler.compile();
+        let entities = self
+            .as_client()
+            .query(&statement, parameters)
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                (
+                    EntityId {
+                        owned_by_id: row.get(owned_by_id_index),
+                        entity_uuid: row.get(entity_uuid_index),
+                        draft_id: row.get(draft_id_index),
+                    },
+                    row.get::<_, EntityEditionId>(edition_id_index),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let permitted_ids = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entities.iter().map(|(entity_id, _)| *entity_id),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0
+            .into_iter()
+            .filter_map(|(entity_id, has_permission)| has_permission.then_some(entity_id))
+            .collect::<HashSet<_>>();
+
+        let edition_ids = entities
+            .into_iter()
+            .filter(|(entity_id, _)| permitted_ids.contains(&entity_id.entity_uuid))
+            .map(|(_, edition_id)| edition_id)
+            .collect::<Vec<_>>();
+
+        // The permitted editions already satisfy the filter, so only they are aggregated. This
+        // avoids joins from the filter which could otherwise count an entity multiple times.
+        let filter = Filter::In(
+            FilterExpression::Path {
+                path: EntityQueryPath::EditionId,
+            },
+            ParameterList::EntityEditionIds(Cow::Borrowed(&edition_ids)),
+        );
+        let mut compiler = SelectCompiler::new(Some(&temporal_axes), params.include_drafts);
+        compiler.add_filter(&filter);
+
+        let group_indices = params
+            .group_by
+            .iter()
+            .map(|path| compiler.add_grouping_selection(path))
+            .collect::<Vec<_>>();
+        let aggregation_indices = params
+            .aggregations
+            .iter()
+            .map(|aggregation| {
+                compiler.add_aggregation_selection(aggregation.function, aggregation.path.as_ref())
+            })
+            .collect::<Vec<_>>();
+
+        let (statement, parameters) = compiler.compile();
+        let groups = self
+            .as_client()
+            .query(&statement, parameters)
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| EntityAggregationGroup {
+                key: group_indices
+                    .iter()
+                    .map(|index| {
+                        row.get::<_, Option<JsonValue>>(*index)
+                            .unwrap_or(JsonValue::Null)
+                    })
+                    .collect(),
+                values: aggregation_indices
+                    .iter()
+                    .map(|index| row.get(*index))
+                    .collect(),
+            })
+            .collect();
+
+        Ok(AggregateEntitiesResponse { groups })
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn find_entity_paths(
+        &self,
+        actor_id: AccountId,
+        mut params: FindEntityPathsParams<'_>,
+    ) -> Result<FindEntityPathsResponse, QueryError> {
+        if let Some(link_filter) = &mut params.link_filter {
+            link_filter
+                .convert_parameters(&StoreProvider {
+                    store: self,
+                    cache: StoreCache::default(),
+                    authorization: Some((actor_id, Consistency::FullyConsistent)),
+                })
+                .await
+                .change_context(QueryError)?;
+        }
+
+        let temporal_axes = params.temporal_axes.resolve();
+        let variable_axis = temporal_axes.variable_time_axis();
+
+        let source_permitted = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                once(params.source_entity_id),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0
+            .get(&params.source_entity_id.entity_uuid)
+            .copied()
+            .unwrap_or(false);
+        if !source_permitted {
+            return Ok(FindEntityPathsResponse { paths: Vec::new() });
+        }
+
+        let mut entities = Read::<Entity>::read_vec(
+            self,
+            &Filter::for_entity_by_entity_id(params.source_entity_id),
+            Some(&temporal_axes),
+            params.include_drafts,
+        )
+        .await?
+        .into_iter()
+        .map(|entity| {
+            (
+                entity.vertex_id(variable_axis),
+                temporal_axes.variable_interval(),
+            )
+        })
+        .collect::<Vec<_>>();
+        if entities.is_empty() {
+            return Ok(FindEntityPathsResponse { paths: Vec::new() });
+        }
+
+        let mut search = EntityPathSearch::new(params.source_entity_id, params.target_entity_id);
+        let mut depth = 0;
+        while !entities.is_empty() && !search.is_finished() && depth < params.max_length {
+            depth += 1;
+            let mut next_entities = Vec::new();
+
+            for direction in [
+                LinkTraversalDirection::Outgoing,
+                LinkTraversalDirection::Incoming,
+            ] {
+                let (link_table, endpoint_table) = match direction {
+                    LinkTraversalDirection::Outgoing => (
+                        ReferenceTable::EntityHasLeftEntity,
+                        ReferenceTable::EntityHasRightEntity,
+                    ),
+                    LinkTraversalDirection::Incoming => (
+                        ReferenceTable::EntityHasRightEntity,
+                        ReferenceTable::EntityHasLeftEntity,
+                    ),
+                };
+
+                let mut traversal_data =
+                    EntityEdgeTraversalData::new(temporal_axes.pinned_timestamp(), variable_axis);
+                for (entity_vertex_id, traversal_interval) in &entities {
+                    traversal_data.push(
+                        *entity_vertex_id,
+                        *traversal_interval,
+                        GraphResolveDepths::default(),
+                    );
+                }
+
+                let link_edges = self
+                    .read_knowledge_edges(&traversal_data, link_table, EdgeDirection::Incoming)
+                    .await?
+                    .map(|(_, edge)| edge)
+                    .collect::<Vec<_>>();
+                if link_edges.is_empty() {
+                    continue;
+                }
+
+                let permitted_links = self
+                    .filter_permitted_entity_editions(
+                        actor_id,
+                        link_edges
+                            .iter()
+                            .map(|edge| edge.right_endpoint_edition_id)
+                            .collect(),
+                        params.link_filter.as_ref(),
+                        &temporal_axes,
+                        params.include_drafts,
+                    )
+                    .await?;
+
+                // A link may be reached from multiple entities, so the entities it was reached
+                // from are collected before the link is followed to its other end.
+                let mut link_origins = HashMap::<EntityId, Vec<EntityId>>::new();
+                let mut traversal_data =
+                    EntityEdgeTraversalData::new(temporal_axes.pinned_timestamp(), variable_axis);
+                for edge in link_edges {
+                    if !permitted_links.contains(&edge.right_endpoint_edition_id) {
+                        continue;
+                    }
+
+                    let origins = link_origins.entry(edge.right_endpoint.base_id).or_default();
+                    if origins.is_empty() {
+                        traversal_data.push(
+                            edge.right_endpoint,
+                            edge.traversal_interval,
+                            GraphResolveDepths::default(),
+                        );
+                    }
+                    if !origins.contains(&edge.left_endpoint.base_id) {
+                        origins.push(edge.left_endpoint.base_id);
+                    }
+                }
+                if link_origins.is_empty() {
+                    continue;
+                }
+
+                let endpoint_edges = self
+                    .read_knowledge_edges(&traversal_data, endpoint_table, EdgeDirection::Outgoing)
+                    .await?
+                    .map(|(_, edge)| edge)
+                    .collect::<Vec<_>>();
+                if endpoint_edges.is_empty() {
+                    continue;
+                }
+
+                let permitted_endpoints = self
+                    .filter_permitted_entity_editions(
+                        actor_id,
+                        endpoint_edges
+                            .iter()
+                            .map(|edge| edge.right_endpoint_edition_id)
+                            .collect(),
+                        None,
+                        &temporal_axes,
+                        params.include_drafts,
+                    )
+                    .await?;
+
+                for edge in endpoint_edges {
+                    if !permitted_endpoints.contains(&edge.right_endpoint_edition_id) {
+                        continue;
+                    }
+
+                    let link_entity_id = edge.left_endpoint.base_id;
+                    for previous_entity_id in
+                        link_origins.get(&link_entity_id).into_iter().flatten()
+                    {
+                        let hop = EntityPathHop {
+                            link_entity_id,
+                            direction,
+                            entity_id: edge.right_endpoint.base_id,
+                        };
+                        if search.visit(depth, *previous_entity_id, hop) {
+                            next_entities.push((edge.right_endpoint, edge.traversal_interval));
+                        }
+                    }
+                }
+            }
+
+            entities = next_entities;
+        }
+
+        Ok(FindEntityPathsResponse {
+            paths: search.into_paths(params.limit),
+        })
+    }
+
     async fn get_entity_by_id(
         &self,
         actor_id: AccountId,
@@ -1358,6 +2068,16 @@ where
                     .attach_printable(params.entity_id)
                     .change_context(UpdateError)
             })?;
+        if params
+            .expected_edition_id
+            .is_some_and(|edition_id| edition_id != locked_row.entity_edition_id)
+        {
+            bail!(
+                Report::new(EntityEditionConflict)
+                    .attach_printable(params.entity_id)
+                    .change_context(UpdateError)
+            );
+        }
         let ClosedTemporalBound::Inclusive(locked_transaction_time) =
             *locked_row.transaction_time.start();
         let ClosedTemporalBound::Inclusive(locked_decision_time) =
@@ -1634,6 +2354,24 @@ where
             }
         };
 
+        let change_kind = match (previous_entity.metadata.archived, archived) {
+            (false, true) => ChangeKind::Archived,
+            (true, false) => ChangeKind::Unarchived,
+            (false, false) | (true, true) => ChangeKind::Updated,
+        };
+        transaction
+            .insert_entity_changes(
+                change_kind,
+                [EntityRecordId {
+                    entity_id: params.entity_id,
+                    edition_id,
+                }],
+                transaction_time,
+                actor_id,
+            )
+            .await
+            .change_context(UpdateError)?;
+
         let entity_metadata = EntityMetadata {
             record_id: EntityRecordId {
                 entity_id: params.entity_id,
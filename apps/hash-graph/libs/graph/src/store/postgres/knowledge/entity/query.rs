@@ -1,24 +1,37 @@
+use alloc::borrow::Cow;
+
 use graph_types::{
     knowledge::{
         entity::{Entity, EntityId, EntityMetadata, EntityProvenance, EntityRecordId, EntityUuid},
         link::LinkData,
+        property::{PropertyPath, PropertyPathElement},
     },
     owned_by_id::OwnedById,
 };
 use hash_graph_store::{
     entity::EntityQueryPath,
+    filter::{JsonPath, PathToken},
     subgraph::edges::{EdgeDirection, KnowledgeGraphEdgeKind},
 };
+use serde_json::Value as JsonValue;
 use tokio_postgres::Row;
 use tracing::instrument;
 use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
 use uuid::Uuid;
 
-use crate::store::postgres::{
-    crud::QueryRecordDecode,
-    query::{Distinctness, PostgresRecord, SelectCompiler, Table},
+use crate::store::{
+    knowledge::{EntityProjection, project_properties},
+    postgres::{
+        crud::QueryRecordDecode,
+        query::{Distinctness, PostgresRecord, SelectCompiler, Table},
+    },
 };
 
+pub enum EntityPropertiesIndices {
+    All(usize),
+    Projected(Vec<(PropertyPath<'static>, usize)>),
+}
+
 pub struct EntityRecordRowIndices {
     pub owned_by_id: usize,
     pub entity_uuid: usize,
@@ -30,7 +43,7 @@ pub struct EntityRecordRowIndices {
     pub type_base_urls_id: usize,
     pub type_versions_id: usize,
 
-    pub properties: usize,
+    pub properties: EntityPropertiesIndices,
 
     pub left_entity_uuid: usize,
     pub left_entity_owned_by_id: usize,
@@ -39,13 +52,13 @@ pub struct EntityRecordRowIndices {
 
     pub provenance: usize,
     pub edition_provenance: usize,
-    pub property_metadata: usize,
+    pub property_metadata: Option<usize>,
 
     pub entity_confidence: usize,
-    pub left_entity_confidence: usize,
-    pub right_entity_confidence: usize,
-    pub left_entity_provenance: usize,
-    pub right_entity_provenance: usize,
+    pub left_entity_confidence: Option<usize>,
+    pub right_entity_confidence: Option<usize>,
+    pub left_entity_provenance: Option<usize>,
+    pub right_entity_provenance: Option<usize>,
 
     pub archived: usize,
 }
@@ -55,6 +68,46 @@ pub struct EntityRecordPaths<'q> {
     pub left_owned_by_id: EntityQueryPath<'q>,
     pub right_entity_uuid: EntityQueryPath<'q>,
     pub right_owned_by_id: EntityQueryPath<'q>,
+    /// The projected property paths alongside the query paths used to select them.
+    ///
+    /// If `None`, the whole properties object is selected.
+    pub properties: Option<Vec<(PropertyPath<'static>, EntityQueryPath<'q>)>>,
+    pub property_metadata: bool,
+    pub link_metadata: bool,
+}
+
+impl EntityRecordPaths<'_> {
+    /// Creates the paths for reading entities restricted to the given [`EntityProjection`].
+    #[must_use]
+    pub fn projected(projection: &EntityProjection<'_>) -> EntityRecordPaths<'static> {
+        EntityRecordPaths {
+            properties: projection.properties.as_ref().map(|paths| {
+                paths
+                    .iter()
+                    .map(|path| {
+                        let json_path = JsonPath::from_path_tokens(
+                            path.as_ref()
+                                .iter()
+                                .map(|element| match element {
+                                    PropertyPathElement::Property(key) => {
+                                        PathToken::Field(Cow::Owned(key.to_string()))
+                                    }
+                                    PropertyPathElement::Index(index) => PathToken::Index(*index),
+                                })
+                                .collect(),
+                        );
+                        (
+                            path.clone().into_owned(),
+                            EntityQueryPath::Properties(Some(json_path)),
+                        )
+                    })
+                    .collect()
+            }),
+            property_metadata: projection.property_metadata,
+            link_metadata: projection.link_metadata,
+            ..EntityRecordPaths::default()
+        }
+    }
 }
 
 impl Default for EntityRecordPaths<'_> {
@@ -80,6 +133,9 @@ impl Default for EntityRecordPaths<'_> {
                 path: Box::new(EntityQueryPath::OwnedById),
                 direction: EdgeDirection::Outgoing,
             },
+            properties: None,
+            property_metadata: true,
+            link_metadata: true,
         }
     }
 }
@@ -116,10 +172,20 @@ impl QueryRecordDecode for Entity {
                         entity_uuid: EntityUuid::new(right_entity_uuid),
                         draft_id: None,
                     },
-                    left_entity_confidence: row.get(indices.left_entity_confidence),
-                    right_entity_confidence: row.get(indices.right_entity_confidence),
-                    left_entity_provenance: row.get(indices.left_entity_provenance),
-                    right_entity_provenance: row.get(indices.right_entity_provenance),
+                    left_entity_confidence: indices
+                        .left_entity_confidence
+                        .and_then(|index| row.get(index)),
+                    right_entity_confidence: indices
+                        .right_entity_confidence
+                        .and_then(|index| row.get(index)),
+                    left_entity_provenance: indices
+                        .left_entity_provenance
+                        .map(|index| row.get(index))
+                        .unwrap_or_default(),
+                    right_entity_provenance: indices
+                        .right_entity_provenance
+                        .map(|index| row.get(index))
+                        .unwrap_or_default(),
                 }),
                 (None, None, None, None) => None,
                 _ => unreachable!(
@@ -139,12 +205,23 @@ impl QueryRecordDecode for Entity {
             tracing::trace!(%entity_id, %distance, "Entity embedding was calculated");
         }
 
-        let property_metadata = row
-            .get::<_, Option<_>>(indices.property_metadata)
+        let property_metadata = indices
+            .property_metadata
+            .and_then(|index| row.get::<_, Option<_>>(index))
             .unwrap_or_default();
 
+        let properties = match &indices.properties {
+            EntityPropertiesIndices::All(index) => row.get(*index),
+            EntityPropertiesIndices::Projected(paths) => {
+                project_properties(paths.iter().filter_map(|(path, index)| {
+                    row.get::<_, Option<JsonValue>>(*index)
+                        .map(|value| (path, value))
+                }))
+            }
+        };
+
         Self {
-            properties: row.get(indices.properties),
+            properties,
             link_data,
             metadata: EntityMetadata {
                 record_id: EntityRecordId {
@@ -220,7 +297,19 @@ impl PostgresRecord for Entity {
             type_base_urls_id: compiler.add_selection_path(&EntityQueryPath::TypeBaseUrls),
             type_versions_id: compiler.add_selection_path(&EntityQueryPath::TypeVersions),
 
-            properties: compiler.add_selection_path(&EntityQueryPath::Properties(None)),
+            properties: match &paths.properties {
+                Some(properties) => EntityPropertiesIndices::Projected(
+                    properties
+                        .iter()
+                        .map(|(path, query_path)| {
+                            (path.clone(), compiler.add_selection_path(query_path))
+                        })
+                        .collect(),
+                ),
+                None => EntityPropertiesIndices::All(
+                    compiler.add_selection_path(&EntityQueryPath::Properties(None)),
+                ),
+            },
 
             left_entity_uuid: compiler.add_selection_path(&paths.left_entity_uuid),
             left_entity_owned_by_id: compiler.add_selection_path(&paths.left_owned_by_id),
@@ -230,18 +319,23 @@ impl PostgresRecord for Entity {
             provenance: compiler.add_selection_path(&EntityQueryPath::Provenance(None)),
             edition_provenance: compiler
                 .add_selection_path(&EntityQueryPath::EditionProvenance(None)),
-            property_metadata: compiler
-                .add_selection_path(&EntityQueryPath::PropertyMetadata(None)),
+            property_metadata: paths
+                .property_metadata
+                .then(|| compiler.add_selection_path(&EntityQueryPath::PropertyMetadata(None))),
 
             entity_confidence: compiler.add_selection_path(&EntityQueryPath::EntityConfidence),
-            left_entity_confidence: compiler
-                .add_selection_path(&EntityQueryPath::LeftEntityConfidence),
-            left_entity_provenance: compiler
-                .add_selection_path(&EntityQueryPath::LeftEntityProvenance),
-            right_entity_confidence: compiler
-                .add_selection_path(&EntityQueryPath::RightEntityConfidence),
-            right_entity_provenance: compiler
-                .add_selection_path(&EntityQueryPath::RightEntityProvenance),
+            left_entity_confidence: paths
+                .link_metadata
+                .then(|| compiler.add_selection_path(&EntityQueryPath::LeftEntityConfidence)),
+            left_entity_provenance: paths
+                .link_metadata
+                .then(|| compiler.add_selection_path(&EntityQueryPath::LeftEntityProvenance)),
+            right_entity_confidence: paths
+                .link_metadata
+                .then(|| compiler.add_selection_path(&EntityQueryPath::RightEntityConfidence)),
+            right_entity_provenance: paths
+                .link_metadata
+                .then(|| compiler.add_selection_path(&EntityQueryPath::RightEntityProvenance)),
 
             archived: compiler.add_selection_path(&EntityQueryPath::Archived),
         }
@@ -1,3 +1,4 @@
+mod change_feed;
 mod crud;
 mod knowledge;
 mod migration;
@@ -58,6 +59,7 @@ pub use self::{
 };
 use crate::store::{
     BaseUrlAlreadyExists, InsertionError, QueryError, StoreError, UpdateError,
+    change_feed::ChangeKind,
     error::{
         DeletionError, OntologyTypeIsNotOwned, OntologyVersionDoesNotExist,
         VersionedUrlAlreadyExists,
@@ -263,18 +265,36 @@ where
         &self,
         ontology_id: OntologyId,
         provenance: &OntologyEditionProvenance,
+        change_kind: ChangeKind,
     ) -> Result<LeftClosedTemporalInterval<TransactionTime>, InsertionError> {
         let query = "
-              INSERT INTO ontology_temporal_metadata (
-                ontology_id,
-                transaction_time,
-                provenance
-              ) VALUES ($1, tstzrange(now(), NULL, '[)'), $2)
-              RETURNING transaction_time;
+              WITH temporal_metadata AS (
+                INSERT INTO ontology_temporal_metadata (
+                  ontology_id,
+                  transaction_time,
+                  provenance
+                ) VALUES ($1, tstzrange(now(), NULL, '[)'), $2)
+                RETURNING ontology_id, transaction_time
+              ), change AS (
+                INSERT INTO change_feed (
+                  change_kind,
+                  ontology_id,
+                  transaction_time,
+                  actor_id
+                )
+                SELECT $3, ontology_id, lower(transaction_time), $4
+                FROM temporal_metadata
+              )
+              SELECT transaction_time FROM temporal_metadata;
             ";
 
         self.as_client()
-            .query_one(query, &[&ontology_id, &provenance])
+            .query_one(query, &[
+                &ontology_id,
+                &provenance,
+                &change_kind,
+                &provenance.created_by_id,
+            ])
             .await
             .change_context(InsertionError)
             .map(|row| row.get(0))
@@ -286,18 +306,30 @@ where
         archived_by_id: EditionArchivedById,
     ) -> Result<OntologyTemporalMetadata, UpdateError> {
         let query = "
-          UPDATE ontology_temporal_metadata
-          SET
-            transaction_time = tstzrange(lower(transaction_time), now(), '[)'),
-            provenance = provenance || JSONB_BUILD_OBJECT(
-                'archivedBy', $3::UUID
+          WITH temporal_metadata AS (
+            UPDATE ontology_temporal_metadata
+            SET
+              transaction_time = tstzrange(lower(transaction_time), now(), '[)'),
+              provenance = provenance || JSONB_BUILD_OBJECT(
+                  'archivedBy', $3::UUID
+              )
+            WHERE ontology_id = (
+              SELECT ontology_id
+              FROM ontology_ids
+              WHERE base_url = $1 AND version = $2
+            ) AND transaction_time @> now()
+            RETURNING ontology_id, transaction_time
+          ), change AS (
+            INSERT INTO change_feed (
+              change_kind,
+              ontology_id,
+              transaction_time,
+              actor_id
             )
-          WHERE ontology_id = (
-            SELECT ontology_id
-            FROM ontology_ids
-            WHERE base_url = $1 AND version = $2
-          ) AND transaction_time @> now()
-          RETURNING transaction_time;
+            SELECT 'archived', ontology_id, upper(transaction_time), $3::UUID
+            FROM temporal_metadata
+          )
+          SELECT transaction_time FROM temporal_metadata;
         ";
 
         let optional = self
@@ -344,22 +376,39 @@ where
         provenance: &OntologyEditionProvenance,
     ) -> Result<OntologyTemporalMetadata, UpdateError> {
         let query = "
-          INSERT INTO ontology_temporal_metadata (
-            ontology_id,
-            transaction_time,
-            provenance
-          ) VALUES (
-            (SELECT ontology_id FROM ontology_ids WHERE base_url = $1 AND version = $2),
-            tstzrange(now(), NULL, '[)'),
-            $3
+          WITH temporal_metadata AS (
+            INSERT INTO ontology_temporal_metadata (
+              ontology_id,
+              transaction_time,
+              provenance
+            ) VALUES (
+              (SELECT ontology_id FROM ontology_ids WHERE base_url = $1 AND version = $2),
+              tstzrange(now(), NULL, '[)'),
+              $3
+            )
+            RETURNING ontology_id, transaction_time
+          ), change AS (
+            INSERT INTO change_feed (
+              change_kind,
+              ontology_id,
+              transaction_time,
+              actor_id
+            )
+            SELECT 'unarchived', ontology_id, lower(transaction_time), $4
+            FROM temporal_metadata
           )
-          RETURNING transaction_time;
+          SELECT transaction_time FROM temporal_metadata;
         ";
 
         Ok(OntologyTemporalMetadata {
             transaction_time: self
                 .as_client()
-                .query_one(query, &[&id.base_url, &id.version, &provenance])
+                .query_one(query, &[
+                    &id.base_url,
+                    &id.version,
+                    &provenance,
+                    &provenance.created_by_id,
+                ])
                 .await
                 .map_err(Report::new)
                 .map_err(|report| match report.current_context().code() {
@@ -879,7 +928,11 @@ where
                 let ontology_id = self.create_ontology_id(record_id, on_conflict).await?;
                 if let Some(ontology_id) = ontology_id {
                     let transaction_time = self
-                        .create_ontology_temporal_metadata(ontology_id, &provenance.edition)
+                        .create_ontology_temporal_metadata(
+                            ontology_id,
+                            &provenance.edition,
+                            ChangeKind::Created,
+                        )
                         .await?;
                     self.create_ontology_owned_metadata(ontology_id, *owned_by_id)
                         .await?;
@@ -900,7 +953,11 @@ where
                 let ontology_id = self.create_ontology_id(record_id, on_conflict).await?;
                 if let Some(ontology_id) = ontology_id {
                     let transaction_time = self
-                        .create_ontology_temporal_metadata(ontology_id, &provenance.edition)
+                        .create_ontology_temporal_metadata(
+                            ontology_id,
+                            &provenance.edition,
+                            ChangeKind::Created,
+                        )
                         .await?;
                     self.create_ontology_external_metadata(ontology_id, *fetched_at)
                         .await?;
@@ -991,7 +1048,7 @@ where
             .expect("ontology id should have been created");
 
         let transaction_time = self
-            .create_ontology_temporal_metadata(ontology_id, provenance)
+            .create_ontology_temporal_metadata(ontology_id, provenance, ChangeKind::Updated)
             .await
             .change_context(UpdateError)?;
         self.create_ontology_owned_metadata(ontology_id, owned_by_id)
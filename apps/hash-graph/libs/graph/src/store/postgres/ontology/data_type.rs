@@ -51,10 +51,10 @@ use crate::store::{
     crud::{QueryResult, Read, ReadPaginated, VersionedUrlSorting},
     error::DeletionError,
     ontology::{
-        ArchiveDataTypeParams, CountDataTypesParams, CreateDataTypeParams,
+        ArchiveDataTypeParams, BreakingChangeBehavior, CountDataTypesParams, CreateDataTypeParams,
         GetDataTypeSubgraphParams, GetDataTypeSubgraphResponse, GetDataTypesParams,
         GetDataTypesResponse, UnarchiveDataTypeParams, UpdateDataTypeEmbeddingParams,
-        UpdateDataTypesParams,
+        UpdateDataTypesParams, UpdateDataTypesResponse,
     },
     postgres::{
         TraversalContext,
@@ -764,13 +764,13 @@ where
         &mut self,
         actor_id: AccountId,
         params: UpdateDataTypesParams<R>,
-    ) -> Result<DataTypeMetadata, UpdateError>
+    ) -> Result<UpdateDataTypesResponse, UpdateError>
     where
         R: IntoIterator<Item = DataTypeRelationAndSubject> + Send + Sync,
     {
         let data_type_validator = DataTypeValidator;
 
-        let old_ontology_id = DataTypeId::from_url(&VersionedUrl {
+        let previous_url = VersionedUrl {
             base_url: params.schema.id.base_url.clone(),
             version: OntologyTypeVersion::new(
                 params
@@ -784,7 +784,8 @@ where
                         "The version of the data type is already at the lowest possible value",
                     )?,
             ),
-        });
+        };
+        let old_ontology_id = DataTypeId::from_url(&previous_url);
         let new_ontology_id = DataTypeId::from_url(&params.schema.id);
         self.authorization_api
             .check_data_type_permission(
@@ -884,6 +885,29 @@ where
             .update_owned_ontology_id(&schema.id, &provenance.edition)
             .await?;
 
+        let changes = if params.breaking_changes == BreakingChangeBehavior::Allow {
+            Vec::new()
+        } else {
+            let previous = Read::<DataTypeWithMetadata>::read_one(
+                &transaction,
+                &Filter::for_versioned_url(&previous_url),
+                Some(
+                    &QueryTemporalAxesUnresolved::DecisionTime {
+                        pinned: PinnedTemporalAxisUnresolved::new(None),
+                        variable: VariableTemporalAxisUnresolved::new(None, None),
+                    }
+                    .resolve(),
+                ),
+                false,
+            )
+            .await
+            .change_context(UpdateError)?;
+            params
+                .breaking_changes
+                .handle_changes(previous.schema.changes_to(&params.schema))
+                .change_context(UpdateError)?
+        };
+
         let data_type_id = DataTypeId::from(ontology_id);
 
         transaction
@@ -977,7 +1001,7 @@ where
                     .change_context(UpdateError)?;
             }
 
-            Ok(metadata)
+            Ok(UpdateDataTypesResponse { metadata, changes })
         }
     }
 
@@ -49,10 +49,10 @@ use crate::store::{
     crud::{QueryResult, Read, ReadPaginated, VersionedUrlSorting},
     error::DeletionError,
     ontology::{
-        ArchiveEntityTypeParams, CountEntityTypesParams, CreateEntityTypeParams,
-        GetEntityTypeSubgraphParams, GetEntityTypeSubgraphResponse, GetEntityTypesParams,
-        GetEntityTypesResponse, UnarchiveEntityTypeParams, UpdateEntityTypeEmbeddingParams,
-        UpdateEntityTypesParams,
+        ArchiveEntityTypeParams, BreakingChangeBehavior, CountEntityTypesParams,
+        CreateEntityTypeParams, GetEntityTypeSubgraphParams, GetEntityTypeSubgraphResponse,
+        GetEntityTypesParams, GetEntityTypesResponse, UnarchiveEntityTypeParams,
+        UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams, UpdateEntityTypesResponse,
     },
     postgres::{
         ResponseCountMap, TraversalContext,
@@ -907,11 +907,11 @@ where
         &mut self,
         actor_id: AccountId,
         params: UpdateEntityTypesParams<R>,
-    ) -> Result<EntityTypeMetadata, UpdateError>
+    ) -> Result<UpdateEntityTypesResponse, UpdateError>
     where
         R: IntoIterator<Item = EntityTypeRelationAndSubject> + Send + Sync,
     {
-        let old_ontology_id = EntityTypeId::from_url(&VersionedUrl {
+        let previous_url = VersionedUrl {
             base_url: params.schema.id.base_url.clone(),
             version: OntologyTypeVersion::new(
                 params
@@ -925,7 +925,8 @@ where
                         "The version of the data type is already at the lowest possible value",
                     )?,
             ),
-        });
+        };
+        let old_ontology_id = EntityTypeId::from_url(&previous_url);
         self.authorization_api
             .check_entity_type_permission(
                 actor_id,
@@ -955,6 +956,29 @@ where
             .update_owned_ontology_id(url, &provenance.edition)
             .await?;
 
+        let changes = if params.breaking_changes == BreakingChangeBehavior::Allow {
+            Vec::new()
+        } else {
+            let previous = Read::<EntityTypeWithMetadata>::read_one(
+                &transaction,
+                &Filter::for_versioned_url(&previous_url),
+                Some(
+                    &QueryTemporalAxesUnresolved::DecisionTime {
+                        pinned: PinnedTemporalAxisUnresolved::new(None),
+                        variable: VariableTemporalAxisUnresolved::new(None, None),
+                    }
+                    .resolve(),
+                ),
+                false,
+            )
+            .await
+            .change_context(UpdateError)?;
+            params
+                .breaking_changes
+                .handle_changes(previous.schema.changes_to(&params.schema))
+                .change_context(UpdateError)?
+        };
+
         let mut insertions = transaction
             .resolve_entity_types([params.schema])
             .await
@@ -1068,7 +1092,7 @@ where
                     .change_context(UpdateError)?;
             }
 
-            Ok(metadata)
+            Ok(UpdateEntityTypesResponse { metadata, changes })
         }
     }
 
@@ -66,6 +66,17 @@ where
             .await
             .change_context(DeletionError)?;
 
+        self.as_client()
+            .query(
+                "
+                    DELETE FROM change_feed
+                    WHERE ontology_id = ANY($1)
+                ",
+                &[&ontology_ids],
+            )
+            .await
+            .change_context(DeletionError)?;
+
         self.as_client()
             .query(
                 "
@@ -22,12 +22,16 @@ use graph_types::{
     },
 };
 use hash_graph_store::{
+    filter::Filter,
     property_type::PropertyTypeQueryPath,
     subgraph::{
         Subgraph, SubgraphRecord,
         edges::{EdgeDirection, GraphResolveDepths, OntologyEdgeKind},
         identifier::{DataTypeVertexId, GraphElementVertexId, PropertyTypeVertexId},
-        temporal_axes::{QueryTemporalAxes, VariableAxis},
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxes, QueryTemporalAxesUnresolved,
+            VariableAxis, VariableTemporalAxisUnresolved,
+        },
     },
 };
 use postgres_types::{Json, ToSql};
@@ -46,10 +50,10 @@ use crate::store::{
     crud::{QueryResult, Read, ReadPaginated, VersionedUrlSorting},
     error::DeletionError,
     ontology::{
-        ArchivePropertyTypeParams, CountPropertyTypesParams, CreatePropertyTypeParams,
-        GetPropertyTypeSubgraphParams, GetPropertyTypeSubgraphResponse, GetPropertyTypesParams,
-        GetPropertyTypesResponse, UnarchivePropertyTypeParams, UpdatePropertyTypeEmbeddingParams,
-        UpdatePropertyTypesParams,
+        ArchivePropertyTypeParams, BreakingChangeBehavior, CountPropertyTypesParams,
+        CreatePropertyTypeParams, GetPropertyTypeSubgraphParams, GetPropertyTypeSubgraphResponse,
+        GetPropertyTypesParams, GetPropertyTypesResponse, UnarchivePropertyTypeParams,
+        UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams, UpdatePropertyTypesResponse,
     },
     postgres::{
         TraversalContext,
@@ -669,13 +673,13 @@ where
         &mut self,
         actor_id: AccountId,
         params: UpdatePropertyTypesParams<R>,
-    ) -> Result<PropertyTypeMetadata, UpdateError>
+    ) -> Result<UpdatePropertyTypesResponse, UpdateError>
     where
         R: IntoIterator<Item = PropertyTypeRelationAndSubject> + Send + Sync,
     {
         let property_type_validator = PropertyTypeValidator;
 
-        let old_ontology_id = PropertyTypeId::from_url(&VersionedUrl {
+        let previous_url = VersionedUrl {
             base_url: params.schema.id.base_url.clone(),
             version: OntologyTypeVersion::new(
                 params
@@ -689,7 +693,8 @@ where
                         "The version of the data type is already at the lowest possible value",
                     )?,
             ),
-        });
+        };
+        let old_ontology_id = PropertyTypeId::from_url(&previous_url);
         self.authorization_api
             .check_property_type_permission(
                 actor_id,
@@ -720,6 +725,29 @@ where
         let (ontology_id, owned_by_id, temporal_versioning) = transaction
             .update_owned_ontology_id(&schema.id, &provenance.edition)
             .await?;
+
+        let changes = if params.breaking_changes == BreakingChangeBehavior::Allow {
+            Vec::new()
+        } else {
+            let previous = Read::<PropertyTypeWithMetadata>::read_one(
+                &transaction,
+                &Filter::for_versioned_url(&previous_url),
+                Some(
+                    &QueryTemporalAxesUnresolved::DecisionTime {
+                        pinned: PinnedTemporalAxisUnresolved::new(None),
+                        variable: VariableTemporalAxisUnresolved::new(None, None),
+                    }
+                    .resolve(),
+                ),
+                false,
+            )
+            .await
+            .change_context(UpdateError)?;
+            params
+                .breaking_changes
+                .handle_changes(previous.schema.changes_to(&params.schema))
+                .change_context(UpdateError)?
+        };
         transaction
             .insert_property_type_with_id(ontology_id, schema)
             .await
@@ -802,7 +830,7 @@ where
                     .change_context(UpdateError)?;
             }
 
-            Ok(metadata)
+            Ok(UpdatePropertyTypesResponse { metadata, changes })
         }
     }
 
@@ -13,10 +13,10 @@ use temporal_versioning::TimeAxis;
 use tracing::instrument;
 
 use crate::store::{
-    NullOrdering, Ordering,
+    AggregationFunction, NullOrdering, Ordering,
     postgres::query::{
-        Alias, AliasedTable, Column, Condition, Distinctness, EqualityOperator, Expression,
-        Function, JoinExpression, OrderByExpression, PostgresQueryPath, PostgresRecord,
+        Alias, AliasedTable, Column, Condition, Constant, Distinctness, EqualityOperator,
+        Expression, Function, JoinExpression, OrderByExpression, PostgresQueryPath, PostgresRecord,
         SelectExpression, SelectStatement, Table, Transpile, WhereExpression, WindowStatement,
         WithExpression,
         expression::{GroupByExpression, PostgresType},
@@ -362,6 +362,144 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         )
     }
 
+    /// Adds the full-text search relevance of `path` for `query` to the selection.
+    ///
+    /// The results are ordered by descending relevance. If a `cursor` is provided, only results
+    /// which are ordered after the cursor are returned.
+    pub fn add_relevance_selection(
+        &mut self,
+        path: &'p R::QueryPath<'q>,
+        query: &'p (dyn ToSql + Sync),
+        cursor: Option<&'p (dyn ToSql + Sync)>,
+    ) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let (column, json_field) = path.terminating_column();
+        let document = self.compile_path_column(path);
+        let is_json = !matches!(json_field, Some(JsonField::StaticText(_)))
+            && column.parameter_type() == ParameterType::Any;
+        let vector = if is_json {
+            Function::JsonToTsVector(Box::new(document))
+        } else {
+            Function::ToTsVector(Box::new(document))
+        };
+        let query = self.add_parameter(query);
+
+        let relevance = Expression::Cast(
+            Box::new(Expression::Function(Function::TsRank(
+                Box::new(Expression::Function(vector)),
+                Box::new(Expression::Function(Function::WebSearchToTsQuery(
+                    Box::new(query),
+                ))),
+            ))),
+            PostgresType::Float8,
+        );
+
+        if let Some(cursor) = cursor {
+            let cursor = self.add_parameter(cursor);
+            self.statement.where_expression.add_cursor(
+                relevance.clone(),
+                Some(cursor),
+                Ordering::Descending,
+                None,
+            );
+            self.artifacts.uses_cursor = true;
+        }
+
+        self.statement
+            .selects
+            .push(SelectExpression::new(relevance.clone(), None));
+        self.statement.distinct.push(relevance.clone());
+        self.statement
+            .order_by_expression
+            .push(relevance, Ordering::Descending, None);
+
+        self.statement.selects.len() - 1
+    }
+
+    /// Groups the selection by `path` and adds its value as `jsonb` to the selection.
+    ///
+    /// The groups are ordered by the selected value.
+    pub fn add_grouping_selection(&mut self, path: &'p R::QueryPath<'q>) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let column = self.compile_path_column(path);
+        let value = Expression::Function(Function::ToJson(Box::new(column.clone())));
+
+        self.statement.group_by_expression.expressions.push(column);
+        self.statement
+            .selects
+            .push(SelectExpression::new(value.clone(), None));
+        self.statement
+            .order_by_expression
+            .push(value, Ordering::Ascending, None);
+
+        self.statement.selects.len() - 1
+    }
+
+    /// Adds the aggregate `function` over `path` as `float8` to the selection.
+    ///
+    /// JSON values are counted by their textual representation, so a JSON `null` is treated like
+    /// a missing value. The other aggregations only take JSON numbers into account, any other JSON
+    /// value is ignored. If no `path` is specified, the rows of each group are counted.
+    pub fn add_aggregation_selection(
+        &mut self,
+        function: AggregationFunction,
+        path: Option<&'p R::QueryPath<'q>>,
+    ) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let (value, numeric) = path.map_or((Expression::Asterisk, Expression::Asterisk), |path| {
+            let (column, json_field) = path.terminating_column();
+            let value = self.compile_path_column(path);
+            if !matches!(json_field, Some(JsonField::StaticText(_)))
+                && column.parameter_type() == ParameterType::Any
+            {
+                let numeric = Expression::Function(Function::JsonExtractText(Box::new(
+                    Expression::Function(Function::JsonPathQueryFirst(
+                        Box::new(value.clone()),
+                        Box::new(Expression::Constant(Constant::String(
+                            r#"$ ? (@.type() == "number")"#,
+                        ))),
+                    )),
+                )));
+                (
+                    Expression::Function(Function::JsonExtractText(Box::new(value))),
+                    numeric,
+                )
+            } else {
+                (value.clone(), value)
+            }
+        });
+        let number = || {
+            Box::new(Expression::Cast(
+                Box::new(numeric.clone()),
+                PostgresType::Float8,
+            ))
+        };
+
+        let aggregation = match function {
+            AggregationFunction::Count => Function::Count(Box::new(value)),
+            AggregationFunction::Sum => Function::Sum(number()),
+            AggregationFunction::Min => Function::Min(number()),
+            AggregationFunction::Max => Function::Max(number()),
+            AggregationFunction::Avg => Function::Avg(number()),
+        };
+
+        self.statement.selects.push(SelectExpression::new(
+            Expression::Cast(
+                Box::new(Expression::Function(aggregation)),
+                PostgresType::Float8,
+            ),
+            None,
+        ));
+
+        self.statement.selects.len() - 1
+    }
+
     /// Adds a new filter to the selection.
     pub fn add_filter(&mut self, filter: &'p Filter<'q, R>)
     where
@@ -582,61 +720,104 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
                      `parameter` expression."
                 ),
             },
+            Filter::In(lhs, ParameterList::Parameters(parameters)) => {
+                let lhs = self.compile_filter_expression(lhs).0;
+                Condition::Any(
+                    parameters
+                        .iter()
+                        .map(|parameter| {
+                            Condition::Equal(
+                                Some(lhs.clone()),
+                                Some(self.compile_parameter(parameter).0),
+                            )
+                        })
+                        .collect(),
+                )
+            }
             Filter::In(lhs, rhs) => Condition::In(
                 self.compile_filter_expression(lhs).0,
                 self.compile_parameter_list(rhs).0,
             ),
-            Filter::StartsWith(lhs, rhs) => {
+            Filter::StartsWith(lhs, rhs) => Condition::StartsWith(
+                self.compile_text_expression(lhs, false),
+                self.compile_text_expression(rhs, false),
+            ),
+            Filter::EndsWith(lhs, rhs) => Condition::EndsWith(
+                self.compile_text_expression(lhs, false),
+                self.compile_text_expression(rhs, false),
+            ),
+            Filter::ContainsSegment(lhs, rhs) => Condition::ContainsSegment(
+                self.compile_text_expression(lhs, false),
+                self.compile_text_expression(rhs, false),
+            ),
+            Filter::FullTextSearch(lhs, rhs) => {
                 let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
                 let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
+                    Function::JsonToTsVector(Box::new(left_filter))
                 } else {
-                    left_filter
+                    Function::ToTsVector(Box::new(left_filter))
                 };
 
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
+                let right_filter = self.compile_text_expression(rhs, false);
 
-                Condition::StartsWith(left_filter, right_filter)
+                Condition::FullTextSearch(
+                    Expression::Function(left_filter),
+                    Expression::Function(Function::WebSearchToTsQuery(Box::new(right_filter))),
+                )
             }
-            Filter::EndsWith(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
-
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
-
-                Condition::EndsWith(left_filter, right_filter)
+            Filter::Matches(lhs, rhs) => Condition::Matches(
+                self.compile_text_expression(lhs, false),
+                self.compile_text_expression(rhs, false),
+            ),
+            Filter::MatchesIgnoreCase(lhs, rhs) => Condition::MatchesIgnoreCase(
+                self.compile_text_expression(lhs, false),
+                self.compile_text_expression(rhs, false),
+            ),
+            Filter::EqualIgnoreCase(lhs, rhs) => Condition::Equal(
+                Some(self.compile_text_expression(lhs, true)),
+                Some(self.compile_text_expression(rhs, true)),
+            ),
+            Filter::StartsWithIgnoreCase(lhs, rhs) => Condition::StartsWith(
+                self.compile_text_expression(lhs, true),
+                self.compile_text_expression(rhs, true),
+            ),
+            Filter::EndsWithIgnoreCase(lhs, rhs) => Condition::EndsWith(
+                self.compile_text_expression(lhs, true),
+                self.compile_text_expression(rhs, true),
+            ),
+            Filter::ContainsSegmentIgnoreCase(lhs, rhs) => Condition::ContainsSegment(
+                self.compile_text_expression(lhs, true),
+                self.compile_text_expression(rhs, true),
+            ),
+            Filter::Exists(expression) => {
+                Condition::NotEqual(Some(self.compile_filter_expression(expression).0), None)
             }
-            Filter::ContainsSegment(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
-
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
+            Filter::IsNull(expression) => {
+                let (expression, parameter_type) = self.compile_filter_expression(expression);
+                if parameter_type == ParameterType::Any {
+                    // A JSON path may either be absent or an explicit `null`
+                    Condition::Any(vec![
+                        Condition::Equal(Some(expression.clone()), None),
+                        Condition::Equal(
+                            Some(expression),
+                            Some(Expression::Cast(
+                                Box::new(Expression::Constant(Constant::String("null"))),
+                                PostgresType::JsonB,
+                            )),
+                        ),
+                    ])
                 } else {
-                    right_filter
-                };
-
-                Condition::ContainsSegment(left_filter, right_filter)
+                    Condition::Equal(Some(expression), None)
+                }
             }
+            Filter::ArrayContains(lhs, rhs) => Condition::JsonArrayContains(
+                self.compile_json_expression(lhs),
+                self.compile_json_expression(rhs),
+            ),
+            Filter::ArrayOverlaps(lhs, rhs) => Condition::JsonArrayOverlaps(
+                self.compile_json_expression(lhs),
+                self.compile_json_expression(rhs),
+            ),
         }
     }
 
@@ -918,6 +1099,48 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         }
     }
 
+    /// Compiles the `expression` to a text expression as used for string matching.
+    ///
+    /// JSON values are extracted as text, if `lowercase` is set, the text is converted to
+    /// lowercase.
+    fn compile_text_expression(
+        &mut self,
+        expression: &'p FilterExpression<'q, R>,
+        lowercase: bool,
+    ) -> Expression
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let (filter, parameter_type) = self.compile_filter_expression(expression);
+        let filter = if parameter_type == ParameterType::Any {
+            Expression::Function(Function::JsonExtractText(Box::new(filter)))
+        } else {
+            filter
+        };
+
+        if lowercase {
+            Expression::Function(Function::Lower(Box::new(filter)))
+        } else {
+            filter
+        }
+    }
+
+    /// Compiles the `expression` to a `jsonb` expression.
+    ///
+    /// Parameters are explicitly cast as their type cannot be inferred by Postgres in all
+    /// contexts, e.g. when passed to `jsonb_build_array`.
+    fn compile_json_expression(&mut self, expression: &'p FilterExpression<'q, R>) -> Expression
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let (compiled, _) = self.compile_filter_expression(expression);
+        if matches!(expression, FilterExpression::Parameter { .. }) {
+            Expression::Cast(Box::new(compiled), PostgresType::JsonB)
+        } else {
+            compiled
+        }
+    }
+
     pub fn compile_parameter_list<'f: 'p>(
         &mut self,
         parameters: &'p ParameterList<'f>,
@@ -935,10 +1158,17 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
                 self.artifacts.parameters.push(uuids);
                 ParameterType::Uuid
             }
-            ParameterList::EntityEditionIds(uuids) => {
+            ParameterList::EntityEditionIds(Cow::Borrowed(uuids)) => {
+                self.artifacts.parameters.push(uuids);
+                ParameterType::Uuid
+            }
+            ParameterList::EntityEditionIds(Cow::Owned(uuids)) => {
                 self.artifacts.parameters.push(uuids);
                 ParameterType::Uuid
             }
+            ParameterList::Parameters(_) => {
+                unreachable!("A list of parameters is compiled to a disjunction of equalities")
+            }
         };
         (
             Expression::Parameter(self.artifacts.parameters.len()),
@@ -22,6 +22,11 @@ pub enum Condition {
     StartsWith(Expression, Expression),
     EndsWith(Expression, Expression),
     ContainsSegment(Expression, Expression),
+    FullTextSearch(Expression, Expression),
+    Matches(Expression, Expression),
+    MatchesIgnoreCase(Expression, Expression),
+    JsonArrayContains(Expression, Expression),
+    JsonArrayOverlaps(Expression, Expression),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -141,6 +146,37 @@ impl Transpile for Condition {
                 rhs.transpile(fmt)?;
                 fmt.write_str(" || '%'")
             }
+            Self::FullTextSearch(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" @@ ")?;
+                rhs.transpile(fmt)
+            }
+            Self::Matches(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" ~ ")?;
+                rhs.transpile(fmt)
+            }
+            Self::MatchesIgnoreCase(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" ~* ")?;
+                rhs.transpile(fmt)
+            }
+            Self::JsonArrayContains(lhs, rhs) => {
+                // Unlike `@>`, this compares the elements for equality instead of containment, so
+                // `[{"a": 1, "b": 2}]` does not contain `{"a": 1}`.
+                fmt.write_str("jsonb_path_exists(")?;
+                lhs.transpile(fmt)?;
+                fmt.write_str(", '$[*] ? (@ == $value)', jsonb_build_object('value', ")?;
+                rhs.transpile(fmt)?;
+                fmt.write_str("))")
+            }
+            Self::JsonArrayOverlaps(lhs, rhs) => {
+                fmt.write_str("jsonb_path_exists(")?;
+                lhs.transpile(fmt)?;
+                fmt.write_str(", '$[*] ? (@ == $values[*])', jsonb_build_object('values', ")?;
+                rhs.transpile(fmt)?;
+                fmt.write_str("))")
+            }
         }
     }
 }
@@ -152,9 +188,10 @@ mod tests {
     use graph_types::ontology::DataTypeWithMetadata;
     use hash_graph_store::{
         data_type::DataTypeQueryPath,
-        filter::{Filter, FilterExpression, Parameter},
+        filter::{Filter, FilterExpression, Parameter, ParameterList},
     };
     use postgres_types::ToSql;
+    use serde_json::json;
 
     use crate::store::postgres::query::{SelectCompiler, Transpile};
 
@@ -356,6 +393,166 @@ mod tests {
         );
     }
 
+    #[test]
+    fn transpile_full_text_search_condition() {
+        test_condition(
+            &Filter::FullTextSearch(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Description,
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Text(Cow::Borrowed("plain text")),
+                    convert: None,
+                },
+            ),
+            r#"to_tsvector('english', "data_types_0_1_0"."schema"->>'description') @@ websearch_to_tsquery('english', $1)"#,
+            &[&"plain text"],
+        );
+    }
+
+    #[test]
+    fn transpile_matches_condition() {
+        test_condition(
+            &Filter::Matches(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Title,
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Text(Cow::Borrowed("^te.t$")),
+                    convert: None,
+                },
+            ),
+            r#""data_types_0_1_0"."schema"->>'title' ~ $1"#,
+            &[&"^te.t$"],
+        );
+
+        test_condition(
+            &Filter::MatchesIgnoreCase(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Title,
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Text(Cow::Borrowed("^te.t$")),
+                    convert: None,
+                },
+            ),
+            r#""data_types_0_1_0"."schema"->>'title' ~* $1"#,
+            &[&"^te.t$"],
+        );
+    }
+
+    #[test]
+    fn transpile_ignore_case_condition() {
+        test_condition(
+            &Filter::EqualIgnoreCase(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Title,
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Text(Cow::Borrowed("Text")),
+                    convert: None,
+                },
+            ),
+            r#"lower("data_types_0_1_0"."schema"->>'title') = lower($1)"#,
+            &[&"Text"],
+        );
+
+        test_condition(
+            &Filter::StartsWithIgnoreCase(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Title,
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Text(Cow::Borrowed("Te")),
+                    convert: None,
+                },
+            ),
+            r#"lower("data_types_0_1_0"."schema"->>'title') LIKE lower($1) || '%'"#,
+            &[&"Te"],
+        );
+    }
+
+    #[test]
+    fn transpile_null_check_condition() {
+        test_condition(
+            &Filter::Exists(FilterExpression::Path {
+                path: DataTypeQueryPath::Description,
+            }),
+            r#""data_types_0_1_0"."schema"->>'description' IS NOT NULL"#,
+            &[],
+        );
+
+        test_condition(
+            &Filter::IsNull(FilterExpression::Path {
+                path: DataTypeQueryPath::Schema(None),
+            }),
+            r#"(("data_types_0_1_0"."schema" IS NULL) OR ("data_types_0_1_0"."schema" = ('null'::jsonb)))"#,
+            &[],
+        );
+    }
+
+    #[test]
+    fn transpile_in_list_condition() {
+        test_condition(
+            &Filter::In(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Title,
+                },
+                ParameterList::Parameters(vec![
+                    Parameter::Text(Cow::Borrowed("Text")),
+                    Parameter::Text(Cow::Borrowed("Number")),
+                ]),
+            ),
+            r#"(("data_types_0_1_0"."schema"->>'title' = $1) OR ("data_types_0_1_0"."schema"->>'title' = $2))"#,
+            &[&"Text", &"Number"],
+        );
+    }
+
+    #[test]
+    fn transpile_array_condition() {
+        test_condition(
+            &Filter::ArrayContains(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Schema(None),
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Any(json!("value")),
+                    convert: None,
+                },
+            ),
+            r#"jsonb_path_exists("data_types_0_1_0"."schema", '$[*] ? (@ == $value)', jsonb_build_object('value', ($1::jsonb)))"#,
+            &[&json!("value")],
+        );
+
+        test_condition(
+            &Filter::ArrayContains(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Schema(None),
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Any(json!({ "a": 1 })),
+                    convert: None,
+                },
+            ),
+            r#"jsonb_path_exists("data_types_0_1_0"."schema", '$[*] ? (@ == $value)', jsonb_build_object('value', ($1::jsonb)))"#,
+            &[&json!({ "a": 1 })],
+        );
+
+        test_condition(
+            &Filter::ArrayOverlaps(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Schema(None),
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Any(json!(["a", "b"])),
+                    convert: None,
+                },
+            ),
+            r#"jsonb_path_exists("data_types_0_1_0"."schema", '$[*] ? (@ == $values[*])', jsonb_build_object('values', ($1::jsonb)))"#,
+            &[&json!(["a", "b"])],
+        );
+    }
+
     #[test]
     fn render_without_parameters() {
         test_condition(
@@ -13,6 +13,10 @@ use crate::store::postgres::query::{
 pub enum Function {
     Min(Box<Expression>),
     Max(Box<Expression>),
+    Count(Box<Expression>),
+    Sum(Box<Expression>),
+    Avg(Box<Expression>),
+    ToJson(Box<Expression>),
     JsonExtractText(Box<Expression>),
     JsonExtractAsText(Box<Expression>, PathToken<'static>),
     JsonExtractPath(Vec<Expression>),
@@ -24,6 +28,10 @@ pub enum Function {
     Upper(Box<Expression>),
     Unnest(Box<Expression>),
     Now,
+    JsonToTsVector(Box<Expression>),
+    ToTsVector(Box<Expression>),
+    WebSearchToTsQuery(Box<Expression>),
+    TsRank(Box<Expression>, Box<Expression>),
 }
 
 impl Transpile for Function {
@@ -39,6 +47,26 @@ impl Transpile for Function {
                 expression.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::Count(expression) => {
+                fmt.write_str("COUNT(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::Sum(expression) => {
+                fmt.write_str("SUM(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::Avg(expression) => {
+                fmt.write_str("AVG(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::ToJson(expression) => {
+                fmt.write_str("to_jsonb(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
             Self::JsonExtractPath(paths) => {
                 fmt.write_str("jsonb_extract_path(")?;
                 for (i, expression) in paths.iter().enumerate() {
@@ -113,6 +141,28 @@ impl Transpile for Function {
                 path.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::JsonToTsVector(expression) => {
+                fmt.write_str("jsonb_to_tsvector('english', ")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(", '[\"string\"]')")
+            }
+            Self::ToTsVector(expression) => {
+                fmt.write_str("to_tsvector('english', ")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::WebSearchToTsQuery(expression) => {
+                fmt.write_str("websearch_to_tsquery('english', ")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::TsRank(vector, query) => {
+                fmt.write_str("ts_rank(")?;
+                vector.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                query.transpile(fmt)?;
+                fmt.write_char(')')
+            }
         }
     }
 }
@@ -140,6 +190,8 @@ pub enum PostgresType {
     Row(Table),
     Text,
     JsonPath,
+    JsonB,
+    Float8,
 }
 
 impl Transpile for PostgresType {
@@ -152,6 +204,8 @@ impl Transpile for PostgresType {
             Self::Row(table) => table.transpile(fmt),
             Self::Text => fmt.write_str("text"),
             Self::JsonPath => fmt.write_str("jsonpath"),
+            Self::JsonB => fmt.write_str("jsonb"),
+            Self::Float8 => fmt.write_str("float8"),
         }
     }
 }
@@ -24,6 +24,7 @@ use bytes::BytesMut;
 use error_stack::Context;
 use graph_types::knowledge::entity::Entity;
 use hash_graph_store::{
+    entity::EntityQueryPath,
     filter::{ParameterConversionError, QueryRecord},
     subgraph::temporal_axes::QueryTemporalAxes,
 };
@@ -245,10 +246,22 @@ where
     where
         's: 'q,
     {
-        if let Some(cursor) = self.cursor() {
+        let mut cursor_values = self.cursor().map(|cursor| cursor.values.as_slice());
+
+        // The relevance is always the first value of the cursor.
+        let relevance = self.relevance.as_ref().map(|query| {
+            let cursor = cursor_values.as_mut().and_then(|values| {
+                let (first, rest) = values.split_first()?;
+                *values = rest;
+                Some(first as &(dyn ToSql + Sync))
+            });
+            compiler.add_relevance_selection(&EntityQueryPath::Properties(None), query, cursor)
+        });
+
+        let indices: Self::Indices = if let Some(cursor_values) = cursor_values {
             self.paths
                 .iter()
-                .zip(&cursor.values)
+                .zip(cursor_values)
                 .map(|(sorting_record, parameter)| {
                     let expression = (*parameter != CursorField::Json(Value::Null))
                         .then(|| compiler.add_parameter(parameter));
@@ -272,7 +285,9 @@ where
                     )
                 })
                 .collect()
-        }
+        };
+
+        relevance.into_iter().chain(indices).collect()
     }
 }
 
@@ -92,14 +92,14 @@ impl Transpile for SelectStatement {
             self.where_expression.transpile(fmt)?;
         }
 
-        if !self.order_by_expression.is_empty() {
+        if !self.group_by_expression.expressions.is_empty() {
             fmt.write_char('\n')?;
-            self.order_by_expression.transpile(fmt)?;
+            self.group_by_expression.transpile(fmt)?;
         }
 
-        if !self.group_by_expression.expressions.is_empty() {
+        if !self.order_by_expression.is_empty() {
             fmt.write_char('\n')?;
-            self.group_by_expression.transpile(fmt)?;
+            self.order_by_expression.transpile(fmt)?;
         }
 
         if let Some(limit) = self.limit {
@@ -135,7 +135,7 @@ mod tests {
     use uuid::Uuid;
 
     use crate::store::{
-        NullOrdering, Ordering,
+        AggregationFunction, NullOrdering, Ordering,
         postgres::query::{
             Distinctness, PostgresRecord, SelectCompiler, test_helper::trim_whitespace,
         },
@@ -1177,6 +1177,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn aggregate_properties() {
+        let mut compiler = SelectCompiler::<Entity>::new(None, false);
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            r#"$."https://blockprotocol.org/@alice/types/property-type/age/""#,
+        ))]);
+        let age_path = EntityQueryPath::Properties(Some(json_path.clone()));
+
+        compiler.add_grouping_selection(&EntityQueryPath::OwnedById);
+        compiler.add_aggregation_selection(AggregationFunction::Count, None);
+        compiler.add_aggregation_selection(AggregationFunction::Avg, Some(&age_path));
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT
+                to_jsonb("entity_temporal_metadata_0_0_0"."web_id"),
+                (COUNT(*)::float8),
+                (AVG((((jsonb_path_query_first(jsonb_path_query_first("entity_editions_0_1_0"."properties", (($1::text)::jsonpath)), '$ ? (@.type() == "number")')) #>> '{}'::text[])::float8))::float8)
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+            GROUP BY "entity_temporal_metadata_0_0_0"."web_id"
+            ORDER BY to_jsonb("entity_temporal_metadata_0_0_0"."web_id") ASC
+            "#,
+            &[&json_path],
+        );
+    }
+
     mod predefined {
         use graph_types::{
             knowledge::entity::{EntityId, EntityUuid},
@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use core::hash::Hash;
 use std::collections::HashMap;
 
@@ -20,129 +21,135 @@ use hash_graph_store::{
 use temporal_versioning::RightBoundedTemporalInterval;
 use type_system::schema::DataTypeId;
 
-use crate::store::{AsClient, PostgresStore, QueryError, crud::Read};
+use crate::store::{QueryError, crud::Read};
 
-impl<C, A> PostgresStore<C, A>
+#[tracing::instrument(level = "info", skip(store, data_type_ids, subgraph))]
+async fn read_data_types_by_ids<S>(
+    store: &S,
+    data_type_ids: &[DataTypeId],
+    subgraph: &mut Subgraph,
+) -> Result<(), QueryError>
 where
-    C: AsClient,
-    A: Send + Sync,
+    S: Read<DataTypeWithMetadata>,
 {
-    #[tracing::instrument(level = "info", skip(self, data_type_ids, subgraph))]
-    async fn read_data_types_by_ids(
-        &self,
-        data_type_ids: &[DataTypeId],
-        subgraph: &mut Subgraph,
-    ) -> Result<(), QueryError> {
-        for data_type in <Self as Read<DataTypeWithMetadata>>::read_vec(
-            self,
-            &Filter::<DataTypeWithMetadata>::In(
-                FilterExpression::Path {
-                    path: DataTypeQueryPath::OntologyId,
-                },
-                ParameterList::DataTypeIds(data_type_ids),
-            ),
-            Some(&subgraph.temporal_axes.resolved),
-            false,
-        )
-        .await?
-        {
-            subgraph.insert_vertex(
-                data_type.vertex_id(subgraph.temporal_axes.resolved.variable_time_axis()),
-                data_type,
-            );
-        }
-
-        Ok(())
+    for data_type in <S as Read<DataTypeWithMetadata>>::read_vec(
+        store,
+        &Filter::<DataTypeWithMetadata>::In(
+            FilterExpression::Path {
+                path: DataTypeQueryPath::OntologyId,
+            },
+            ParameterList::DataTypeIds(data_type_ids),
+        ),
+        Some(&subgraph.temporal_axes.resolved),
+        false,
+    )
+    .await?
+    {
+        subgraph.insert_vertex(
+            data_type.vertex_id(subgraph.temporal_axes.resolved.variable_time_axis()),
+            data_type,
+        );
     }
 
-    #[tracing::instrument(level = "info", skip(self, property_type_ids, subgraph))]
-    async fn read_property_types_by_ids(
-        &self,
-        property_type_ids: &[PropertyTypeId],
-        subgraph: &mut Subgraph,
-    ) -> Result<(), QueryError> {
-        for property_type in <Self as Read<PropertyTypeWithMetadata>>::read_vec(
-            self,
-            &Filter::<PropertyTypeWithMetadata>::In(
-                FilterExpression::Path {
-                    path: PropertyTypeQueryPath::OntologyId,
-                },
-                ParameterList::PropertyTypeIds(property_type_ids),
-            ),
-            Some(&subgraph.temporal_axes.resolved),
-            false,
-        )
-        .await?
-        {
-            subgraph.insert_vertex(
-                property_type.vertex_id(subgraph.temporal_axes.resolved.variable_time_axis()),
-                property_type,
-            );
-        }
+    Ok(())
+}
 
-        Ok(())
+#[tracing::instrument(level = "info", skip(store, property_type_ids, subgraph))]
+async fn read_property_types_by_ids<S>(
+    store: &S,
+    property_type_ids: &[PropertyTypeId],
+    subgraph: &mut Subgraph,
+) -> Result<(), QueryError>
+where
+    S: Read<PropertyTypeWithMetadata>,
+{
+    for property_type in <S as Read<PropertyTypeWithMetadata>>::read_vec(
+        store,
+        &Filter::<PropertyTypeWithMetadata>::In(
+            FilterExpression::Path {
+                path: PropertyTypeQueryPath::OntologyId,
+            },
+            ParameterList::PropertyTypeIds(property_type_ids),
+        ),
+        Some(&subgraph.temporal_axes.resolved),
+        false,
+    )
+    .await?
+    {
+        subgraph.insert_vertex(
+            property_type.vertex_id(subgraph.temporal_axes.resolved.variable_time_axis()),
+            property_type,
+        );
     }
 
-    #[tracing::instrument(level = "info", skip(self, entity_type_ids, subgraph))]
-    async fn read_entity_types_by_ids(
-        &self,
-        entity_type_ids: &[EntityTypeId],
-        subgraph: &mut Subgraph,
-    ) -> Result<(), QueryError> {
-        for entity_type in <Self as Read<EntityTypeWithMetadata>>::read_vec(
-            self,
-            &Filter::<EntityTypeWithMetadata>::In(
-                FilterExpression::Path {
-                    path: EntityTypeQueryPath::OntologyId,
-                },
-                ParameterList::EntityTypeIds(entity_type_ids),
-            ),
-            Some(&subgraph.temporal_axes.resolved),
-            false,
-        )
-        .await?
-        {
-            subgraph.insert_vertex(
-                entity_type.vertex_id(subgraph.temporal_axes.resolved.variable_time_axis()),
-                entity_type,
-            );
-        }
+    Ok(())
+}
 
-        Ok(())
+#[tracing::instrument(level = "info", skip(store, entity_type_ids, subgraph))]
+async fn read_entity_types_by_ids<S>(
+    store: &S,
+    entity_type_ids: &[EntityTypeId],
+    subgraph: &mut Subgraph,
+) -> Result<(), QueryError>
+where
+    S: Read<EntityTypeWithMetadata>,
+{
+    for entity_type in <S as Read<EntityTypeWithMetadata>>::read_vec(
+        store,
+        &Filter::<EntityTypeWithMetadata>::In(
+            FilterExpression::Path {
+                path: EntityTypeQueryPath::OntologyId,
+            },
+            ParameterList::EntityTypeIds(entity_type_ids),
+        ),
+        Some(&subgraph.temporal_axes.resolved),
+        false,
+    )
+    .await?
+    {
+        subgraph.insert_vertex(
+            entity_type.vertex_id(subgraph.temporal_axes.resolved.variable_time_axis()),
+            entity_type,
+        );
     }
 
-    #[tracing::instrument(level = "info", skip(self, edition_ids, subgraph))]
-    async fn read_entities_by_ids(
-        &self,
-        edition_ids: &[EntityEditionId],
-        subgraph: &mut Subgraph,
-        include_drafts: bool,
-    ) -> Result<(), QueryError> {
-        let entities = <Self as Read<Entity>>::read_vec(
-            self,
-            &Filter::<Entity>::In(
-                FilterExpression::Path {
-                    path: EntityQueryPath::EditionId,
-                },
-                ParameterList::EntityEditionIds(edition_ids),
-            ),
-            Some(&subgraph.temporal_axes.resolved),
-            include_drafts,
-        )
-        .await?;
+    Ok(())
+}
 
-        let span = tracing::trace_span!("insert_into_subgraph", count = entities.len());
-        let _enter = span.enter();
+#[tracing::instrument(level = "info", skip(store, edition_ids, subgraph))]
+async fn read_entities_by_ids<S>(
+    store: &S,
+    edition_ids: &[EntityEditionId],
+    subgraph: &mut Subgraph,
+    include_drafts: bool,
+) -> Result<(), QueryError>
+where
+    S: Read<Entity>,
+{
+    let entities = <S as Read<Entity>>::read_vec(
+        store,
+        &Filter::<Entity>::In(
+            FilterExpression::Path {
+                path: EntityQueryPath::EditionId,
+            },
+            ParameterList::EntityEditionIds(Cow::Borrowed(edition_ids)),
+        ),
+        Some(&subgraph.temporal_axes.resolved),
+        include_drafts,
+    )
+    .await?;
 
-        for entity in entities {
-            subgraph.insert_vertex(
-                entity.vertex_id(subgraph.temporal_axes.resolved.variable_time_axis()),
-                entity,
-            );
-        }
+    let span = tracing::trace_span!("insert_into_subgraph", count = entities.len());
+    let _enter = span.enter();
 
-        Ok(())
+    for entity in entities {
+        subgraph.insert_vertex(
+            entity.vertex_id(subgraph.temporal_axes.resolved.variable_time_axis()),
+            entity,
+        );
     }
+
+    Ok(())
 }
 
 #[derive(Debug)]
@@ -246,45 +253,51 @@ pub struct TraversalContext {
 
 impl TraversalContext {
     #[tracing::instrument(level = "info", skip(self, store, subgraph))]
-    pub async fn read_traversed_vertices<C: AsClient, A: Send + Sync>(
+    pub async fn read_traversed_vertices<S>(
         self,
-        store: &PostgresStore<C, A>,
+        store: &S,
         subgraph: &mut Subgraph,
         include_drafts: bool,
-    ) -> Result<(), QueryError> {
+    ) -> Result<(), QueryError>
+    where
+        S: Read<DataTypeWithMetadata>
+            + Read<PropertyTypeWithMetadata>
+            + Read<EntityTypeWithMetadata>
+            + Read<Entity>,
+    {
         if !self.data_types.0.is_empty() {
-            store
-                .read_data_types_by_ids(
-                    &self.data_types.0.into_keys().collect::<Vec<_>>(),
-                    subgraph,
-                )
-                .await?;
+            read_data_types_by_ids(
+                store,
+                &self.data_types.0.into_keys().collect::<Vec<_>>(),
+                subgraph,
+            )
+            .await?;
         }
         if !self.property_types.0.is_empty() {
-            store
-                .read_property_types_by_ids(
-                    &self.property_types.0.into_keys().collect::<Vec<_>>(),
-                    subgraph,
-                )
-                .await?;
+            read_property_types_by_ids(
+                store,
+                &self.property_types.0.into_keys().collect::<Vec<_>>(),
+                subgraph,
+            )
+            .await?;
         }
         if !self.entity_types.0.is_empty() {
-            store
-                .read_entity_types_by_ids(
-                    &self.entity_types.0.into_keys().collect::<Vec<_>>(),
-                    subgraph,
-                )
-                .await?;
+            read_entity_types_by_ids(
+                store,
+                &self.entity_types.0.into_keys().collect::<Vec<_>>(),
+                subgraph,
+            )
+            .await?;
         }
 
         if !self.entities.0.is_empty() {
-            store
-                .read_entities_by_ids(
-                    &self.entities.0.into_keys().collect::<Vec<_>>(),
-                    subgraph,
-                    include_drafts,
-                )
-                .await?;
+            read_entities_by_ids(
+                store,
+                &self.entities.0.into_keys().collect::<Vec<_>>(),
+                subgraph,
+                include_drafts,
+            )
+            .await?;
         }
 
         Ok(())
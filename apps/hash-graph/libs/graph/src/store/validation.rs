@@ -35,7 +35,7 @@ use type_system::{
 };
 use validation::EntityProvider;
 
-use crate::store::{AsClient, PostgresStore, QueryError, crud::Read};
+use crate::store::{AsClient, MemoryStore, PostgresStore, QueryError, crud::Read};
 
 #[derive(Debug, Clone)]
 enum Access<T> {
@@ -552,3 +552,281 @@ where
         Ok(self.cache.entities.grant(entity_id, entity).await)
     }
 }
+
+impl<A> StoreProvider<'_, MemoryStore<A>>
+where
+    A: AuthorizationApi,
+{
+    async fn authorize_data_type(&self, type_id: DataTypeId) -> Result<(), Report<QueryError>> {
+        if let Some((actor_id, consistency)) = self.authorization {
+            self.store
+                .authorization_api
+                .check_data_type_permission(
+                    actor_id,
+                    DataTypePermission::View,
+                    type_id,
+                    consistency,
+                )
+                .await
+                .change_context(QueryError)?
+                .assert_permission()
+                .change_context(QueryError)?;
+        }
+
+        Ok(())
+    }
+
+    async fn authorize_property_type(
+        &self,
+        type_id: PropertyTypeId,
+    ) -> Result<(), Report<QueryError>> {
+        if let Some((actor_id, consistency)) = self.authorization {
+            self.store
+                .authorization_api
+                .check_property_type_permission(
+                    actor_id,
+                    PropertyTypePermission::View,
+                    type_id,
+                    consistency,
+                )
+                .await
+                .change_context(QueryError)?
+                .assert_permission()
+                .change_context(QueryError)?;
+        }
+
+        Ok(())
+    }
+
+    async fn authorize_entity_type(&self, type_id: EntityTypeId) -> Result<(), Report<QueryError>> {
+        if let Some((actor_id, consistency)) = self.authorization {
+            self.store
+                .authorization_api
+                .check_entity_type_permission(
+                    actor_id,
+                    EntityTypePermission::View,
+                    type_id,
+                    consistency,
+                )
+                .await
+                .change_context(QueryError)?
+                .assert_permission()
+                .change_context(QueryError)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<A> OntologyTypeProvider<DataTypeWithMetadata> for StoreProvider<'_, MemoryStore<A>>
+where
+    A: AuthorizationApi,
+{
+    type Value = Arc<DataTypeWithMetadata>;
+
+    #[expect(refining_impl_trait)]
+    async fn provide_type(
+        &self,
+        type_id: &VersionedUrl,
+    ) -> Result<Arc<DataTypeWithMetadata>, Report<QueryError>> {
+        let data_type_id = DataTypeId::from_url(type_id);
+
+        if let Some(cached) = self.cache.data_types.get(&data_type_id).await {
+            return cached;
+        }
+
+        if let Err(error) = self.authorize_data_type(data_type_id).await {
+            self.cache.data_types.deny(data_type_id).await;
+            return Err(error);
+        }
+
+        let schema = self
+            .store
+            .read_one(
+                &Filter::<DataTypeWithMetadata>::for_versioned_url(type_id),
+                Some(
+                    &QueryTemporalAxesUnresolved::DecisionTime {
+                        pinned: PinnedTemporalAxisUnresolved::new(None),
+                        variable: VariableTemporalAxisUnresolved::new(None, None),
+                    }
+                    .resolve(),
+                ),
+                false,
+            )
+            .await?;
+
+        Ok(self.cache.data_types.grant(data_type_id, schema).await)
+    }
+}
+
+impl<A> DataTypeProvider for StoreProvider<'_, MemoryStore<A>>
+where
+    A: AuthorizationApi,
+{
+    #[expect(refining_impl_trait)]
+    async fn is_parent_of(
+        &self,
+        child: &VersionedUrl,
+        parent: &BaseUrl,
+    ) -> Result<bool, Report<QueryError>> {
+        Ok(self.store.data_type_is_parent_of(child, parent).await)
+    }
+
+    #[expect(refining_impl_trait)]
+    async fn find_conversion(
+        &self,
+        source_data_type_id: &VersionedUrl,
+        target_data_type_id: &VersionedUrl,
+    ) -> Result<impl Borrow<Vec<ConversionExpression>>, Report<QueryError>> {
+        let source = DataTypeId::from_url(source_data_type_id);
+        let target = DataTypeId::from_url(target_data_type_id);
+
+        if let Some(cached) = self.cache.conversions.get(&(source, target)).await {
+            return cached;
+        }
+
+        let expression = self
+            .store
+            .find_data_type_conversion(source_data_type_id, target_data_type_id)
+            .await?;
+
+        Ok(self
+            .cache
+            .conversions
+            .grant((source, target), expression)
+            .await)
+    }
+}
+
+impl<A> OntologyTypeProvider<PropertyType> for StoreProvider<'_, MemoryStore<A>>
+where
+    A: AuthorizationApi,
+{
+    type Value = Arc<PropertyType>;
+
+    #[expect(refining_impl_trait)]
+    async fn provide_type(
+        &self,
+        type_id: &VersionedUrl,
+    ) -> Result<Arc<PropertyType>, Report<QueryError>> {
+        let property_type_id = PropertyTypeId::from_url(type_id);
+
+        if let Some(cached) = self.cache.property_types.get(&property_type_id).await {
+            return cached;
+        }
+
+        if let Err(error) = self.authorize_property_type(property_type_id).await {
+            self.cache.property_types.deny(property_type_id).await;
+            return Err(error);
+        }
+
+        let schema = self
+            .store
+            .read_one(
+                &Filter::<PropertyTypeWithMetadata>::for_versioned_url(type_id),
+                Some(
+                    &QueryTemporalAxesUnresolved::DecisionTime {
+                        pinned: PinnedTemporalAxisUnresolved::new(None),
+                        variable: VariableTemporalAxisUnresolved::new(None, None),
+                    }
+                    .resolve(),
+                ),
+                false,
+            )
+            .await
+            .map(|property_type| property_type.schema)?;
+
+        Ok(self
+            .cache
+            .property_types
+            .grant(property_type_id, schema)
+            .await)
+    }
+}
+
+impl<A> PropertyTypeProvider for StoreProvider<'_, MemoryStore<A>> where A: AuthorizationApi {}
+
+impl<A> OntologyTypeProvider<ClosedEntityType> for StoreProvider<'_, MemoryStore<A>>
+where
+    A: AuthorizationApi,
+{
+    type Value = Arc<ClosedEntityType>;
+
+    #[expect(refining_impl_trait)]
+    async fn provide_type(
+        &self,
+        type_id: &VersionedUrl,
+    ) -> Result<Arc<ClosedEntityType>, Report<QueryError>> {
+        let entity_type_id = EntityTypeId::from_url(type_id);
+
+        if let Some(cached) = self.cache.entity_types.get(&entity_type_id).await {
+            return cached;
+        }
+
+        if let Err(error) = self.authorize_entity_type(entity_type_id).await {
+            self.cache.entity_types.deny(entity_type_id).await;
+            return Err(error);
+        }
+
+        let schema = match self.store.closed_entity_type(type_id).await {
+            Ok(schema) => schema,
+            Err(error) => {
+                self.cache.entity_types.malformed(entity_type_id).await;
+                return Err(error);
+            }
+        };
+
+        Ok(self.cache.entity_types.grant(entity_type_id, schema).await)
+    }
+}
+
+impl<A> EntityTypeProvider for StoreProvider<'_, MemoryStore<A>>
+where
+    A: AuthorizationApi,
+{
+    #[expect(refining_impl_trait)]
+    async fn is_parent_of(
+        &self,
+        child: &VersionedUrl,
+        parent: &BaseUrl,
+    ) -> Result<bool, Report<QueryError>> {
+        Ok(self.store.entity_type_is_parent_of(child, parent).await)
+    }
+}
+
+impl<A> EntityProvider for StoreProvider<'_, MemoryStore<A>>
+where
+    A: AuthorizationApi,
+{
+    #[expect(refining_impl_trait)]
+    async fn provide_entity(&self, entity_id: EntityId) -> Result<Arc<Entity>, Report<QueryError>> {
+        if let Some(cached) = self.cache.entities.get(&entity_id).await {
+            return cached;
+        }
+        if let Some((actor_id, consistency)) = self.authorization {
+            self.store
+                .authorization_api
+                .check_entity_permission(actor_id, EntityPermission::View, entity_id, consistency)
+                .await
+                .change_context(QueryError)?
+                .assert_permission()
+                .change_context(QueryError)?;
+        }
+
+        let entity = self
+            .store
+            .read_one(
+                &Filter::for_entity_by_entity_id(entity_id),
+                Some(
+                    &QueryTemporalAxesUnresolved::DecisionTime {
+                        pinned: PinnedTemporalAxisUnresolved::new(None),
+                        variable: VariableTemporalAxisUnresolved::new(None, None),
+                    }
+                    .resolve(),
+                ),
+                entity_id.draft_id.is_some(),
+            )
+            .await?;
+        Ok(self.cache.entities.grant(entity_id, entity).await)
+    }
+}
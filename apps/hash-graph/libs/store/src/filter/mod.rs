@@ -1,5 +1,6 @@
 mod parameter;
 mod path;
+mod pattern;
 
 use alloc::borrow::Cow;
 use core::{borrow::Borrow, fmt, hash::Hash};
@@ -20,6 +21,7 @@ use type_system::{
 pub use self::{
     parameter::{Parameter, ParameterConversionError, ParameterList, ParameterType},
     path::{JsonPath, PathToken},
+    pattern::{PatternError, validate_pattern},
 };
 use crate::{
     data_type::DataTypeQueryPath,
@@ -120,11 +122,63 @@ pub enum Filter<'p, R: QueryRecord> {
         FilterExpression<'p, R>,
         FilterExpression<'p, R>,
     ),
-    #[serde(skip)]
     In(FilterExpression<'p, R>, ParameterList<'p>),
     StartsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     EndsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     ContainsSegment(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches if the words of the search query on the right-hand side occur in the text values of
+    /// the left-hand side.
+    ///
+    /// The query supports the web search syntax, i.e. quoted phrases, `or` and `-` to exclude
+    /// words.
+    FullTextSearch(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches if the left-hand side matches the regular expression on the right-hand side.
+    ///
+    /// The pattern is validated when the filter is deserialized. Only the subset of regular
+    /// expressions which is interpreted the same way by all stores is supported, see
+    /// [`validate_pattern`].
+    #[serde(deserialize_with = "deserialize_pattern")]
+    Matches(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    #[serde(deserialize_with = "deserialize_pattern")]
+    MatchesIgnoreCase(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    EqualIgnoreCase(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    StartsWithIgnoreCase(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    EndsWithIgnoreCase(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    ContainsSegmentIgnoreCase(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches if the expression has a value, for JSON paths this includes an explicit `null`.
+    Exists(FilterExpression<'p, R>),
+    /// Matches if the expression has no value or, for JSON paths, is `null`.
+    IsNull(FilterExpression<'p, R>),
+    /// Matches if the JSON array on the left-hand side contains the scalar value on the right-hand
+    /// side.
+    ArrayContains(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches if the JSON arrays on both sides have at least one scalar value in common.
+    ArrayOverlaps(FilterExpression<'p, R>, FilterExpression<'p, R>),
+}
+
+/// Deserializes the operands of a regular expression filter.
+///
+/// If the pattern is passed as parameter, it's validated to be a regular expression which is
+/// supported by all stores, see [`validate_pattern`].
+fn deserialize_pattern<'de: 'p, 'p, D, R>(
+    deserializer: D,
+) -> Result<(FilterExpression<'p, R>, FilterExpression<'p, R>), D::Error>
+where
+    D: de::Deserializer<'de>,
+    R: QueryRecord<QueryPath<'p>: Deserialize<'de>>,
+{
+    let (lhs, rhs) =
+        <(FilterExpression<'p, R>, FilterExpression<'p, R>)>::deserialize(deserializer)?;
+
+    if let FilterExpression::Parameter {
+        parameter: Parameter::Text(pattern),
+        convert: _,
+    } = &rhs
+    {
+        validate_pattern(pattern).map_err(de::Error::custom)?;
+    }
+
+    Ok((lhs, rhs))
 }
 
 impl<'p, R> Filter<'p, R>
@@ -392,24 +446,54 @@ where
             Self::In(lhs, rhs) => {
                 lhs.apply_parameter_conversion(data_type_provider).await?;
 
-                if let FilterExpression::Parameter {
-                    parameter,
-                    convert: _,
-                } = lhs
-                {
-                    match rhs {
+                match (lhs, rhs) {
+                    (
+                        FilterExpression::Parameter {
+                            parameter,
+                            convert: _,
+                        },
                         ParameterList::DataTypeIds(_)
                         | ParameterList::PropertyTypeIds(_)
                         | ParameterList::EntityTypeIds(_)
-                        | ParameterList::EntityEditionIds(_) => {
-                            parameter.convert_to_parameter_type(ParameterType::Uuid)?;
+                        | ParameterList::EntityEditionIds(_),
+                    ) => {
+                        parameter.convert_to_parameter_type(ParameterType::Uuid)?;
+                    }
+                    (FilterExpression::Path { path }, ParameterList::Parameters(parameters)) => {
+                        for parameter in parameters {
+                            parameter.convert_to_parameter_type(path.expected_type())?;
                         }
                     }
+                    (..) => {}
+                }
+            }
+            Self::Exists(expression) | Self::IsNull(expression) => {
+                expression
+                    .apply_parameter_conversion(data_type_provider)
+                    .await?;
+            }
+            Self::ArrayContains(lhs, rhs) | Self::ArrayOverlaps(lhs, rhs) => {
+                lhs.apply_parameter_conversion(data_type_provider).await?;
+                rhs.apply_parameter_conversion(data_type_provider).await?;
+
+                if let FilterExpression::Parameter {
+                    parameter,
+                    convert: _,
+                } = rhs
+                {
+                    parameter.convert_to_parameter_type(ParameterType::Any)?;
                 }
             }
             Self::StartsWith(lhs, rhs)
             | Self::EndsWith(lhs, rhs)
-            | Self::ContainsSegment(lhs, rhs) => {
+            | Self::ContainsSegment(lhs, rhs)
+            | Self::FullTextSearch(lhs, rhs)
+            | Self::Matches(lhs, rhs)
+            | Self::MatchesIgnoreCase(lhs, rhs)
+            | Self::EqualIgnoreCase(lhs, rhs)
+            | Self::StartsWithIgnoreCase(lhs, rhs)
+            | Self::EndsWithIgnoreCase(lhs, rhs)
+            | Self::ContainsSegmentIgnoreCase(lhs, rhs) => {
                 lhs.apply_parameter_conversion(data_type_provider).await?;
                 rhs.apply_parameter_conversion(data_type_provider).await?;
 
@@ -661,4 +745,80 @@ mod tests {
         )
         .await;
     }
+
+    #[tokio::test]
+    async fn matches_ignore_case() {
+        let expected = json!({
+          "matchesIgnoreCase": [
+            { "path": ["title"] },
+            { "parameter": "^te.t$" }
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::<DataTypeWithMetadata>::MatchesIgnoreCase(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Title,
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Text(Cow::Borrowed("^te.t$")),
+                    convert: None,
+                },
+            ),
+            &expected,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn in_list() {
+        let expected = json!({
+          "in": [
+            { "path": ["version"] },
+            [1, 2]
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::<DataTypeWithMetadata>::In(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Version,
+                },
+                ParameterList::Parameters(vec![
+                    Parameter::OntologyTypeVersion(OntologyTypeVersion::new(1)),
+                    Parameter::OntologyTypeVersion(OntologyTypeVersion::new(2)),
+                ]),
+            ),
+            &expected,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn is_null() {
+        let expected = json!({
+          "isNull": { "path": ["description"] }
+        });
+
+        test_filter_representation(
+            &Filter::<DataTypeWithMetadata>::IsNull(FilterExpression::Path {
+                path: DataTypeQueryPath::Description,
+            }),
+            &expected,
+        )
+        .await;
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        let filter = json!({
+          "matches": [
+            { "path": ["title"] },
+            { "parameter": "(unclosed" }
+          ]
+        });
+
+        Filter::<DataTypeWithMetadata>::deserialize(&filter)
+            .expect_err("invalid pattern should be rejected");
+    }
 }
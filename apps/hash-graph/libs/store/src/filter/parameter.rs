@@ -7,7 +7,7 @@ use graph_types::{
     knowledge::entity::EntityEditionId,
     ontology::{EntityTypeId, PropertyTypeId},
 };
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 use serde_json::{Number as JsonNumber, Value as JsonValue};
 use temporal_versioning::Timestamp;
 use type_system::{
@@ -69,12 +69,23 @@ impl fmt::Display for ParameterType {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ParameterList<'p> {
     DataTypeIds(&'p [DataTypeId]),
     PropertyTypeIds(&'p [PropertyTypeId]),
     EntityTypeIds(&'p [EntityTypeId]),
-    EntityEditionIds(&'p [EntityEditionId]),
+    EntityEditionIds(Cow<'p, [EntityEditionId]>),
+    Parameters(Vec<Parameter<'p>>),
+}
+
+/// Only a list of literal [`Parameter`]s can be deserialized.
+impl<'de: 'p, 'p> Deserialize<'de> for ParameterList<'p> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(Self::Parameters)
+    }
 }
 
 impl Parameter<'_> {
@@ -0,0 +1,156 @@
+use regex::Regex;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PatternError {
+    #[error("invalid regular expression: {0}")]
+    Syntax(#[from] regex::Error),
+    #[error(
+        "the escape sequence `\\{0}` is not supported as it is interpreted differently by the \
+         stores"
+    )]
+    UnsupportedEscape(char),
+    #[error("groups other than `(...)` and `(?:...)` are not supported")]
+    UnsupportedGroup,
+    #[error("nested character classes and class set operations are not supported")]
+    UnsupportedClass,
+}
+
+/// Escape sequences which behave the same in Rust regular expressions and in POSIX advanced
+/// regular expressions as used by Postgres.
+const PORTABLE_ESCAPES: &[char] = &['d', 'D', 's', 'S', 'w', 'W', 'n', 'r', 't'];
+
+/// Validates that `pattern` is a regular expression supported by all stores.
+///
+/// The stores evaluate patterns with different engines: the in-memory store uses the [`regex`]
+/// crate while Postgres uses its POSIX advanced regular expressions. Only the subset both engines
+/// interpret the same way is accepted:
+///
+/// - literals and escaped meta-characters, `.`, `^`, and `$`,
+/// - alternation `|`, capturing groups `(...)`, and non-capturing groups `(?:...)`,
+/// - the quantifiers `*`, `+`, `?`, `{n}`, `{n,}`, `{n,m}`, and their lazy variants,
+/// - bracket expressions including ranges, negation, and POSIX classes such as `[[:alpha:]]`,
+/// - the escapes `\d`, `\D`, `\s`, `\S`, `\w`, `\W`, `\n`, `\r`, and `\t`.
+///
+/// Notably rejected are word boundaries (`\b` is a backspace in Postgres), `\A`, `\z`, Unicode
+/// classes (`\p{..}`), named groups, inline flags, and nested character classes.
+///
+/// # Errors
+///
+/// - [`Syntax`] if the pattern is not a valid regular expression.
+/// - [`UnsupportedEscape`] if the pattern uses an escape sequence outside of the subset.
+/// - [`UnsupportedGroup`] if the pattern uses a group other than `(...)` or `(?:...)`.
+/// - [`UnsupportedClass`] if the pattern uses nested character classes or set operations.
+///
+/// [`Syntax`]: PatternError::Syntax
+/// [`UnsupportedEscape`]: PatternError::UnsupportedEscape
+/// [`UnsupportedGroup`]: PatternError::UnsupportedGroup
+/// [`UnsupportedClass`]: PatternError::UnsupportedClass
+pub fn validate_pattern(pattern: &str) -> Result<(), PatternError> {
+    Regex::new(pattern)?;
+
+    let mut chars = pattern.chars().peekable();
+    let mut in_class = false;
+    while let Some(token) = chars.next() {
+        match token {
+            '\\' => {
+                // The pattern is valid, so an escape is always followed by another character.
+                let Some(escaped) = chars.next() else {
+                    break;
+                };
+                let portable = if in_class {
+                    // Postgres does not allow negated classes inside of bracket expressions.
+                    matches!(escaped, 'd' | 's' | 'w' | 'n' | 'r' | 't')
+                } else {
+                    PORTABLE_ESCAPES.contains(&escaped)
+                };
+                if !portable && escaped.is_alphanumeric() {
+                    return Err(PatternError::UnsupportedEscape(escaped));
+                }
+            }
+            '(' if !in_class => {
+                if chars.next_if_eq(&'?').is_some() && chars.next_if_eq(&':').is_none() {
+                    return Err(PatternError::UnsupportedGroup);
+                }
+            }
+            '[' if !in_class => {
+                in_class = true;
+                chars.next_if_eq(&'^');
+                // A leading `]` is a literal in both dialects.
+                chars.next_if_eq(&']');
+            }
+            '[' => {
+                if chars.next_if_eq(&':').is_none() {
+                    return Err(PatternError::UnsupportedClass);
+                }
+                // Skip the POSIX class name up to and including the closing `:]`.
+                while let Some(class_char) = chars.next() {
+                    if class_char == ':' && chars.next_if_eq(&']').is_some() {
+                        break;
+                    }
+                }
+            }
+            ']' if in_class => in_class = false,
+            '&' | '-' | '~' if in_class => {
+                if chars.next_if_eq(&token).is_some() {
+                    return Err(PatternError::UnsupportedClass);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn portable() {
+        for pattern in [
+            "^te.t$",
+            "a|b",
+            "(ab)+(?:cd)*?",
+            "x{2,3}",
+            r"\d+\.\d*",
+            r"[\w-]+",
+            "[^a-z]",
+            "[]a]",
+            "[[:alpha:][:digit:]]",
+        ] {
+            validate_pattern(pattern)
+                .unwrap_or_else(|error| panic!("`{pattern}` should be accepted: {error}"));
+        }
+    }
+
+    #[test]
+    fn not_portable() {
+        for pattern in [
+            r"\bword\b",
+            r"\Astart",
+            r"end\z",
+            r"\p{L}",
+            r"[\D]",
+            "(?i)case",
+            "(?P<name>a)",
+            "[a-z&&[^aeiou]]",
+            "[a--b]",
+            "[[a]]",
+        ] {
+            assert!(
+                validate_pattern(pattern).is_err(),
+                "`{pattern}` should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            validate_pattern("(unclosed"),
+            Err(PatternError::Syntax(_))
+        ));
+    }
+}
@@ -0,0 +1,759 @@
+//! Detection of changes between two versions of an ontology type.
+//!
+//! A change is backwards-compatible if every value or entity which is valid against the previous
+//! version of a type is also valid against the next version. Any other change is breaking.
+
+use core::fmt;
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use crate::{
+    schema::{
+        DataType, EntityType, EntityTypeReference, OneOfSchema, PropertyType, PropertyValueArray,
+        PropertyValues, ValueOrArray,
+    },
+    url::{BaseUrl, VersionedUrl},
+};
+
+/// Whether a [`TypeChange`] keeps existing data valid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Compatibility {
+    /// Everything which is valid against the previous version is valid against the next version.
+    Compatible,
+    /// Data which is valid against the previous version may be invalid against the next version.
+    Breaking,
+}
+
+/// A change between two versions of a [`DataType`], [`PropertyType`], or [`EntityType`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum TypeChange {
+    /// The title, description, or other information without effect on validation was changed.
+    MetadataChanged,
+    /// A type to inherit from was added.
+    ParentAdded { parent: VersionedUrl },
+    /// A type to inherit from was removed.
+    ParentRemoved { parent: VersionedUrl },
+    /// The data type cannot be used for values anymore.
+    BecameAbstract,
+    /// The data type can be used for values.
+    BecameConcrete,
+    /// The constraints of the data type accept every value they accepted before.
+    ConstraintsWidened,
+    /// The constraints of the data type may reject values they accepted before.
+    ConstraintsChanged,
+    /// A possible value was added to the property type.
+    ValueAdded {
+        #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
+        value: PropertyValues,
+    },
+    /// A possible value was removed from the property type.
+    ValueRemoved {
+        #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
+        value: PropertyValues,
+    },
+    /// A property was added to the entity type.
+    PropertyAdded { property: BaseUrl, required: bool },
+    /// A property was removed from the entity type.
+    PropertyRemoved { property: BaseUrl },
+    /// An existing property became required.
+    PropertyRequired { property: BaseUrl },
+    /// An existing property became optional.
+    PropertyOptional { property: BaseUrl },
+    /// An existing property references a different property type.
+    ///
+    /// The referenced property types are not resolved, so this is considered breaking even if the
+    /// new property type accepts every value of the previous one.
+    PropertyTypeChanged {
+        property: BaseUrl,
+        from: VersionedUrl,
+        to: VersionedUrl,
+    },
+    /// An existing property accepts more values than before.
+    PropertyCardinalityWidened { property: BaseUrl },
+    /// An existing property may reject a number of values it accepted before.
+    PropertyCardinalityChanged { property: BaseUrl },
+    /// A link was added to the entity type.
+    LinkAdded { link: VersionedUrl },
+    /// A link was removed from the entity type.
+    LinkRemoved { link: VersionedUrl },
+    /// An existing link accepts more destinations than before.
+    LinkDestinationsWidened { link: VersionedUrl },
+    /// An existing link may reject destinations it accepted before.
+    LinkDestinationsChanged { link: VersionedUrl },
+    /// An existing link accepts more links than before.
+    LinkCardinalityWidened { link: VersionedUrl },
+    /// An existing link may reject a number of links it accepted before.
+    LinkCardinalityChanged { link: VersionedUrl },
+}
+
+impl TypeChange {
+    /// Returns whether data which is valid against the previous version is kept valid.
+    #[must_use]
+    pub const fn compatibility(&self) -> Compatibility {
+        match self {
+            Self::MetadataChanged
+            | Self::BecameConcrete
+            | Self::ConstraintsWidened
+            | Self::ValueAdded { .. }
+            | Self::PropertyAdded {
+                required: false, ..
+            }
+            | Self::PropertyOptional { .. }
+            | Self::PropertyCardinalityWidened { .. }
+            | Self::LinkAdded { .. }
+            | Self::LinkDestinationsWidened { .. }
+            | Self::LinkCardinalityWidened { .. } => Compatibility::Compatible,
+            Self::ParentAdded { .. }
+            | Self::ParentRemoved { .. }
+            | Self::BecameAbstract
+            | Self::ConstraintsChanged
+            | Self::ValueRemoved { .. }
+            | Self::PropertyAdded { required: true, .. }
+            | Self::PropertyRemoved { .. }
+            | Self::PropertyRequired { .. }
+            | Self::PropertyTypeChanged { .. }
+            | Self::PropertyCardinalityChanged { .. }
+            | Self::LinkRemoved { .. }
+            | Self::LinkDestinationsChanged { .. }
+            | Self::LinkCardinalityChanged { .. } => Compatibility::Breaking,
+        }
+    }
+
+    /// Returns `true` if the change may invalidate existing data.
+    #[must_use]
+    pub const fn is_breaking(&self) -> bool {
+        matches!(self.compatibility(), Compatibility::Breaking)
+    }
+}
+
+impl fmt::Display for TypeChange {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetadataChanged => fmt.write_str("the metadata of the type was changed"),
+            Self::ParentAdded { parent } => write!(fmt, "the parent `{parent}` was added"),
+            Self::ParentRemoved { parent } => write!(fmt, "the parent `{parent}` was removed"),
+            Self::BecameAbstract => fmt.write_str("the data type became abstract"),
+            Self::BecameConcrete => fmt.write_str("the data type is not abstract anymore"),
+            Self::ConstraintsWidened => {
+                fmt.write_str("the constraints of the data type were widened")
+            }
+            Self::ConstraintsChanged => {
+                fmt.write_str("the constraints of the data type were narrowed or changed")
+            }
+            Self::ValueAdded { .. } => {
+                fmt.write_str("a possible value was added to the property type")
+            }
+            Self::ValueRemoved { .. } => {
+                fmt.write_str("a possible value was removed from the property type")
+            }
+            Self::PropertyAdded {
+                property,
+                required: true,
+            } => write!(fmt, "the required property `{property}` was added"),
+            Self::PropertyAdded {
+                property,
+                required: false,
+            } => write!(fmt, "the optional property `{property}` was added"),
+            Self::PropertyRemoved { property } => {
+                write!(fmt, "the property `{property}` was removed")
+            }
+            Self::PropertyRequired { property } => {
+                write!(fmt, "the property `{property}` became required")
+            }
+            Self::PropertyOptional { property } => {
+                write!(fmt, "the property `{property}` became optional")
+            }
+            Self::PropertyTypeChanged { property, from, to } => write!(
+                fmt,
+                "the property `{property}` was changed from `{from}` to `{to}`"
+            ),
+            Self::PropertyCardinalityWidened { property } => write!(
+                fmt,
+                "the number of values of the property `{property}` was widened"
+            ),
+            Self::PropertyCardinalityChanged { property } => write!(
+                fmt,
+                "the number of values of the property `{property}` was narrowed or changed"
+            ),
+            Self::LinkAdded { link } => write!(fmt, "the link `{link}` was added"),
+            Self::LinkRemoved { link } => write!(fmt, "the link `{link}` was removed"),
+            Self::LinkDestinationsWidened { link } => {
+                write!(fmt, "the destinations of the link `{link}` were widened")
+            }
+            Self::LinkDestinationsChanged { link } => write!(
+                fmt,
+                "the destinations of the link `{link}` were narrowed or changed"
+            ),
+            Self::LinkCardinalityWidened { link } => {
+                write!(fmt, "the number of links `{link}` was widened")
+            }
+            Self::LinkCardinalityChanged { link } => {
+                write!(fmt, "the number of links `{link}` was narrowed or changed")
+            }
+        }
+    }
+}
+
+/// Compares the bounds of two arrays.
+///
+/// Returns `None` if the bounds are equal, otherwise whether the next bounds include the previous
+/// ones.
+fn compare_cardinality<T>(
+    previous: &PropertyValueArray<T>,
+    next: &PropertyValueArray<T>,
+) -> Option<Compatibility> {
+    if previous.min_items == next.min_items && previous.max_items == next.max_items {
+        return None;
+    }
+
+    let min_items_covered = next.min_items.unwrap_or(0) <= previous.min_items.unwrap_or(0);
+    let max_items_covered = match (previous.max_items, next.max_items) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(previous), Some(next)) => next >= previous,
+    };
+
+    Some(if min_items_covered && max_items_covered {
+        Compatibility::Compatible
+    } else {
+        Compatibility::Breaking
+    })
+}
+
+impl DataType {
+    /// Returns the changes between this data type and the `next` version of it.
+    #[must_use]
+    pub fn changes_to(&self, next: &Self) -> Vec<TypeChange> {
+        let mut changes = Vec::new();
+
+        if self.title != next.title
+            || self.title_plural != next.title_plural
+            || self.description != next.description
+            || self.label != next.label
+        {
+            changes.push(TypeChange::MetadataChanged);
+        }
+
+        changes.extend(
+            next.all_of
+                .iter()
+                .filter(|parent| !self.all_of.contains(parent))
+                .map(|parent| TypeChange::ParentAdded {
+                    parent: parent.url.clone(),
+                }),
+        );
+        changes.extend(
+            self.all_of
+                .iter()
+                .filter(|parent| !next.all_of.contains(parent))
+                .map(|parent| TypeChange::ParentRemoved {
+                    parent: parent.url.clone(),
+                }),
+        );
+
+        match (self.r#abstract, next.r#abstract) {
+            (false, true) => changes.push(TypeChange::BecameAbstract),
+            (true, false) => changes.push(TypeChange::BecameConcrete),
+            (false, false) | (true, true) => {}
+        }
+
+        match (
+            next.constraints.is_superset_of(&self.constraints),
+            self.constraints.is_superset_of(&next.constraints),
+        ) {
+            (false, _) => changes.push(TypeChange::ConstraintsChanged),
+            (true, false) => changes.push(TypeChange::ConstraintsWidened),
+            // Both constraints accept the same values.
+            (true, true) => {}
+        }
+
+        changes
+    }
+}
+
+impl PropertyType {
+    /// Returns the changes between this property type and the `next` version of it.
+    #[must_use]
+    pub fn changes_to(&self, next: &Self) -> Vec<TypeChange> {
+        let mut changes = Vec::new();
+
+        if self.title != next.title
+            || self.title_plural != next.title_plural
+            || self.description != next.description
+        {
+            changes.push(TypeChange::MetadataChanged);
+        }
+
+        changes.extend(
+            next.one_of
+                .iter()
+                .filter(|value| !self.one_of.contains(value))
+                .map(|value| TypeChange::ValueAdded {
+                    value: value.clone(),
+                }),
+        );
+        changes.extend(
+            self.one_of
+                .iter()
+                .filter(|value| !next.one_of.contains(value))
+                .map(|value| TypeChange::ValueRemoved {
+                    value: value.clone(),
+                }),
+        );
+
+        changes
+    }
+}
+
+impl EntityType {
+    /// Returns the changes between this entity type and the `next` version of it.
+    #[must_use]
+    pub fn changes_to(&self, next: &Self) -> Vec<TypeChange> {
+        let mut changes = Vec::new();
+
+        if self.title != next.title
+            || self.title_plural != next.title_plural
+            || self.description != next.description
+            || self.inverse != next.inverse
+        {
+            changes.push(TypeChange::MetadataChanged);
+        }
+
+        changes.extend(next.all_of.difference(&self.all_of).map(|parent| {
+            TypeChange::ParentAdded {
+                parent: parent.url.clone(),
+            }
+        }));
+        changes.extend(self.all_of.difference(&next.all_of).map(|parent| {
+            TypeChange::ParentRemoved {
+                parent: parent.url.clone(),
+            }
+        }));
+
+        self.property_changes(next, &mut changes);
+        self.link_changes(next, &mut changes);
+
+        changes
+    }
+
+    fn property_changes(&self, next: &Self, changes: &mut Vec<TypeChange>) {
+        for (property, previous_value) in &self.properties {
+            let Some(next_value) = next.properties.get(property) else {
+                changes.push(TypeChange::PropertyRemoved {
+                    property: property.clone(),
+                });
+                continue;
+            };
+
+            match (
+                self.required.contains(property),
+                next.required.contains(property),
+            ) {
+                (false, true) => changes.push(TypeChange::PropertyRequired {
+                    property: property.clone(),
+                }),
+                (true, false) => changes.push(TypeChange::PropertyOptional {
+                    property: property.clone(),
+                }),
+                (false, false) | (true, true) => {}
+            }
+
+            let (previous_reference, next_reference) = match (previous_value, next_value) {
+                (ValueOrArray::Value(previous), ValueOrArray::Value(next)) => (previous, next),
+                (ValueOrArray::Array(previous), ValueOrArray::Array(next)) => {
+                    match compare_cardinality(previous, next) {
+                        Some(Compatibility::Compatible) => {
+                            changes.push(TypeChange::PropertyCardinalityWidened {
+                                property: property.clone(),
+                            });
+                        }
+                        Some(Compatibility::Breaking) => {
+                            changes.push(TypeChange::PropertyCardinalityChanged {
+                                property: property.clone(),
+                            });
+                        }
+                        None => {}
+                    }
+                    (&previous.items, &next.items)
+                }
+                (
+                    ValueOrArray::Value(previous),
+                    ValueOrArray::Array(PropertyValueArray { items: next, .. }),
+                )
+                | (
+                    ValueOrArray::Array(PropertyValueArray {
+                        items: previous, ..
+                    }),
+                    ValueOrArray::Value(next),
+                ) => {
+                    // A single value and an array of values are not interchangeable.
+                    changes.push(TypeChange::PropertyCardinalityChanged {
+                        property: property.clone(),
+                    });
+                    (previous, next)
+                }
+            };
+
+            if previous_reference != next_reference {
+                changes.push(TypeChange::PropertyTypeChanged {
+                    property: property.clone(),
+                    from: previous_reference.url.clone(),
+                    to: next_reference.url.clone(),
+                });
+            }
+        }
+
+        changes.extend(
+            next.properties
+                .keys()
+                .filter(|property| !self.properties.contains_key(*property))
+                .map(|property| TypeChange::PropertyAdded {
+                    property: property.clone(),
+                    required: next.required.contains(property),
+                }),
+        );
+    }
+
+    fn link_changes(&self, next: &Self, changes: &mut Vec<TypeChange>) {
+        for (link, previous_value) in &self.links {
+            let Some(next_value) = next.links.get(link) else {
+                changes.push(TypeChange::LinkRemoved { link: link.clone() });
+                continue;
+            };
+
+            match compare_cardinality(previous_value, next_value) {
+                Some(Compatibility::Compatible) => {
+                    changes.push(TypeChange::LinkCardinalityWidened { link: link.clone() });
+                }
+                Some(Compatibility::Breaking) => {
+                    changes.push(TypeChange::LinkCardinalityChanged { link: link.clone() });
+                }
+                None => {}
+            }
+
+            match (&previous_value.items, &next_value.items) {
+                (Some(previous), Some(next)) => {
+                    let previous = destinations(previous);
+                    let next = destinations(next);
+                    if previous != next {
+                        changes.push(if previous.is_subset(&next) {
+                            TypeChange::LinkDestinationsWidened { link: link.clone() }
+                        } else {
+                            TypeChange::LinkDestinationsChanged { link: link.clone() }
+                        });
+                    }
+                }
+                (Some(_), None) => {
+                    changes.push(TypeChange::LinkDestinationsWidened { link: link.clone() });
+                }
+                (None, Some(_)) => {
+                    changes.push(TypeChange::LinkDestinationsChanged { link: link.clone() });
+                }
+                (None, None) => {}
+            }
+        }
+
+        changes.extend(
+            next.links
+                .keys()
+                .filter(|link| !self.links.contains_key(*link))
+                .map(|link| TypeChange::LinkAdded { link: link.clone() }),
+        );
+    }
+}
+
+fn destinations(schema: &OneOfSchema<EntityTypeReference>) -> HashSet<&EntityTypeReference> {
+    schema.possibilities.iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::schema::{DataTypeReference, ValueConstraints};
+
+    fn data_type_with_constraints(constraints: serde_json::Value) -> DataType {
+        let mut data_type: DataType = serde_json::from_str(graph_test_data::data_type::NUMBER_V1)
+            .expect("failed to parse data type");
+        data_type.constraints =
+            serde_json::from_value(constraints).expect("failed to parse constraints");
+        data_type
+    }
+
+    #[test]
+    fn data_type_metadata() {
+        let previous: DataType = serde_json::from_str(graph_test_data::data_type::NUMBER_V1)
+            .expect("failed to parse data type");
+        let mut next = previous.clone();
+        assert_eq!(previous.changes_to(&next), []);
+
+        next.description = Some("A number".to_owned());
+        assert_eq!(previous.changes_to(&next), [TypeChange::MetadataChanged]);
+        assert!(!TypeChange::MetadataChanged.is_breaking());
+    }
+
+    #[test]
+    fn data_type_parents() {
+        let previous: DataType = serde_json::from_str(graph_test_data::data_type::NUMBER_V1)
+            .expect("failed to parse data type");
+        let mut next = previous.clone();
+        let parent = VersionedUrl::from_str(
+            "https://blockprotocol.org/@blockprotocol/types/data-type/value/v/1",
+        )
+        .expect("invalid URL");
+        next.all_of.clear();
+        next.r#abstract = true;
+
+        let changes = previous.changes_to(&next);
+        assert_eq!(changes, [
+            TypeChange::ParentRemoved {
+                parent: parent.clone()
+            },
+            TypeChange::BecameAbstract,
+        ]);
+        assert!(changes.iter().all(TypeChange::is_breaking));
+
+        next.all_of.push(DataTypeReference { url: parent });
+        assert_eq!(next.changes_to(&previous), [TypeChange::BecameConcrete]);
+    }
+
+    #[test]
+    fn number_constraints() {
+        let previous = data_type_with_constraints(json!({
+            "type": "number",
+            "minimum": 0.0,
+            "maximum": 10.0,
+        }));
+        let widened = data_type_with_constraints(json!({
+            "type": "number",
+            "minimum": 0.0,
+        }));
+        let narrowed = data_type_with_constraints(json!({
+            "type": "number",
+            "minimum": 0.0,
+            "maximum": 10.0,
+            "exclusiveMaximum": true,
+        }));
+        let multiple_of = data_type_with_constraints(json!({
+            "type": "number",
+            "minimum": 0.0,
+            "maximum": 10.0,
+            "multipleOf": 2.0,
+        }));
+
+        assert_eq!(previous.changes_to(&previous.clone()), []);
+        assert_eq!(previous.changes_to(&widened), [
+            TypeChange::ConstraintsWidened
+        ]);
+        assert_eq!(widened.changes_to(&previous), [
+            TypeChange::ConstraintsChanged
+        ]);
+        assert_eq!(previous.changes_to(&narrowed), [
+            TypeChange::ConstraintsChanged
+        ]);
+        assert_eq!(previous.changes_to(&multiple_of), [
+            TypeChange::ConstraintsChanged
+        ]);
+        assert_eq!(multiple_of.changes_to(&previous), [
+            TypeChange::ConstraintsWidened
+        ]);
+    }
+
+    #[test]
+    fn enum_constraints() {
+        let previous = data_type_with_constraints(json!({
+            "type": "string",
+            "const": "foo",
+        }));
+        let widened = data_type_with_constraints(json!({
+            "type": "string",
+            "enum": ["foo", "bar"],
+        }));
+        let unconstrained = data_type_with_constraints(json!({
+            "type": "string",
+        }));
+        let number = data_type_with_constraints(json!({
+            "type": "number",
+        }));
+
+        assert_eq!(previous.changes_to(&widened), [
+            TypeChange::ConstraintsWidened
+        ]);
+        assert_eq!(widened.changes_to(&previous), [
+            TypeChange::ConstraintsChanged
+        ]);
+        assert_eq!(widened.changes_to(&unconstrained), [
+            TypeChange::ConstraintsWidened
+        ]);
+        assert_eq!(unconstrained.changes_to(&widened), [
+            TypeChange::ConstraintsChanged
+        ]);
+        assert_eq!(unconstrained.changes_to(&number), [
+            TypeChange::ConstraintsChanged
+        ]);
+    }
+
+    #[test]
+    fn any_of_constraints() {
+        let previous = data_type_with_constraints(json!({
+            "type": "string",
+        }));
+        let next = data_type_with_constraints(json!({
+            "anyOf": [
+                { "type": "string" },
+                { "type": "number" },
+            ],
+        }));
+        assert!(matches!(next.constraints, ValueConstraints::AnyOf(_)));
+
+        assert_eq!(previous.changes_to(&next), [TypeChange::ConstraintsWidened]);
+        assert_eq!(next.changes_to(&previous), [TypeChange::ConstraintsChanged]);
+    }
+
+    #[test]
+    fn object_constraints() {
+        let previous = data_type_with_constraints(json!({
+            "type": "object",
+            "properties": {
+                "amount": { "type": "number" },
+                "currency": { "type": "string" },
+                "rate": { "type": "number" },
+            },
+            "required": ["amount", "currency", "rate"],
+        }));
+        let reordered = data_type_with_constraints(json!({
+            "type": "object",
+            "properties": {
+                "rate": { "type": "number" },
+                "currency": { "type": "string" },
+                "amount": { "type": "number" },
+            },
+            "required": ["rate", "currency", "amount"],
+        }));
+        let relaxed = data_type_with_constraints(json!({
+            "type": "object",
+            "properties": {
+                "amount": { "type": "number" },
+                "currency": { "type": "string" },
+                "rate": { "type": "number" },
+            },
+            "required": ["amount", "currency"],
+        }));
+
+        assert_eq!(previous.changes_to(&reordered), []);
+        assert_eq!(reordered.changes_to(&previous), []);
+        assert_eq!(previous.changes_to(&relaxed), [
+            TypeChange::ConstraintsChanged
+        ]);
+    }
+
+    #[test]
+    fn property_type_values() {
+        let previous: PropertyType = serde_json::from_str(graph_test_data::property_type::TEXT_V1)
+            .expect("failed to parse property type");
+        let mut next = previous.clone();
+        let value = PropertyValues::DataTypeReference(DataTypeReference {
+            url: VersionedUrl::from_str(
+                "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1",
+            )
+            .expect("invalid URL"),
+        });
+        next.one_of.push(value.clone());
+
+        assert_eq!(previous.changes_to(&next), [TypeChange::ValueAdded {
+            value: value.clone()
+        }]);
+        assert_eq!(next.changes_to(&previous), [TypeChange::ValueRemoved {
+            value
+        }]);
+        assert!(next.changes_to(&previous)[0].is_breaking());
+    }
+
+    #[test]
+    fn entity_type_properties() {
+        let previous: EntityType = serde_json::from_str(graph_test_data::entity_type::BOOK_V1)
+            .expect("failed to parse entity type");
+        let name =
+            BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/name/".to_owned())
+                .expect("invalid base URL");
+        let blurb =
+            BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/blurb/".to_owned())
+                .expect("invalid base URL");
+
+        let mut next = previous.clone();
+        next.required.remove(&name);
+        next.required.insert(blurb.clone());
+        let changes = previous.changes_to(&next);
+        assert_eq!(changes.len(), 2);
+        assert!(changes.contains(&TypeChange::PropertyOptional {
+            property: name.clone()
+        }));
+        assert!(changes.contains(&TypeChange::PropertyRequired {
+            property: blurb.clone()
+        }));
+
+        let mut next = previous.clone();
+        next.properties.remove(&blurb);
+        assert_eq!(previous.changes_to(&next), [TypeChange::PropertyRemoved {
+            property: blurb.clone()
+        }]);
+        assert_eq!(next.changes_to(&previous), [TypeChange::PropertyAdded {
+            property: blurb,
+            required: false,
+        }]);
+
+        let mut next = previous.clone();
+        let Some(ValueOrArray::Array(array)) = next.properties.get_mut(&name) else {
+            panic!("expected an array property");
+        };
+        array.max_items = Some(1);
+        assert_eq!(previous.changes_to(&next), [
+            TypeChange::PropertyCardinalityChanged {
+                property: name.clone()
+            }
+        ]);
+        assert_eq!(next.changes_to(&previous), [
+            TypeChange::PropertyCardinalityWidened { property: name }
+        ]);
+    }
+
+    #[test]
+    fn entity_type_links() {
+        let previous: EntityType = serde_json::from_str(graph_test_data::entity_type::BOOK_V1)
+            .expect("failed to parse entity type");
+        let written_by = VersionedUrl::from_str(
+            "https://blockprotocol.org/@alice/types/entity-type/written-by/v/1",
+        )
+        .expect("invalid URL");
+
+        let mut next = previous.clone();
+        next.links
+            .get_mut(&written_by)
+            .expect("link should exist")
+            .items = None;
+        assert_eq!(previous.changes_to(&next), [
+            TypeChange::LinkDestinationsWidened {
+                link: written_by.clone()
+            }
+        ]);
+        assert_eq!(next.changes_to(&previous), [
+            TypeChange::LinkDestinationsChanged {
+                link: written_by.clone()
+            }
+        ]);
+
+        next.links.clear();
+        assert_eq!(previous.changes_to(&next), [TypeChange::LinkRemoved {
+            link: written_by.clone()
+        }]);
+        assert_eq!(next.changes_to(&previous), [TypeChange::LinkAdded {
+            link: written_by
+        }]);
+    }
+}
@@ -6,13 +6,15 @@ use std::collections::{HashMap, hash_map::Entry};
 
 #[cfg(feature = "postgres")]
 use bytes::BytesMut;
+use error_stack::{Report, ReportSink};
 #[cfg(feature = "postgres")]
 use postgres_types::{FromSql, IsNull, ToSql, Type};
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 
 use crate::{
     Valid,
-    schema::{DataType, DataTypeId, data_type::DataTypeEdge},
+    schema::{ConstraintError, DataType, DataTypeId, data_type::DataTypeEdge},
     url::VersionedUrl,
 };
 
@@ -31,6 +33,30 @@ impl ClosedDataType {
         // Valid closed schemas imply that the schema is valid
         Valid::new_ref_unchecked(&self.schema)
     }
+
+    /// Validates the given JSON value against the constraints of this data type and all data types
+    /// it inherits from.
+    ///
+    /// Constraints are inherited, so a value has to satisfy the constraints of every parent, e.g.
+    /// a list with `maxItems` inheriting from a list with `minItems` only accepts arrays whose
+    /// length is in between both bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON value is not a valid instance of the data type or one of its
+    /// parents.
+    pub fn validate_constraints(&self, value: &JsonValue) -> Result<(), Report<[ConstraintError]>> {
+        let mut status = ReportSink::new();
+
+        status.attempt(self.schema.validate_constraints(value));
+        for definition in self.definitions.values() {
+            if definition.id != self.schema.id {
+                status.attempt(definition.validate_constraints(value));
+            }
+        }
+
+        status.finish()
+    }
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -92,3 +118,60 @@ impl DataTypeInheritanceData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::schema::ArrayValidationError;
+
+    fn data_type(schema: JsonValue) -> Arc<DataType> {
+        Arc::new(serde_json::from_value(schema).expect("failed to parse data type"))
+    }
+
+    #[test]
+    fn inherited_array_constraints() {
+        let short_list = data_type(json!({
+          "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+          "kind": "dataType",
+          "$id": "https://example.com/data-type/short-list/v/1",
+          "title": "Short List",
+          "allOf": [{ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/list/v/1" }],
+          "type": "array",
+          "maxItems": 3,
+        }));
+        let short_non_empty_list = ClosedDataType {
+            schema: data_type(json!({
+              "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+              "kind": "dataType",
+              "$id": "https://example.com/data-type/short-non-empty-list/v/1",
+              "title": "Short Non-Empty List",
+              "allOf": [{ "$ref": "https://example.com/data-type/short-list/v/1" }],
+              "type": "array",
+              "minItems": 1,
+            })),
+            definitions: HashMap::from([(short_list.id.clone(), short_list)]),
+        };
+
+        short_non_empty_list
+            .validate_constraints(&json!([1, 2]))
+            .expect("value should be valid");
+
+        let report = short_non_empty_list
+            .validate_constraints(&json!([]))
+            .expect_err("value should violate the constraints of the data type");
+        assert!(report.frames().any(|frame| matches!(
+            frame.downcast_ref::<ArrayValidationError>(),
+            Some(ArrayValidationError::MinItems { .. })
+        )));
+
+        let report = short_non_empty_list
+            .validate_constraints(&json!([1, 2, 3, 4]))
+            .expect_err("value should violate the constraints of the parent");
+        assert!(report.frames().any(|frame| matches!(
+            frame.downcast_ref::<ArrayValidationError>(),
+            Some(ArrayValidationError::MaxItems { .. })
+        )));
+    }
+}
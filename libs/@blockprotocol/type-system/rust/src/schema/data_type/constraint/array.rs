@@ -7,7 +7,8 @@ use thiserror::Error;
 use crate::schema::{
     ConstraintError, JsonSchemaValueType, NumberSchema, StringSchema, ValueLabel,
     data_type::constraint::{
-        boolean::validate_boolean_value, number::validate_number_value,
+        boolean::validate_boolean_value,
+        number::{is_false, validate_number_value},
         string::validate_string_value,
     },
 };
@@ -28,6 +29,10 @@ pub enum ArrayValidationError {
     Items,
     #[error("The elements in the tuple do not match the expected item constraints")]
     PrefixItems,
+    #[error("The element at index `{index}` is equal to the element at index `{duplicate_of}`")]
+    UniqueItems { index: usize, duplicate_of: usize },
+    #[error("None of the elements in the array match the expected `contains` constraints")]
+    Contains,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -124,6 +129,14 @@ impl ArraySchema {
 pub struct ArrayConstraints {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub items: Option<ArrayItemsSchema>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_items: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<usize>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub unique_items: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains: Option<ArrayItemsSchema>,
 }
 
 impl ArrayConstraints {
@@ -132,8 +145,16 @@ impl ArrayConstraints {
     /// # Errors
     ///
     /// - [`Items`] if the value does not match the expected item constraints.
+    /// - [`MinItems`] if the value has too few items.
+    /// - [`MaxItems`] if the value has too many items.
+    /// - [`UniqueItems`] if the value contains duplicate items.
+    /// - [`Contains`] if no item of the value matches the `contains` constraints.
     ///
     /// [`Items`]: ArrayValidationError::Items
+    /// [`MinItems`]: ArrayValidationError::MinItems
+    /// [`MaxItems`]: ArrayValidationError::MaxItems
+    /// [`UniqueItems`]: ArrayValidationError::UniqueItems
+    /// [`Contains`]: ArrayValidationError::Contains
     pub fn validate_value(
         &self,
         values: &[JsonValue],
@@ -150,10 +171,53 @@ impl ArrayConstraints {
             );
         }
 
+        if let Some(expected) = self.min_items {
+            if values.len() < expected {
+                status.capture(ArrayValidationError::MinItems {
+                    actual: values.len(),
+                    expected,
+                });
+            }
+        }
+
+        if let Some(expected) = self.max_items {
+            if values.len() > expected {
+                status.capture(ArrayValidationError::MaxItems {
+                    actual: values.len(),
+                    expected,
+                });
+            }
+        }
+
+        if self.unique_items {
+            validate_unique_items(values, &mut status);
+        }
+
+        if let Some(contains) = &self.contains {
+            if !values
+                .iter()
+                .any(|value| contains.constraints.validate_value(value).is_ok())
+            {
+                status.capture(ArrayValidationError::Contains);
+            }
+        }
+
         status.finish()
     }
 }
 
+/// Captures an error for every item which is equal to a previous item.
+fn validate_unique_items(values: &[JsonValue], status: &mut ReportSink<ArrayValidationError>) {
+    for (index, value) in values.iter().enumerate() {
+        if let Some(duplicate_of) = values[..index].iter().position(|other| other == value) {
+            status.capture(ArrayValidationError::UniqueItems {
+                index,
+                duplicate_of,
+            });
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(target_arch = "wasm32", derive(tsify::Tsify))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -166,6 +230,8 @@ pub struct TupleConstraints {
         tsify(type = "[ArrayItemsSchema, ...ArrayItemsSchema[]]")
     )]
     pub prefix_items: Vec<ArrayItemsSchema>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub unique_items: bool,
 }
 
 impl TupleConstraints {
@@ -176,10 +242,12 @@ impl TupleConstraints {
     /// - [`MinItems`] if the value has too few items.
     /// - [`MaxItems`] if the value has too many items.
     /// - [`PrefixItems`] if the value does not match the expected item constraints.
+    /// - [`UniqueItems`] if the value contains duplicate items.
     ///
     /// [`MinItems`]: ArrayValidationError::MinItems
     /// [`MaxItems`]: ArrayValidationError::MaxItems
     /// [`PrefixItems`]: ArrayValidationError::PrefixItems
+    /// [`UniqueItems`]: ArrayValidationError::UniqueItems
     pub fn validate_value(
         &self,
         values: &[JsonValue],
@@ -211,6 +279,10 @@ impl TupleConstraints {
                 .change_context(ArrayValidationError::PrefixItems),
         );
 
+        if self.unique_items {
+            validate_unique_items(values, &mut status);
+        }
+
         status.finish()
     }
 }
@@ -333,6 +405,89 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn array_length() {
+        let array_schema = read_schema(&json!({
+            "type": "array",
+            "items": {
+                "type": "number",
+            },
+            "minItems": 1,
+            "maxItems": 2,
+        }));
+
+        check_constraints(&array_schema, &json!([1]));
+        check_constraints(&array_schema, &json!([1, 2]));
+        check_constraints_error(&array_schema, &json!([]), [
+            ArrayValidationError::MinItems {
+                actual: 0,
+                expected: 1,
+            },
+        ]);
+        check_constraints_error(&array_schema, &json!([1, 2, 3]), [
+            ArrayValidationError::MaxItems {
+                actual: 3,
+                expected: 2,
+            },
+        ]);
+    }
+
+    #[test]
+    fn unique_items() {
+        let array_schema = read_schema(&json!({
+            "type": "array",
+            "uniqueItems": true,
+        }));
+
+        check_constraints(&array_schema, &json!([]));
+        check_constraints(&array_schema, &json!([1, "1", true]));
+        check_constraints_error(&array_schema, &json!([1, 2, 1, 2]), [
+            ArrayValidationError::UniqueItems {
+                index: 2,
+                duplicate_of: 0,
+            },
+            ArrayValidationError::UniqueItems {
+                index: 3,
+                duplicate_of: 1,
+            },
+        ]);
+    }
+
+    #[test]
+    fn contains() {
+        let array_schema = read_schema(&json!({
+            "type": "array",
+            "contains": {
+                "type": "string",
+            },
+        }));
+
+        check_constraints(&array_schema, &json!(["a"]));
+        check_constraints(&array_schema, &json!([1, "a", true]));
+        check_constraints_error(&array_schema, &json!([]), [ArrayValidationError::Contains]);
+        check_constraints_error(&array_schema, &json!([1, 2]), [
+            ArrayValidationError::Contains,
+        ]);
+    }
+
+    #[test]
+    fn unique_tuple() {
+        let array_schema = read_schema(&json!({
+            "type": "array",
+            "items": false,
+            "prefixItems": [{ "type": "number" }, { "type": "number" }],
+            "uniqueItems": true,
+        }));
+
+        check_constraints(&array_schema, &json!([1, 2]));
+        check_constraints_error(&array_schema, &json!([1, 1]), [
+            ArrayValidationError::UniqueItems {
+                index: 1,
+                duplicate_of: 0,
+            },
+        ]);
+    }
+
     #[test]
     fn missing_type() {
         from_value::<ValueConstraints>(json!({
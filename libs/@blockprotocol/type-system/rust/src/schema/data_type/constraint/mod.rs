@@ -18,7 +18,7 @@ pub use self::{
     error::ConstraintError,
     null::NullTypeTag,
     number::{NumberConstraints, NumberSchema, NumberTypeTag, NumberValidationError},
-    object::ObjectTypeTag,
+    object::{ObjectConstraints, ObjectTypeTag, ObjectValidationError},
     string::{
         StringConstraints, StringFormat, StringFormatError, StringSchema, StringTypeTag,
         StringValidationError,
@@ -57,6 +57,30 @@ impl ValueConstraints {
             Self::AnyOf(constraints) => constraints.validate_value(value),
         }
     }
+
+    /// Returns `true` if every value which is valid against `other` is also valid against these
+    /// constraints.
+    ///
+    /// The check is conservative: `false` may be returned for equivalent constraints which are
+    /// expressed differently.
+    pub(crate) fn is_superset_of(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Typed(lhs), Self::Typed(rhs)) => lhs.is_superset_of(rhs),
+            (Self::Typed(lhs), Self::AnyOf(rhs)) => rhs
+                .any_of
+                .iter()
+                .all(|schema| lhs.is_superset_of(&schema.constraints)),
+            (Self::AnyOf(lhs), Self::Typed(rhs)) => lhs
+                .any_of
+                .iter()
+                .any(|schema| schema.constraints.is_superset_of(rhs)),
+            (Self::AnyOf(lhs), Self::AnyOf(rhs)) => rhs.any_of.iter().all(|rhs| {
+                lhs.any_of
+                    .iter()
+                    .any(|lhs| lhs.constraints.is_superset_of(&rhs.constraints))
+            }),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,7 +92,7 @@ pub enum SingleValueConstraints {
     Number(NumberSchema),
     String(StringSchema),
     Array(ArraySchema),
-    Object,
+    Object(ObjectConstraints),
 }
 
 impl SingleValueConstraints {
@@ -90,7 +114,30 @@ impl SingleValueConstraints {
             Self::Number(schema) => validate_number_value(value, schema),
             Self::String(schema) => validate_string_value(value, schema),
             Self::Array(array) => validate_array_value(value, array),
-            Self::Object => validate_object_value(value),
+            Self::Object(constraints) => validate_object_value(value, constraints),
+        }
+    }
+
+    fn is_superset_of(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Null, Self::Null) | (Self::Boolean, Self::Boolean) => true,
+            (Self::Number(lhs), Self::Number(rhs)) => lhs.is_superset_of(rhs),
+            (Self::String(lhs), Self::String(rhs)) => lhs.is_superset_of(rhs),
+            // Array constraints are only considered to accept the same values if they are equal.
+            (Self::Array(lhs), Self::Array(rhs)) => {
+                matches!(
+                    (serde_json::to_value(lhs), serde_json::to_value(rhs)),
+                    (Ok(lhs), Ok(rhs)) if lhs == rhs
+                )
+            }
+            // The same applies to object constraints.
+            (Self::Object(lhs), Self::Object(rhs)) => {
+                matches!(
+                    (serde_json::to_value(lhs), serde_json::to_value(rhs)),
+                    (Ok(lhs), Ok(rhs)) if lhs == rhs
+                )
+            }
+            _ => false,
         }
     }
 }
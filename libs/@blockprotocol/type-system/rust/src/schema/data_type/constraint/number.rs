@@ -9,7 +9,7 @@ use crate::schema::{ConstraintError, JsonSchemaValueType};
     clippy::trivially_copy_pass_by_ref,
     reason = "Only used in serde skip_serializing_if"
 )]
-const fn is_false(value: &bool) -> bool {
+pub(super) const fn is_false(value: &bool) -> bool {
     !*value
 }
 
@@ -153,6 +153,34 @@ impl NumberSchema {
         }
         Ok(())
     }
+
+    /// Returns `true` if every number which is valid against `other` is also valid against this
+    /// schema.
+    ///
+    /// The check is conservative: `false` may be returned for equivalent schemas which are
+    /// expressed differently.
+    pub(crate) fn is_superset_of(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Constrained(lhs), Self::Constrained(rhs)) => lhs.is_superset_of(rhs),
+            (Self::Constrained(lhs), Self::Const { r#const }) => {
+                lhs.validate_value(*r#const).is_ok()
+            }
+            (Self::Constrained(lhs), Self::Enum { r#enum }) => r#enum
+                .iter()
+                .all(|value| lhs.validate_value(*value).is_ok()),
+            (Self::Const { r#const: lhs }, Self::Const { r#const: rhs }) => float_eq(*lhs, *rhs),
+            (Self::Const { r#const }, Self::Enum { r#enum }) => {
+                r#enum.iter().all(|value| float_eq(*value, *r#const))
+            }
+            (Self::Enum { r#enum }, Self::Const { r#const }) => {
+                r#enum.iter().any(|value| float_eq(*value, *r#const))
+            }
+            (Self::Enum { r#enum: lhs }, Self::Enum { r#enum: rhs }) => rhs
+                .iter()
+                .all(|value| lhs.iter().any(|expected| float_eq(*value, *expected))),
+            (Self::Const { .. } | Self::Enum { .. }, Self::Constrained(_)) => false,
+        }
+    }
 }
 
 pub(crate) fn validate_number_value(
@@ -247,6 +275,32 @@ impl NumberConstraints {
 
         status.finish()
     }
+
+    fn is_superset_of(&self, other: &Self) -> bool {
+        let minimum_covered = match (self.minimum, other.minimum) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(lhs), Some(rhs)) => {
+                float_less(lhs, rhs)
+                    || (float_eq(lhs, rhs) && (!self.exclusive_minimum || other.exclusive_minimum))
+            }
+        };
+        let maximum_covered = match (self.maximum, other.maximum) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(lhs), Some(rhs)) => {
+                float_less(rhs, lhs)
+                    || (float_eq(lhs, rhs) && (!self.exclusive_maximum || other.exclusive_maximum))
+            }
+        };
+        let multiple_of_covered = match (self.multiple_of, other.multiple_of) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(lhs), Some(rhs)) => float_multiple_of(rhs, lhs),
+        };
+
+        minimum_covered && maximum_covered && multiple_of_covered
+    }
 }
 
 #[cfg(test)]
@@ -1,8 +1,31 @@
-use error_stack::{Report, bail};
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use error_stack::{Report, ReportSink, ResultExt, bail};
 use serde::{Deserialize, Serialize};
-use serde_json::Value as JsonValue;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use thiserror::Error;
+
+use crate::schema::{ConstraintError, JsonSchemaValueType, SingleValueSchema};
 
-use crate::schema::{ConstraintError, JsonSchemaValueType};
+#[derive(Debug, Error)]
+pub enum ObjectValidationError {
+    #[error(
+        "The object has too few properties, expected `{actual}` to be greater than or equal to \
+         `{expected}`"
+    )]
+    MinProperties { actual: usize, expected: usize },
+    #[error(
+        "The object has too many properties, expected `{actual}` to be less than or equal to \
+         `{expected}`"
+    )]
+    MaxProperties { actual: usize, expected: usize },
+    #[error("The required property `{property}` is missing")]
+    MissingRequired { property: String },
+    #[error("The property `{property}` is not allowed")]
+    AdditionalProperty { property: String },
+    #[error("The property `{property}` does not match the expected constraints")]
+    Property { property: String },
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(target_arch = "wasm32", derive(tsify::Tsify))]
@@ -11,9 +34,106 @@ pub enum ObjectTypeTag {
     Object,
 }
 
-pub(crate) fn validate_object_value(value: &JsonValue) -> Result<(), Report<ConstraintError>> {
-    if value.is_object() {
-        Ok(())
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(target_arch = "wasm32", derive(tsify::Tsify))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ObjectConstraints {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[cfg_attr(
+        target_arch = "wasm32",
+        tsify(type = "Record<string, SingleValueSchema>")
+    )]
+    pub properties: BTreeMap<String, SingleValueSchema>,
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string[]"))]
+    pub required: BTreeSet<String>,
+    /// Whether properties which are not listed in [`properties`] are allowed.
+    ///
+    /// If not specified, additional properties are allowed.
+    ///
+    /// [`properties`]: Self::properties
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_properties: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_properties: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_properties: Option<usize>,
+}
+
+impl ObjectConstraints {
+    /// Validates the provided value against the object constraints.
+    ///
+    /// # Errors
+    ///
+    /// - [`Property`] if a property does not match the expected constraints.
+    /// - [`MissingRequired`] if a required property is missing.
+    /// - [`AdditionalProperty`] if a property is not allowed.
+    /// - [`MinProperties`] if the value has too few properties.
+    /// - [`MaxProperties`] if the value has too many properties.
+    ///
+    /// [`Property`]: ObjectValidationError::Property
+    /// [`MissingRequired`]: ObjectValidationError::MissingRequired
+    /// [`AdditionalProperty`]: ObjectValidationError::AdditionalProperty
+    /// [`MinProperties`]: ObjectValidationError::MinProperties
+    /// [`MaxProperties`]: ObjectValidationError::MaxProperties
+    pub fn validate_value(
+        &self,
+        object: &JsonMap<String, JsonValue>,
+    ) -> Result<(), Report<[ObjectValidationError]>> {
+        let mut status = ReportSink::new();
+
+        for (property, value) in object {
+            if let Some(schema) = self.properties.get(property) {
+                status.attempt(schema.constraints.validate_value(value).change_context(
+                    ObjectValidationError::Property {
+                        property: property.clone(),
+                    },
+                ));
+            } else if self.additional_properties == Some(false) {
+                status.capture(ObjectValidationError::AdditionalProperty {
+                    property: property.clone(),
+                });
+            }
+        }
+
+        for property in &self.required {
+            if !object.contains_key(property) {
+                status.capture(ObjectValidationError::MissingRequired {
+                    property: property.clone(),
+                });
+            }
+        }
+
+        if let Some(expected) = self.min_properties {
+            if object.len() < expected {
+                status.capture(ObjectValidationError::MinProperties {
+                    actual: object.len(),
+                    expected,
+                });
+            }
+        }
+
+        if let Some(expected) = self.max_properties {
+            if object.len() > expected {
+                status.capture(ObjectValidationError::MaxProperties {
+                    actual: object.len(),
+                    expected,
+                });
+            }
+        }
+
+        status.finish()
+    }
+}
+
+pub(crate) fn validate_object_value(
+    value: &JsonValue,
+    constraints: &ObjectConstraints,
+) -> Result<(), Report<ConstraintError>> {
+    if let JsonValue::Object(object) = value {
+        constraints
+            .validate_value(object)
+            .change_context(ConstraintError::ValueConstraint)
     } else {
         bail!(ConstraintError::InvalidType {
             actual: JsonSchemaValueType::from(value),
@@ -21,3 +141,190 @@ pub(crate) fn validate_object_value(value: &JsonValue) -> Result<(), Report<Cons
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+    use crate::schema::{
+        StringValidationError,
+        data_type::constraint::{
+            ValueConstraints,
+            tests::{check_constraints, check_constraints_error, read_schema},
+        },
+    };
+
+    #[test]
+    fn unconstrained() {
+        let object_schema = read_schema(&json!({
+            "type": "object",
+        }));
+
+        check_constraints(&object_schema, &json!({}));
+        check_constraints(&object_schema, &json!({ "a": 1, "b": "2" }));
+        check_constraints_error(&object_schema, &json!([]), [ConstraintError::InvalidType {
+            actual: JsonSchemaValueType::Array,
+            expected: JsonSchemaValueType::Object,
+        }]);
+    }
+
+    #[test]
+    fn properties() {
+        let object_schema = read_schema(&json!({
+            "type": "object",
+            "properties": {
+                "street": {
+                    "type": "string",
+                    "description": "The street of the address",
+                    "minLength": 1,
+                },
+                "number": {
+                    "type": "number",
+                },
+            },
+            "required": ["street"],
+        }));
+
+        check_constraints(&object_schema, &json!({ "street": "Main Street" }));
+        check_constraints(
+            &object_schema,
+            &json!({ "street": "Main Street", "number": 5, "city": "London" }),
+        );
+        check_constraints_error(&object_schema, &json!({ "number": 5 }), [
+            ObjectValidationError::MissingRequired {
+                property: "street".to_owned(),
+            },
+        ]);
+        check_constraints_error(&object_schema, &json!({ "street": "" }), [
+            ObjectValidationError::Property {
+                property: "street".to_owned(),
+            },
+        ]);
+        check_constraints_error(&object_schema, &json!({ "street": "" }), [
+            StringValidationError::MinLength {
+                actual: 0,
+                expected: 1,
+            },
+        ]);
+    }
+
+    #[test]
+    fn multiple_required() {
+        let object_schema = read_schema(&json!({
+            "type": "object",
+            "properties": {
+                "amount": {
+                    "type": "number",
+                },
+                "currency": {
+                    "type": "string",
+                },
+            },
+            "required": ["currency", "amount"],
+        }));
+
+        check_constraints(&object_schema, &json!({ "amount": 5, "currency": "GBP" }));
+        check_constraints_error(&object_schema, &json!({ "amount": 5 }), [
+            ObjectValidationError::MissingRequired {
+                property: "currency".to_owned(),
+            },
+        ]);
+        check_constraints_error(&object_schema, &json!({}), [
+            ObjectValidationError::MissingRequired {
+                property: "amount".to_owned(),
+            },
+            ObjectValidationError::MissingRequired {
+                property: "currency".to_owned(),
+            },
+        ]);
+    }
+
+    #[test]
+    fn additional_properties() {
+        let object_schema = read_schema(&json!({
+            "type": "object",
+            "properties": {
+                "amount": {
+                    "type": "number",
+                },
+                "currency": {
+                    "type": "string",
+                },
+            },
+            "additionalProperties": false,
+        }));
+
+        check_constraints(&object_schema, &json!({ "amount": 5, "currency": "GBP" }));
+        check_constraints_error(
+            &object_schema,
+            &json!({ "amount": 5, "currency": "GBP", "rate": 1 }),
+            [ObjectValidationError::AdditionalProperty {
+                property: "rate".to_owned(),
+            }],
+        );
+    }
+
+    #[test]
+    fn object_length() {
+        let object_schema = read_schema(&json!({
+            "type": "object",
+            "minProperties": 1,
+            "maxProperties": 2,
+        }));
+
+        check_constraints(&object_schema, &json!({ "a": 1 }));
+        check_constraints(&object_schema, &json!({ "a": 1, "b": 2 }));
+        check_constraints_error(&object_schema, &json!({}), [
+            ObjectValidationError::MinProperties {
+                actual: 0,
+                expected: 1,
+            },
+        ]);
+        check_constraints_error(&object_schema, &json!({ "a": 1, "b": 2, "c": 3 }), [
+            ObjectValidationError::MaxProperties {
+                actual: 3,
+                expected: 2,
+            },
+        ]);
+    }
+
+    #[test]
+    fn nested_object() {
+        let object_schema = read_schema(&json!({
+            "type": "object",
+            "properties": {
+                "location": {
+                    "type": "object",
+                    "properties": {
+                        "latitude": {
+                            "type": "number",
+                            "minimum": -90.0,
+                            "maximum": 90.0,
+                        },
+                    },
+                    "required": ["latitude"],
+                },
+            },
+        }));
+
+        check_constraints(&object_schema, &json!({ "location": { "latitude": 51.5 } }));
+        check_constraints_error(&object_schema, &json!({ "location": {} }), [
+            ObjectValidationError::Property {
+                property: "location".to_owned(),
+            },
+            ObjectValidationError::MissingRequired {
+                property: "latitude".to_owned(),
+            },
+        ]);
+    }
+
+    #[test]
+    fn additional_object_properties() {
+        from_value::<ValueConstraints>(json!({
+            "type": "object",
+            "unknown": "property",
+        }))
+        .expect_err("Deserialized object schema with unknown property");
+    }
+}
@@ -21,12 +21,20 @@ use crate::schema::{ConstraintError, JsonSchemaValueType};
 #[serde(rename_all = "kebab-case")]
 pub enum StringFormat {
     Uri,
+    UriReference,
+    Iri,
+    IriReference,
+    UriTemplate,
+    JsonPointer,
+    RelativeJsonPointer,
     Hostname,
+    IdnHostname,
     Ipv4,
     Ipv6,
     Uuid,
     Regex,
     Email,
+    IdnEmail,
     Date,
     Time,
     DateTime,
@@ -37,12 +45,20 @@ impl StringFormat {
     pub(crate) const fn as_str(self) -> &'static str {
         match self {
             Self::Uri => "uri",
+            Self::UriReference => "uri-reference",
+            Self::Iri => "iri",
+            Self::IriReference => "iri-reference",
+            Self::UriTemplate => "uri-template",
+            Self::JsonPointer => "json-pointer",
+            Self::RelativeJsonPointer => "relative-json-pointer",
             Self::Hostname => "hostname",
+            Self::IdnHostname => "idn-hostname",
             Self::Ipv4 => "ipv4",
             Self::Ipv6 => "ipv6",
             Self::Uuid => "uuid",
             Self::Regex => "regex",
             Self::Email => "email",
+            Self::IdnEmail => "idn-email",
             Self::Date => "date",
             Self::Time => "time",
             Self::DateTime => "date-time",
@@ -55,6 +71,22 @@ impl StringFormat {
 pub enum StringFormatError {
     #[error(transparent)]
     Url(url::ParseError),
+    #[error("The character `{0}` is not allowed in the value")]
+    Character(char),
+    #[error("The value contains a `%` which is not followed by two hexadecimal digits")]
+    PercentEncoding,
+    #[error("The value is not a valid URI template")]
+    UriTemplate,
+    #[error(
+        "The value is not a valid JSON pointer, expected it to be empty or to start with `/` and \
+         to only contain `~0` and `~1` as escape sequences"
+    )]
+    JsonPointer,
+    #[error(
+        "The value is not a valid relative JSON pointer, expected a non-negative integer followed \
+         by `#` or a JSON pointer"
+    )]
+    RelativeJsonPointer,
     #[error(transparent)]
     Uuid(uuid::Error),
     #[error(transparent)]
@@ -78,7 +110,13 @@ impl StringFormat {
     ///
     /// # Errors
     ///
-    /// - [`Url`] if the value is not a valid URL.
+    /// - [`Url`] if the value is not a valid URL, URI reference, IRI, IRI reference, or hostname.
+    /// - [`Character`] if the value contains a character which is not allowed in a URI, IRI, or
+    ///   their references.
+    /// - [`PercentEncoding`] if the value contains an invalid percent-encoding.
+    /// - [`UriTemplate`] if the value is not a valid URI template as specified in RFC 6570.
+    /// - [`JsonPointer`] if the value is not a valid JSON pointer as specified in RFC 6901.
+    /// - [`RelativeJsonPointer`] if the value is not a valid relative JSON pointer.
     /// - [`IpAddress`] if the value is not a valid IP address as specified by [`Ipv4Addr`] or
     ///   [`Ipv6Addr`].
     /// - [`Uuid`] if the value is not a valid [UUID][uuid::Uuid].
@@ -91,6 +129,11 @@ impl StringFormat {
     /// - [`Duration`] if the value is not a valid [ISO 8601 duration][iso8601_duration::Duration].
     ///
     /// [`Url`]: StringFormatError::Url
+    /// [`Character`]: StringFormatError::Character
+    /// [`PercentEncoding`]: StringFormatError::PercentEncoding
+    /// [`UriTemplate`]: StringFormatError::UriTemplate
+    /// [`JsonPointer`]: StringFormatError::JsonPointer
+    /// [`RelativeJsonPointer`]: StringFormatError::RelativeJsonPointer
     /// [`IpAddress`]: StringFormatError::IpAddress
     /// [`Uuid`]: StringFormatError::Uuid
     /// [`Regex`]: StringFormatError::Regex
@@ -112,11 +155,30 @@ impl StringFormat {
 
         static DATE_TIME_REGEX: OnceLock<Regex> = OnceLock::new();
 
+        // References are resolved against an arbitrary base to check that they are well-formed
+        static REFERENCE_BASE: OnceLock<Url> = OnceLock::new();
+
         match self {
             Self::Uri => {
                 Url::parse(value).map_err(StringFormatError::Url)?;
             }
-            Self::Hostname => {
+            Self::UriReference | Self::IriReference => {
+                validate_reference_characters(value, self == Self::IriReference)?;
+                REFERENCE_BASE
+                    .get_or_init(|| {
+                        Url::parse("https://example.com/").expect("failed to parse reference base")
+                    })
+                    .join(value)
+                    .map_err(StringFormatError::Url)?;
+            }
+            Self::Iri => {
+                validate_reference_characters(value, true)?;
+                Url::parse(value).map_err(StringFormatError::Url)?;
+            }
+            Self::UriTemplate => validate_uri_template(value)?,
+            Self::JsonPointer => validate_json_pointer(value)?,
+            Self::RelativeJsonPointer => validate_relative_json_pointer(value)?,
+            Self::Hostname | Self::IdnHostname => {
                 Host::parse(value).map_err(StringFormatError::Url)?;
             }
             Self::Ipv4 => {
@@ -131,7 +193,7 @@ impl StringFormat {
             Self::Regex => {
                 Regex::new(value).map_err(StringFormatError::Regex)?;
             }
-            Self::Email => {
+            Self::Email | Self::IdnEmail => {
                 EmailAddress::from_str(value).map_err(StringFormatError::Email)?;
             }
             Self::Date => {
@@ -172,6 +234,152 @@ impl StringFormat {
     }
 }
 
+/// Checks that the value only consists of characters allowed in a URI reference as specified in
+/// RFC 3986.
+///
+/// If `allow_non_ascii` is set, non-ASCII characters are allowed as well, as done by IRI
+/// references in RFC 3987.
+fn validate_reference_characters(
+    value: &str,
+    allow_non_ascii: bool,
+) -> Result<(), StringFormatError> {
+    let mut characters = value.chars();
+    while let Some(character) = characters.next() {
+        match character {
+            '%' => validate_percent_encoding(&mut characters)?,
+            _ if character.is_ascii_alphanumeric()
+                || "-._~:/?#[]@!$&'()*+,;=".contains(character) => {}
+            _ if allow_non_ascii && !character.is_ascii() && !character.is_control() => {}
+            _ => return Err(StringFormatError::Character(character)),
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the characters following a `%` are two hexadecimal digits.
+fn validate_percent_encoding(
+    characters: &mut impl Iterator<Item = char>,
+) -> Result<(), StringFormatError> {
+    if characters
+        .next()
+        .is_some_and(|character| character.is_ascii_hexdigit())
+        && characters
+            .next()
+            .is_some_and(|character| character.is_ascii_hexdigit())
+    {
+        Ok(())
+    } else {
+        Err(StringFormatError::PercentEncoding)
+    }
+}
+
+/// Checks that the value is a URI template as specified in RFC 6570.
+fn validate_uri_template(value: &str) -> Result<(), StringFormatError> {
+    fn validate_literals(literals: &str) -> Result<(), StringFormatError> {
+        let mut characters = literals.chars();
+        while let Some(character) = characters.next() {
+            match character {
+                '%' => validate_percent_encoding(&mut characters)?,
+                ' ' | '"' | '\'' | '<' | '>' | '\\' | '^' | '`' | '{' | '|' | '}' => {
+                    return Err(StringFormatError::Character(character));
+                }
+                _ if character.is_control() => return Err(StringFormatError::Character(character)),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_variable_name(name: &str) -> Result<(), StringFormatError> {
+        for segment in name.split('.') {
+            if segment.is_empty() {
+                return Err(StringFormatError::UriTemplate);
+            }
+            let mut characters = segment.chars();
+            while let Some(character) = characters.next() {
+                match character {
+                    '%' => validate_percent_encoding(&mut characters)?,
+                    'a'..='z' | 'A'..='Z' | '0'..='9' | '_' => {}
+                    _ => return Err(StringFormatError::UriTemplate),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_expression(expression: &str) -> Result<(), StringFormatError> {
+        let variables = expression
+            .strip_prefix(['+', '#', '.', '/', ';', '?', '&', '=', ',', '!', '@', '|'])
+            .unwrap_or(expression);
+        for variable in variables.split(',') {
+            let name = if let Some(name) = variable.strip_suffix('*') {
+                name
+            } else if let Some((name, max_length)) = variable.split_once(':') {
+                if max_length.is_empty()
+                    || max_length.len() > 4
+                    || max_length.starts_with('0')
+                    || !max_length.bytes().all(|byte| byte.is_ascii_digit())
+                {
+                    return Err(StringFormatError::UriTemplate);
+                }
+                name
+            } else {
+                variable
+            };
+            validate_variable_name(name)?;
+        }
+        Ok(())
+    }
+
+    let mut parts = value.split('{');
+    validate_literals(parts.next().unwrap_or_default())?;
+    for part in parts {
+        let (expression, literals) = part.split_once('}').ok_or(StringFormatError::UriTemplate)?;
+        validate_expression(expression)?;
+        validate_literals(literals)?;
+    }
+    Ok(())
+}
+
+/// Checks that the value is a JSON pointer as specified in RFC 6901.
+fn validate_json_pointer(value: &str) -> Result<(), StringFormatError> {
+    if !value.is_empty() && !value.starts_with('/') {
+        return Err(StringFormatError::JsonPointer);
+    }
+    let mut characters = value.chars();
+    while let Some(character) = characters.next() {
+        if character == '~' && !matches!(characters.next(), Some('0' | '1')) {
+            return Err(StringFormatError::JsonPointer);
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the value is a relative JSON pointer, i.e. a non-negative integer, optionally
+/// followed by an index manipulation, and either `#` or a JSON pointer.
+fn validate_relative_json_pointer(value: &str) -> Result<(), StringFormatError> {
+    /// Strips a non-negative integer without leading zeros from the start of the value.
+    fn strip_non_negative_integer(value: &str) -> Option<&str> {
+        let end = value
+            .find(|character: char| !character.is_ascii_digit())
+            .unwrap_or(value.len());
+        let (integer, rest) = value.split_at(end);
+        (integer == "0" || (!integer.is_empty() && !integer.starts_with('0'))).then_some(rest)
+    }
+
+    let mut rest =
+        strip_non_negative_integer(value).ok_or(StringFormatError::RelativeJsonPointer)?;
+    if let Some(index_manipulation) = rest.strip_prefix(['+', '-']) {
+        rest = strip_non_negative_integer(index_manipulation)
+            .ok_or(StringFormatError::RelativeJsonPointer)?;
+    }
+    if rest == "#" || validate_json_pointer(rest).is_ok() {
+        Ok(())
+    } else {
+        Err(StringFormatError::RelativeJsonPointer)
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum StringValidationError {
     #[error(
@@ -257,6 +465,30 @@ impl StringSchema {
         }
         Ok(())
     }
+
+    /// Returns `true` if every string which is valid against `other` is also valid against this
+    /// schema.
+    ///
+    /// The check is conservative: `false` may be returned for equivalent schemas which are
+    /// expressed differently.
+    pub(crate) fn is_superset_of(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Constrained(lhs), Self::Constrained(rhs)) => lhs.is_superset_of(rhs),
+            (Self::Constrained(lhs), Self::Const { r#const }) => {
+                lhs.validate_value(r#const).is_ok()
+            }
+            (Self::Constrained(lhs), Self::Enum { r#enum }) => {
+                r#enum.iter().all(|value| lhs.validate_value(value).is_ok())
+            }
+            (Self::Const { r#const: lhs }, Self::Const { r#const: rhs }) => lhs == rhs,
+            (Self::Const { r#const }, Self::Enum { r#enum }) => {
+                r#enum.iter().all(|value| value == r#const)
+            }
+            (Self::Enum { r#enum }, Self::Const { r#const }) => r#enum.contains(r#const),
+            (Self::Enum { r#enum: lhs }, Self::Enum { r#enum: rhs }) => rhs.is_subset(lhs),
+            (Self::Const { .. } | Self::Enum { .. }, Self::Constrained(_)) => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -330,6 +562,29 @@ impl StringConstraints {
 
         status.finish()
     }
+
+    fn is_superset_of(&self, other: &Self) -> bool {
+        let min_length_covered = match (self.min_length, other.min_length) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(lhs), Some(rhs)) => lhs <= rhs,
+        };
+        let max_length_covered = match (self.max_length, other.max_length) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(lhs), Some(rhs)) => lhs >= rhs,
+        };
+        // Regular expressions cannot be compared in general, so only an unchanged pattern is
+        // considered to accept the same strings.
+        let pattern_covered = match (&self.pattern, &other.pattern) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(lhs), Some(rhs)) => lhs.as_str() == rhs.as_str(),
+        };
+        let format_covered = self.format.is_none() || self.format == other.format;
+
+        min_length_covered && max_length_covered && pattern_covered && format_covered
+    }
 }
 
 pub(crate) fn validate_string_value(
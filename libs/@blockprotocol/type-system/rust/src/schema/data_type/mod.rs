@@ -6,9 +6,10 @@ pub use self::{
     constraint::{
         AnyOfConstraints, ArrayConstraints, ArraySchema, ArrayTypeTag, ArrayValidationError,
         BooleanTypeTag, ConstraintError, NullTypeTag, NumberConstraints, NumberSchema,
-        NumberTypeTag, NumberValidationError, ObjectTypeTag, SingleValueConstraints,
-        SingleValueSchema, StringConstraints, StringFormat, StringFormatError, StringSchema,
-        StringTypeTag, StringValidationError, TupleConstraints,
+        NumberTypeTag, NumberValidationError, ObjectConstraints, ObjectTypeTag,
+        ObjectValidationError, SingleValueConstraints, SingleValueSchema, StringConstraints,
+        StringFormat, StringFormatError, StringSchema, StringTypeTag, StringValidationError,
+        TupleConstraints,
     },
     conversion::{
         ConversionDefinition, ConversionExpression, ConversionValue, Conversions, Operator,
@@ -151,8 +152,8 @@ mod raw {
             ObjectTypeTag, StringTypeTag, ValueLabel,
             data_type::constraint::{
                 AnyOfConstraints, ArrayConstraints, ArraySchema, NumberConstraints, NumberSchema,
-                SingleValueConstraints, StringConstraints, StringSchema, TupleConstraints,
-                ValueConstraints,
+                ObjectConstraints, SingleValueConstraints, StringConstraints, StringSchema,
+                TupleConstraints, ValueConstraints,
             },
         },
         url::VersionedUrl,
@@ -236,6 +237,8 @@ mod raw {
             r#type: ObjectTypeTag,
             #[serde(flatten)]
             common: ValueSchemaMetadata,
+            #[serde(flatten)]
+            constraints: ObjectConstraints,
         },
         Array {
             r#type: ArrayTypeTag,
@@ -357,9 +360,13 @@ mod raw {
                         r#enum,
                     })),
                 ),
-                DataType::Object { r#type: _, common } => (
+                DataType::Object {
+                    r#type: _,
+                    common,
+                    constraints,
+                } => (
                     common,
-                    ValueConstraints::Typed(SingleValueConstraints::Object),
+                    ValueConstraints::Typed(SingleValueConstraints::Object(constraints)),
                 ),
                 DataType::Array {
                     r#type: _,
@@ -799,6 +806,56 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    async fn constrained_array() {
+        ensure_validation::<DataType, _>(
+            json!({
+              "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+              "kind": "dataType",
+              "$id": "https://blockprotocol.org/@blockprotocol/types/data-type/tags/v/1",
+              "title": "Tags",
+              "description": "A non-empty list of distinct tags",
+              "allOf": [{ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/list/v/1" }],
+              "type": "array",
+              "abstract": false,
+              "items": { "type": "string" },
+              "minItems": 1,
+              "maxItems": 10,
+              "uniqueItems": true,
+              "contains": { "type": "string", "minLength": 1 },
+            }),
+            DataTypeValidator,
+            JsonEqualityCheck::Yes,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn constrained_object() {
+        ensure_validation::<DataType, _>(
+            json!({
+              "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+              "kind": "dataType",
+              "$id": "https://blockprotocol.org/@blockprotocol/types/data-type/money/v/1",
+              "title": "Money",
+              "description": "An amount of money in a specific currency",
+              "allOf": [{ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/object/v/1" }],
+              "type": "object",
+              "abstract": false,
+              "properties": {
+                "amount": { "type": "number" },
+                "currency": { "type": "string", "minLength": 3, "maxLength": 3 },
+              },
+              "required": ["currency"],
+              "additionalProperties": false,
+              "minProperties": 1,
+            }),
+            DataTypeValidator,
+            JsonEqualityCheck::Yes,
+        )
+        .await;
+    }
+
     #[test]
     fn additional_properties() {
         // The error is suboptimal, but most importantly, it does error.
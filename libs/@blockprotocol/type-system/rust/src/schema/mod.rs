@@ -10,21 +10,23 @@ mod entity_type;
 mod property_type;
 
 mod array;
+mod compatibility;
 mod object;
 mod one_of;
 
 pub use self::{
     array::{PropertyArraySchema, PropertyValueArray, ValueOrArray},
+    compatibility::{Compatibility, TypeChange},
     data_type::{
         AnyOfConstraints, ArrayConstraints, ArraySchema, ArrayTypeTag, ArrayValidationError,
         BooleanTypeTag, ClosedDataType, ConstraintError, ConversionDefinition,
         ConversionExpression, ConversionValue, Conversions, DataType, DataTypeId,
         DataTypeInheritanceData, DataTypeReference, DataTypeValidator, InheritanceDepth,
         JsonSchemaValueType, NullTypeTag, NumberConstraints, NumberSchema, NumberTypeTag,
-        NumberValidationError, ObjectTypeTag, OntologyTypeResolver, Operator,
-        SingleValueConstraints, SingleValueSchema, StringConstraints, StringFormat,
-        StringFormatError, StringSchema, StringTypeTag, StringValidationError, TupleConstraints,
-        ValidateDataTypeError, ValueLabel, Variable,
+        NumberValidationError, ObjectConstraints, ObjectTypeTag, ObjectValidationError,
+        OntologyTypeResolver, Operator, SingleValueConstraints, SingleValueSchema,
+        StringConstraints, StringFormat, StringFormatError, StringSchema, StringTypeTag,
+        StringValidationError, TupleConstraints, ValidateDataTypeError, ValueLabel, Variable,
     },
     entity_type::{
         ClosedEntityType, ClosedEntityTypeSchemaData, EntityType, EntityTypeReference,
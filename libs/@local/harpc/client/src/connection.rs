@@ -0,0 +1,116 @@
+use alloc::sync::Arc;
+use core::future::ready;
+
+use bytes::{Buf, Bytes};
+use futures::StreamExt;
+use harpc_net::{codec::ErrorEncoder, session::client};
+use harpc_service::role::ClientSession;
+use harpc_tower::{
+    body::{Body, BodyExt, controlled::Controlled, full::Full},
+    either::Either,
+    metadata::Metadata,
+    net::unpack::Unpack,
+    request::Request,
+    response::{Parts, Response},
+};
+use harpc_wire_protocol::response::kind::ResponseKind;
+
+/// A connection to a harpc server.
+///
+/// Every request sent over the connection is a separate transaction, a connection can therefore be
+/// shared and cloned freely.
+#[derive_where::derive_where(Clone; C)]
+pub struct Connection<C> {
+    inner: Arc<client::Connection>,
+
+    codec: C,
+}
+
+impl<C> Connection<C> {
+    pub(crate) fn new(inner: client::Connection, codec: C) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            codec,
+        }
+    }
+
+    /// The codec used to encode and decode values sent over this connection.
+    pub const fn codec(&self) -> &C {
+        &self.codec
+    }
+
+    /// Check if the connection is healthy.
+    ///
+    /// An unhealthy connection is in the process of being closed, any request sent over it will
+    /// fail.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.inner.is_healthy()
+    }
+}
+
+impl<C> ClientSession for Connection<C>
+where
+    C: ErrorEncoder + Send + Sync,
+{
+    type Body = Either<Unpack, Controlled<ResponseKind, Full<Bytes>>>;
+
+    async fn send_request<B>(&self, request: Request<B>) -> Response<Self::Body>
+    where
+        B: Body<Control = !, Data: Send, Error: Send> + Send + 'static,
+    {
+        let service = request.service();
+        let procedure = request.procedure();
+        let parts = Parts::new(request.session());
+
+        // Metadata is best-effort, if it cannot be sent the request is still sent without it.
+        let metadata =
+            request
+                .extensions()
+                .get::<Metadata>()
+                .and_then(|metadata| match metadata.encode() {
+                    Ok(bytes) => client::RequestMetadata::new(bytes)
+                        .inspect_err(|error| {
+                            tracing::warn!(%error, "request metadata is too large, omitting...");
+                        })
+                        .ok(),
+                    Err(error) => {
+                        tracing::warn!(?error, "unable to encode request metadata, omitting...");
+                        None
+                    }
+                });
+
+        // The transaction is unable to carry errors in the request, the request is terminated once
+        // the body reports its first error, the server is then responsible to detect the truncated
+        // payload.
+        let payload = request
+            .into_body()
+            .into_stream()
+            .into_data_stream()
+            .take_while(|data| ready(data.is_ok()))
+            .filter_map(|data| ready(data.ok()))
+            .map(|mut data| data.copy_to_bytes(data.remaining()));
+
+        let response = match metadata {
+            Some(metadata) => {
+                self.inner
+                    .call_with_metadata(service, procedure, metadata, payload)
+                    .await
+            }
+            None => self.inner.call(service, procedure, payload).await,
+        };
+
+        match response {
+            // Dropping the response before it has been received completely means that the caller
+            // has given up on the request, so there's no need for the server to continue.
+            Ok(stream) => {
+                Response::from_parts(parts, Either::Left(Unpack::new(stream.cancel_on_drop())))
+            }
+            Err(report) => {
+                let error = self.codec.encode_report(report).await;
+
+                Response::from_error(parts, error).map_body(Either::Right)
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use harpc_wire_protocol::response::kind::ErrorCode;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+pub enum ClientError {
+    #[error("unable to start the transport layer")]
+    StartTransportLayer,
+    #[error("unable to establish a connection to the server")]
+    Connect,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+pub enum CallError {
+    #[error("unable to encode the request")]
+    Encode,
+    #[error("unable to decode the response")]
+    Decode,
+    #[error("the server did not indicate the kind of the response")]
+    MissingResponseKind,
+    #[error("the server returned an empty response")]
+    EmptyResponse,
+    #[error("the server responded with an error (code: {code:?})")]
+    Remote { code: ErrorCode },
+}
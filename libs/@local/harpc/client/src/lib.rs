@@ -0,0 +1,90 @@
+#![feature(never_type)]
+
+extern crate alloc;
+
+pub mod connection;
+pub mod error;
+pub mod service;
+
+use alloc::sync::Arc;
+
+use error_stack::{Report, ResultExt};
+use harpc_net::{
+    session::client::{SessionConfig, SessionLayer},
+    transport::{Transport, TransportConfig, TransportLayer},
+};
+use libp2p_core::Multiaddr;
+use tokio_util::sync::{CancellationToken, DropGuard};
+
+use self::{connection::Connection, error::ClientError};
+
+/// Configuration used to create a new [`Client`].
+#[derive(Default)]
+pub struct ClientConfig {
+    pub transport: TransportConfig,
+    pub session: SessionConfig,
+}
+
+struct ClientInner {
+    session: SessionLayer,
+
+    _guard: DropGuard,
+}
+
+/// A harpc client, which is able to establish connections to harpc servers.
+///
+/// The client is cheap to clone, all clones share the same underlying transport layer. The
+/// transport layer is shut down once the last clone of the client has been dropped.
+#[derive_where::derive_where(Clone; C)]
+pub struct Client<C> {
+    inner: Arc<ClientInner>,
+
+    codec: C,
+}
+
+impl<C> Client<C> {
+    /// Create a new client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying transport layer fails to start.
+    pub fn new(
+        config: ClientConfig,
+        transport: impl Transport,
+        codec: C,
+    ) -> Result<Self, Report<ClientError>> {
+        let cancel = CancellationToken::new();
+
+        let transport = TransportLayer::start(config.transport, transport, cancel.clone())
+            .change_context(ClientError::StartTransportLayer)?;
+
+        let session = SessionLayer::new(config.session, transport);
+
+        Ok(Self {
+            inner: Arc::new(ClientInner {
+                session,
+                _guard: cancel.drop_guard(),
+            }),
+            codec,
+        })
+    }
+
+    /// Establish a new connection to the server at the given address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server cannot be reached.
+    pub async fn connect(&self, address: Multiaddr) -> Result<Connection<C>, Report<ClientError>>
+    where
+        C: Clone,
+    {
+        let connection = self
+            .inner
+            .session
+            .dial(address)
+            .await
+            .change_context(ClientError::Connect)?;
+
+        Ok(Connection::new(connection, self.codec.clone()))
+    }
+}
@@ -0,0 +1,184 @@
+use core::{future::ready, marker::PhantomData, pin::pin};
+
+use bytes::{Buf, Bytes};
+use error_stack::Report;
+use futures::{StreamExt, TryStreamExt, stream};
+use harpc_net::{
+    codec::{ErrorDecoder, ErrorEncoder, ValueDecoder, ValueEncoder},
+    session::client::CLIENT_SESSION_ID,
+};
+use harpc_service::{
+    Service,
+    procedure::{Procedure, ProcedureIdentifier},
+    role::ClientSession,
+};
+use harpc_tower::{
+    Extensions,
+    body::{BodyExt, Frame, stream::StreamBody},
+    metadata::Metadata,
+    request::{self, Request},
+};
+use harpc_wire_protocol::{
+    request::{procedure::ProcedureDescriptor, service::ServiceDescriptor},
+    response::kind::{ErrorCode, ResponseKind},
+};
+use tracing_opentelemetry::OpenTelemetrySpanExt as _;
+
+use crate::{connection::Connection, error::CallError};
+
+/// Returns `true` if the error has been emitted by the server, client or session layer of the
+/// server.
+///
+/// These errors are never wrapped in a report and need to be decoded as plain errors.
+const fn is_plain_error(code: ErrorCode) -> bool {
+    code.is_server_layer() || code.is_client_layer() || code.is_session_layer()
+}
+
+/// A codec which is able to encode the procedure `P` and decode its response `R`.
+///
+/// This trait is implemented for every codec that satisfies its bounds, it exists to shorten the
+/// bounds required by [`ServiceClient::call`].
+pub trait CallCodec<P, R>:
+    ValueEncoder<P, Error: Send + Sync + 'static>
+    + ErrorEncoder
+    + ValueDecoder<R, Error: Send + Sync + 'static>
+    + ErrorDecoder<Output: Send + Sync + 'static, Error: Send + Sync + 'static>
+    + Clone
+    + Send
+    + Sync
+{
+}
+
+impl<C, P, R> CallCodec<P, R> for C where
+    C: ValueEncoder<P, Error: Send + Sync + 'static>
+        + ErrorEncoder
+        + ValueDecoder<R, Error: Send + Sync + 'static>
+        + ErrorDecoder<Output: Send + Sync + 'static, Error: Send + Sync + 'static>
+        + Clone
+        + Send
+        + Sync
+{
+}
+
+/// A typed stub to call the procedures of the service `S`.
+#[derive_where::derive_where(Clone; C)]
+pub struct ServiceClient<S, C> {
+    connection: Connection<C>,
+
+    _service: PhantomData<fn() -> *const S>,
+}
+
+impl<S, C> ServiceClient<S, C>
+where
+    S: Service,
+{
+    #[must_use]
+    pub const fn new(connection: Connection<C>) -> Self {
+        Self {
+            connection,
+            _service: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub const fn connection(&self) -> &Connection<C> {
+        &self.connection
+    }
+
+    /// Call the procedure `P` of the service and wait for its response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be encoded, the response cannot be decoded, or the
+    /// server responded with an error. Errors returned by the server are attached to the report
+    /// in their decoded form.
+    pub async fn call<P, R>(&self, procedure: P) -> Result<R, Report<CallError>>
+    where
+        P: Procedure<Service = S> + Send + Sync,
+        C: CallCodec<P, R>,
+    {
+        let codec = self.connection.codec();
+
+        let payload: Vec<Bytes> = codec
+            .clone()
+            .encode_stream(stream::iter([procedure]))
+            .await
+            .try_collect()
+            .await
+            .map_err(|error| Report::new(CallError::Encode).attach(error))?;
+
+        // Propagate the trace context of the caller, so that the server is able to correlate its
+        // spans with ours.
+        let mut metadata = Metadata::new();
+        let context = tracing::Span::current().context();
+        opentelemetry::global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&context, &mut metadata);
+        });
+
+        let mut extensions = Extensions::new();
+        if !metadata.is_empty() {
+            extensions.insert(metadata);
+        }
+
+        let request = Request::new(
+            request::Parts {
+                service: ServiceDescriptor {
+                    id: S::ID,
+                    version: S::VERSION,
+                },
+                procedure: ProcedureDescriptor {
+                    id: P::ID.into_id(),
+                },
+                session: CLIENT_SESSION_ID,
+                extensions,
+            },
+            StreamBody::new(stream::iter(
+                payload
+                    .into_iter()
+                    .map(|bytes| Ok::<_, !>(Frame::new_data(bytes))),
+            )),
+        );
+
+        let response = self.connection.send_request(request).await;
+        let mut frames = pin!(response.into_body().into_stream());
+
+        let kind = match frames.next().await {
+            Some(Ok(frame)) => match frame.into_control() {
+                Ok(control) => *AsRef::<ResponseKind>::as_ref(&control),
+                Err(_) => return Err(Report::new(CallError::MissingResponseKind)),
+            },
+            Some(Err(error)) => error,
+            None => return Err(Report::new(CallError::EmptyResponse)),
+        };
+
+        // A response consists of a single control frame followed by its data, any subsequent
+        // control frame would start a new response, which isn't supported by procedures.
+        let data = frames
+            .take_while(|frame| ready(frame.as_ref().is_ok_and(|frame| frame.data().is_some())))
+            .filter_map(|frame| ready(frame.ok().and_then(|frame| frame.into_data().ok())))
+            .map(|mut data| data.copy_to_bytes(data.remaining()));
+
+        match kind {
+            ResponseKind::Ok => {
+                let values = codec.clone().decode_stream(data.map(Ok::<_, !>)).await;
+                let mut values = pin!(values);
+
+                values
+                    .next()
+                    .await
+                    .ok_or_else(|| Report::new(CallError::EmptyResponse))?
+                    .map_err(|error| Report::new(CallError::Decode).attach(error))
+            }
+            ResponseKind::Err(code) => {
+                let error = if is_plain_error(code) {
+                    codec.decode_error(data).await
+                } else {
+                    codec.decode_report(data).await
+                }
+                .map_err(|error| Report::new(CallError::Decode).attach(error))?;
+
+                Err(Report::new(CallError::Remote { code }).attach(error))
+            }
+        }
+    }
+}
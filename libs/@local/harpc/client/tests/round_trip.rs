@@ -0,0 +1,228 @@
+#![feature(never_type)]
+
+use core::{num::NonZero, time::Duration};
+
+use error_stack::Report;
+use harpc_client::{Client, ClientConfig, connection::Connection, error::CallError};
+use harpc_codec::json::{JsonCodec, RemoteError};
+use harpc_net::{
+    session::server::{SessionConfig, SessionLayer},
+    transport::{MemoryTransport, TransportConfig, TransportLayer, memory_address},
+};
+use harpc_server::{router::RouterBuilder, serve::serve};
+use harpc_service::role::Server;
+use harpc_tower::layer::{
+    boxed::BoxedResponseLayer, report::HandleReportLayer, timeout::TimeoutLayer,
+};
+use harpc_wire_protocol::response::kind::ErrorCode;
+use libp2p_core::Multiaddr;
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+
+const TIMEOUT: Duration = Duration::from_millis(100);
+const ECHO_ERROR_CODE: ErrorCode = ErrorCode::new(NonZero::new(0x10).expect("infallible"));
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Echo {
+    message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Fail;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Sleep;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("the echo service failed")]
+struct EchoError;
+
+#[harpc_service::service(id = 0x00, version = "0.0")]
+trait EchoService {
+    #[procedure(id = 0x00)]
+    fn echo(&self, payload: Echo) -> Result<Echo, Report<EchoError>>;
+
+    #[procedure(id = 0x01)]
+    fn fail(&self, payload: Fail) -> Result<Echo, Report<EchoError>>;
+
+    #[procedure(id = 0x02)]
+    fn sleep(&self, payload: Sleep) -> Result<Echo, Report<EchoError>>;
+}
+
+#[derive(Debug, Clone)]
+struct EchoServiceImpl;
+
+impl<S> EchoService<Server<S>> for EchoServiceImpl
+where
+    S: Send + Sync,
+{
+    async fn echo(&self, _: &S, payload: Echo) -> Result<Echo, Report<EchoError>> {
+        Ok(payload)
+    }
+
+    async fn fail(&self, _: &S, _: Fail) -> Result<Echo, Report<EchoError>> {
+        Err(Report::new(EchoError).attach(ECHO_ERROR_CODE))
+    }
+
+    async fn sleep(&self, _: &S, _: Sleep) -> Result<Echo, Report<EchoError>> {
+        tokio::time::sleep(TIMEOUT * 10).await;
+
+        Ok(Echo {
+            message: "too late".to_owned(),
+        })
+    }
+}
+
+/// Starts an echo server on a memory address, which runs until `cancel` is cancelled.
+async fn start_server(cancel: CancellationToken) -> Multiaddr {
+    let transport = TransportLayer::start(
+        TransportConfig::default(),
+        MemoryTransport::default(),
+        cancel,
+    )
+    .expect("should be able to start the transport layer");
+
+    let address = memory_address();
+    let stream = SessionLayer::new(SessionConfig::default(), transport, JsonCodec)
+        .listen(address.clone())
+        .await
+        .expect("should be able to listen on the memory address");
+
+    let router = RouterBuilder::new::<()>(JsonCodec)
+        .with_builder(|builder| {
+            builder
+                .layer(BoxedResponseLayer::new())
+                .layer(TimeoutLayer::new(JsonCodec, TIMEOUT))
+                .layer(HandleReportLayer::new(JsonCodec))
+        })
+        .register(EchoServiceDelegate::new(EchoServiceImpl))
+        .build();
+
+    tokio::spawn(serve(stream, router));
+
+    address
+}
+
+/// Connects to the server at `address`.
+///
+/// The client is returned alongside the connection, as dropping it shuts down its transport layer.
+async fn connect(address: Multiaddr) -> (Client<JsonCodec>, Connection<JsonCodec>) {
+    let client = Client::new(
+        ClientConfig::default(),
+        MemoryTransport::default(),
+        JsonCodec,
+    )
+    .expect("should be able to create the client");
+
+    let connection = client
+        .connect(address)
+        .await
+        .expect("should be able to connect to the server");
+
+    (client, connection)
+}
+
+fn remote_error(report: &Report<CallError>) -> (ErrorCode, &RemoteError) {
+    let CallError::Remote { code } = *report.current_context() else {
+        panic!("expected a remote error, got {report:?}");
+    };
+    let error = report
+        .downcast_ref::<RemoteError>()
+        .expect("remote errors should be attached to the report");
+
+    (code, error)
+}
+
+#[tokio::test]
+async fn success() {
+    let cancel = CancellationToken::new();
+    let address = start_server(cancel.clone()).await;
+    let (_client, connection) = connect(address).await;
+    let client = EchoServiceClient::new(connection);
+
+    let payload = Echo {
+        message: "hello".to_owned(),
+    };
+    let response = client
+        .echo(payload.clone())
+        .await
+        .expect("echo should succeed");
+    assert_eq!(response, payload);
+
+    cancel.cancel();
+}
+
+#[tokio::test]
+async fn decode_report() {
+    let cancel = CancellationToken::new();
+    let address = start_server(cancel.clone()).await;
+    let (_client, connection) = connect(address).await;
+    let client = EchoServiceClient::new(connection);
+
+    let report = client
+        .fail(Fail)
+        .await
+        .expect_err("fail should return an error");
+    let (code, error) = remote_error(&report);
+    assert_eq!(code, ECHO_ERROR_CODE);
+    assert_eq!(error.message, EchoError.to_string());
+
+    cancel.cancel();
+}
+
+#[tokio::test]
+async fn decode_plain_error() {
+    let cancel = CancellationToken::new();
+    let address = start_server(cancel.clone()).await;
+    let (_client, connection) = connect(address).await;
+    let client = EchoServiceClient::new(connection);
+
+    let report = client
+        .sleep(Sleep)
+        .await
+        .expect_err("sleep should exceed the deadline");
+    let (code, error) = remote_error(&report);
+    assert_eq!(code, ErrorCode::DEADLINE_EXCEEDED);
+    assert!(
+        error.message.contains("deadline"),
+        "unexpected message: {}",
+        error.message
+    );
+    assert!(error.causes.is_empty());
+
+    cancel.cancel();
+}
+
+#[tokio::test]
+async fn connection_loss() {
+    let cancel = CancellationToken::new();
+    let address = start_server(cancel.clone()).await;
+    let (_client, connection) = connect(address).await;
+    let client = EchoServiceClient::new(connection.clone());
+
+    client
+        .echo(Echo {
+            message: "hello".to_owned(),
+        })
+        .await
+        .expect("echo should succeed while the server is running");
+
+    cancel.cancel();
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while connection.is_healthy() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("the connection should become unhealthy once the server shut down");
+
+    let report = client
+        .echo(Echo {
+            message: "hello".to_owned(),
+        })
+        .await
+        .expect_err("echo should fail once the connection has been lost");
+    let (code, _) = remote_error(&report);
+    assert_eq!(code, ErrorCode::INTERNAL_SERVER_ERROR);
+}
@@ -0,0 +1,319 @@
+//! A codec serializing values as newline-delimited JSON.
+//!
+//! Every value is encoded as a single line of JSON, which allows a stream of values to be decoded
+//! incrementally, regardless of how the underlying transport splits the bytes into frames.
+
+use core::{
+    future::ready,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::{Buf, Bytes, BytesMut};
+use error_stack::{FrameKind, Report};
+use futures::{Stream, StreamExt};
+use harpc_net::{
+    codec::{ErrorDecoder, ErrorEncoder, ValueDecoder, ValueEncoder, WireError},
+    session::error::TransactionError,
+};
+use harpc_wire_protocol::response::kind::ErrorCode;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+/// An error, which has been encoded by the [`JsonCodec`].
+///
+/// Reports are encoded with the message of their current context, followed by the messages of all
+/// contexts they have been caused by. Attachments are not sent to the client.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[error("{message}")]
+pub struct RemoteError {
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub causes: Vec<String>,
+}
+
+impl RemoteError {
+    fn from_report<C>(report: &Report<C>) -> Self {
+        let mut contexts = report.frames().filter_map(|frame| match frame.kind() {
+            FrameKind::Context(context) => Some(context.to_string()),
+            FrameKind::Attachment(_) => None,
+        });
+
+        Self {
+            message: contexts.next().unwrap_or_default(),
+            causes: contexts.collect(),
+        }
+    }
+
+    fn into_bytes(self) -> Bytes {
+        serde_json::to_vec(&self)
+            .expect("a remote error only consists of strings and is always serializable")
+            .into()
+    }
+}
+
+/// A codec encoding values and errors as JSON.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct JsonCodec;
+
+impl<T> ValueEncoder<T> for JsonCodec
+where
+    T: Serialize,
+{
+    type Error = serde_json::Error;
+
+    fn encode_stream(
+        self,
+        items: impl Stream<Item = T> + Send + Sync,
+    ) -> impl Future<Output = impl Stream<Item = Result<Bytes, Self::Error>> + Send + Sync> + Send
+    {
+        ready(items.map(|item| {
+            let mut buffer = serde_json::to_vec(&item)?;
+            buffer.push(b'\n');
+
+            Ok(Bytes::from(buffer))
+        }))
+    }
+}
+
+impl<T> ValueDecoder<T> for JsonCodec
+where
+    T: DeserializeOwned,
+{
+    type Error = serde_json::Error;
+
+    fn decode_stream<B, E>(
+        self,
+        items: impl Stream<Item = Result<B, E>> + Send + Sync,
+    ) -> impl Future<Output = impl Stream<Item = Result<T, Self::Error>> + Send + Sync> + Send
+    where
+        B: Buf,
+    {
+        ready(JsonValueStream::new(items))
+    }
+}
+
+impl ErrorEncoder for JsonCodec {
+    fn encode_report<C>(&self, report: Report<C>) -> impl Future<Output = TransactionError> + Send {
+        let code = report
+            .request_ref::<ErrorCode>()
+            .next()
+            .copied()
+            .unwrap_or(ErrorCode::INTERNAL_SERVER_ERROR);
+
+        ready(TransactionError {
+            code,
+            bytes: RemoteError::from_report(&report).into_bytes(),
+        })
+    }
+
+    fn encode_error<E>(&self, error: E) -> impl Future<Output = TransactionError> + Send
+    where
+        E: WireError + Send,
+    {
+        ready(TransactionError {
+            code: error.code(),
+            bytes: RemoteError {
+                message: error.to_string(),
+                causes: Vec::new(),
+            }
+            .into_bytes(),
+        })
+    }
+}
+
+impl ErrorDecoder for JsonCodec {
+    type Error = serde_json::Error;
+    type Output = RemoteError;
+
+    async fn decode_report(
+        &self,
+        bytes: impl Stream<Item = Bytes> + Send + Sync,
+    ) -> Result<Self::Output, Self::Error> {
+        let buffer = bytes
+            .fold(BytesMut::new(), |mut buffer, bytes| {
+                buffer.extend_from_slice(&bytes);
+                ready(buffer)
+            })
+            .await;
+
+        serde_json::from_slice(&buffer)
+    }
+
+    async fn decode_error(
+        &self,
+        bytes: impl Stream<Item = Bytes> + Send + Sync,
+    ) -> Result<Self::Output, Self::Error> {
+        self.decode_report(bytes).await
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Splits a stream of bytes into lines and decodes each line as a value.
+    ///
+    /// If the underlying stream returns an error, the stream is terminated after the values
+    /// which have already been received have been decoded.
+    #[must_use = "streams do nothing unless polled"]
+    struct JsonValueStream<S, T> {
+        #[pin]
+        inner: S,
+        buffer: BytesMut,
+        // Offset into `buffer` up to which no newline has been found.
+        scanned: usize,
+        exhausted: bool,
+        _marker: PhantomData<fn() -> *const T>,
+    }
+}
+
+impl<S, T> JsonValueStream<S, T> {
+    fn new(inner: S) -> Self {
+        Self {
+            inner,
+            buffer: BytesMut::new(),
+            scanned: 0,
+            exhausted: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, B, E, T> Stream for JsonValueStream<S, T>
+where
+    S: Stream<Item = Result<B, E>>,
+    B: Buf,
+    T: DeserializeOwned,
+{
+    type Item = Result<T, serde_json::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(position) = this.buffer[*this.scanned..]
+                .iter()
+                .position(|&byte| byte == b'\n')
+            {
+                let line = this.buffer.split_to(*this.scanned + position + 1);
+                *this.scanned = 0;
+
+                if line.trim_ascii().is_empty() {
+                    continue;
+                }
+
+                return Poll::Ready(Some(serde_json::from_slice(&line)));
+            }
+
+            *this.scanned = this.buffer.len();
+
+            if *this.exhausted {
+                let remaining = this.buffer.split();
+                *this.scanned = 0;
+
+                if remaining.trim_ascii().is_empty() {
+                    return Poll::Ready(None);
+                }
+
+                return Poll::Ready(Some(serde_json::from_slice(&remaining)));
+            }
+
+            match core::task::ready!(this.inner.as_mut().poll_next(cx)) {
+                Some(Ok(mut bytes)) => {
+                    while bytes.has_remaining() {
+                        let chunk = bytes.chunk();
+                        let length = chunk.len();
+
+                        this.buffer.extend_from_slice(chunk);
+                        bytes.advance(length);
+                    }
+                }
+                Some(Err(_)) | None => *this.exhausted = true,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+    use error_stack::Report;
+    use futures::{StreamExt as _, TryStreamExt as _, stream};
+    use harpc_net::codec::{ErrorDecoder as _, ErrorEncoder as _, ValueDecoder, ValueEncoder};
+    use harpc_wire_protocol::response::kind::ErrorCode;
+
+    use super::{JsonCodec, RemoteError};
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("the account does not exist")]
+    struct AccountNotFound;
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("unable to create the account group")]
+    struct CreateAccountGroupError;
+
+    async fn decode<T>(chunks: &[&'static str]) -> Vec<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let chunks = chunks
+            .iter()
+            .map(|chunk| Ok::<_, !>(Bytes::from_static(chunk.as_bytes())));
+
+        ValueDecoder::<T>::decode_stream(JsonCodec, stream::iter(chunks))
+            .await
+            .try_collect()
+            .await
+            .expect("should be able to decode values")
+    }
+
+    #[tokio::test]
+    async fn encode_values() {
+        let bytes: Vec<Bytes> = ValueEncoder::encode_stream(JsonCodec, stream::iter([1_u32, 2, 3]))
+            .await
+            .try_collect()
+            .await
+            .expect("should be able to encode values");
+
+        assert_eq!(bytes, ["1\n", "2\n", "3\n"]);
+    }
+
+    #[tokio::test]
+    async fn decode_values_across_frames() {
+        let values: Vec<Vec<u32>> = decode(&["[1,", "2]\n[3", "]\n", "\n[4]"]).await;
+
+        assert_eq!(values, [vec![1, 2], vec![3], vec![4]]);
+    }
+
+    #[tokio::test]
+    async fn decode_invalid_value() {
+        let values: Vec<_> = ValueDecoder::<u32>::decode_stream(
+            JsonCodec,
+            stream::iter([Ok::<_, !>(Bytes::from_static(b"\"a\"\n"))]),
+        )
+        .await
+        .collect()
+        .await;
+
+        assert_eq!(values.len(), 1);
+        assert!(values[0].is_err());
+    }
+
+    #[tokio::test]
+    async fn report_roundtrip() {
+        let report = Report::new(AccountNotFound)
+            .attach(ErrorCode::NOT_FOUND)
+            .change_context(CreateAccountGroupError);
+
+        let error = JsonCodec.encode_report(report).await;
+        assert_eq!(error.code, ErrorCode::NOT_FOUND);
+
+        let decoded = JsonCodec
+            .decode_report(stream::iter([error.bytes]))
+            .await
+            .expect("should be able to decode the report");
+
+        assert_eq!(decoded, RemoteError {
+            message: "unable to create the account group".to_owned(),
+            causes: vec!["the account does not exist".to_owned()],
+        });
+    }
+}
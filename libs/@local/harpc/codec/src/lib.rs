@@ -0,0 +1,4 @@
+#![feature(never_type)]
+
+#[cfg(feature = "json")]
+pub mod json;
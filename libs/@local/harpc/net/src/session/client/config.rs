@@ -1,5 +1,7 @@
 use core::{num::NonZero, time::Duration};
 
+use harpc_wire_protocol::compression::Compression;
+
 use crate::macros::non_zero;
 
 /// Configuration for the client session layer
@@ -57,6 +59,14 @@ pub struct SessionConfig {
     ///
     /// **Default:** `false`
     pub no_delay: bool,
+
+    /// Compression proposed to the server for every transaction.
+    /// The payload of the request is compressed using the given compression, and the server is
+    /// asked to compress the response in the same way. Compression reduces the bandwidth used,
+    /// at the cost of CPU time, and is most effective for large payloads.
+    ///
+    /// **Default:** `None`
+    pub compression: Option<Compression>,
 }
 
 impl Default for SessionConfig {
@@ -71,6 +81,63 @@ impl Default for SessionConfig {
             per_transaction_response_byte_stream_buffer_size: non_zero!(32),
 
             no_delay: false,
+
+            compression: None,
+        }
+    }
+}
+
+/// Configuration for the [`ConnectionPool`].
+///
+/// [`ConnectionPool`]: crate::session::client::ConnectionPool
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PoolConfig {
+    /// Delay before an address is dialed again after the first failed attempt to connect to it.
+    /// The delay doubles with every consecutive failure, up to `backoff_max`, and is reset once a
+    /// connection has been established.
+    ///
+    /// **Default:** `100ms`
+    pub backoff_initial: Duration,
+
+    /// Upper limit of the delay between two attempts to connect to the same address.
+    ///
+    /// **Default:** `10s`
+    pub backoff_max: Duration,
+
+    /// Maximum time an attempt to connect to an address may take. An attempt which takes longer
+    /// is aborted and counts as a failure, so that an address which is unresponsive does not
+    /// stall calls which could be served by the other addresses of the pool.
+    ///
+    /// **Default:** `5s`
+    pub dial_timeout: Duration,
+
+    /// Maximum number of times an idempotent call is retried, if the connection it has been sent
+    /// over has been closed before a response has been received.
+    /// Between retries the pool waits according to the same backoff used for reconnects.
+    ///
+    /// **Default:** `3`
+    pub max_retries: u32,
+}
+
+impl PoolConfig {
+    /// The delay after the given number of consecutive failures.
+    pub(crate) fn backoff(&self, failures: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(failures.saturating_sub(1));
+
+        self.backoff_initial
+            .saturating_mul(factor)
+            .min(self.backoff_max)
+    }
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            backoff_initial: Duration::from_millis(100),
+            backoff_max: Duration::from_secs(10),
+            dial_timeout: Duration::from_secs(5),
+
+            max_retries: 3,
         }
     }
 }
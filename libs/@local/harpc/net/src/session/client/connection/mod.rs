@@ -27,7 +27,7 @@ use tokio_util::{
 
 use self::collection::{TransactionCollection, TransactionState, TransactionStorage};
 pub use self::stream::ResponseStream;
-use super::{config::SessionConfig, transaction::TransactionTask};
+use super::{config::SessionConfig, metadata::RequestMetadata, transaction::TransactionTask};
 use crate::session::{error::ConnectionPartiallyClosedError, gc::ConnectionGarbageCollectorTask};
 
 /// Delegate requests to the respective transaction
@@ -340,6 +340,38 @@ impl Connection {
         service: ServiceDescriptor,
         procedure: ProcedureDescriptor,
         payload: impl Stream<Item = Bytes> + Send + 'static,
+    ) -> error_stack::Result<ResponseStream, ConnectionPartiallyClosedError> {
+        self.call_inner(service, procedure, None, payload).await
+    }
+
+    /// Call a service procedure, sending the given metadata alongside the request
+    ///
+    /// The metadata is delivered to the server as part of the first packet of the request and
+    /// can be retrieved through [`TransactionContext::metadata`].
+    ///
+    /// # Errors
+    ///
+    /// This will return an error if the connection is unhealthy, meaning that the underlying
+    /// connection is currently in its process of being closed.
+    ///
+    /// [`TransactionContext::metadata`]: crate::session::server::transaction::TransactionContext::metadata
+    pub async fn call_with_metadata(
+        &self,
+        service: ServiceDescriptor,
+        procedure: ProcedureDescriptor,
+        metadata: RequestMetadata,
+        payload: impl Stream<Item = Bytes> + Send + 'static,
+    ) -> error_stack::Result<ResponseStream, ConnectionPartiallyClosedError> {
+        self.call_inner(service, procedure, Some(metadata), payload)
+            .await
+    }
+
+    async fn call_inner(
+        &self,
+        service: ServiceDescriptor,
+        procedure: ProcedureDescriptor,
+        metadata: Option<RequestMetadata>,
+        payload: impl Stream<Item = Bytes> + Send + 'static,
     ) -> error_stack::Result<ResponseStream, ConnectionPartiallyClosedError> {
         // While not strictly necessary (as the transaction will immediately terminate if the
         // underlying connection is closed) and the `ResponseStream` will return `None` it is a good
@@ -355,6 +387,7 @@ impl Connection {
         let (permit, response_rx) = self.transactions.acquire().await;
 
         let (stream_tx, stream_rx) = mpsc::channel(1);
+        let cancel_request = CancellationToken::new();
 
         // Important: the resulting stream won't be directly notified if the payload stream couldn't
         // be sent, completely (which can only happen if the `Sink` has been shutdown). This is
@@ -366,10 +399,12 @@ impl Connection {
             permit,
             service,
             procedure,
+            metadata,
             response_rx,
             response_tx: stream_tx,
             request_rx: payload,
             request_tx: self.tx.clone(),
+            cancel_request: cancel_request.clone(),
         };
 
         task.spawn(&self.tasks);
@@ -381,6 +416,8 @@ impl Connection {
         // terminated once the payload stream is exhausted.
         // This means we can allow scenarios in which the response does not matter and we only want
         // to send a request.
-        Ok(ResponseStream::new(stream_rx))
+        // To stop the transaction on the server, it needs to be cancelled explicitly through the
+        // `ResponseStream`.
+        Ok(ResponseStream::new(stream_rx, cancel_request))
     }
 }
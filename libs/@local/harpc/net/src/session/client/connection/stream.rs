@@ -5,29 +5,90 @@ use core::{
 
 use futures::{Stream, prelude::stream::FusedStream};
 use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 
 use crate::session::client::{ErrorStream, ValueStream};
 
 #[derive(Debug)]
 pub struct ResponseStream {
     inner: mpsc::Receiver<Result<ValueStream, ErrorStream>>,
+    peeked: Option<Result<ValueStream, ErrorStream>>,
+
+    cancel: CancellationToken,
+    cancel_on_drop: bool,
 
     terminated: bool,
 }
 
 impl ResponseStream {
-    pub(crate) const fn new(inner: mpsc::Receiver<Result<ValueStream, ErrorStream>>) -> Self {
+    pub(crate) const fn new(
+        inner: mpsc::Receiver<Result<ValueStream, ErrorStream>>,
+        cancel: CancellationToken,
+    ) -> Self {
         Self {
             inner,
+            peeked: None,
+            cancel,
+            cancel_on_drop: false,
             terminated: false,
         }
     }
+
+    /// Wait for the next response, without consuming it.
+    ///
+    /// Returns `None` if the transaction has been terminated, the response returned is the one that
+    /// is yielded by the next call to [`StreamExt::next`].
+    ///
+    /// [`StreamExt::next`]: futures::StreamExt::next
+    pub async fn peek(&mut self) -> Option<&Result<ValueStream, ErrorStream>> {
+        if self.peeked.is_none() && !self.terminated {
+            self.peeked = self.inner.recv().await;
+
+            if self.peeked.is_none() {
+                self.terminated = true;
+            }
+        }
+
+        self.peeked.as_ref()
+    }
+
+    /// Cancel the transaction.
+    ///
+    /// The server is notified that the response is no longer of interest and stops processing the
+    /// transaction, any response that has not yet been received is discarded.
+    ///
+    /// This has no effect if the response has already been received completely.
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Cancel the transaction once the stream is dropped.
+    ///
+    /// By default, dropping the stream only discards the response, while the server continues
+    /// processing the transaction.
+    #[must_use]
+    pub const fn cancel_on_drop(mut self) -> Self {
+        self.cancel_on_drop = true;
+        self
+    }
+}
+
+impl Drop for ResponseStream {
+    fn drop(&mut self) {
+        if self.cancel_on_drop && !self.terminated {
+            self.cancel.cancel();
+        }
+    }
 }
 
 impl Stream for ResponseStream {
     type Item = Result<ValueStream, ErrorStream>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(value) = self.peeked.take() {
+            return Poll::Ready(Some(value));
+        }
+
         if self.terminated {
             return Poll::Ready(None);
         }
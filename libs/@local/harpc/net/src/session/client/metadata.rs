@@ -0,0 +1,53 @@
+use bytes::{BufMut, Bytes, BytesMut};
+use harpc_wire_protocol::payload::Payload;
+
+use crate::session::error::MetadataTooLargeError;
+
+/// Metadata that is sent alongside a request.
+///
+/// The metadata is sent as part of the first packet of a request and is opaque to the session
+/// layer, it is therefore limited in size to what fits into a single packet.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RequestMetadata(Bytes);
+
+impl RequestMetadata {
+    /// The maximum size of the metadata in bytes.
+    pub const MAX_SIZE: usize = Payload::MAX_SIZE - 2;
+
+    /// Create new request metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata exceeds [`Self::MAX_SIZE`].
+    pub fn new(bytes: impl Into<Bytes>) -> Result<Self, MetadataTooLargeError> {
+        let bytes = bytes.into();
+
+        if bytes.len() > Self::MAX_SIZE {
+            return Err(MetadataTooLargeError {
+                size: bytes.len(),
+                limit: Self::MAX_SIZE,
+            });
+        }
+
+        Ok(Self(bytes))
+    }
+
+    #[must_use]
+    pub const fn as_bytes(&self) -> &Bytes {
+        &self.0
+    }
+
+    /// Encode the metadata as prefix of the request payload.
+    pub(crate) fn into_prefix(self) -> Bytes {
+        let mut buffer = BytesMut::with_capacity(self.0.len() + 2);
+
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "the length is checked on construction"
+        )]
+        buffer.put_u16(self.0.len() as u16);
+        buffer.extend_from_slice(&self.0);
+
+        buffer.freeze()
+    }
+}
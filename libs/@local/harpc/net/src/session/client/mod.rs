@@ -1,5 +1,7 @@
 mod config;
 mod connection;
+mod metadata;
+mod pool;
 mod transaction;
 
 use error_stack::{Result, ResultExt};
@@ -8,13 +10,21 @@ use tokio_util::sync::CancellationToken;
 
 use self::connection::ConnectionParts;
 pub use self::{
-    config::SessionConfig,
+    config::{PoolConfig, SessionConfig},
     connection::{Connection, ResponseStream},
+    metadata::RequestMetadata,
+    pool::ConnectionPool,
     transaction::stream::{ErrorStream, TransactionStream, ValueStream},
 };
-use super::error::SessionError;
+use super::{error::SessionError, server::SessionId};
 use crate::transport::{TransportLayer, connection::OutgoingConnection};
 
+/// Placeholder session used when creating requests on the client side.
+///
+/// Sessions are assigned by the server and are never communicated to the client, requests
+/// created by a client therefore carry this value instead.
+pub const CLIENT_SESSION_ID: SessionId = SessionId(usize::MAX);
+
 pub struct SessionLayer {
     config: SessionConfig,
 
@@ -0,0 +1,79 @@
+use libp2p::{Multiaddr, metrics::Registry};
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{counter::Counter, family::Family},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
+struct AddressLabels {
+    address: String,
+}
+
+impl AddressLabels {
+    fn new(address: &Multiaddr) -> Self {
+        Self {
+            address: address.to_string(),
+        }
+    }
+}
+
+/// Metrics of a [`ConnectionPool`].
+///
+/// Metrics are shared between all clones, registering them makes them available through the
+/// registry, while the pool continues to record them.
+///
+/// [`ConnectionPool`]: super::ConnectionPool
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PoolMetrics {
+    connections: Family<AddressLabels, Counter>,
+    connection_failures: Family<AddressLabels, Counter>,
+    calls: Family<AddressLabels, Counter>,
+    retries: Counter,
+}
+
+impl PoolMetrics {
+    pub(crate) fn register(&self, registry: &mut Registry) {
+        let registry = registry.sub_registry_with_prefix("harpc_client_pool");
+
+        registry.register(
+            "connections",
+            "Number of connections established to an address",
+            self.connections.clone(),
+        );
+        registry.register(
+            "connection_failures",
+            "Number of failed attempts to connect to an address",
+            self.connection_failures.clone(),
+        );
+        registry.register(
+            "calls",
+            "Number of calls sent to an address",
+            self.calls.clone(),
+        );
+        registry.register(
+            "retries",
+            "Number of idempotent calls that have been retried",
+            self.retries.clone(),
+        );
+    }
+
+    pub(crate) fn record_connection(&self, address: &Multiaddr) {
+        self.connections
+            .get_or_create(&AddressLabels::new(address))
+            .inc();
+    }
+
+    pub(crate) fn record_connection_failure(&self, address: &Multiaddr) {
+        self.connection_failures
+            .get_or_create(&AddressLabels::new(address))
+            .inc();
+    }
+
+    pub(crate) fn record_call(&self, address: &Multiaddr) {
+        self.calls.get_or_create(&AddressLabels::new(address)).inc();
+    }
+
+    pub(crate) fn record_retry(&self) {
+        self.retries.inc();
+    }
+}
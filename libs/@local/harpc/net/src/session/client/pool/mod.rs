@@ -0,0 +1,352 @@
+mod metrics;
+
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use bytes::Bytes;
+use error_stack::Report;
+use futures::Stream;
+use harpc_wire_protocol::{
+    request::{procedure::ProcedureDescriptor, service::ServiceDescriptor},
+    response::kind::ErrorCode,
+};
+use libp2p::{Multiaddr, metrics::Registry};
+use tokio::{
+    sync::{Mutex, MutexGuard},
+    time::Instant,
+};
+
+use self::metrics::PoolMetrics;
+use super::{Connection, ResponseStream, SessionLayer, config::PoolConfig};
+use crate::session::error::{PoolError, SessionError};
+
+struct EndpointState {
+    connection: Option<Arc<Connection>>,
+
+    failures: u32,
+    retry_at: Option<Instant>,
+}
+
+struct Endpoint {
+    address: Multiaddr,
+
+    // `tokio` mutex, as the lock is held while dialing, this ensures that concurrent callers do
+    // not dial the same address multiple times.
+    state: Mutex<EndpointState>,
+}
+
+impl Endpoint {
+    fn new(address: Multiaddr) -> Self {
+        Self {
+            address,
+            state: Mutex::new(EndpointState {
+                connection: None,
+                failures: 0,
+                retry_at: None,
+            }),
+        }
+    }
+}
+
+fn is_connection_error(code: ErrorCode) -> bool {
+    code == ErrorCode::CONNECTION_CLOSED || code == ErrorCode::CONNECTION_SHUTDOWN
+}
+
+/// Returns the connection of `result`, adding the error to `errors` if connecting failed.
+fn collect_error(
+    result: Result<Option<Arc<Connection>>, Report<SessionError>>,
+    errors: &mut Option<Report<[SessionError]>>,
+) -> Option<Arc<Connection>> {
+    match result {
+        Ok(connection) => connection,
+        Err(report) => {
+            match errors {
+                Some(errors) => errors.push(report),
+                None => *errors = Some(report.expand()),
+            }
+
+            None
+        }
+    }
+}
+
+/// A pool of connections to a set of equivalent servers.
+///
+/// Calls are distributed round-robin across all addresses of the pool. Connections are
+/// established lazily and re-established once they have been closed, e.g. because the server
+/// has been restarted. An address that cannot be reached is skipped until its backoff, as
+/// configured through [`PoolConfig`], has elapsed.
+pub struct ConnectionPool {
+    config: PoolConfig,
+    session: SessionLayer,
+
+    endpoints: Box<[Endpoint]>,
+    next: AtomicUsize,
+
+    metrics: PoolMetrics,
+}
+
+impl ConnectionPool {
+    #[must_use]
+    pub fn new(
+        session: SessionLayer,
+        config: PoolConfig,
+        addresses: impl IntoIterator<Item = Multiaddr>,
+    ) -> Self {
+        Self {
+            config,
+            session,
+
+            endpoints: addresses.into_iter().map(Endpoint::new).collect(),
+            next: AtomicUsize::new(0),
+
+            metrics: PoolMetrics::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn session(&self) -> &SessionLayer {
+        &self.session
+    }
+
+    /// Register the metrics of the pool with the given registry.
+    ///
+    /// The metrics are prefixed with `harpc_client_pool` and labelled with the address they refer
+    /// to.
+    pub fn register_metrics(&self, registry: &mut Registry) {
+        self.metrics.register(registry);
+    }
+
+    /// Returns a healthy connection to the endpoint, dialing it if necessary.
+    ///
+    /// Returns `None` if the endpoint is currently backing off.
+    async fn connect(
+        &self,
+        endpoint: &Endpoint,
+        mut state: MutexGuard<'_, EndpointState>,
+    ) -> Result<Option<Arc<Connection>>, Report<SessionError>> {
+        if let Some(connection) = state
+            .connection
+            .as_ref()
+            .filter(|connection| connection.is_healthy())
+        {
+            return Ok(Some(Arc::clone(connection)));
+        }
+
+        state.connection = None;
+
+        if state
+            .retry_at
+            .is_some_and(|retry_at| retry_at > Instant::now())
+        {
+            return Ok(None);
+        }
+
+        let dial = tokio::time::timeout(
+            self.config.dial_timeout,
+            self.session.dial(endpoint.address.clone()),
+        )
+        .await
+        .unwrap_or_else(|_elapsed| {
+            Err(Report::new(SessionError).attach_printable(format!(
+                "unable to connect within {:?}",
+                self.config.dial_timeout
+            )))
+        });
+
+        match dial {
+            Ok(connection) => {
+                tracing::debug!(address = %endpoint.address, "connection established");
+                self.metrics.record_connection(&endpoint.address);
+
+                let connection = Arc::new(connection);
+
+                state.connection = Some(Arc::clone(&connection));
+                state.failures = 0;
+                state.retry_at = None;
+
+                Ok(Some(connection))
+            }
+            Err(report) => {
+                self.metrics.record_connection_failure(&endpoint.address);
+
+                state.failures = state.failures.saturating_add(1);
+                let backoff = self.config.backoff(state.failures);
+                state.retry_at = Some(Instant::now() + backoff);
+
+                tracing::warn!(address = %endpoint.address, ?backoff, ?report, "unable to connect");
+
+                Err(report)
+            }
+        }
+    }
+
+    /// Remove the connection from the endpoint, so that the next call establishes a new one.
+    async fn invalidate(&self, endpoint: &Endpoint, connection: &Arc<Connection>) {
+        let mut state = endpoint.state.lock().await;
+
+        if state
+            .connection
+            .as_ref()
+            .is_some_and(|current| Arc::ptr_eq(current, connection))
+        {
+            state.connection = None;
+        }
+    }
+
+    async fn acquire(&self) -> Result<(&Endpoint, Arc<Connection>), Report<PoolError>> {
+        let Some(start) = self
+            .next
+            .fetch_add(1, Ordering::Relaxed)
+            .checked_rem(self.endpoints.len())
+        else {
+            return Err(Report::new(PoolError::Unavailable)
+                .attach_printable("the pool does not contain any address"));
+        };
+
+        let (head, tail) = self.endpoints.split_at(start);
+
+        let mut errors: Option<Report<[SessionError]>> = None;
+        let mut busy = Vec::new();
+
+        for endpoint in tail.iter().chain(head) {
+            // The lock is held while another caller dials the endpoint, instead of waiting for
+            // the dial to complete, the remaining endpoints are tried first.
+            let Ok(state) = endpoint.state.try_lock() else {
+                busy.push(endpoint);
+                continue;
+            };
+
+            let result = self.connect(endpoint, state).await;
+            if let Some(connection) = collect_error(result, &mut errors) {
+                return Ok((endpoint, connection));
+            }
+        }
+
+        for endpoint in busy {
+            let state = endpoint.state.lock().await;
+
+            let result = self.connect(endpoint, state).await;
+            if let Some(connection) = collect_error(result, &mut errors) {
+                return Ok((endpoint, connection));
+            }
+        }
+
+        Err(errors.map_or_else(
+            || Report::new(PoolError::Unavailable),
+            |errors| errors.change_context(PoolError::Unavailable),
+        ))
+    }
+
+    /// Call a service procedure on one of the servers of the pool.
+    ///
+    /// The call is not retried, as the pool is unable to tell if the server has already processed
+    /// the request, use [`Self::call_idempotent`] for procedures that are safe to repeat.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PoolError::Unavailable`] if no server can be reached, or
+    /// [`PoolError::ConnectionClosed`] if the connection has been closed while sending the request.
+    pub async fn call(
+        &self,
+        service: ServiceDescriptor,
+        procedure: ProcedureDescriptor,
+        payload: impl Stream<Item = Bytes> + Send + 'static,
+    ) -> Result<ResponseStream, Report<PoolError>> {
+        let (endpoint, connection) = self.acquire().await?;
+        self.metrics.record_call(&endpoint.address);
+
+        match connection.call(service, procedure, payload).await {
+            Ok(stream) => Ok(stream),
+            Err(report) => {
+                self.invalidate(endpoint, &connection).await;
+
+                Err(report.change_context(PoolError::ConnectionClosed))
+            }
+        }
+    }
+
+    async fn try_call_idempotent(
+        &self,
+        service: ServiceDescriptor,
+        procedure: ProcedureDescriptor,
+        payload: impl Stream<Item = Bytes> + Send + 'static,
+    ) -> Result<ResponseStream, Report<PoolError>> {
+        let (endpoint, connection) = self.acquire().await?;
+        self.metrics.record_call(&endpoint.address);
+
+        let mut stream = match connection.call(service, procedure, payload).await {
+            Ok(stream) => stream,
+            Err(report) => {
+                self.invalidate(endpoint, &connection).await;
+
+                return Err(report.change_context(PoolError::ConnectionClosed));
+            }
+        };
+
+        // The server responds with a connection error if it is shutting down, in which case the
+        // request hasn't been processed. If the stream terminates without any response, the
+        // connection has been closed before the server was able to respond.
+        let code = match stream.peek().await {
+            None => None,
+            Some(Err(error)) if is_connection_error(error.code()) => Some(error.code()),
+            Some(_) => return Ok(stream),
+        };
+
+        self.invalidate(endpoint, &connection).await;
+
+        let mut report = Report::new(PoolError::ConnectionClosed);
+        if let Some(code) = code {
+            report = report.attach(code);
+        }
+
+        Err(report)
+    }
+
+    /// Call an idempotent service procedure on one of the servers of the pool.
+    ///
+    /// If the connection is closed before a response has been received, the call is retried
+    /// (possibly on a different server), up to [`PoolConfig::max_retries`] times. The payload is
+    /// therefore cloned for every attempt.
+    ///
+    /// Unlike [`Self::call`], this waits until the first response has been received.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PoolError::Unavailable`] if no server can be reached, or
+    /// [`PoolError::ConnectionClosed`] if the connection has been closed before a response has
+    /// been received, once all retries have been exhausted.
+    pub async fn call_idempotent<P>(
+        &self,
+        service: ServiceDescriptor,
+        procedure: ProcedureDescriptor,
+        payload: P,
+    ) -> Result<ResponseStream, Report<PoolError>>
+    where
+        P: Stream<Item = Bytes> + Clone + Send + 'static,
+    {
+        let mut retries = 0;
+
+        loop {
+            let report = match self
+                .try_call_idempotent(service, procedure, payload.clone())
+                .await
+            {
+                Ok(stream) => return Ok(stream),
+                Err(report) => report,
+            };
+
+            if retries >= self.config.max_retries {
+                return Err(report);
+            }
+
+            retries += 1;
+            self.metrics.record_retry();
+
+            let backoff = self.config.backoff(retries);
+            tracing::debug!(retries, ?backoff, ?report, "retrying idempotent call");
+
+            tokio::time::sleep(backoff).await;
+        }
+    }
+}
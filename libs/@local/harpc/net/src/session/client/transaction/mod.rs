@@ -19,9 +19,12 @@ use tokio::{pin, select, sync::mpsc};
 use tokio_util::{sync::CancellationToken, task::TaskTracker};
 
 use self::stream::{ErrorStream, StreamState, ValueStream};
-use super::config::SessionConfig;
+use super::{config::SessionConfig, metadata::RequestMetadata};
 use crate::{
-    session::writer::{RequestContext, RequestWriter, WriterOptions},
+    session::{
+        compression::decompress_response,
+        writer::{RequestContext, RequestWriter, WriterOptions, new_cancel_request},
+    },
     stream::TerminatedChannelStream,
 };
 
@@ -41,6 +44,10 @@ pub(crate) struct TransactionReceiveTask<P> {
     rx: tachyonix::Receiver<Response>,
     tx: mpsc::Sender<Result<ValueStream, ErrorStream>>,
 
+    // used to notify the server that the transaction has been cancelled by the consumer
+    cancel_request: CancellationToken,
+    request_tx: mpsc::Sender<Request>,
+
     permit: Arc<P>,
 }
 
@@ -84,6 +91,21 @@ where
         ControlFlow::Continue(payload.into_bytes())
     }
 
+    async fn cancel(&self) {
+        // stop sending any further request frames, before notifying the server, so that the
+        // cancellation is the last frame of the transaction.
+        self.permit.cancellation_token().cancel();
+
+        if self
+            .request_tx
+            .send(new_cancel_request(self.permit.id()))
+            .await
+            .is_err()
+        {
+            tracing::info!("connection has been closed, unable to cancel transaction");
+        }
+    }
+
     #[expect(
         clippy::integer_division_remainder_used,
         reason = "required for select! macro"
@@ -96,10 +118,16 @@ where
             // We cannot early break if tx is closed, because we might still deliver some responses
             let response = select! {
                 response = self.rx.recv() => response,
-                () = cancel.cancelled() => break
+                () = cancel.cancelled() => break,
+                () = self.cancel_request.cancelled() => {
+                    tracing::debug!("transaction has been cancelled by the consumer");
+
+                    self.cancel().await;
+                    break;
+                }
             };
 
-            let Ok(response) = response else {
+            let Ok(mut response) = response else {
                 // sender has been prematurely dropped, this might be because the transaction has
                 // failed in some fashion or the request has been dropped.
                 tracing::info!("connection prematurely dropped");
@@ -124,6 +152,18 @@ where
                 continue;
             }
 
+            if let Err(error) = decompress_response(&mut response) {
+                tracing::warn!(
+                    ?error,
+                    "unable to decompress response, cancelling transaction"
+                );
+
+                // the consumer will be indirectly informed, as the stream ends without the
+                // EndOfResponse flag.
+                self.cancel().await;
+                break;
+            }
+
             let end_of_response = response.header.flags.contains(ResponseFlag::EndOfResponse);
 
             let bytes = match response.body {
@@ -183,6 +223,8 @@ pub(crate) struct TransactionSendTask<S, P> {
     service: ServiceDescriptor,
     procedure: ProcedureDescriptor,
 
+    metadata: Option<RequestMetadata>,
+
     rx: S,
     tx: mpsc::Sender<Request>,
 
@@ -208,9 +250,18 @@ where
                 id: self.permit.id(),
                 service: self.service,
                 procedure: self.procedure,
+                metadata: self.metadata.is_some(),
+                compression: self.config.compression,
             },
             &self.tx,
         );
+
+        if let Some(metadata) = self.metadata {
+            // the metadata is always smaller than a single packet, the prefix is therefore
+            // guaranteed to be part of the begin packet.
+            writer.push(metadata.into_prefix());
+        }
+
         let rx = self.rx;
 
         pin!(rx);
@@ -250,11 +301,15 @@ pub(crate) struct TransactionTask<S, P> {
     pub service: ServiceDescriptor,
     pub procedure: ProcedureDescriptor,
 
+    pub metadata: Option<RequestMetadata>,
+
     pub response_rx: tachyonix::Receiver<Response>,
     pub response_tx: mpsc::Sender<Result<ValueStream, ErrorStream>>,
 
     pub request_rx: S,
     pub request_tx: mpsc::Sender<Request>,
+
+    pub cancel_request: CancellationToken,
 }
 
 impl<S, P> TransactionTask<S, P>
@@ -270,6 +325,8 @@ where
                 config: self.config,
                 rx: self.response_rx,
                 tx: self.response_tx,
+                cancel_request: self.cancel_request,
+                request_tx: self.request_tx.clone(),
                 permit: Arc::clone(&permit),
             }
             .run(),
@@ -282,6 +339,8 @@ where
                 service: self.service,
                 procedure: self.procedure,
 
+                metadata: self.metadata,
+
                 rx: self.request_rx,
                 tx: self.request_tx,
 
@@ -4,6 +4,7 @@ use core::{assert_matches::assert_matches, num::NonZero, time::Duration};
 use bytes::{Bytes, BytesMut};
 use futures::StreamExt;
 use harpc_wire_protocol::{
+    compression::Compression,
     flags::BitFlagsOp,
     payload::Payload,
     protocol::{Protocol, ProtocolVersion},
@@ -30,7 +31,8 @@ use super::{
     ClientTransactionPermit, ErrorStream, TransactionReceiveTask, TransactionSendTask, ValueStream,
 };
 use crate::session::{
-    client::{TransactionStream, config::SessionConfig, transaction::StreamState},
+    client::{RequestMetadata, TransactionStream, config::SessionConfig, transaction::StreamState},
+    compression::{compress, decompress},
     test::Descriptor,
 };
 
@@ -69,10 +71,14 @@ fn setup_recv_mapped<T>(
 
     let permit_value = with_permit(&permit);
 
+    let (request_tx, _) = mpsc::channel(8);
+
     let task = TransactionReceiveTask {
         config,
         rx: response_rx,
         tx: stream_tx,
+        cancel_request: CancellationToken::new(),
+        request_tx,
         permit: Arc::new(permit),
     };
 
@@ -723,6 +729,49 @@ async fn receive_cancel() {
         .expect("should not panic");
 }
 
+#[tokio::test]
+async fn receive_cancel_request() {
+    let (_response_tx, response_rx) = tachyonix::channel(8);
+    let (stream_tx, _stream_rx) = mpsc::channel(8);
+    let (request_tx, mut request_rx) = mpsc::channel(8);
+
+    let permit = Arc::new(StaticTransactionPermit {
+        id: mock_request_id(0x00),
+        cancel: CancellationToken::new(),
+    });
+    let cancel_request = CancellationToken::new();
+
+    let task = TransactionReceiveTask {
+        config: SessionConfig::default(),
+        rx: response_rx,
+        tx: stream_tx,
+        cancel_request: cancel_request.clone(),
+        request_tx,
+        permit: Arc::clone(&permit),
+    };
+
+    let handle = tokio::spawn(task.run());
+
+    cancel_request.cancel();
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+
+    // the server is notified and any other task of the transaction is stopped
+    let request = request_rx
+        .recv()
+        .await
+        .expect("should have received request");
+    assert_eq!(request.header.request_id, mock_request_id(0x00));
+    assert!(request.header.flags.contains(RequestFlag::CancelRequest));
+    assert!(request.header.flags.contains(RequestFlag::EndOfRequest));
+    assert!(request.body.payload().is_empty());
+
+    assert!(permit.cancel.is_cancelled());
+}
+
 fn setup_send_mapped<T>(
     config: SessionConfig,
     descriptor: Descriptor,
@@ -747,6 +796,7 @@ fn setup_send_mapped<T>(
         config,
         service: descriptor.service,
         procedure: descriptor.procedure,
+        metadata: None,
         rx: ReceiverStream::new(bytes_rx),
         tx: request_tx,
         permit: Arc::new(permit),
@@ -1083,3 +1133,187 @@ async fn send_cancel() {
         .expect("should finish within timeout")
         .expect("should not panic");
 }
+
+#[tokio::test]
+async fn send_metadata() {
+    let descriptor = Descriptor::default();
+
+    let (bytes_tx, bytes_rx) = mpsc::channel(8);
+    let (request_tx, mut request_rx) = mpsc::channel(8);
+
+    let task = TransactionSendTask {
+        config: SessionConfig {
+            no_delay: true,
+            ..SessionConfig::default()
+        },
+        service: descriptor.service,
+        procedure: descriptor.procedure,
+        metadata: Some(
+            RequestMetadata::new(Bytes::from_static(b"metadata")).expect("metadata is small"),
+        ),
+        rx: ReceiverStream::new(bytes_rx),
+        tx: request_tx,
+        permit: Arc::new(StaticTransactionPermit {
+            id: mock_request_id(0x00),
+            cancel: CancellationToken::new(),
+        }),
+    };
+
+    let handle = tokio::spawn(task.run());
+
+    bytes_tx
+        .send(Bytes::from_static(b"apple"))
+        .await
+        .expect("able to send bytes");
+
+    let request = request_rx.recv().await.expect("able to receive request");
+    assert!(request.header.flags.contains(RequestFlag::ContainsMetadata));
+    assert_matches!(
+        request.body,
+        RequestBody::Begin(RequestBegin {
+            payload,
+            ..
+        }) if *payload.as_bytes() == Bytes::from_static(b"\x00\x08metadataapple")
+    );
+
+    drop(bytes_tx);
+
+    // only the begin packet is marked as containing metadata
+    let request = request_rx.recv().await.expect("able to receive request");
+    assert!(!request.header.flags.contains(RequestFlag::ContainsMetadata));
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+}
+
+#[test]
+fn metadata_too_large() {
+    let bytes = Bytes::from(vec![0_u8; RequestMetadata::MAX_SIZE + 1]);
+
+    let error = RequestMetadata::new(bytes).expect_err("metadata should be too large");
+    assert_eq!(error.limit, RequestMetadata::MAX_SIZE);
+    assert_eq!(error.size, RequestMetadata::MAX_SIZE + 1);
+}
+
+#[tokio::test]
+async fn send_compressed() {
+    let descriptor = Descriptor::default();
+    let (tx, mut rx, handle) = setup_send(
+        SessionConfig {
+            no_delay: true,
+            compression: Some(Compression::Lz4),
+            ..SessionConfig::default()
+        },
+        descriptor,
+    );
+
+    let payload = Bytes::from("apple".repeat(64));
+
+    tx.send(payload.clone()).await.expect("able to send bytes");
+
+    let request = rx.recv().await.expect("able to receive request");
+    assert_eq!(request.header.flags.compression(), Some(Compression::Lz4));
+    assert!(request.header.flags.contains(RequestFlag::Compressed));
+
+    let RequestBody::Begin(RequestBegin {
+        payload: compressed,
+        ..
+    }) = request.body
+    else {
+        panic!("expected begin request, got {:?}", request.body);
+    };
+    assert_eq!(
+        decompress(Compression::Lz4, compressed.as_bytes()).expect("able to decompress"),
+        payload
+    );
+
+    drop(tx);
+
+    // the compression is proposed on every packet, even if the packet itself isn't compressed
+    let request = rx.recv().await.expect("able to receive request");
+    assert!(request.header.flags.contains(RequestFlag::EndOfRequest));
+    assert_eq!(request.header.flags.compression(), Some(Compression::Lz4));
+    assert!(!request.header.flags.contains(RequestFlag::Compressed));
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+}
+
+#[tokio::test]
+async fn receive_compressed() {
+    let (tx, mut rx, handle) = setup_recv(SessionConfig::default());
+
+    let payload = Bytes::from("hello world".repeat(64));
+    let compressed = compress(Compression::Zstd, &payload).expect("should be compressible");
+
+    tx.send(make_response_begin(
+        ResponseFlag::Compressed | ResponseFlag::CompressionZstd,
+        ResponseKind::Ok,
+        compressed,
+    ))
+    .await
+    .expect("able to send response");
+
+    // packets that aren't compressed are passed through as-is
+    tx.send(make_response_frame(
+        ResponseFlag::EndOfResponse | ResponseFlag::CompressionZstd,
+        b"!" as &[_],
+    ))
+    .await
+    .expect("able to send response");
+
+    let mut stream = rx
+        .recv()
+        .await
+        .expect("able to receive stream")
+        .expect("should be ok stream");
+
+    assert_eq!(
+        stream.next().await.expect("should receive payload"),
+        payload
+    );
+    assert_eq!(
+        stream
+            .next()
+            .await
+            .expect("should receive payload")
+            .as_ref(),
+        b"!"
+    );
+    assert!(stream.next().await.is_none());
+
+    assert_eq!(
+        stream.state().map(StreamState::is_end_of_response),
+        Some(true)
+    );
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+}
+
+#[tokio::test]
+async fn receive_compressed_malformed() {
+    let (tx, mut rx, handle) = setup_recv(SessionConfig::default());
+
+    tx.send(make_response_begin(
+        ResponseFlag::Compressed | ResponseFlag::CompressionLz4,
+        ResponseKind::Ok,
+        b"\xFF\xFF\xFF\xFF" as &[_],
+    ))
+    .await
+    .expect("able to send response");
+
+    // the transaction is cancelled, without ever delivering a response
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+
+    assert!(rx.recv().await.is_none());
+}
@@ -0,0 +1,168 @@
+use bytes::Bytes;
+use error_stack::Report;
+use harpc_wire_protocol::{
+    compression::Compression,
+    flags::BitFlagsOp,
+    payload::Payload,
+    request::{
+        Request, begin::RequestBegin, body::RequestBody, flags::RequestFlag, frame::RequestFrame,
+    },
+    response::{
+        Response, begin::ResponseBegin, body::ResponseBody, flags::ResponseFlag,
+        frame::ResponseFrame,
+    },
+};
+
+use super::error::DecompressionError;
+
+/// Compress the payload of a single packet.
+///
+/// Returns `None` if the compressed payload wouldn't be smaller than the original, in that case
+/// the packet should be sent uncompressed.
+pub(crate) fn compress(compression: Compression, bytes: &[u8]) -> Option<Bytes> {
+    if bytes.is_empty() {
+        return None;
+    }
+
+    let compressed = match compression {
+        // level `0` uses the default compression level of zstd
+        Compression::Zstd => match zstd::bulk::compress(bytes, 0) {
+            Ok(compressed) => compressed,
+            Err(error) => {
+                tracing::warn!(?error, "unable to compress payload, sending uncompressed");
+                return None;
+            }
+        },
+        Compression::Lz4 => lz4_flex::block::compress(bytes),
+    };
+
+    (compressed.len() < bytes.len()).then(|| Bytes::from(compressed))
+}
+
+/// Decompress the payload of a single packet.
+///
+/// Packets are compressed individually and never exceed [`Payload::MAX_SIZE`] uncompressed, any
+/// payload that decompresses to more than that is rejected.
+pub(crate) fn decompress(
+    compression: Compression,
+    bytes: &[u8],
+) -> Result<Bytes, Report<DecompressionError>> {
+    match compression {
+        Compression::Zstd => zstd::bulk::decompress(bytes, Payload::MAX_SIZE)
+            .map(Bytes::from)
+            .map_err(|error| Report::new(error).change_context(DecompressionError)),
+        Compression::Lz4 => {
+            let mut buffer = vec![0; Payload::MAX_SIZE];
+
+            let length = lz4_flex::block::decompress_into(bytes, &mut buffer)
+                .map_err(|error| Report::new(error).change_context(DecompressionError))?;
+            buffer.truncate(length);
+
+            Ok(Bytes::from(buffer))
+        }
+    }
+}
+
+fn decompress_payload(
+    compression: Option<Compression>,
+    payload: &mut Payload,
+) -> Result<(), Report<DecompressionError>> {
+    let Some(compression) = compression else {
+        return Err(Report::new(DecompressionError)
+            .attach_printable("packet is compressed, but does not specify a compression"));
+    };
+
+    *payload = Payload::new(decompress(compression, payload.as_bytes())?);
+
+    Ok(())
+}
+
+/// Decompress the payload of a request in place, if it is compressed.
+///
+/// Once decompressed the packet is no longer marked as compressed.
+pub(crate) fn decompress_request(request: &mut Request) -> Result<(), Report<DecompressionError>> {
+    if !request.header.flags.contains(RequestFlag::Compressed) {
+        return Ok(());
+    }
+
+    let (RequestBody::Begin(RequestBegin { payload, .. })
+    | RequestBody::Frame(RequestFrame { payload })) = &mut request.body;
+
+    decompress_payload(request.header.flags.compression(), payload)?;
+    request.header.flags = request.header.flags.remove(RequestFlag::Compressed);
+
+    Ok(())
+}
+
+/// Decompress the payload of a response in place, if it is compressed.
+///
+/// Once decompressed the packet is no longer marked as compressed.
+pub(crate) fn decompress_response(
+    response: &mut Response,
+) -> Result<(), Report<DecompressionError>> {
+    if !response.header.flags.contains(ResponseFlag::Compressed) {
+        return Ok(());
+    }
+
+    let (ResponseBody::Begin(ResponseBegin { payload, .. })
+    | ResponseBody::Frame(ResponseFrame { payload })) = &mut response.body;
+
+    decompress_payload(response.header.flags.compression(), payload)?;
+    response.header.flags = response.header.flags.remove(ResponseFlag::Compressed);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+    use harpc_wire_protocol::{compression::Compression, payload::Payload};
+
+    use super::{compress, decompress};
+
+    const COMPRESSIONS: [Compression; 2] = [Compression::Zstd, Compression::Lz4];
+
+    #[test]
+    fn round_trip() {
+        let bytes = "hello world, ".repeat(1024).into_bytes();
+
+        for compression in COMPRESSIONS {
+            let compressed =
+                compress(compression, &bytes).expect("repetitive payload should be compressible");
+            assert!(compressed.len() < bytes.len());
+
+            let decompressed =
+                decompress(compression, &compressed).expect("should be able to decompress");
+            assert_eq!(decompressed, Bytes::from(bytes.clone()));
+        }
+    }
+
+    #[test]
+    fn incompressible() {
+        for compression in COMPRESSIONS {
+            assert_eq!(compress(compression, b""), None);
+            // too short to be compressed, the overhead outweighs any gain
+            assert_eq!(compress(compression, b"a"), None);
+        }
+    }
+
+    #[test]
+    fn decompress_malformed() {
+        for compression in COMPRESSIONS {
+            decompress(compression, &[0xFF; 32]).expect_err("should not decompress garbage");
+        }
+    }
+
+    #[test]
+    fn decompress_too_large() {
+        // a payload larger than a packet can never have been produced by the writer
+        let bytes = vec![0_u8; Payload::MAX_SIZE + 1];
+
+        for compression in COMPRESSIONS {
+            let compressed = compress(compression, &bytes).expect("should be compressible");
+
+            decompress(compression, &compressed)
+                .expect_err("should reject payloads larger than a packet");
+        }
+    }
+}
@@ -48,6 +48,16 @@ impl WireError for InstanceTransactionLimitReachedError {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+#[error("the payload of the packet could not be decompressed")]
+pub struct DecompressionError;
+
+impl WireError for DecompressionError {
+    fn code(&self) -> ErrorCode {
+        ErrorCode::DECOMPRESSION_FAILED
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
 #[error("session has been clossed")]
 pub struct ConnectionClosedError;
@@ -79,3 +89,18 @@ pub struct ConnectionPartiallyClosedError {
     pub read: bool,
     pub write: bool,
 }
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+#[error("the request metadata is {size} bytes large, which exceeds the limit of {limit} bytes")]
+pub struct MetadataTooLargeError {
+    pub size: usize,
+    pub limit: usize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+pub enum PoolError {
+    #[error("none of the addresses of the pool can be reached")]
+    Unavailable,
+    #[error("the connection has been closed before a response has been received")]
+    ConnectionClosed,
+}
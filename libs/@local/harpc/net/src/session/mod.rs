@@ -7,6 +7,7 @@ pub mod client;
 pub mod error;
 pub mod server;
 
+mod compression;
 mod gc;
 mod writer;
 
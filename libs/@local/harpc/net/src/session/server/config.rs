@@ -149,6 +149,15 @@ pub struct SessionConfig {
     ///
     /// **Default:** `false`
     pub no_delay: bool,
+
+    /// Accept the compression proposed by the client for a transaction.
+    /// If accepted, the response is compressed using the proposed compression, which reduces the
+    /// bandwidth used at the cost of CPU time. If not accepted, the response is sent uncompressed.
+    ///
+    /// Compressed requests are decompressed regardless of this setting.
+    ///
+    /// **Default:** `true`
+    pub accept_compression: bool,
 }
 
 impl Default for SessionConfig {
@@ -173,6 +182,8 @@ impl Default for SessionConfig {
             per_transaction_response_byte_stream_buffer_size: non_zero!(16),
 
             no_delay: false,
+
+            accept_compression: true,
         }
     }
 }
@@ -155,11 +155,17 @@ impl TransactionCollection {
         }
     }
 
-    fn cancel(&self, id: RequestId) {
+    /// Cancel the transaction with the given id, if it exists.
+    ///
+    /// The request stream of the transaction is closed and any pending response is discarded.
+    /// The state is removed once the permit of the transaction has been dropped.
+    pub(crate) fn cancel(&self, id: RequestId) {
         let guard = Guard::new();
         let Some(state) = self.storage.peek(&id, &guard) else {
             return;
         };
+
+        state.sender.close();
         state.cancel.cancel();
     }
 
@@ -6,9 +6,12 @@ use alloc::sync::Arc;
 use core::{fmt::Debug, future};
 use std::io;
 
+use bytes::{Buf, Bytes};
 use futures::{FutureExt, Sink, Stream, StreamExt, stream};
 use harpc_wire_protocol::{
-    request::{Request, body::RequestBody, id::RequestId},
+    flags::BitFlagsOp,
+    payload::Payload,
+    request::{Request, body::RequestBody, flags::RequestFlag, id::RequestId},
     response::{Response, kind::ResponseKind},
 };
 use libp2p::PeerId;
@@ -28,14 +31,37 @@ use super::{
 use crate::{
     codec::{ErrorEncoder, WireError},
     session::{
+        compression::decompress_request,
         error::{
-            ConnectionGracefulShutdownError, InstanceTransactionLimitReachedError, TransactionError,
+            ConnectionGracefulShutdownError, DecompressionError,
+            InstanceTransactionLimitReachedError, TransactionError,
         },
         gc::ConnectionGarbageCollectorTask,
         writer::{ResponseContext, ResponseWriter, WriterOptions},
     },
 };
 
+/// Split the metadata prefix from the payload of a `Begin` request.
+///
+/// Returns `None` if the payload is too short to contain the metadata.
+fn split_metadata(payload: &mut Payload) -> Option<Bytes> {
+    let mut bytes = core::mem::replace(payload, Payload::new(Bytes::new())).into_bytes();
+
+    if bytes.len() < 2 {
+        return None;
+    }
+
+    let length = usize::from(bytes.get_u16());
+    if bytes.len() < length {
+        return None;
+    }
+
+    let metadata = bytes.split_to(length);
+    *payload = Payload::new(bytes);
+
+    Some(metadata)
+}
+
 struct ConnectionDelegateTask<T> {
     rx: mpsc::Receiver<Response>,
 
@@ -92,6 +118,7 @@ where
             ResponseContext {
                 id,
                 kind: ResponseKind::Err(code),
+                compression: None,
             },
             tx,
         );
@@ -108,13 +135,51 @@ where
         &self,
         tx: mpsc::Sender<Response>,
         tasks: &TaskTracker,
-        request: Request,
+        mut request: Request,
     ) {
         // check if this is a `Begin` request, in that case we need to create a new transaction,
         // otherwise, this is already a transaction and we need to forward it, or log out if it is a
         // rogue request
         let request_id = request.header.request_id;
 
+        // a cancel request terminates the transaction, regardless of the body, this also means that
+        // a `Begin` request that is marked as cancelled never starts a transaction.
+        if request.header.flags.contains(RequestFlag::CancelRequest) {
+            tracing::debug!(%request_id, "transaction has been cancelled by the client");
+
+            self.transactions.cancel(request_id);
+            return;
+        }
+
+        // decompress the packet first, so that neither the metadata handling nor the transaction
+        // need to be aware of the compression.
+        if let Err(error) = decompress_request(&mut request) {
+            tracing::info!(%request_id, ?error, "unable to decompress request, dropping request");
+
+            // the transaction might have already been started by a previous packet, in that case
+            // it is no longer able to complete.
+            self.transactions.cancel(request_id);
+            self.respond_error(request_id, DecompressionError, &tx)
+                .await;
+            return;
+        }
+
+        // the metadata is only part of the first packet, it isn't part of the payload the
+        // transaction receives.
+        let metadata = match &mut request.body {
+            RequestBody::Begin(begin)
+                if request.header.flags.contains(RequestFlag::ContainsMetadata) =>
+            {
+                let Some(metadata) = split_metadata(&mut begin.payload) else {
+                    tracing::info!(%request_id, "malformed request metadata, dropping request");
+                    return;
+                };
+
+                Some(metadata)
+            }
+            RequestBody::Begin(_) | RequestBody::Frame(_) => None,
+        };
+
         // these transactions then need to be propagated to the main session layer via an mpsc
         // channel, which drops a transaction if there's too many.
         match &request.body {
@@ -174,12 +239,22 @@ where
                     }
                 };
 
+                // the response is compressed using the compression proposed by the client, unless
+                // disabled
+                let compression = request
+                    .header
+                    .flags
+                    .compression()
+                    .filter(|_| self.config.accept_compression);
+
                 let (transaction, task) = Transaction::from_request(begin, TransactionParts {
                     peer: self.peer,
                     session: self.session,
                     config: self.config,
                     rx: request_rx,
                     tx: tx.clone(),
+                    metadata,
+                    compression,
                     permit,
                 });
 
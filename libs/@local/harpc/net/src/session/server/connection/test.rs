@@ -709,6 +709,151 @@ async fn transaction() {
     assert_eq!(response.body.payload().as_bytes().as_ref(), b"world");
 }
 
+#[tokio::test]
+async fn transaction_cancel() {
+    // a cancelled transaction stops receiving requests and does not send a response
+    let Setup {
+        mut output,
+        events: _events,
+        stream,
+        mut sink,
+        handle: _handle,
+        storage,
+    } = Setup::new(SessionConfig::default());
+
+    stream
+        .send(Ok(make_request_begin(
+            RequestFlags::EMPTY,
+            b"hello" as &[_],
+        )))
+        .await
+        .expect("should be able to send message");
+
+    let transaction = output.recv().await.expect("should receive transaction");
+    let cancel = transaction.cancellation_token();
+    let (_, mut txn_sink, mut txn_stream) = transaction.into_parts();
+
+    assert_eq!(
+        txn_stream.next().await,
+        Some(Bytes::from_static(b"hello" as &[_]))
+    );
+
+    stream
+        .send(Ok(make_request_frame(
+            RequestFlag::CancelRequest | RequestFlag::EndOfRequest,
+            Bytes::new(),
+        )))
+        .await
+        .expect("should be able to send message");
+
+    tokio::time::timeout(Duration::from_secs(1), cancel.cancelled())
+        .await
+        .expect("transaction should have been cancelled");
+
+    // the request stream is terminated, but incomplete
+    assert_eq!(txn_stream.next().await, None);
+    assert_eq!(txn_stream.is_incomplete(), Some(true));
+
+    // anything sent afterwards is discarded
+    let _result = txn_sink
+        .send(Ok(Bytes::from_static(b"world" as &[_])))
+        .await;
+    drop(txn_sink);
+    drop(txn_stream);
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(sink.try_recv().is_err());
+    assert!(storage.is_empty());
+}
+
+#[tokio::test]
+async fn transaction_cancel_begin() {
+    // a `Begin` request that has been cancelled never starts a transaction
+    let Setup {
+        mut output,
+        events: _events,
+        stream,
+        mut sink,
+        handle: _handle,
+        storage,
+    } = Setup::new(SessionConfig::default());
+
+    stream
+        .send(Ok(make_request_begin(
+            RequestFlag::CancelRequest | RequestFlag::EndOfRequest,
+            b"hello" as &[_],
+        )))
+        .await
+        .expect("should be able to send message");
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(output.try_recv().is_err());
+    assert!(sink.try_recv().is_err());
+    assert!(storage.is_empty());
+}
+
+#[tokio::test]
+async fn transaction_metadata() {
+    // the metadata prefix is split from the payload and exposed on the context
+    let Setup {
+        mut output,
+        events: _events,
+        stream,
+        sink: _sink,
+        handle: _handle,
+        storage: _,
+    } = Setup::new(SessionConfig::default());
+
+    stream
+        .send(Ok(make_request_begin(
+            RequestFlag::ContainsMetadata | RequestFlag::EndOfRequest,
+            b"\x00\x08metadatahello" as &[_],
+        )))
+        .await
+        .expect("should be able to send message");
+
+    let transaction = output.recv().await.expect("should receive transaction");
+    assert_eq!(
+        transaction.context().metadata(),
+        Some(&Bytes::from_static(b"metadata"))
+    );
+
+    let (_, _, mut txn_stream) = transaction.into_parts();
+    assert_eq!(
+        txn_stream.next().await,
+        Some(Bytes::from_static(b"hello" as &[_]))
+    );
+}
+
+#[tokio::test]
+async fn transaction_metadata_malformed() {
+    // a metadata prefix that is longer than the payload drops the request
+    let Setup {
+        mut output,
+        events: _events,
+        stream,
+        mut sink,
+        handle: _handle,
+        storage,
+    } = Setup::new(SessionConfig::default());
+
+    stream
+        .send(Ok(make_request_begin(
+            RequestFlag::ContainsMetadata | RequestFlag::EndOfRequest,
+            b"\x00\xFFhello" as &[_],
+        )))
+        .await
+        .expect("should be able to send message");
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(output.try_recv().is_err());
+    assert!(sink.try_recv().is_err());
+    assert!(storage.is_empty());
+}
+
 #[tokio::test]
 async fn transaction_multiple() {
     // send and finish multiple transactions simultaneously
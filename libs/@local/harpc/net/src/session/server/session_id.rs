@@ -17,7 +17,7 @@ impl SessionIdProducer {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct SessionId(usize);
+pub struct SessionId(pub(crate) usize);
 
 #[cfg(any(test, feature = "test-utils"))]
 pub(crate) mod test_utils {
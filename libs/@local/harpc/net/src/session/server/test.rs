@@ -39,10 +39,8 @@ use crate::{
     macros::non_zero,
     session::{server::config::ConcurrentConnectionLimit, test::StringEncoder},
     transport::{
-        TransportLayer,
-        connection::OutgoingConnection,
-        error::TransportError,
-        test::{layer, memory_address},
+        TransportLayer, connection::OutgoingConnection, error::TransportError, memory_address,
+        test::layer,
     },
 };
 
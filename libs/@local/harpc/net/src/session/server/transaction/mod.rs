@@ -10,6 +10,7 @@ use core::{
 use bytes::Bytes;
 use futures::{Sink, Stream, StreamExt, stream::FusedStream};
 use harpc_wire_protocol::{
+    compression::Compression,
     flags::BitFlagsOp,
     request::{
         Request, begin::RequestBegin, flags::RequestFlag, id::RequestId,
@@ -37,6 +38,7 @@ pub(crate) trait ServerTransactionPermit: Send + Sync + 'static {
 
 struct TransactionSendDelegateTask<P> {
     config: SessionConfig,
+    compression: Option<Compression>,
 
     // TODO: consider switching to `tachyonix` crate for better performance (not yet tested)
     // as well as more predictable buffering behavioud. `PollSender` is prone to just buffer
@@ -68,6 +70,7 @@ where
             ResponseContext {
                 id: self.permit.id(),
                 kind: ResponseKind::Ok,
+                compression: self.compression,
             },
             &self.tx,
         );
@@ -115,6 +118,7 @@ where
                         ResponseContext {
                             id: self.permit.id(),
                             kind: ResponseKind::Err(code),
+                            compression: self.compression,
                         },
                         &self.tx,
                     );
@@ -132,6 +136,7 @@ where
 
 pub(crate) struct TransactionTask<P> {
     config: SessionConfig,
+    compression: Option<Compression>,
 
     response_rx: mpsc::Receiver<Result<Bytes, TransactionError>>,
     response_tx: mpsc::Sender<Response>,
@@ -146,6 +151,7 @@ where
     pub(super) fn start(self, tasks: &TaskTracker) {
         let send = TransactionSendDelegateTask {
             config: self.config,
+            compression: self.compression,
 
             rx: self.response_rx,
             tx: self.response_tx,
@@ -166,6 +172,10 @@ pub(crate) struct TransactionParts<P> {
     pub rx: tachyonix::Receiver<Request>,
     pub tx: mpsc::Sender<Response>,
 
+    pub metadata: Option<Bytes>,
+    /// The compression used for the response.
+    pub compression: Option<Compression>,
+
     pub permit: P,
 }
 
@@ -180,6 +190,8 @@ pub struct TransactionContext {
 
     service: ServiceDescriptor,
     procedure: ProcedureDescriptor,
+
+    metadata: Option<Bytes>,
 }
 
 impl TransactionContext {
@@ -207,6 +219,14 @@ impl TransactionContext {
     pub const fn procedure(&self) -> ProcedureDescriptor {
         self.procedure
     }
+
+    /// The metadata the client has sent alongside the request, if any.
+    ///
+    /// The metadata is opaque to the session layer, its interpretation is left to the consumer.
+    #[must_use]
+    pub const fn metadata(&self) -> Option<&Bytes> {
+        self.metadata.as_ref()
+    }
 }
 
 pub struct Transaction {
@@ -231,6 +251,8 @@ impl Transaction {
             config,
             rx,
             tx,
+            metadata,
+            compression,
             permit,
         }: TransactionParts<TransactionPermit>,
     ) -> (Self, TransactionTask<TransactionPermit>) {
@@ -249,6 +271,7 @@ impl Transaction {
                 session,
                 service: body.service,
                 procedure: body.procedure,
+                metadata,
             },
 
             request: rx,
@@ -259,6 +282,7 @@ impl Transaction {
 
         let task = TransactionTask {
             config,
+            compression,
 
             response_rx,
             response_tx: tx,
@@ -286,6 +310,15 @@ impl Transaction {
         (context, sink, stream)
     }
 
+    /// A token that is cancelled once the transaction has been cancelled.
+    ///
+    /// This happens if the client explicitly cancels the transaction, or the transaction has been
+    /// aborted by the session, in both cases no response will be delivered.
+    #[must_use]
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.permit.cancellation_token().child_token()
+    }
+
     #[must_use]
     pub fn is_closed(&self) -> bool {
         self.response.is_closed()
@@ -4,6 +4,7 @@ use core::{num::NonZero, time::Duration};
 use bytes::Bytes;
 use harpc_types::{procedure::ProcedureId, service::ServiceId, version::Version};
 use harpc_wire_protocol::{
+    compression::Compression,
     flags::BitFlagsOp,
     payload::Payload,
     protocol::{Protocol, ProtocolVersion},
@@ -34,6 +35,7 @@ use tokio_util::sync::CancellationToken;
 
 use super::{ServerTransactionPermit, TransactionStream};
 use crate::session::{
+    compression::decompress,
     error::TransactionError,
     server::{
         SessionConfig, connection::test::make_transaction_permit,
@@ -76,6 +78,17 @@ fn setup_send(
     mpsc::Sender<Result<Bytes, TransactionError>>,
     mpsc::Receiver<Response>,
     JoinHandle<()>,
+) {
+    setup_send_compressed(no_delay, None)
+}
+
+fn setup_send_compressed(
+    no_delay: bool,
+    compression: Option<Compression>,
+) -> (
+    mpsc::Sender<Result<Bytes, TransactionError>>,
+    mpsc::Receiver<Response>,
+    JoinHandle<()>,
 ) {
     // we choose 8 here, so that we can buffer all replies easily and not spawn an extra task
     let (bytes_tx, bytes_rx) = mpsc::channel(8);
@@ -87,6 +100,7 @@ fn setup_send(
         } else {
             config_delay()
         },
+        compression,
         rx: bytes_rx,
         tx: response_tx,
         permit: Arc::new(StaticTransactionPermit {
@@ -1192,6 +1206,54 @@ async fn send_no_delay_error_split_large() {
     });
 }
 
+#[tokio::test]
+async fn send_compressed() {
+    let (bytes_tx, mut response_rx, handle) = setup_send_compressed(false, Some(Compression::Zstd));
+
+    let payload = Bytes::from_static(&[0; Payload::MAX_SIZE + 8]);
+
+    bytes_tx
+        .send(Ok(payload.clone()))
+        .await
+        .expect("should not be closed");
+
+    drop(bytes_tx);
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+
+    let mut responses = Vec::with_capacity(4);
+    let available = response_rx.recv_many(&mut responses, 4).await;
+    assert_eq!(available, 2);
+
+    // every packet is compressed on its own
+    let ResponseBody::Begin(ResponseBegin {
+        kind,
+        payload: begin,
+    }) = &responses[0].body
+    else {
+        panic!("expected begin response, got {:?}", responses[0]);
+    };
+    assert_eq!(
+        responses[0].header.flags,
+        ResponseFlags::from(ResponseFlag::Compressed | ResponseFlag::CompressionZstd)
+    );
+    assert_eq!(*kind, ResponseKind::Ok);
+    assert!(begin.len() < Payload::MAX_SIZE);
+    assert_eq!(
+        decompress(Compression::Zstd, begin.as_bytes()).expect("should be able to decompress"),
+        payload[..Payload::MAX_SIZE]
+    );
+
+    // the remainder is too small to benefit from compression, and is therefore sent as-is
+    assert_frame(&responses[1], ExpectedFrame {
+        flags: ResponseFlags::from(ResponseFlag::EndOfResponse | ResponseFlag::CompressionZstd),
+        payload: &payload[Payload::MAX_SIZE..],
+    });
+}
+
 async fn setup_recv() -> (tachyonix::Sender<Request>, TransactionStream) {
     let (permit, tx, rx) =
         make_transaction_permit(SessionConfig::default(), mock_request_id(0x00)).await;
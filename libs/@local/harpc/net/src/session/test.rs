@@ -1,7 +1,7 @@
 use alloc::sync::Arc;
 use core::{future::ready, iter, net::Ipv4Addr, time::Duration};
 
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
 use error_stack::{Report, ResultExt};
 use futures::{prelude::stream, sink::SinkExt, stream::StreamExt};
 use harpc_types::{procedure::ProcedureId, service::ServiceId, version::Version};
@@ -10,13 +10,13 @@ use harpc_wire_protocol::{
     response::kind::ErrorCode,
 };
 use humansize::ISizeFormatter;
-use libp2p::{Multiaddr, multiaddr};
+use libp2p::{Multiaddr, core::transport::MemoryTransport, metrics::Registry, multiaddr};
 use tokio::{sync::Barrier, task::JoinSet, time::Instant};
 use tokio_util::sync::CancellationToken;
 
 use super::{
     client::{self, Connection},
-    error::TransactionError,
+    error::{PoolError, TransactionError},
     server::{
         self, ListenStream,
         transaction::{TransactionSink, TransactionStream},
@@ -24,7 +24,7 @@ use super::{
 };
 use crate::{
     codec::{ErrorEncoder, WireError},
-    transport::{Transport, TransportConfig, TransportLayer, test::memory_address},
+    transport::{Transport, TransportConfig, TransportLayer, memory_address},
 };
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -431,3 +431,239 @@ async fn echo_tcp_concurrent() {
 
     echo_concurrent(libp2p::tcp::tokio::Transport::default, address, 4).await;
 }
+
+async fn echo_server(address: Multiaddr) -> (server::SessionLayer<StringEncoder>, impl Drop) {
+    let (server, guard) = server(
+        TransportConfig::default(),
+        server::SessionConfig::default(),
+        MemoryTransport::default(),
+    );
+
+    let stream = server
+        .listen(address)
+        .await
+        .expect("should be able to listen on memory address");
+    SimpleEchoService::spawn(stream);
+
+    (server, guard)
+}
+
+fn pool(
+    config: client::PoolConfig,
+    addresses: impl IntoIterator<Item = Multiaddr>,
+) -> (client::ConnectionPool, impl Drop) {
+    let (session, guard) = client(
+        TransportConfig::default(),
+        client::SessionConfig::default(),
+        MemoryTransport::default(),
+    );
+
+    (
+        client::ConnectionPool::new(session, config, addresses),
+        guard,
+    )
+}
+
+fn encode_metrics(registry: &Registry) -> String {
+    let mut output = String::new();
+    prometheus_client::encoding::text::encode(&mut output, registry)
+        .expect("should be able to encode metrics");
+
+    output
+}
+
+async fn call_echo(pool: &client::ConnectionPool) -> Bytes {
+    let descriptor = Descriptor::default();
+
+    let mut stream = pool
+        .call_idempotent(
+            descriptor.service,
+            descriptor.procedure,
+            stream::iter([Bytes::from_static(b"hello world")]),
+        )
+        .await
+        .expect("should be able to call");
+
+    let response = stream
+        .next()
+        .await
+        .expect("should receive a response")
+        .expect("value response");
+
+    response
+        .fold(BytesMut::new(), |mut bytes, chunk| {
+            bytes.extend_from_slice(&chunk);
+            ready(bytes)
+        })
+        .await
+        .freeze()
+}
+
+#[test_log::test(tokio::test)]
+async fn pool_round_robin() {
+    let first = memory_address();
+    let second = memory_address();
+
+    let _first_server = echo_server(first.clone()).await;
+    let _second_server = echo_server(second.clone()).await;
+
+    let (pool, _guard) = pool(client::PoolConfig::default(), [
+        first.clone(),
+        second.clone(),
+    ]);
+
+    let mut registry = Registry::default();
+    pool.register_metrics(&mut registry);
+
+    for _ in 0..4 {
+        assert_eq!(call_echo(&pool).await, Bytes::from_static(b"hello world"));
+    }
+
+    let metrics = encode_metrics(&registry);
+
+    for address in [first, second] {
+        assert!(metrics.contains(&format!(
+            "harpc_client_pool_calls_total{{address=\"{address}\"}} 2"
+        )));
+        assert!(metrics.contains(&format!(
+            "harpc_client_pool_connections_total{{address=\"{address}\"}} 1"
+        )));
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn pool_reconnect() {
+    let address = memory_address();
+
+    let (pool, _guard) = pool(client::PoolConfig::default(), [address.clone()]);
+
+    let mut registry = Registry::default();
+    pool.register_metrics(&mut registry);
+
+    let server = echo_server(address.clone()).await;
+    assert_eq!(call_echo(&pool).await, Bytes::from_static(b"hello world"));
+
+    // restart the server, the connection of the pool is now closed
+    drop(server);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let _server = echo_server(address.clone()).await;
+
+    assert_eq!(call_echo(&pool).await, Bytes::from_static(b"hello world"));
+
+    let metrics = encode_metrics(&registry);
+    assert!(metrics.contains(&format!(
+        "harpc_client_pool_connections_total{{address=\"{address}\"}} 2"
+    )));
+}
+
+#[test_log::test(tokio::test)]
+async fn pool_unavailable() {
+    let address = memory_address();
+
+    let (pool, _guard) = pool(
+        client::PoolConfig {
+            backoff_initial: Duration::from_secs(60),
+            max_retries: 0,
+            ..client::PoolConfig::default()
+        },
+        [address.clone()],
+    );
+
+    let mut registry = Registry::default();
+    pool.register_metrics(&mut registry);
+
+    let descriptor = Descriptor::default();
+
+    for _ in 0..2 {
+        let report = pool
+            .call(
+                descriptor.service,
+                descriptor.procedure,
+                stream::iter([Bytes::from_static(b"hello world")]),
+            )
+            .await
+            .expect_err("should not be able to reach server");
+
+        assert_eq!(*report.current_context(), PoolError::Unavailable);
+    }
+
+    // the second call happens during the backoff of the address, so it isn't dialed again
+    let metrics = encode_metrics(&registry);
+    assert!(metrics.contains(&format!(
+        "harpc_client_pool_connection_failures_total{{address=\"{address}\"}} 1"
+    )));
+}
+
+#[test_log::test(tokio::test)]
+async fn pool_dial_timeout() {
+    // accepts connections, but never completes the protocol negotiation
+    let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+        .await
+        .expect("should be able to bind listener");
+    let port = listener
+        .local_addr()
+        .expect("listener should have a local address")
+        .port();
+    tokio::spawn(async move {
+        let mut connections = Vec::new();
+        while let Ok((stream, _)) = listener.accept().await {
+            connections.push(stream);
+        }
+    });
+
+    let address: Multiaddr = [
+        multiaddr::Protocol::Ip4(Ipv4Addr::LOCALHOST),
+        multiaddr::Protocol::Tcp(port),
+    ]
+    .into_iter()
+    .collect();
+
+    let (session, _guard) = client(
+        TransportConfig::default(),
+        client::SessionConfig::default(),
+        libp2p::tcp::tokio::Transport::default(),
+    );
+    let pool = client::ConnectionPool::new(
+        session,
+        client::PoolConfig {
+            dial_timeout: Duration::from_millis(100),
+            max_retries: 0,
+            ..client::PoolConfig::default()
+        },
+        [address],
+    );
+
+    let descriptor = Descriptor::default();
+
+    let report = tokio::time::timeout(
+        Duration::from_secs(5),
+        pool.call(
+            descriptor.service,
+            descriptor.procedure,
+            stream::iter([Bytes::from_static(b"hello world")]),
+        ),
+    )
+    .await
+    .expect("dialing the unresponsive address should time out")
+    .expect_err("should not be able to reach server");
+
+    assert_eq!(*report.current_context(), PoolError::Unavailable);
+}
+
+#[test_log::test(tokio::test)]
+async fn pool_empty() {
+    let (pool, _guard) = pool(client::PoolConfig::default(), []);
+
+    let descriptor = Descriptor::default();
+
+    let report = pool
+        .call(
+            descriptor.service,
+            descriptor.procedure,
+            stream::iter([Bytes::from_static(b"hello world")]),
+        )
+        .await
+        .expect_err("pool without addresses should be unavailable");
+
+    assert_eq!(*report.current_context(), PoolError::Unavailable);
+}
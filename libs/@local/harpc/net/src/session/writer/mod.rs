@@ -4,6 +4,7 @@ mod test;
 use bytes::{Buf, Bytes};
 use bytes_utils::SegmentedBuf;
 use harpc_wire_protocol::{
+    compression::Compression,
     flags::BitFlagsOp,
     payload::Payload,
     protocol::{Protocol, ProtocolVersion},
@@ -30,6 +31,8 @@ use harpc_wire_protocol::{
 };
 use tokio::sync::mpsc;
 
+use super::compression;
+
 pub(crate) struct WriterOptions {
     /// Whether to enable no-delay for packet transmission.
     ///
@@ -52,10 +55,14 @@ pub(crate) struct WriterOptions {
 pub(crate) trait NetworkPacket {
     type Context;
 
+    /// The compression applied to the payload of every packet, if any.
+    fn compression(context: &Self::Context) -> Option<Compression>;
+
     fn new_begin(context: &Self::Context, bytes: Bytes) -> Self;
     fn new_frame(context: &Self::Context, bytes: Bytes) -> Self;
 
     fn mark_end(&mut self);
+    fn mark_compressed(&mut self);
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -64,6 +71,11 @@ pub(crate) struct RequestContext {
 
     pub service: ServiceDescriptor,
     pub procedure: ProcedureDescriptor,
+
+    /// Whether the payload of the begin packet is prefixed with metadata.
+    pub metadata: bool,
+    /// The compression proposed for the transaction.
+    pub compression: Option<Compression>,
 }
 
 fn new_request_header(context: RequestContext) -> RequestHeader {
@@ -72,16 +84,44 @@ fn new_request_header(context: RequestContext) -> RequestHeader {
             version: ProtocolVersion::V1,
         },
         request_id: context.id,
-        flags: RequestFlags::empty(),
+        flags: RequestFlags::empty().with_compression(context.compression),
+    }
+}
+
+/// Create a request, which cancels the transaction with the given id.
+///
+/// The request is a standalone frame, it is therefore independent of the state of any
+/// [`RequestWriter`] that is used to send the payload of the transaction.
+pub(crate) fn new_cancel_request(id: RequestId) -> Request {
+    Request {
+        header: RequestHeader {
+            protocol: Protocol {
+                version: ProtocolVersion::V1,
+            },
+            request_id: id,
+            flags: RequestFlags::from(RequestFlag::CancelRequest | RequestFlag::EndOfRequest),
+        },
+        body: RequestBody::Frame(RequestFrame {
+            payload: Payload::new(Bytes::new()),
+        }),
     }
 }
 
 impl NetworkPacket for Request {
     type Context = RequestContext;
 
+    fn compression(context: &Self::Context) -> Option<Compression> {
+        context.compression
+    }
+
     fn new_begin(context: &Self::Context, bytes: Bytes) -> Self {
+        let mut header = new_request_header(*context);
+        if context.metadata {
+            header.flags = header.flags.insert(RequestFlag::ContainsMetadata);
+        }
+
         Self {
-            header: new_request_header(*context),
+            header,
             body: RequestBody::Begin(RequestBegin {
                 service: context.service,
                 procedure: context.procedure,
@@ -102,12 +142,19 @@ impl NetworkPacket for Request {
     fn mark_end(&mut self) {
         self.header.flags = self.header.flags.insert(RequestFlag::EndOfRequest);
     }
+
+    fn mark_compressed(&mut self) {
+        self.header.flags = self.header.flags.insert(RequestFlag::Compressed);
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub(crate) struct ResponseContext {
     pub id: RequestId,
     pub kind: ResponseKind,
+
+    /// The compression accepted for the transaction.
+    pub compression: Option<Compression>,
 }
 
 fn new_response_header(context: ResponseContext) -> ResponseHeader {
@@ -116,13 +163,17 @@ fn new_response_header(context: ResponseContext) -> ResponseHeader {
             version: ProtocolVersion::V1,
         },
         request_id: context.id,
-        flags: ResponseFlags::empty(),
+        flags: ResponseFlags::empty().with_compression(context.compression),
     }
 }
 
 impl NetworkPacket for Response {
     type Context = ResponseContext;
 
+    fn compression(context: &Self::Context) -> Option<Compression> {
+        context.compression
+    }
+
     fn new_begin(context: &Self::Context, bytes: Bytes) -> Self {
         Self {
             header: new_response_header(*context),
@@ -145,6 +196,10 @@ impl NetworkPacket for Response {
     fn mark_end(&mut self) {
         self.header.flags = self.header.flags.insert(ResponseFlag::EndOfResponse);
     }
+
+    fn mark_compressed(&mut self) {
+        self.header.flags = self.header.flags.insert(ResponseFlag::Compressed);
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
@@ -201,11 +256,24 @@ where
     }
 
     fn make(&self, bytes: Bytes) -> T {
-        if self.index == 0 {
+        // every packet is compressed on its own, so that the receiver is able to decompress it
+        // without having to wait for subsequent packets.
+        let compressed = T::compression(&self.context)
+            .and_then(|compression| compression::compress(compression, &bytes));
+        let is_compressed = compressed.is_some();
+        let bytes = compressed.unwrap_or(bytes);
+
+        let mut packet = if self.index == 0 {
             T::new_begin(&self.context, bytes)
         } else {
             T::new_frame(&self.context, bytes)
+        };
+
+        if is_compressed {
+            packet.mark_compressed();
         }
+
+        packet
     }
 
     async fn send(&mut self, packet: T) -> Result<(), mpsc::error::SendError<T>> {
@@ -22,6 +22,7 @@ fn push() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: None,
         },
         &tx,
     );
@@ -46,6 +47,7 @@ async fn write_remaining_on_empty() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: None,
         },
         &tx,
     );
@@ -74,6 +76,7 @@ async fn write_remaining_no_delay_increments_index() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: None,
         },
         &tx,
     );
@@ -100,6 +103,7 @@ async fn flush_calls_write() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: None,
         },
         &tx,
     );
@@ -121,6 +125,7 @@ async fn write_calls_write_remaining_on_no_delay() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: None,
         },
         &tx,
     );
@@ -146,6 +151,7 @@ async fn split_single() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: None,
         },
         &tx,
     );
@@ -181,6 +187,7 @@ async fn split_multiple() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: None,
         },
         &tx,
     );
@@ -226,6 +233,7 @@ async fn delay_push_merge_on_write() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: None,
         },
         &tx,
     );
@@ -256,6 +264,7 @@ async fn delay_write_checks_closed_state_even_if_not_writing() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: None,
         },
         &tx,
     );
@@ -280,6 +289,7 @@ async fn delay_write_checks_closed_state_not_if_empty_buffer() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compression: None,
         },
         &tx,
     );
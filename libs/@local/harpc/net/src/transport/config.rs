@@ -1,7 +1,9 @@
 use core::{num::NonZero, time::Duration};
 
 use libp2p::{
+    Multiaddr,
     core::upgrade,
+    multiaddr::Protocol,
     ping, swarm,
     yamux::{self, WindowUpdateMode},
 };
@@ -164,8 +166,55 @@ impl SwarmConfig {
     }
 }
 
+/// The underlying transport used by the transport layer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum TransportKind {
+    /// TCP, addresses are of the form `/ip4/<address>/tcp/<port>`.
+    #[default]
+    Tcp,
+    /// Unix domain sockets, addresses are of the form `/unix/<path>`.
+    ///
+    /// See [`UnixTransport`] for more information.
+    ///
+    /// [`UnixTransport`]: crate::transport::UnixTransport
+    #[cfg(unix)]
+    Unix,
+    /// In-process channels, addresses are of the form `/memory/<port>`.
+    ///
+    /// Only transport layers within the same process are able to connect to each other.
+    Memory,
+}
+
+impl TransportKind {
+    /// Determine the transport able to handle the given address.
+    ///
+    /// Returns `None` if none of the transports supports the address.
+    #[must_use]
+    pub fn from_address(address: &Multiaddr) -> Option<Self> {
+        match address.iter().next()? {
+            Protocol::Ip4(_)
+            | Protocol::Ip6(_)
+            | Protocol::Dns(_)
+            | Protocol::Dns4(_)
+            | Protocol::Dns6(_) => Some(Self::Tcp),
+            #[cfg(unix)]
+            Protocol::Unix(_) => Some(Self::Unix),
+            Protocol::Memory(_) => Some(Self::Memory),
+            _ => None,
+        }
+    }
+}
+
 /// Configuration for the transport layer.
 pub struct TransportConfig {
+    /// The transport used if the transport layer is started through
+    /// [`TransportLayer::start_from_config`].
+    ///
+    /// **Default:** [`TransportKind::Tcp`]
+    ///
+    /// [`TransportLayer::start_from_config`]: crate::transport::TransportLayer::start_from_config
+    pub kind: TransportKind,
+
     /// Configuration for the ping protocol.
     pub ping: ping::Config,
 
@@ -185,6 +234,7 @@ pub struct TransportConfig {
 impl Default for TransportConfig {
     fn default() -> Self {
         Self {
+            kind: TransportKind::default(),
             ping: ping::Config::default(),
             swarm: SwarmConfig::default(),
             yamux: YamuxConfig::default(),
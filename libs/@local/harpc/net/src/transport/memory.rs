@@ -0,0 +1,21 @@
+use core::sync::atomic::{AtomicU64, Ordering};
+
+pub use libp2p::core::transport::MemoryTransport;
+use libp2p::{Multiaddr, multiaddr::Protocol};
+
+/// Create a new, unique address for the [`MemoryTransport`].
+///
+/// The memory transport connects transport layers within the same process, without going through
+/// the network stack, which makes it well suited for tests and co-located services.
+#[must_use]
+pub fn memory_address() -> Multiaddr {
+    // to allow for unique port numbers, even if called concurrently we use an atomic
+    // we're not starting at `0` as `0` indicates that the port should be chosen by the
+    // underlying transport.
+    static CHANNEL: AtomicU64 = AtomicU64::new(1);
+
+    // `SeqCst` just to be on the safe side.
+    let id = CHANNEL.fetch_add(1, Ordering::SeqCst);
+
+    Multiaddr::empty().with(Protocol::Memory(id))
+}
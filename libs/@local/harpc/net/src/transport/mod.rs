@@ -4,10 +4,13 @@ mod config;
 pub mod connection;
 pub mod error;
 mod ipc;
+mod memory;
 mod server;
 mod task;
 #[cfg(test)]
 pub(crate) mod test;
+#[cfg(unix)]
+mod unix;
 
 use alloc::sync::Arc;
 
@@ -19,6 +22,8 @@ use tokio_util::{
     codec::Framed, compat::FuturesAsyncReadCompatExt, sync::CancellationToken, task::TaskTracker,
 };
 
+#[cfg(unix)]
+pub use self::unix::{UnixTransport, unix_address};
 use self::{
     client::ClientCodec,
     connection::{IncomingConnections, OutgoingConnection},
@@ -26,8 +31,9 @@ use self::{
     task::TransportTask,
 };
 pub use self::{
-    config::{SwarmConfig, TransportConfig, YamuxConfig},
+    config::{SwarmConfig, TransportConfig, TransportKind, YamuxConfig},
     ipc::TransportLayerIpc,
+    memory::{MemoryTransport, memory_address},
 };
 
 const PROTOCOL_NAME: StreamProtocol = StreamProtocol::new("/harpc/1.0.0");
@@ -87,6 +93,27 @@ impl TransportLayer {
         })
     }
 
+    /// Create a new transport layer, using the transport selected by [`TransportConfig::kind`].
+    ///
+    /// This will create a new task, which will drive the internal state of the transport layer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task fails to start.
+    pub fn start_from_config(
+        config: TransportConfig,
+        cancel: CancellationToken,
+    ) -> Result<Self, TransportError> {
+        match config.kind {
+            TransportKind::Tcp => {
+                Self::start(config, libp2p::tcp::tokio::Transport::default(), cancel)
+            }
+            #[cfg(unix)]
+            TransportKind::Unix => Self::start(config, UnixTransport::new(), cancel),
+            TransportKind::Memory => Self::start(config, MemoryTransport::default(), cancel),
+        }
+    }
+
     pub(crate) fn cancellation_token(&self) -> CancellationToken {
         self.cancel.clone()
     }
@@ -269,6 +269,13 @@ impl TransportTask {
         }
     }
 
+    fn handle_connection_closed(&mut self, peer_id: PeerId) {
+        // Once the last connection to a peer has been closed, the address may be taken over by a
+        // different peer (e.g. because the server has been restarted with a new identity), so any
+        // subsequent lookup needs to dial the address again.
+        self.peers.retain(|_, peer| *peer != peer_id);
+    }
+
     fn handle_event(&mut self, event: SwarmEvent<TransportBehaviourEvent>) {
         tracing::debug!(?event, "received swarm event");
 
@@ -296,6 +303,13 @@ impl TransportTask {
             } => {
                 self.handle_outgoing_connection_error(connection_id, peer_id, error);
             }
+            SwarmEvent::ConnectionClosed {
+                peer_id,
+                num_established: 0,
+                ..
+            } => {
+                self.handle_connection_closed(peer_id);
+            }
             _ => {}
         }
     }
@@ -1,10 +1,6 @@
-use core::{
-    assert_matches::assert_matches,
-    iter,
-    net::Ipv4Addr,
-    sync::atomic::{AtomicU64, Ordering},
-    time::Duration,
-};
+#[cfg(unix)]
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::{assert_matches::assert_matches, net::Ipv4Addr, time::Duration};
 
 use futures::{SinkExt, StreamExt, sink};
 use harpc_wire_protocol::{
@@ -26,7 +22,9 @@ use libp2p::{
 use libp2p_stream::OpenStreamError;
 use tokio_util::sync::CancellationToken;
 
-use super::{TransportConfig, TransportLayer};
+use super::{TransportConfig, TransportKind, TransportLayer, memory_address};
+#[cfg(unix)]
+use super::{UnixTransport, unix_address};
 use crate::transport::connection::{IncomingConnection, OutgoingConnection};
 
 static EXAMPLE_REQUEST: Request = Request {
@@ -57,18 +55,6 @@ static EXAMPLE_RESPONSE: Response = Response {
 
 const DEFAULT_DELAY: Duration = Duration::from_millis(10);
 
-pub(crate) fn memory_address() -> libp2p::Multiaddr {
-    // to allow for unique port numbers, even if the tests are run concurrently we use an atomic
-    // we're not starting at `0` as `0` indicates that the port should be chosen by the
-    // underlying transport.
-    static CHANNEL: AtomicU64 = AtomicU64::new(1);
-
-    // `SeqCst` just to be on the safe side.
-    let id = CHANNEL.fetch_add(1, Ordering::SeqCst);
-
-    iter::once(multiaddr::Protocol::Memory(id)).collect()
-}
-
 pub(crate) fn layer() -> (TransportLayer, impl Drop) {
     let transport = MemoryTransport::default();
     let config = TransportConfig::default();
@@ -479,3 +465,146 @@ async fn listen_on() {
         .await
         .expect("memory transport should be able to listen on memory address");
 }
+
+#[tokio::test]
+async fn start_from_config() {
+    let cancel = CancellationToken::new();
+    let _guard = cancel.clone().drop_guard();
+
+    let layer = TransportLayer::start_from_config(
+        TransportConfig {
+            kind: TransportKind::Memory,
+            ..TransportConfig::default()
+        },
+        cancel,
+    )
+    .expect("should be able to create swarm");
+
+    layer
+        .listen_on(memory_address())
+        .await
+        .expect("memory transport should be able to listen on memory address");
+}
+
+#[test]
+fn transport_kind_from_address() {
+    let tcp: Multiaddr = [
+        multiaddr::Protocol::Ip4(Ipv4Addr::LOCALHOST),
+        multiaddr::Protocol::Tcp(8080),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(TransportKind::from_address(&tcp), Some(TransportKind::Tcp));
+
+    assert_eq!(
+        TransportKind::from_address(&memory_address()),
+        Some(TransportKind::Memory)
+    );
+
+    #[cfg(unix)]
+    assert_eq!(
+        TransportKind::from_address(&unix_address("/tmp/harpc.sock")),
+        Some(TransportKind::Unix)
+    );
+
+    assert_eq!(TransportKind::from_address(&Multiaddr::empty()), None);
+}
+
+#[cfg(unix)]
+fn unix_socket_address() -> Multiaddr {
+    // every test needs a separate socket, even if the tests are run concurrently
+    static SOCKET: AtomicU64 = AtomicU64::new(0);
+
+    let id = SOCKET.fetch_add(1, Ordering::SeqCst);
+
+    unix_address(std::env::temp_dir().join(format!("harpc-{}-{id}.sock", std::process::id())))
+}
+
+#[cfg(unix)]
+fn layer_unix() -> (TransportLayer, impl Drop) {
+    let cancel = CancellationToken::new();
+
+    let layer = TransportLayer::start(
+        TransportConfig::default(),
+        UnixTransport::new(),
+        cancel.clone(),
+    )
+    .expect("should be able to create swarm");
+
+    (layer, cancel.drop_guard())
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn send_request_unix() {
+    let (server, _guard_server) = layer_unix();
+    let (client, _guard_client) = layer_unix();
+
+    let address = unix_socket_address();
+
+    server
+        .listen_on(address.clone())
+        .await
+        .expect("unix transport should be able to listen on unix address");
+
+    let server_id = server.peer_id();
+
+    let mut stream = server.listen().await.expect("should be able to listen");
+
+    let handle = tokio::spawn(async move {
+        let Some(IncomingConnection {
+            sink, mut stream, ..
+        }) = stream.next().await
+        else {
+            panic!("should receive connection");
+        };
+
+        drop(sink);
+
+        let request = stream
+            .next()
+            .await
+            .expect("should receive another request")
+            .expect("should be well-formed request");
+        assert_eq!(request, EXAMPLE_REQUEST);
+    });
+
+    // wait for `DEFAULT_DELAY` to make sure the server is ready
+    // this is more than strictly necessary, but it's better to be safe
+    tokio::time::sleep(DEFAULT_DELAY).await;
+
+    let peer_id = client
+        .lookup_peer(address)
+        .await
+        .expect("should be able to lookup peer");
+    assert_eq!(peer_id, server_id);
+
+    let OutgoingConnection {
+        mut sink, stream, ..
+    } = client
+        .dial(server_id)
+        .await
+        .expect("should be able to dial");
+
+    drop(stream);
+
+    sink.send(EXAMPLE_REQUEST.clone())
+        .await
+        .expect("should be able to send request");
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn listen_on_unix_unsupported_address() {
+    let (layer, _guard) = layer_unix();
+
+    layer
+        .listen_on(memory_address())
+        .await
+        .expect_err("unix transport should not be able to listen on memory address");
+}
@@ -0,0 +1,194 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use std::{collections::VecDeque, io, path::PathBuf};
+
+use futures::{
+    FutureExt,
+    future::{self, BoxFuture, Ready},
+};
+use libp2p::{
+    Multiaddr, TransportError,
+    core::transport::{DialOpts, ListenerId, TransportEvent},
+    multiaddr::Protocol,
+};
+use tokio::net::{UnixListener, UnixStream};
+use tokio_util::compat::{Compat, TokioAsyncReadCompatExt as _};
+
+/// Create the address of a Unix domain socket located at the given path.
+///
+/// # Panics
+///
+/// Panics if the path is not valid UTF-8, as multiaddrs are unable to represent such paths.
+pub fn unix_address(path: impl Into<PathBuf>) -> Multiaddr {
+    let path = path.into();
+    let path = path
+        .into_os_string()
+        .into_string()
+        .expect("path of a unix domain socket should be valid UTF-8");
+
+    Multiaddr::empty().with(Protocol::Unix(path.into()))
+}
+
+/// Extract the path of the socket from an address of the form `/unix/<path>`.
+///
+/// The address may be suffixed with the peer id of the remote, which the swarm appends to the
+/// addresses of known peers.
+fn multiaddr_to_path(address: &Multiaddr) -> Option<PathBuf> {
+    let mut protocols = address.iter();
+
+    let Protocol::Unix(path) = protocols.next()? else {
+        return None;
+    };
+
+    if protocols.any(|protocol| !matches!(protocol, Protocol::P2p(_))) {
+        return None;
+    }
+
+    Some(PathBuf::from(path.as_ref()))
+}
+
+struct Listener {
+    id: ListenerId,
+    address: Multiaddr,
+    path: PathBuf,
+
+    inner: UnixListener,
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        // the socket file outlives the listener, remove it so that the path can be reused
+        if let Err(error) = std::fs::remove_file(&self.path) {
+            tracing::debug!(?error, path = %self.path.display(), "unable to remove unix socket");
+        }
+    }
+}
+
+type Upgrade = Ready<Result<Compat<UnixStream>, io::Error>>;
+
+/// Transport over Unix domain sockets.
+///
+/// Addresses are of the form `/unix/<path>`, see [`unix_address`]. This is intended for
+/// co-located services, e.g. sidecar deployments, where going through the TCP stack is
+/// unnecessary.
+pub struct UnixTransport {
+    listeners: Vec<Listener>,
+    events: VecDeque<TransportEvent<Upgrade, io::Error>>,
+}
+
+impl UnixTransport {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            listeners: Vec::new(),
+            events: VecDeque::new(),
+        }
+    }
+}
+
+impl Default for UnixTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl libp2p::Transport for UnixTransport {
+    type Dial = BoxFuture<'static, Result<Self::Output, Self::Error>>;
+    type Error = io::Error;
+    type ListenerUpgrade = Upgrade;
+    type Output = Compat<UnixStream>;
+
+    fn listen_on(
+        &mut self,
+        id: ListenerId,
+        address: Multiaddr,
+    ) -> Result<(), TransportError<Self::Error>> {
+        let Some(path) = multiaddr_to_path(&address) else {
+            return Err(TransportError::MultiaddrNotSupported(address));
+        };
+
+        let inner = UnixListener::bind(&path).map_err(TransportError::Other)?;
+
+        self.events.push_back(TransportEvent::NewAddress {
+            listener_id: id,
+            listen_addr: address.clone(),
+        });
+
+        self.listeners.push(Listener {
+            id,
+            address,
+            path,
+            inner,
+        });
+
+        Ok(())
+    }
+
+    fn remove_listener(&mut self, id: ListenerId) -> bool {
+        let Some(index) = self.listeners.iter().position(|listener| listener.id == id) else {
+            return false;
+        };
+
+        self.listeners.remove(index);
+        self.events.push_back(TransportEvent::ListenerClosed {
+            listener_id: id,
+            reason: Ok(()),
+        });
+
+        true
+    }
+
+    fn dial(
+        &mut self,
+        address: Multiaddr,
+        _: DialOpts,
+    ) -> Result<Self::Dial, TransportError<Self::Error>> {
+        let Some(path) = multiaddr_to_path(&address) else {
+            return Err(TransportError::MultiaddrNotSupported(address));
+        };
+
+        Ok(async move {
+            UnixStream::connect(path)
+                .await
+                .map(|stream| stream.compat())
+        }
+        .boxed())
+    }
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<TransportEvent<Self::ListenerUpgrade, Self::Error>> {
+        let this = self.get_mut();
+
+        if let Some(event) = this.events.pop_front() {
+            return Poll::Ready(event);
+        }
+
+        for listener in &this.listeners {
+            match listener.inner.poll_accept(cx) {
+                Poll::Ready(Ok((stream, _))) => {
+                    return Poll::Ready(TransportEvent::Incoming {
+                        listener_id: listener.id,
+                        upgrade: future::ready(Ok(stream.compat())),
+                        local_addr: listener.address.clone(),
+                        // the dialing side of a unix domain socket is unnamed, the only address
+                        // we know of is the one of the listener
+                        send_back_addr: listener.address.clone(),
+                    });
+                }
+                Poll::Ready(Err(error)) => {
+                    return Poll::Ready(TransportEvent::ListenerError {
+                        listener_id: listener.id,
+                        error,
+                    });
+                }
+                Poll::Pending => {}
+            }
+        }
+
+        Poll::Pending
+    }
+}
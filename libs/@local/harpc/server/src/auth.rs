@@ -0,0 +1,223 @@
+//! Authentication of sessions.
+//!
+//! A client authenticates a session by presenting credentials to a service of the server, which
+//! verifies them using a [`Verifier`] and stores the resulting identity in the session state
+//! ([`Authentication`]). Every subsequent request in the same session is then able to retrieve the
+//! identity from the session, instead of the client having to send it along every request.
+//!
+//! Sessions are bound to a connection, a client therefore needs to authenticate once per
+//! connection.
+
+use error_stack::Report;
+use harpc_wire_protocol::response::kind::ErrorCode;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum AuthenticationError {
+    #[error("the presented credentials are invalid")]
+    InvalidCredentials,
+    #[error("the session has not been authenticated")]
+    Unauthenticated,
+}
+
+impl AuthenticationError {
+    /// Creates a report of the error, which is transmitted with [`ErrorCode::UNAUTHENTICATED`].
+    #[must_use]
+    pub fn into_report(self) -> Report<Self> {
+        Report::new(self).attach(ErrorCode::UNAUTHENTICATED)
+    }
+}
+
+/// Verifies the credentials `C` presented by a client.
+///
+/// Implementations decide what a valid credential is, e.g. a pre-shared token
+/// ([`PreSharedTokenVerifier`]) or a credential signed by a trusted issuer.
+pub trait Verifier<C>: Send + Sync + 'static {
+    /// The identity the credentials resolve to.
+    type Identity: Clone + Send + Sync + 'static;
+
+    /// Verifies the credentials and returns the identity of the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuthenticationError::InvalidCredentials`] if the credentials cannot be verified.
+    fn verify(
+        &self,
+        credentials: C,
+    ) -> impl Future<Output = Result<Self::Identity, Report<AuthenticationError>>> + Send;
+}
+
+/// Authentication state of a session.
+///
+/// This is meant to be used as the session value of a [`Router`], a freshly created session is
+/// unauthenticated.
+///
+/// [`Router`]: crate::router::Router
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Authentication<I> {
+    identity: Option<I>,
+}
+
+impl<I> Authentication<I> {
+    #[must_use]
+    pub const fn unauthenticated() -> Self {
+        Self { identity: None }
+    }
+
+    #[must_use]
+    pub const fn authenticated(identity: I) -> Self {
+        Self {
+            identity: Some(identity),
+        }
+    }
+
+    #[must_use]
+    pub const fn identity(&self) -> Option<&I> {
+        self.identity.as_ref()
+    }
+
+    /// Returns the identity of the session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuthenticationError::Unauthenticated`] if the session has not been authenticated.
+    pub fn require(&self) -> Result<&I, Report<AuthenticationError>> {
+        self.identity
+            .as_ref()
+            .ok_or_else(|| AuthenticationError::Unauthenticated.into_report())
+    }
+}
+
+impl<I> Default for Authentication<I> {
+    fn default() -> Self {
+        Self::unauthenticated()
+    }
+}
+
+/// Credentials consisting of a token shared out-of-band between client and server, and the
+/// identity the client wants to act as.
+///
+/// Possession of the token proves that the client is trusted to act as any identity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreSharedToken<I> {
+    pub token: String,
+    pub identity: I,
+}
+
+/// Verifies [`PreSharedToken`] credentials against a set of accepted tokens.
+#[derive(Debug, Clone, Default)]
+pub struct PreSharedTokenVerifier {
+    tokens: Vec<Box<[u8]>>,
+}
+
+impl PreSharedTokenVerifier {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { tokens: Vec::new() }
+    }
+
+    /// Accepts the given token.
+    #[must_use]
+    pub fn with_token(mut self, token: impl AsRef<[u8]>) -> Self {
+        self.tokens.push(Box::from(token.as_ref()));
+        self
+    }
+
+    fn is_accepted(&self, token: &[u8]) -> bool {
+        // Compare against every token without short-circuiting, so that the time taken does not
+        // reveal which (or how much of a) token matched.
+        self.tokens.iter().fold(false, |accepted, expected| {
+            accepted | constant_time_eq(expected, token)
+        })
+    }
+}
+
+fn constant_time_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+
+    lhs.iter()
+        .zip(rhs)
+        .fold(0, |difference, (lhs, rhs)| difference | (lhs ^ rhs))
+        == 0
+}
+
+impl<I> Verifier<PreSharedToken<I>> for PreSharedTokenVerifier
+where
+    I: Clone + Send + Sync + 'static,
+{
+    type Identity = I;
+
+    async fn verify(
+        &self,
+        credentials: PreSharedToken<I>,
+    ) -> Result<Self::Identity, Report<AuthenticationError>> {
+        if self.is_accepted(credentials.token.as_bytes()) {
+            Ok(credentials.identity)
+        } else {
+            Err(AuthenticationError::InvalidCredentials.into_report())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use harpc_wire_protocol::response::kind::ErrorCode;
+
+    use super::{
+        Authentication, AuthenticationError, PreSharedToken, PreSharedTokenVerifier, Verifier as _,
+    };
+
+    #[tokio::test]
+    async fn pre_shared_token() {
+        let verifier = PreSharedTokenVerifier::new()
+            .with_token("first")
+            .with_token("second");
+
+        let identity = verifier
+            .verify(PreSharedToken {
+                token: "second".to_owned(),
+                identity: 42_u32,
+            })
+            .await
+            .expect("token should be accepted");
+        assert_eq!(identity, 42);
+    }
+
+    #[tokio::test]
+    async fn pre_shared_token_invalid() {
+        let verifier = PreSharedTokenVerifier::new().with_token("first");
+
+        for token in ["", "firs", "first!", "second"] {
+            let report = verifier
+                .verify(PreSharedToken {
+                    token: token.to_owned(),
+                    identity: 42_u32,
+                })
+                .await
+                .expect_err("token should be rejected");
+
+            assert_eq!(
+                *report.current_context(),
+                AuthenticationError::InvalidCredentials
+            );
+            assert_eq!(
+                report.request_ref::<ErrorCode>().next(),
+                Some(&ErrorCode::UNAUTHENTICATED)
+            );
+        }
+    }
+
+    #[test]
+    fn require_identity() {
+        let session = Authentication::<u32>::default();
+        let report = session.require().expect_err("session is unauthenticated");
+        assert_eq!(
+            *report.current_context(),
+            AuthenticationError::Unauthenticated
+        );
+
+        let session = Authentication::authenticated(42_u32);
+        assert_eq!(session.require().ok(), Some(&42));
+    }
+}
@@ -2,6 +2,7 @@
 
 extern crate alloc;
 
+pub mod auth;
 pub mod delegate;
 pub mod error;
 pub mod router;
@@ -6,7 +6,7 @@ use harpc_tower::{
     request::{self, Request},
     response::BoxedResponse,
 };
-use tokio::pin;
+use tokio::{pin, select};
 use tokio_util::task::TaskTracker;
 use tower::{Service, ServiceExt};
 
@@ -33,15 +33,33 @@ where
         reason = "Semaphore permit being dropped is expected, used for control flow in Arc"
     )]
     while let Some(transaction) = stream.next().await {
+        let cancel = transaction.cancellation_token();
         let (context, sink, stream) = transaction.into_parts();
 
-        let parts = request::Parts::from_transaction(&context);
+        // The token is exposed to services, so that they are able to abort work which isn't
+        // driven by the future itself, e.g. spawned tasks.
+        let mut parts = request::Parts::from_transaction(&context);
+        parts.extensions.insert(cancel.clone());
+
         let request = Request::new(parts, RequestBody::new(stream));
 
         let Ok(service): Result<S, !> = make_service.call(()).await;
 
         tasks.spawn(async move {
-            let Ok(response): Result<BoxedResponse<!>, !> = service.oneshot(request).await;
+            // Once cancelled, no response will be delivered, dropping the future aborts any
+            // remaining work.
+            #[expect(
+                clippy::integer_division_remainder_used,
+                reason = "required for select! macro"
+            )]
+            let response = select! {
+                response = service.oneshot(request) => response,
+                () = cancel.cancelled() => {
+                    tracing::debug!("transaction has been cancelled");
+                    return;
+                }
+            };
+            let Ok(response): Result<BoxedResponse<!>, !> = response;
             let response = response.into_body();
 
             let pack = Pack::new(response).map(Ok);
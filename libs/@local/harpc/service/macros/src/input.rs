@@ -0,0 +1,53 @@
+use proc_macro2::Span;
+use syn::{Attribute, Ident, LitStr, Type, Visibility};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Version {
+    pub major: u8,
+    pub minor: u8,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Deprecation {
+    pub since: Version,
+    pub reason: Option<LitStr>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Metadata {
+    pub since: Version,
+    pub deprecation: Option<Deprecation>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ServiceInput {
+    pub attributes: Vec<Attribute>,
+    pub visibility: Visibility,
+    /// Name of the trait, e.g. `AccountService`.
+    pub ident: Ident,
+    /// Name of the service, e.g. `Account`.
+    pub name: Ident,
+
+    pub id: u16,
+    pub version: Version,
+    pub metadata: Metadata,
+
+    pub procedures: Vec<ProcedureInput>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ProcedureInput {
+    pub attributes: Vec<Attribute>,
+    /// Name of the trait method, e.g. `create_account`.
+    pub ident: Ident,
+    /// Name of the procedure identifier variant, e.g. `CreateAccount`.
+    pub variant: Ident,
+
+    pub id: u16,
+    pub id_span: Span,
+    pub metadata: Metadata,
+
+    pub payload: Type,
+    pub output: Type,
+    pub error: Type,
+}
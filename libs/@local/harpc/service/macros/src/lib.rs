@@ -0,0 +1,73 @@
+#![expect(
+    unreachable_pub,
+    reason = "This is a proc but as we want to document this crate as well this should be a \
+              warning instead"
+)]
+
+use proc_macro::TokenStream;
+
+use crate::{parse::parse, render::render};
+
+mod input;
+mod parse;
+mod render;
+
+/// Declares a harpc service from a trait definition.
+///
+/// The name of the trait must end in `Service`, the remaining prefix is used as the name of the
+/// service. Every method of the trait is a procedure, which takes `&self` and a single payload and
+/// returns a `Result`. The payload type becomes the `Procedure` of the service and must be
+/// unique across the service.
+///
+/// ```ignore
+/// #[harpc_service::service(id = 0x00, version = "0.1", since = "0.0")]
+/// pub trait AccountService {
+///     #[procedure(id = 0x00)]
+///     fn create_account(&self, payload: CreateAccount) -> Result<AccountId, Report<AccountError>>;
+///
+///     #[procedure(id = 0x01, deprecated(since = "0.1", reason = "use `create_account`"))]
+///     fn register_account(&self, payload: RegisterAccount) -> Result<AccountId, Report<AccountError>>;
+/// }
+/// ```
+///
+/// For the trait `AccountService` this generates:
+///
+/// - the trait `AccountService<R: Role>`, in which every procedure additionally receives the
+///   session of the role and returns a `Send` future,
+/// - the service `Account` and the procedure identifier `AccountProcedureId`, implementing
+///   `Service` and `ProcedureIdentifier`,
+/// - an implementation of `Procedure` for every payload,
+/// - `AccountServiceDelegate<T>`, a `ServiceDelegate` forwarding requests to an implementation of
+///   `AccountService<Server<S>>`. Errors returned by a procedure must be `Report`s.
+/// - `AccountServiceClient<C>`, a client stub with a method for every procedure, which requires a
+///   dependency on `harpc-client`.
+///
+/// # Attributes
+///
+/// The `service` attribute accepts:
+///
+/// - `id`: The id of the service (required).
+/// - `version`: The current version of the service in the format `major.minor` (required).
+/// - `since`: The version the service was introduced in, defaults to `version`.
+/// - `deprecated(since = "...", reason = "...")`: Marks the service as deprecated.
+///
+/// Every procedure requires a `procedure` attribute, which accepts `id` (required), `since`
+/// (defaults to the `since` of the service) and `deprecated`. Procedure ids must be unique within
+/// a service, and a deprecation can neither predate the introduction nor be newer than the current
+/// version of the service.
+#[proc_macro_attribute]
+pub fn service(arguments: TokenStream, item: TokenStream) -> TokenStream {
+    parse(arguments.into(), item.into())
+        .map_or_else(syn::Error::into_compile_error, |input| render(&input))
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ui() {
+        let test_cases = trybuild::TestCases::new();
+        test_cases.compile_fail("tests/ui/fail/*rs");
+        test_cases.pass("tests/ui/pass/*rs");
+    }
+}
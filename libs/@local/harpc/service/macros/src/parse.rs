@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Span, TokenStream};
+use syn::{
+    Attribute, FnArg, GenericArgument, Generics, Ident, ItemTrait, LitInt, LitStr, PathArguments,
+    ReturnType, TraitItem, TraitItemFn, Type, ext::IdentExt as _, meta::ParseNestedMeta,
+    parse::Parser as _, spanned::Spanned as _,
+};
+
+use crate::input::{Deprecation, Metadata, ProcedureInput, ServiceInput, Version};
+
+fn set<T>(slot: &mut Option<T>, value: T, meta: &ParseNestedMeta, name: &str) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(meta.error(format!(
+            "the attribute `{name}` has been specified multiple times"
+        )));
+    }
+
+    *slot = Some(value);
+    Ok(())
+}
+
+fn parse_version(literal: &LitStr) -> syn::Result<Version> {
+    let error = || {
+        syn::Error::new(
+            literal.span(),
+            "expected a version in the format `major.minor`",
+        )
+    };
+
+    let value = literal.value();
+    let (major, minor) = value.split_once('.').ok_or_else(error)?;
+
+    Ok(Version {
+        major: major.parse().map_err(|_| error())?,
+        minor: minor.parse().map_err(|_| error())?,
+    })
+}
+
+fn parse_id(meta: &ParseNestedMeta) -> syn::Result<(u16, Span)> {
+    let literal: LitInt = meta.value()?.parse()?;
+
+    Ok((literal.base10_parse()?, literal.span()))
+}
+
+fn parse_deprecation(meta: &ParseNestedMeta) -> syn::Result<Deprecation> {
+    let mut since = None;
+    let mut reason = None;
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("since") {
+            let version = parse_version(&meta.value()?.parse()?)?;
+            set(&mut since, version, &meta, "since")
+        } else if meta.path.is_ident("reason") {
+            let value = meta.value()?.parse()?;
+            set(&mut reason, value, &meta, "reason")
+        } else {
+            Err(meta.error("expected `since` or `reason`"))
+        }
+    })?;
+
+    Ok(Deprecation {
+        since: since.ok_or_else(|| meta.error("missing `since` in `deprecated`"))?,
+        reason,
+    })
+}
+
+/// Arguments shared between the `service` and `procedure` attributes.
+#[derive(Default)]
+struct Arguments {
+    id: Option<(u16, Span)>,
+    version: Option<Version>,
+    since: Option<Version>,
+    deprecation: Option<Deprecation>,
+}
+
+impl Arguments {
+    fn parse_meta(&mut self, meta: &ParseNestedMeta, allow_version: bool) -> syn::Result<()> {
+        if meta.path.is_ident("id") {
+            set(&mut self.id, parse_id(meta)?, meta, "id")
+        } else if allow_version && meta.path.is_ident("version") {
+            let version = parse_version(&meta.value()?.parse()?)?;
+            set(&mut self.version, version, meta, "version")
+        } else if meta.path.is_ident("since") {
+            let version = parse_version(&meta.value()?.parse()?)?;
+            set(&mut self.since, version, meta, "since")
+        } else if meta.path.is_ident("deprecated") {
+            set(
+                &mut self.deprecation,
+                parse_deprecation(meta)?,
+                meta,
+                "deprecated",
+            )
+        } else if allow_version {
+            Err(meta.error("expected one of `id`, `version`, `since` or `deprecated`"))
+        } else {
+            Err(meta.error("expected one of `id`, `since` or `deprecated`"))
+        }
+    }
+}
+
+fn validate_metadata(metadata: &Metadata, version: Version, span: Span) -> syn::Result<()> {
+    if metadata.since > version {
+        return Err(syn::Error::new(
+            span,
+            "`since` cannot be newer than the version of the service",
+        ));
+    }
+
+    if let Some(deprecation) = &metadata.deprecation {
+        if deprecation.since < metadata.since {
+            return Err(syn::Error::new(
+                span,
+                "`deprecated(since)` cannot be older than `since`",
+            ));
+        }
+
+        if deprecation.since > version {
+            return Err(syn::Error::new(
+                span,
+                "`deprecated(since)` cannot be newer than the version of the service",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn ensure_not_generic(generics: &Generics, message: &str) -> syn::Result<()> {
+    if generics.params.is_empty() && generics.where_clause.is_none() {
+        Ok(())
+    } else {
+        Err(syn::Error::new_spanned(generics, message))
+    }
+}
+
+/// Extracts the `T` and `E` of a `Result<T, E>`.
+fn parse_result(output: &ReturnType) -> Option<(Type, Type)> {
+    let ReturnType::Type(_, output) = output else {
+        return None;
+    };
+
+    let Type::Path(path) = &**output else {
+        return None;
+    };
+
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+
+    let mut types = arguments.args.iter();
+    match (types.next(), types.next(), types.next()) {
+        (Some(GenericArgument::Type(output)), Some(GenericArgument::Type(error)), None) => {
+            Some((output.clone(), error.clone()))
+        }
+        _ => None,
+    }
+}
+
+fn to_pascal_case(ident: &Ident) -> Ident {
+    let name: String = ident
+        .unraw()
+        .to_string()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect::<String>()
+            })
+        })
+        .collect();
+
+    Ident::new(&name, ident.span())
+}
+
+fn parse_procedure(
+    function: TraitItemFn,
+    service: &Metadata,
+    version: Version,
+) -> syn::Result<ProcedureInput> {
+    let TraitItemFn {
+        attrs,
+        sig,
+        default,
+        ..
+    } = function;
+
+    if let Some(default) = default {
+        return Err(syn::Error::new_spanned(
+            default,
+            "procedures cannot have a default implementation",
+        ));
+    }
+
+    ensure_not_generic(&sig.generics, "procedures cannot be generic")?;
+
+    let mut procedure: Option<Attribute> = None;
+    let mut attributes = Vec::with_capacity(attrs.len());
+    for attribute in attrs {
+        if !attribute.path().is_ident("procedure") {
+            attributes.push(attribute);
+        } else if procedure.is_some() {
+            return Err(syn::Error::new_spanned(
+                attribute,
+                "the attribute `procedure` has been specified multiple times",
+            ));
+        } else {
+            procedure = Some(attribute);
+        }
+    }
+
+    let procedure = procedure.ok_or_else(|| {
+        syn::Error::new_spanned(&sig.ident, "missing `#[procedure(id = ...)]` attribute")
+    })?;
+
+    let mut arguments = Arguments::default();
+    procedure.parse_nested_meta(|meta| arguments.parse_meta(&meta, false))?;
+
+    let (id, id_span) = arguments.id.ok_or_else(|| {
+        syn::Error::new_spanned(&procedure, "missing `id` in `#[procedure(...)]`")
+    })?;
+
+    let metadata = Metadata {
+        since: arguments.since.unwrap_or(service.since),
+        deprecation: arguments.deprecation,
+    };
+    validate_metadata(&metadata, version, procedure.span())?;
+
+    let mut inputs = sig.inputs.iter();
+    match inputs.next() {
+        Some(FnArg::Receiver(receiver))
+            if receiver.reference.is_some()
+                && receiver.mutability.is_none()
+                && receiver.colon_token.is_none() => {}
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &sig,
+                "procedures must take `&self` as their first argument",
+            ));
+        }
+    }
+
+    let payload = match (inputs.next(), inputs.next()) {
+        (Some(FnArg::Typed(payload)), None) => payload,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &sig.inputs,
+                "procedures must take exactly one payload argument",
+            ));
+        }
+    };
+
+    let (output, error) = parse_result(&sig.output).ok_or_else(|| {
+        syn::Error::new_spanned(&sig.output, "procedures must return a `Result<T, E>`")
+    })?;
+
+    Ok(ProcedureInput {
+        attributes,
+        variant: to_pascal_case(&sig.ident),
+        ident: sig.ident.clone(),
+        id,
+        id_span,
+        metadata,
+        payload: (*payload.ty).clone(),
+        output,
+        error,
+    })
+}
+
+pub(crate) fn parse(arguments: TokenStream, item: TokenStream) -> syn::Result<ServiceInput> {
+    let mut service_arguments = Arguments::default();
+    syn::meta::parser(|meta| service_arguments.parse_meta(&meta, true)).parse2(arguments)?;
+
+    let item: ItemTrait = syn::parse2(item)?;
+
+    ensure_not_generic(&item.generics, "services cannot be generic")?;
+    if !item.supertraits.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item.supertraits,
+            "services cannot have supertraits",
+        ));
+    }
+
+    let name = item
+        .ident
+        .unraw()
+        .to_string()
+        .strip_suffix("Service")
+        .filter(|name| !name.is_empty())
+        .map(|name| Ident::new(name, item.ident.span()))
+        .ok_or_else(|| {
+            syn::Error::new_spanned(&item.ident, "the name of a service must end in `Service`")
+        })?;
+
+    let (id, _) = service_arguments
+        .id
+        .ok_or_else(|| syn::Error::new(Span::call_site(), "missing `id` in `#[service(...)]`"))?;
+    let version = service_arguments.version.ok_or_else(|| {
+        syn::Error::new(Span::call_site(), "missing `version` in `#[service(...)]`")
+    })?;
+
+    let metadata = Metadata {
+        since: service_arguments.since.unwrap_or(version),
+        deprecation: service_arguments.deprecation,
+    };
+    validate_metadata(&metadata, version, Span::call_site())?;
+
+    let procedures = item
+        .items
+        .into_iter()
+        .map(|item| match item {
+            TraitItem::Fn(function) => parse_procedure(function, &metadata, version),
+            item => Err(syn::Error::new_spanned(
+                item,
+                "services may only contain procedures",
+            )),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let mut ids = HashMap::with_capacity(procedures.len());
+    for procedure in &procedures {
+        if let Some(existing) = ids.insert(procedure.id, &procedure.ident) {
+            return Err(syn::Error::new(
+                procedure.id_span,
+                format!(
+                    "procedure id {} is already used by `{existing}`",
+                    procedure.id
+                ),
+            ));
+        }
+    }
+
+    Ok(ServiceInput {
+        attributes: item.attrs,
+        visibility: item.vis,
+        ident: item.ident,
+        name,
+        id,
+        version,
+        metadata,
+        procedures,
+    })
+}
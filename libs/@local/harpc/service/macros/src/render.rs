@@ -0,0 +1,377 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::input::{Metadata, ProcedureInput, ServiceInput, Version};
+
+fn render_version(Version { major, minor }: Version) -> TokenStream {
+    let major = Literal::u8_unsuffixed(major);
+    let minor = Literal::u8_unsuffixed(minor);
+
+    quote!(::harpc_service::__private::Version {
+        major: #major,
+        minor: #minor,
+    })
+}
+
+fn render_metadata(metadata: &Metadata) -> TokenStream {
+    let since = render_version(metadata.since);
+    let deprecation = metadata.deprecation.as_ref().map_or_else(
+        || quote!(::core::option::Option::None),
+        |deprecation| {
+            let since = render_version(deprecation.since);
+            let reason = deprecation.reason.as_ref().map_or_else(
+                || quote!(::core::option::Option::None),
+                |reason| quote!(::core::option::Option::Some(#reason)),
+            );
+
+            quote!(::core::option::Option::Some(::harpc_service::metadata::Deprecation {
+                since: #since,
+                reason: #reason,
+            }))
+        },
+    );
+
+    quote!(::harpc_service::metadata::Metadata {
+        since: #since,
+        deprecation: #deprecation,
+    })
+}
+
+fn render_trait(input: &ServiceInput) -> TokenStream {
+    let ServiceInput {
+        attributes,
+        visibility,
+        ident,
+        ..
+    } = input;
+
+    let procedures = input.procedures.iter().map(
+        |ProcedureInput {
+             attributes,
+             ident,
+             payload,
+             output,
+             error,
+             ..
+         }| {
+            quote! {
+                #(#attributes)*
+                fn #ident(
+                    &self,
+                    session: &R::Session,
+                    payload: #payload,
+                ) -> impl ::core::future::Future<
+                    Output = ::core::result::Result<#output, #error>
+                > + ::core::marker::Send;
+            }
+        },
+    );
+
+    quote! {
+        #(#attributes)*
+        #visibility trait #ident<R>
+        where
+            R: ::harpc_service::role::Role,
+        {
+            #(#procedures)*
+        }
+    }
+}
+
+fn render_service(input: &ServiceInput) -> TokenStream {
+    let ServiceInput {
+        visibility, name, ..
+    } = input;
+    let procedure_id = format_ident!("{name}ProcedureId");
+
+    let id = Literal::u16_unsuffixed(input.id);
+    let version = render_version(input.version);
+    let metadata = render_metadata(&input.metadata);
+
+    let variants: Vec<_> = input
+        .procedures
+        .iter()
+        .map(|procedure| &procedure.variant)
+        .collect();
+    let ids: Vec<_> = input
+        .procedures
+        .iter()
+        .map(|procedure| Literal::u16_unsuffixed(procedure.id))
+        .collect();
+
+    let procedures = input.procedures.iter().rfold(
+        quote!(::harpc_service::__private::HNil),
+        |tail, procedure| {
+            let payload = &procedure.payload;
+            quote!(::harpc_service::__private::HCons<#payload, #tail>)
+        },
+    );
+
+    let procedure_impls = input.procedures.iter().map(|procedure| {
+        let payload = &procedure.payload;
+        let variant = &procedure.variant;
+        let metadata = render_metadata(&procedure.metadata);
+
+        quote! {
+            impl ::harpc_service::procedure::Procedure for #payload {
+                type Service = #name;
+
+                const ID: <Self::Service as ::harpc_service::Service>::ProcedureId =
+                    #procedure_id::#variant;
+
+                fn metadata() -> ::harpc_service::metadata::Metadata {
+                    #metadata
+                }
+            }
+        }
+    });
+
+    quote! {
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        #visibility enum #procedure_id {
+            #(#variants,)*
+        }
+
+        impl ::harpc_service::procedure::ProcedureIdentifier for #procedure_id {
+            fn from_id(
+                id: ::harpc_service::__private::ProcedureId,
+            ) -> ::core::option::Option<Self> {
+                match id.value() {
+                    #(#ids => ::core::option::Option::Some(Self::#variants),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            fn into_id(self) -> ::harpc_service::__private::ProcedureId {
+                match self {
+                    #(Self::#variants => ::harpc_service::__private::ProcedureId::new(#ids),)*
+                }
+            }
+        }
+
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        #visibility struct #name;
+
+        impl ::harpc_service::Service for #name {
+            type ProcedureId = #procedure_id;
+            type Procedures = #procedures;
+
+            const ID: ::harpc_service::__private::ServiceId =
+                ::harpc_service::__private::ServiceId::new(#id);
+            const VERSION: ::harpc_service::__private::Version = #version;
+
+            fn metadata() -> ::harpc_service::metadata::Metadata {
+                #metadata
+            }
+        }
+
+        #(#procedure_impls)*
+    }
+}
+
+fn render_delegate(input: &ServiceInput) -> TokenStream {
+    let ServiceInput {
+        visibility,
+        ident,
+        name,
+        ..
+    } = input;
+    let procedure_id = format_ident!("{name}ProcedureId");
+    let delegate = format_ident!("{ident}Delegate");
+
+    let codec_bounds = input.procedures.iter().map(|procedure| {
+        let payload = &procedure.payload;
+        let output = &procedure.output;
+
+        quote! {
+            + ::harpc_service::__private::ValueDecoder<
+                #payload,
+                Error: ::core::marker::Send + ::core::marker::Sync + 'static
+            >
+            + ::harpc_service::__private::ValueEncoder<
+                #output,
+                Error: ::core::marker::Send + ::core::marker::Sync + 'static
+            >
+        }
+    });
+
+    let arms = input.procedures.iter().map(|procedure| {
+        let ProcedureInput {
+            ident,
+            variant,
+            payload,
+            ..
+        } = procedure;
+
+        quote! {
+            #procedure_id::#variant => {
+                let payload = ::harpc_service::delegate::decode_request::<#payload, _, _>(
+                    request.into_body(),
+                    ::core::clone::Clone::clone(&codec),
+                )
+                .await?;
+
+                let output = ::harpc_service::__private::ResultExt::change_context(
+                    self.service.#ident(&session, payload).await,
+                    ::harpc_service::delegate::DelegateError::Procedure,
+                )?;
+
+                ::harpc_service::delegate::encode_response(session_id, output, codec).await
+            }
+        }
+    });
+
+    let documentation = format!(
+        " Server-side adapter, which delegates requests for the [`{name}`] service to an \
+         implementation of [`{ident}`]."
+    );
+
+    quote! {
+        #[doc = #documentation]
+        #[derive(Debug, Clone)]
+        #visibility struct #delegate<T> {
+            pub service: T,
+        }
+
+        impl<T> #delegate<T> {
+            #[must_use]
+            pub const fn new(service: T) -> Self {
+                Self { service }
+            }
+        }
+
+        impl<T, S, C> ::harpc_service::delegate::ServiceDelegate<S, C> for #delegate<T>
+        where
+            T: #ident<::harpc_service::role::Server<S>> + ::core::marker::Send + ::core::marker::Sync,
+            S: ::core::marker::Send + ::core::marker::Sync,
+            C: ::core::clone::Clone
+                + ::core::marker::Send
+                + ::core::marker::Sync
+                + 'static
+                #(#codec_bounds)*,
+        {
+            type Body = ::harpc_service::delegate::ResponseBody;
+            type Error = ::harpc_service::__private::Report<
+                ::harpc_service::delegate::DelegateError
+            >;
+            type Service = #name;
+
+            async fn call<B>(
+                self,
+                request: ::harpc_service::__private::Request<B>,
+                session: S,
+                codec: C,
+            ) -> ::core::result::Result<
+                ::harpc_service::__private::Response<Self::Body>,
+                Self::Error,
+            >
+            where
+                B: ::harpc_service::__private::Body<
+                        Control = ::harpc_service::__private::Never,
+                        Error: ::core::marker::Send + ::core::marker::Sync,
+                    > + ::core::marker::Send
+                    + ::core::marker::Sync,
+            {
+                let session_id = request.session();
+                let id = request.procedure().id;
+
+                let ::core::option::Option::Some(procedure) =
+                    <#procedure_id as ::harpc_service::procedure::ProcedureIdentifier>::from_id(id)
+                else {
+                    return ::core::result::Result::Err(::harpc_service::__private::Report::new(
+                        ::harpc_service::delegate::DelegateError::UnknownProcedure { id },
+                    ));
+                };
+
+                match procedure {
+                    #(#arms)*
+                }
+            }
+        }
+    }
+}
+
+fn render_client(input: &ServiceInput) -> TokenStream {
+    let ServiceInput {
+        visibility,
+        ident,
+        name,
+        ..
+    } = input;
+    let client = format_ident!("{ident}Client");
+
+    let methods = input.procedures.iter().map(|procedure| {
+        let ProcedureInput {
+            ident,
+            payload,
+            output,
+            ..
+        } = procedure;
+
+        let documentation = format!(
+            " Calls the `{ident}` procedure of the [`{name}`] service.\n\n # Errors\n\n Returns \
+             an error if the call fails or the server responded with an error."
+        );
+
+        quote! {
+            #[doc = #documentation]
+            pub async fn #ident(
+                &self,
+                payload: #payload,
+            ) -> ::core::result::Result<
+                #output,
+                ::harpc_service::__private::Report<::harpc_client::error::CallError>,
+            >
+            where
+                C: ::harpc_client::service::CallCodec<#payload, #output>,
+            {
+                self.inner.call(payload).await
+            }
+        }
+    });
+
+    let documentation = format!(" Client stub for the [`{name}`] service.");
+
+    quote! {
+        #[doc = #documentation]
+        #visibility struct #client<C> {
+            inner: ::harpc_client::service::ServiceClient<#name, C>,
+        }
+
+        impl<C> ::core::clone::Clone for #client<C>
+        where
+            C: ::core::clone::Clone,
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    inner: ::core::clone::Clone::clone(&self.inner),
+                }
+            }
+        }
+
+        impl<C> #client<C> {
+            #[must_use]
+            pub const fn new(connection: ::harpc_client::connection::Connection<C>) -> Self {
+                Self {
+                    inner: ::harpc_client::service::ServiceClient::new(connection),
+                }
+            }
+
+            #(#methods)*
+        }
+    }
+}
+
+pub(crate) fn render(input: &ServiceInput) -> TokenStream {
+    let definition = render_trait(input);
+    let service = render_service(input);
+    let delegate = render_delegate(input);
+    let client = render_client(input);
+
+    quote! {
+        #definition
+        #service
+        #delegate
+        #client
+    }
+}
@@ -0,0 +1,9 @@
+pub struct CreateAccount;
+
+#[harpc_service::service(id = 0x00, version = "0.2")]
+pub trait AccountService {
+    #[procedure(id = 0x00, since = "0.1", deprecated(since = "0.0"))]
+    fn create_account(&self, payload: CreateAccount) -> Result<u64, ()>;
+}
+
+fn main() {}
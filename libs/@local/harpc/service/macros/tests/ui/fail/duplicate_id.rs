@@ -0,0 +1,13 @@
+pub struct CreateAccount;
+pub struct RegisterAccount;
+
+#[harpc_service::service(id = 0x00, version = "0.0")]
+pub trait AccountService {
+    #[procedure(id = 0x00)]
+    fn create_account(&self, payload: CreateAccount) -> Result<u64, ()>;
+
+    #[procedure(id = 0x00)]
+    fn register_account(&self, payload: RegisterAccount) -> Result<u64, ()>;
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+use error_stack::Report;
+use harpc_service::role::Server;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unable to create the account")]
+pub struct AccountError;
+
+pub struct CreateAccount {
+    pub id: u64,
+}
+
+pub struct DeleteAccount(pub u64);
+
+#[harpc_service::service(id = 0x00, version = "0.0")]
+pub trait AccountService {
+    #[procedure(id = 0x00)]
+    fn create_account(
+        &self,
+        CreateAccount { id }: CreateAccount,
+    ) -> Result<u64, Report<AccountError>>;
+
+    #[procedure(id = 0x01)]
+    fn delete_account(&self, _: DeleteAccount) -> Result<(), Report<AccountError>>;
+}
+
+pub struct AccountServiceImpl;
+
+impl<S> AccountService<Server<S>> for AccountServiceImpl
+where
+    S: Send + Sync,
+{
+    async fn create_account(
+        &self,
+        _: &S,
+        CreateAccount { id }: CreateAccount,
+    ) -> Result<u64, Report<AccountError>> {
+        Ok(id)
+    }
+
+    async fn delete_account(
+        &self,
+        _: &S,
+        DeleteAccount(_): DeleteAccount,
+    ) -> Result<(), Report<AccountError>> {
+        Ok(())
+    }
+}
+
+fn main() {}
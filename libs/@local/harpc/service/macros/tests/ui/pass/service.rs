@@ -0,0 +1,30 @@
+use error_stack::Report;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unable to create the account")]
+pub struct AccountError;
+
+pub struct CreateAccount;
+pub struct RegisterAccount;
+
+#[harpc_service::service(id = 0x00, version = "0.1", since = "0.0")]
+pub trait AccountService {
+    /// Creates a new account.
+    #[procedure(id = 0x00)]
+    fn create_account(&self, payload: CreateAccount) -> Result<u64, Report<AccountError>>;
+
+    #[procedure(
+        id = 0x01,
+        deprecated(since = "0.1", reason = "use `create_account` instead")
+    )]
+    fn register_account(&self, payload: RegisterAccount) -> Result<u64, Report<AccountError>>;
+}
+
+fn main() {
+    use harpc_service::{Service as _, procedure::Procedure as _};
+
+    assert_eq!(Account::ID, harpc_service::__private::ServiceId::new(0x00));
+    assert!(Account::metadata().deprecation.is_none());
+    assert!(CreateAccount::metadata().deprecation.is_none());
+    assert!(RegisterAccount::metadata().deprecation.is_some());
+}
@@ -1,8 +1,40 @@
-use harpc_tower::{body::Body, request::Request, response::Response};
+use alloc::vec;
+
+use bytes::Bytes;
+use error_stack::Report;
+use futures::{StreamExt, TryStreamExt, stream};
+use harpc_net::{
+    codec::{ValueDecoder, ValueEncoder},
+    session::server::SessionId,
+};
+use harpc_tower::{
+    body::{Body, BodyExt, Frame, controlled::Controlled, stream::StreamBody},
+    request::Request,
+    response::{Parts, Response},
+};
+use harpc_types::procedure::ProcedureId;
 use harpc_wire_protocol::response::kind::ResponseKind;
 
 use crate::Service;
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum DelegateError {
+    #[error("the service does not have a procedure with id {id:?}")]
+    UnknownProcedure { id: ProcedureId },
+    #[error("unable to decode the request")]
+    Decode,
+    #[error("the request did not contain a payload")]
+    MissingPayload,
+    #[error("unable to encode the response")]
+    Encode,
+    #[error("the procedure returned an error")]
+    Procedure,
+}
+
+/// Body of a response created by [`encode_response`].
+pub type ResponseBody =
+    Controlled<ResponseKind, StreamBody<stream::Iter<vec::IntoIter<Result<Frame<Bytes, !>, !>>>>>;
+
 /// Delegates service calls to an inner typed service.
 ///
 /// This trait acts as a bridge between the generic RPC layer and a typed service implementation.
@@ -44,3 +76,55 @@ pub trait ServiceDelegate<S, C> {
     where
         B: Body<Control = !, Error: Send + Sync> + Send + Sync;
 }
+
+/// Decodes the payload of a request.
+///
+/// Only the first value of the request is decoded, as every procedure receives a single payload.
+///
+/// # Errors
+///
+/// Returns an error if the request is empty or the payload cannot be decoded.
+pub async fn decode_request<P, B, C>(body: B, codec: C) -> Result<P, Report<DelegateError>>
+where
+    B: Body<Control = !, Error: Send + Sync> + Send + Sync,
+    C: ValueDecoder<P, Error: Send + Sync + 'static> + Send,
+{
+    let data = body.into_stream().into_data_stream();
+
+    let stream = codec.decode_stream(data).await;
+    let mut stream = core::pin::pin!(stream);
+
+    stream
+        .next()
+        .await
+        .ok_or_else(|| Report::new(DelegateError::MissingPayload))?
+        .map_err(|error| Report::new(DelegateError::Decode).attach(error))
+}
+
+/// Encodes the value returned by a procedure into a response.
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be encoded.
+pub async fn encode_response<T, C>(
+    session: SessionId,
+    value: T,
+    codec: C,
+) -> Result<Response<ResponseBody>, Report<DelegateError>>
+where
+    T: Send + Sync,
+    C: ValueEncoder<T, Error: Send + Sync + 'static> + Send,
+{
+    let frames: Vec<_> = codec
+        .encode_stream(stream::iter([value]))
+        .await
+        .map_ok(|bytes| Ok(Frame::new_data(bytes)))
+        .try_collect()
+        .await
+        .map_err(|error| Report::new(DelegateError::Encode).attach(error))?;
+
+    Ok(Response::from_parts(
+        Parts::new(session),
+        Controlled::new(ResponseKind::Ok, StreamBody::new(stream::iter(frames))),
+    ))
+}
@@ -1,5 +1,8 @@
 #![feature(never_type, marker_trait_attr)]
 
+extern crate alloc;
+
+pub use harpc_service_macros::service;
 use harpc_types::{service::ServiceId, version::Version};
 
 use self::{metadata::Metadata, procedure::ProcedureIdentifier};
@@ -9,6 +12,19 @@ pub mod metadata;
 pub mod procedure;
 pub mod role;
 
+#[doc(hidden)]
+pub mod __private {
+    pub use error_stack::{Report, ResultExt};
+    pub use frunk::{HCons, HNil};
+    pub use harpc_net::codec::{ValueDecoder, ValueEncoder};
+    pub use harpc_tower::{body::Body, request::Request, response::Response};
+    pub use harpc_types::{procedure::ProcedureId, service::ServiceId, version::Version};
+
+    /// Alias for the never type, so that generated code can refer to it without requiring the
+    /// `never_type` feature.
+    pub type Never = !;
+}
+
 pub trait Service {
     type ProcedureId: ProcedureIdentifier;
     /// Heteregenous list of procedures that are part of this service, used for type-level
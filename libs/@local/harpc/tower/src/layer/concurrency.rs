@@ -0,0 +1,311 @@
+use alloc::sync::Arc;
+use core::task::{Context, Poll};
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+use bytes::Bytes;
+use harpc_net::{
+    codec::{ErrorEncoder, WireError},
+    session::server::SessionId,
+};
+use harpc_wire_protocol::response::kind::{ErrorCode, ResponseKind};
+use tower::{Layer, Service, ServiceExt};
+
+use crate::{
+    Extensions,
+    body::{Body, controlled::Controlled, full::Full},
+    either::Either,
+    request::Request,
+    response::{Parts, Response},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+#[error("the session has reached the limit of {limit} concurrent requests")]
+pub struct ConcurrencyLimitReachedError {
+    pub limit: usize,
+}
+
+impl WireError for ConcurrencyLimitReachedError {
+    fn code(&self) -> ErrorCode {
+        ErrorCode::CONCURRENCY_LIMIT_REACHED
+    }
+}
+
+type InFlight = Arc<Mutex<HashMap<SessionId, usize>>>;
+
+/// Releases the slot of a request once dropped.
+struct ConcurrencyPermit {
+    in_flight: InFlight,
+    session: SessionId,
+}
+
+impl ConcurrencyPermit {
+    fn acquire(in_flight: &InFlight, session: SessionId, limit: usize) -> Option<Self> {
+        let mut guard = in_flight.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let count = guard.entry(session).or_default();
+        if *count >= limit {
+            return None;
+        }
+
+        *count += 1;
+        drop(guard);
+
+        Some(Self {
+            in_flight: Arc::clone(in_flight),
+            session,
+        })
+    }
+}
+
+impl Drop for ConcurrencyPermit {
+    fn drop(&mut self) {
+        let mut guard = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(count) = guard.get_mut(&self.session) {
+            *count = count.saturating_sub(1);
+
+            // remove the entry, so that the map doesn't grow with every session ever seen
+            if *count == 0 {
+                guard.remove(&self.session);
+            }
+        }
+    }
+}
+
+/// Limits the number of requests a single session can have in flight.
+///
+/// A request is in flight until the inner service has produced a response, requests exceeding
+/// the limit are rejected with a [`ConcurrencyLimitReachedError`].
+///
+/// The limit is shared between all services the layer is applied to.
+#[derive(Debug, Clone)]
+pub struct ConcurrencyLimitLayer<E> {
+    encoder: E,
+
+    limit: usize,
+    in_flight: InFlight,
+}
+
+impl<E> ConcurrencyLimitLayer<E> {
+    pub fn new(encoder: E, limit: usize) -> Self {
+        Self {
+            encoder,
+            limit,
+            in_flight: Arc::default(),
+        }
+    }
+}
+
+impl<S, E> Layer<S> for ConcurrencyLimitLayer<E>
+where
+    E: Clone,
+{
+    type Service = ConcurrencyLimitService<S, E>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ConcurrencyLimitService {
+            inner,
+            encoder: self.encoder.clone(),
+            limit: self.limit,
+            in_flight: Arc::clone(&self.in_flight),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConcurrencyLimitService<S, E> {
+    inner: S,
+
+    encoder: E,
+
+    limit: usize,
+    in_flight: InFlight,
+}
+
+impl<S, E, ReqBody, ResBody> Service<Request<ReqBody>> for ConcurrencyLimitService<S, E>
+where
+    S: Service<Request<ReqBody>, Error = !, Response = Response<ResBody>> + Clone + Send,
+    E: ErrorEncoder + Clone,
+    ReqBody: Body<Control = !>,
+    ResBody: Body<Control: AsRef<ResponseKind>>,
+{
+    type Error = !;
+    type Response = Response<Either<ResBody, Controlled<ResponseKind, Full<Bytes>>>>;
+
+    type Future = impl Future<Output = Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // we're always ready because we clone the inner service, therefore it is unused and always
+        // ready, the limit is enforced once we know the session of the request
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let encoder = self.encoder.clone();
+        let limit = self.limit;
+
+        let clone = self.inner.clone();
+        let inner = core::mem::replace(&mut self.inner, clone);
+
+        let session = req.session();
+        let permit = ConcurrencyPermit::acquire(&self.in_flight, session, limit);
+
+        async move {
+            let Some(permit) = permit else {
+                let error = encoder
+                    .encode_error(ConcurrencyLimitReachedError { limit })
+                    .await;
+
+                return Ok(Response::from_error(
+                    Parts {
+                        session,
+                        extensions: Extensions::new(),
+                    },
+                    error,
+                )
+                .map_body(Either::Right));
+            };
+
+            let Ok(response) = inner.oneshot(req).await;
+            drop(permit);
+
+            Ok(response.map_body(Either::Left))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+    use harpc_net::test_utils::mock_session_id;
+    use harpc_wire_protocol::response::kind::{ErrorCode, ResponseKind};
+    use tokio_test::assert_ready;
+    use tower::{Layer, ServiceExt};
+    use tower_test::mock::spawn_with;
+
+    use crate::{
+        Extensions,
+        body::{BodyExt, controlled::Controlled, full::Full},
+        layer::{
+            concurrency::ConcurrencyLimitLayer,
+            error::test::{PlainErrorEncoder, request},
+        },
+        request::{self, Request},
+        response::{self, Response},
+    };
+
+    fn ok_response() -> Response<Controlled<ResponseKind, Full<Bytes>>> {
+        Response::from_parts(
+            response::Parts {
+                session: request().session(),
+                extensions: Extensions::new(),
+            },
+            Controlled::new(
+                ResponseKind::Ok,
+                Full::new(Bytes::from_static(b"response" as &[_])),
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn limit_reached() {
+        let (mut service, mut handle) = spawn_with(|service| {
+            ConcurrencyLimitLayer::new(PlainErrorEncoder, 1)
+                .layer(service.map_err(|error| -> ! { panic!("unexpected error: {error:?}") }))
+        });
+
+        assert_ready!(service.poll_ready()).expect("should be ready");
+        let first = tokio::spawn(service.call(request()));
+        let (_, send_response) = handle.next_request().await.expect("should receive request");
+
+        // the first request is still in flight, the second one is rejected
+        assert_ready!(service.poll_ready()).expect("should be ready");
+        let mut response = service
+            .call(request())
+            .await
+            .expect("response should be present");
+
+        let Ok(frame) = response
+            .body_mut()
+            .frame()
+            .await
+            .expect("frame should be present");
+        let control = frame
+            .into_control()
+            .expect("should be control frame")
+            .into_inner();
+        assert_eq!(
+            control,
+            ResponseKind::Err(ErrorCode::CONCURRENCY_LIMIT_REACHED)
+        );
+
+        // once the first request has completed, new requests are accepted again
+        send_response.send_response(ok_response());
+        first
+            .await
+            .expect("should be able to join")
+            .expect("response should be present");
+
+        assert_ready!(service.poll_ready()).expect("should be ready");
+        let third = tokio::spawn(service.call(request()));
+        let (_, send_response) = handle.next_request().await.expect("should receive request");
+        send_response.send_response(ok_response());
+
+        let mut response = third
+            .await
+            .expect("should be able to join")
+            .expect("response should be present");
+
+        let Ok(frame) = response
+            .body_mut()
+            .frame()
+            .await
+            .expect("frame should be present");
+        let control = frame
+            .into_control()
+            .expect("should be control frame")
+            .into_inner();
+        assert_eq!(control, ResponseKind::Ok);
+    }
+
+    #[tokio::test]
+    async fn limit_per_session() {
+        let (mut service, mut handle) = spawn_with(|service| {
+            ConcurrencyLimitLayer::new(PlainErrorEncoder, 1)
+                .layer(service.map_err(|error| -> ! { panic!("unexpected error: {error:?}") }))
+        });
+
+        assert_ready!(service.poll_ready()).expect("should be ready");
+        let _first = tokio::spawn(service.call(request()));
+        let _pending = handle.next_request().await.expect("should receive request");
+
+        let other = request();
+        let other = Request::new(
+            request::Parts {
+                service: other.service(),
+                procedure: other.procedure(),
+                session: mock_session_id(0x01),
+                extensions: Extensions::new(),
+            },
+            other.into_body(),
+        );
+
+        // a different session is not affected by the limit of the first one
+        assert_ready!(service.poll_ready()).expect("should be ready");
+        let second = tokio::spawn(service.call(other));
+        let (actual, send_response) = handle.next_request().await.expect("should receive request");
+        assert_eq!(actual.session(), mock_session_id(0x01));
+        send_response.send_response(ok_response());
+
+        second
+            .await
+            .expect("should be able to join")
+            .expect("response should be present");
+    }
+}
@@ -1,3 +1,7 @@
 pub mod boxed;
+pub mod concurrency;
 pub mod error;
+pub mod rate_limit;
 pub mod report;
+pub mod timeout;
+pub mod trace;
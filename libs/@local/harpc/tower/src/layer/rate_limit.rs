@@ -0,0 +1,295 @@
+use alloc::sync::Arc;
+use core::{
+    task::{Context, Poll},
+    time::Duration,
+};
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+use bytes::Bytes;
+use harpc_net::{
+    codec::{ErrorEncoder, WireError},
+    session::server::SessionId,
+};
+use harpc_wire_protocol::response::kind::{ErrorCode, ResponseKind};
+use tokio::time::Instant;
+use tower::{Layer, Service, ServiceExt};
+
+use crate::{
+    Extensions,
+    body::{Body, controlled::Controlled, full::Full},
+    either::Either,
+    request::Request,
+    response::{Parts, Response},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+#[error("the session has exceeded the limit of {limit} requests per {period:?}")]
+pub struct RateLimitReachedError {
+    pub limit: u64,
+    pub period: Duration,
+}
+
+impl WireError for RateLimitReachedError {
+    fn code(&self) -> ErrorCode {
+        ErrorCode::RATE_LIMIT_REACHED
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Window {
+    start: Instant,
+    count: u64,
+}
+
+#[derive(Debug)]
+struct RateLimitState {
+    windows: HashMap<SessionId, Window>,
+    last_prune: Instant,
+}
+
+impl RateLimitState {
+    fn new() -> Self {
+        Self {
+            windows: HashMap::new(),
+            last_prune: Instant::now(),
+        }
+    }
+
+    fn acquire(&mut self, session: SessionId, limit: u64, period: Duration) -> bool {
+        let now = Instant::now();
+
+        // windows of sessions that haven't sent a request in the last period are no longer of
+        // interest, remove them so that the map doesn't grow with every session ever seen
+        if now.duration_since(self.last_prune) >= period {
+            self.windows
+                .retain(|_, window| now.duration_since(window.start) < period);
+            self.last_prune = now;
+        }
+
+        let window = self.windows.entry(session).or_insert(Window {
+            start: now,
+            count: 0,
+        });
+
+        if now.duration_since(window.start) >= period {
+            *window = Window {
+                start: now,
+                count: 0,
+            };
+        }
+
+        if window.count >= limit {
+            return false;
+        }
+
+        window.count += 1;
+        true
+    }
+}
+
+/// Limits the number of requests a single session can send within a period of time.
+///
+/// Requests are counted in fixed windows of the given period, which start with the first request
+/// of a session. Requests exceeding the limit are rejected with a [`RateLimitReachedError`].
+///
+/// The limit is shared between all services the layer is applied to.
+#[derive(Debug, Clone)]
+pub struct RateLimitLayer<E> {
+    encoder: E,
+
+    limit: u64,
+    period: Duration,
+    state: Arc<Mutex<RateLimitState>>,
+}
+
+impl<E> RateLimitLayer<E> {
+    pub fn new(encoder: E, limit: u64, period: Duration) -> Self {
+        Self {
+            encoder,
+            limit,
+            period,
+            state: Arc::new(Mutex::new(RateLimitState::new())),
+        }
+    }
+}
+
+impl<S, E> Layer<S> for RateLimitLayer<E>
+where
+    E: Clone,
+{
+    type Service = RateLimitService<S, E>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            encoder: self.encoder.clone(),
+            limit: self.limit,
+            period: self.period,
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RateLimitService<S, E> {
+    inner: S,
+
+    encoder: E,
+
+    limit: u64,
+    period: Duration,
+    state: Arc<Mutex<RateLimitState>>,
+}
+
+impl<S, E, ReqBody, ResBody> Service<Request<ReqBody>> for RateLimitService<S, E>
+where
+    S: Service<Request<ReqBody>, Error = !, Response = Response<ResBody>> + Clone + Send,
+    E: ErrorEncoder + Clone,
+    ReqBody: Body<Control = !>,
+    ResBody: Body<Control: AsRef<ResponseKind>>,
+{
+    type Error = !;
+    type Response = Response<Either<ResBody, Controlled<ResponseKind, Full<Bytes>>>>;
+
+    type Future = impl Future<Output = Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // we're always ready because we clone the inner service, therefore it is unused and always
+        // ready, the limit is enforced once we know the session of the request
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let encoder = self.encoder.clone();
+        let limit = self.limit;
+        let period = self.period;
+
+        let clone = self.inner.clone();
+        let inner = core::mem::replace(&mut self.inner, clone);
+
+        let session = req.session();
+        let allowed = self
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .acquire(session, limit, period);
+
+        async move {
+            if !allowed {
+                let error = encoder
+                    .encode_error(RateLimitReachedError { limit, period })
+                    .await;
+
+                return Ok(Response::from_error(
+                    Parts {
+                        session,
+                        extensions: Extensions::new(),
+                    },
+                    error,
+                )
+                .map_body(Either::Right));
+            }
+
+            let Ok(response) = inner.oneshot(req).await;
+
+            Ok(response.map_body(Either::Left))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use bytes::Bytes;
+    use harpc_wire_protocol::response::kind::{ErrorCode, ResponseKind};
+    use tokio_test::assert_ready;
+    use tower::{Layer, ServiceExt};
+    use tower_test::mock::spawn_with;
+
+    use crate::{
+        Extensions,
+        body::{BodyExt, controlled::Controlled, full::Full},
+        layer::{
+            error::test::{PlainErrorEncoder, request},
+            rate_limit::RateLimitLayer,
+        },
+        response::{self, Response},
+    };
+
+    fn ok_response() -> Response<Controlled<ResponseKind, Full<Bytes>>> {
+        Response::from_parts(
+            response::Parts {
+                session: request().session(),
+                extensions: Extensions::new(),
+            },
+            Controlled::new(
+                ResponseKind::Ok,
+                Full::new(Bytes::from_static(b"response" as &[_])),
+            ),
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn limit_reached_until_next_window() {
+        let (mut service, mut handle) = spawn_with(|service| {
+            RateLimitLayer::new(PlainErrorEncoder, 1, Duration::from_secs(1))
+                .layer(service.map_err(|error| -> ! { panic!("unexpected error: {error:?}") }))
+        });
+
+        // the first request is passed through
+        assert_ready!(service.poll_ready()).expect("should be ready");
+        let first = tokio::spawn(service.call(request()));
+        let (_, send_response) = handle.next_request().await.expect("should receive request");
+        send_response.send_response(ok_response());
+        first
+            .await
+            .expect("should be able to join")
+            .expect("response should be present");
+
+        // the second one within the same window is rejected
+        assert_ready!(service.poll_ready()).expect("should be ready");
+        let mut response = service
+            .call(request())
+            .await
+            .expect("response should be present");
+
+        let Ok(frame) = response
+            .body_mut()
+            .frame()
+            .await
+            .expect("frame should be present");
+        let control = frame
+            .into_control()
+            .expect("should be control frame")
+            .into_inner();
+        assert_eq!(control, ResponseKind::Err(ErrorCode::RATE_LIMIT_REACHED));
+
+        // once the window has passed, requests are accepted again
+        tokio::time::advance(Duration::from_secs(1)).await;
+
+        assert_ready!(service.poll_ready()).expect("should be ready");
+        let third = tokio::spawn(service.call(request()));
+        let (_, send_response) = handle.next_request().await.expect("should receive request");
+        send_response.send_response(ok_response());
+
+        let mut response = third
+            .await
+            .expect("should be able to join")
+            .expect("response should be present");
+
+        let Ok(frame) = response
+            .body_mut()
+            .frame()
+            .await
+            .expect("frame should be present");
+        let control = frame
+            .into_control()
+            .expect("should be control frame")
+            .into_inner();
+        assert_eq!(control, ResponseKind::Ok);
+    }
+}
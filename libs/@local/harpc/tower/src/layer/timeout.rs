@@ -0,0 +1,257 @@
+use alloc::sync::Arc;
+use core::{
+    task::{Context, Poll},
+    time::Duration,
+};
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use harpc_net::codec::{ErrorEncoder, WireError};
+use harpc_types::{procedure::ProcedureId, service::ServiceId};
+use harpc_wire_protocol::response::kind::{ErrorCode, ResponseKind};
+use tower::{Layer, Service, ServiceExt};
+
+use crate::{
+    Extensions,
+    body::{Body, controlled::Controlled, full::Full},
+    either::Either,
+    request::Request,
+    response::{Parts, Response},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+#[error("the deadline of {timeout:?} to respond to the request has been exceeded")]
+pub struct DeadlineExceededError {
+    pub timeout: Duration,
+}
+
+impl WireError for DeadlineExceededError {
+    fn code(&self) -> ErrorCode {
+        ErrorCode::DEADLINE_EXCEEDED
+    }
+}
+
+/// Limits the time a service has to produce a response.
+///
+/// The deadline only applies to the response itself, not to the body of the response, which is
+/// streamed to the client once the response has been produced. If the deadline is exceeded the
+/// request is aborted and a [`DeadlineExceededError`] is returned to the client.
+///
+/// A default deadline applies to every procedure, which can be overridden for individual
+/// procedures.
+#[derive(Debug, Clone)]
+pub struct TimeoutLayer<E> {
+    encoder: E,
+
+    timeout: Duration,
+    overrides: Arc<HashMap<(ServiceId, ProcedureId), Duration>>,
+}
+
+impl<E> TimeoutLayer<E> {
+    pub fn new(encoder: E, timeout: Duration) -> Self {
+        Self {
+            encoder,
+            timeout,
+            overrides: Arc::new(HashMap::new()),
+        }
+    }
+
+    /// Override the deadline of a single procedure.
+    #[must_use]
+    pub fn with_procedure(
+        mut self,
+        service: ServiceId,
+        procedure: ProcedureId,
+        timeout: Duration,
+    ) -> Self {
+        Arc::make_mut(&mut self.overrides).insert((service, procedure), timeout);
+
+        self
+    }
+}
+
+impl<S, E> Layer<S> for TimeoutLayer<E>
+where
+    E: Clone,
+{
+    type Service = TimeoutService<S, E>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TimeoutService {
+            inner,
+            encoder: self.encoder.clone(),
+            timeout: self.timeout,
+            overrides: Arc::clone(&self.overrides),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimeoutService<S, E> {
+    inner: S,
+
+    encoder: E,
+
+    timeout: Duration,
+    overrides: Arc<HashMap<(ServiceId, ProcedureId), Duration>>,
+}
+
+impl<S, E> TimeoutService<S, E> {
+    fn timeout_for(&self, service: ServiceId, procedure: ProcedureId) -> Duration {
+        self.overrides
+            .get(&(service, procedure))
+            .copied()
+            .unwrap_or(self.timeout)
+    }
+}
+
+impl<S, E, ReqBody, ResBody> Service<Request<ReqBody>> for TimeoutService<S, E>
+where
+    S: Service<Request<ReqBody>, Error = !, Response = Response<ResBody>> + Clone + Send,
+    E: ErrorEncoder + Clone,
+    ReqBody: Body<Control = !>,
+    ResBody: Body<Control: AsRef<ResponseKind>>,
+{
+    type Error = !;
+    type Response = Response<Either<ResBody, Controlled<ResponseKind, Full<Bytes>>>>;
+
+    type Future = impl Future<Output = Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // we're always ready because we clone the inner service, therefore it is unused and always
+        // ready
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let encoder = self.encoder.clone();
+        let timeout = self.timeout_for(req.service().id, req.procedure().id);
+
+        let clone = self.inner.clone();
+        let inner = core::mem::replace(&mut self.inner, clone);
+
+        let session = req.session();
+
+        async move {
+            match tokio::time::timeout(timeout, inner.oneshot(req)).await {
+                Ok(Ok(response)) => Ok(response.map_body(Either::Left)),
+                Err(_) => {
+                    let error = encoder
+                        .encode_error(DeadlineExceededError { timeout })
+                        .await;
+
+                    Ok(Response::from_error(
+                        Parts {
+                            session,
+                            extensions: Extensions::new(),
+                        },
+                        error,
+                    )
+                    .map_body(Either::Right))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use bytes::Bytes;
+    use harpc_types::{procedure::ProcedureId, service::ServiceId};
+    use harpc_wire_protocol::response::kind::{ErrorCode, ResponseKind};
+    use tokio_test::{assert_pending, assert_ready};
+    use tower::{Layer, ServiceExt};
+    use tower_test::mock::spawn_with;
+
+    use crate::{
+        Extensions,
+        body::{BodyExt, controlled::Controlled, full::Full},
+        layer::{
+            error::test::{PlainErrorEncoder, request},
+            timeout::TimeoutLayer,
+        },
+        response::{self, Response},
+    };
+
+    fn ok_response() -> Response<Controlled<ResponseKind, Full<Bytes>>> {
+        Response::from_parts(
+            response::Parts {
+                session: request().session(),
+                extensions: Extensions::new(),
+            },
+            Controlled::new(
+                ResponseKind::Ok,
+                Full::new(Bytes::from_static(b"response" as &[_])),
+            ),
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn deadline_exceeded() {
+        let (mut service, mut handle) = spawn_with(|service| {
+            TimeoutLayer::new(PlainErrorEncoder, Duration::from_secs(1))
+                .layer(service.map_err(|error| -> ! { panic!("unexpected error: {error:?}") }))
+        });
+
+        assert_pending!(handle.poll_request());
+        assert_ready!(service.poll_ready()).expect("should be ready");
+
+        let response = tokio::spawn(service.call(request()));
+
+        // keep the request pending, without ever responding
+        let _request = handle.next_request().await.expect("should receive request");
+
+        let mut response = response
+            .await
+            .expect("should be able to join")
+            .expect("response should be present");
+
+        let body = response.body_mut();
+        let Ok(frame) = body.frame().await.expect("frame should be present");
+        let control = frame
+            .into_control()
+            .expect("should be control frame")
+            .into_inner();
+        assert_eq!(control, ResponseKind::Err(ErrorCode::DEADLINE_EXCEEDED));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn procedure_override() {
+        let request = request();
+
+        let (mut service, mut handle) = spawn_with(|service| {
+            TimeoutLayer::new(PlainErrorEncoder, Duration::from_secs(1))
+                .with_procedure(
+                    ServiceId::new(0x00),
+                    ProcedureId::new(0x00),
+                    Duration::from_secs(10),
+                )
+                .layer(service.map_err(|error| -> ! { panic!("unexpected error: {error:?}") }))
+        });
+
+        assert_ready!(service.poll_ready()).expect("should be ready");
+
+        let response = tokio::spawn(service.call(request));
+
+        let (_, send_response) = handle.next_request().await.expect("should receive request");
+
+        // longer than the default deadline, but shorter than the override
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        send_response.send_response(ok_response());
+
+        let mut response = response
+            .await
+            .expect("should be able to join")
+            .expect("response should be present");
+
+        let body = response.body_mut();
+        let Ok(frame) = body.frame().await.expect("frame should be present");
+        let control = frame
+            .into_control()
+            .expect("should be control frame")
+            .into_inner();
+        assert_eq!(control, ResponseKind::Ok);
+    }
+}
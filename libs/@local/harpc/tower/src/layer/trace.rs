@@ -0,0 +1,285 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use harpc_wire_protocol::response::kind::ResponseKind;
+use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt};
+use opentelemetry_sdk::trace::{IdGenerator, RandomIdGenerator};
+use tower::{Layer, Service, ServiceExt};
+use tracing::{Instrument, Span, field::Empty};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::{
+    body::{Body, BodyState, Frame, SizeHint},
+    metadata::Metadata,
+    request::Request,
+    response::Response,
+};
+
+fn extract_remote_context(metadata: Option<&Metadata>) -> opentelemetry::Context {
+    let ctx = metadata.map_or_else(opentelemetry::Context::new, |metadata| {
+        opentelemetry::global::get_text_map_propagator(|propagator| propagator.extract(metadata))
+    });
+
+    if ctx.span().span_context().is_valid() {
+        // Remote context where the trace is correlated.
+        ctx
+    } else {
+        // New, local context with generated ids.
+        ctx.with_remote_span_context(SpanContext::new(
+            RandomIdGenerator::default().new_trace_id(),
+            SpanId::INVALID,
+            ctx.span().span_context().trace_flags(),
+            // explicitly make it non-remote
+            false,
+            ctx.span().span_context().trace_state().clone(),
+        ))
+    }
+}
+
+fn make_span<B>(request: &Request<B>) -> Span
+where
+    B: Body<Control = !>,
+{
+    let service = request.service();
+    let procedure = request.procedure();
+
+    let remote_context = extract_remote_context(request.extensions().get::<Metadata>());
+    let trace_id = remote_context.span().span_context().trace_id();
+
+    // Implementing the attributes outlined by
+    // https://opentelemetry.io/docs/specs/semconv/rpc/rpc-spans/
+    let span = tracing::info_span!("rpc-request",
+        trace_id = %trace_id,
+        otel.kind = "server",
+        otel.name = %format!("{}/{}", service.id.value(), procedure.id.value()),
+        rpc.system = "harpc",
+        rpc.service = service.id.value(),
+        rpc.service.version = %service.version,
+        rpc.method = procedure.id.value(),
+        rpc.session = ?request.session(),
+        rpc.error_code = Empty,
+        otel.status_code = Empty,
+    );
+    span.set_parent(remote_context);
+    span
+}
+
+/// Creates a span for every request, which is correlated with the trace of the client.
+///
+/// The trace context of the client is extracted from the [`Metadata`] of the request using the
+/// globally configured text map propagator. The span is kept alive until the response body has
+/// been fully transmitted, and is marked as failed if the response is an error.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TraceLayer {
+    _private: (),
+}
+
+impl TraceLayer {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl Default for TraceLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for TraceLayer {
+    type Service = TraceService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TraceService { inner }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TraceService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for TraceService<S>
+where
+    S: Service<Request<ReqBody>, Error = !, Response = Response<ResBody>> + Clone + Send,
+    ReqBody: Body<Control = !>,
+    ResBody: Body<Control: AsRef<ResponseKind>>,
+{
+    type Error = !;
+    type Response = Response<TraceBody<ResBody>>;
+
+    type Future = impl Future<Output = Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // we're always ready because we clone the inner service, therefore it is unused and always
+        // ready
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let clone = self.inner.clone();
+        let inner = core::mem::replace(&mut self.inner, clone);
+
+        let span = make_span(&req);
+
+        async move {
+            let Ok(response) = inner.oneshot(req).instrument(span.clone()).await;
+
+            Ok(response.map_body(|body| TraceBody::new(body, span)))
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Response body that records the outcome of the request on the span of the request.
+    #[derive(Debug)]
+    pub struct TraceBody<B> {
+        span: Span,
+
+        #[pin]
+        inner: B,
+    }
+}
+
+impl<B> TraceBody<B> {
+    pub const fn new(inner: B, span: Span) -> Self {
+        Self { span, inner }
+    }
+}
+
+impl<B> Body for TraceBody<B>
+where
+    B: Body<Control: AsRef<ResponseKind>>,
+{
+    type Control = B::Control;
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+    ) -> Poll<Option<Result<Frame<Self::Data, Self::Control>, Self::Error>>> {
+        let this = self.project();
+        let _guard = this.span.enter();
+
+        let frame = ready!(this.inner.poll_frame(cx));
+
+        match &frame {
+            Some(Ok(Frame::Control(control))) => {
+                if let ResponseKind::Err(code) = *control.as_ref() {
+                    this.span.record("rpc.error_code", code.value().get());
+                    this.span.record("otel.status_code", "ERROR");
+                }
+            }
+            Some(Err(_)) => {
+                this.span.record("otel.status_code", "ERROR");
+            }
+            Some(Ok(Frame::Data(_))) | None => {}
+        }
+
+        Poll::Ready(frame)
+    }
+
+    fn state(&self) -> Option<BodyState> {
+        self.inner.state()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+    use harpc_wire_protocol::response::kind::{ErrorCode, ResponseKind};
+    use opentelemetry::propagation::TextMapPropagator as _;
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use tokio_test::assert_ready;
+    use tower::{Layer, ServiceExt};
+    use tower_test::mock::spawn_with;
+
+    use crate::{
+        Extensions,
+        body::{BodyExt, controlled::Controlled, full::Full},
+        layer::{error::test::request, trace::TraceLayer},
+        metadata::Metadata,
+        response::{self, Response},
+    };
+
+    #[test]
+    fn extract_remote_context() {
+        opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+
+        let metadata: Metadata = [(
+            "traceparent",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+        )]
+        .into_iter()
+        .collect();
+
+        let context = super::extract_remote_context(Some(&metadata));
+        let span = opentelemetry::trace::TraceContextExt::span(&context);
+        let span_context = span.span_context();
+
+        assert!(span_context.is_remote());
+        assert_eq!(
+            span_context.trace_id().to_string(),
+            "0af7651916cd43dd8448eb211c80319c"
+        );
+    }
+
+    #[test]
+    fn extract_missing_context() {
+        let context = super::extract_remote_context(None);
+        let span = opentelemetry::trace::TraceContextExt::span(&context);
+
+        // a new trace is started
+        assert!(!span.span_context().is_remote());
+
+        // injecting the context yields no parent, as the span id is invalid
+        let mut metadata = Metadata::new();
+        TraceContextPropagator::new().inject_context(&context, &mut metadata);
+        assert!(metadata.get("traceparent").is_none());
+    }
+
+    #[tokio::test]
+    async fn passthrough() {
+        let (mut service, mut handle) = spawn_with(|service| {
+            TraceLayer::new()
+                .layer(service.map_err(|error| -> ! { panic!("unexpected error: {error:?}") }))
+        });
+
+        assert_ready!(service.poll_ready()).expect("should be ready");
+        let response = tokio::spawn(service.call(request()));
+
+        let (actual, send_response) = handle.next_request().await.expect("should receive request");
+        send_response.send_response(Response::from_parts(
+            response::Parts {
+                session: actual.session(),
+                extensions: Extensions::new(),
+            },
+            Controlled::new(
+                ResponseKind::Err(ErrorCode::INTERNAL_SERVER_ERROR),
+                Full::new(Bytes::from_static(b"error" as &[_])),
+            ),
+        ));
+
+        let mut response = response
+            .await
+            .expect("should be able to join")
+            .expect("response should be present");
+
+        let body = response.body_mut();
+        let Ok(frame) = body.frame().await.expect("frame should be present");
+        let control = frame.into_control().expect("should be control frame");
+        assert_eq!(control, ResponseKind::Err(ErrorCode::INTERNAL_SERVER_ERROR));
+
+        let Ok(frame) = body.frame().await.expect("frame should be present");
+        let data = frame.into_data().expect("should be data frame");
+        assert_eq!(data, Bytes::from_static(b"error" as &[_]));
+    }
+}
@@ -6,12 +6,15 @@
 )]
 #![cfg_attr(test, feature(noop_waker, assert_matches))]
 
+extern crate alloc;
+
 pub use self::extensions::Extensions;
 
 pub mod body;
 pub mod either;
 pub(crate) mod extensions;
 pub mod layer;
+pub mod metadata;
 pub mod net;
 pub mod request;
 pub mod response;
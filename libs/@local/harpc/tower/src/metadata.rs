@@ -0,0 +1,199 @@
+use alloc::collections::BTreeMap;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use error_stack::Report;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+pub enum MetadataError {
+    #[error("the metadata is malformed")]
+    Malformed,
+    #[error("the metadata contains a key or value that is not valid UTF-8")]
+    InvalidUtf8,
+    #[error("the metadata contains a key or value that is longer than 65535 bytes")]
+    EntryTooLarge,
+}
+
+/// Text map sent alongside a request.
+///
+/// The metadata is transmitted as part of the first packet of a request, and is used to convey
+/// information that is independent of the procedure, like the trace context of the caller.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metadata {
+    entries: BTreeMap<String, String>,
+}
+
+impl Metadata {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        self.entries.insert(key.into(), value.into())
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.entries.remove(key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Encode the metadata into its binary representation.
+    ///
+    /// Each entry is encoded as the length of the key (2 bytes), the key, the length of the value
+    /// (2 bytes) and the value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a key or value exceeds 65535 bytes.
+    pub fn encode(&self) -> Result<Bytes, Report<MetadataError>> {
+        let mut buffer = BytesMut::new();
+
+        for (key, value) in &self.entries {
+            for part in [key, value] {
+                let length = u16::try_from(part.len())
+                    .map_err(|_| Report::new(MetadataError::EntryTooLarge))?;
+
+                buffer.put_u16(length);
+                buffer.extend_from_slice(part.as_bytes());
+            }
+        }
+
+        Ok(buffer.freeze())
+    }
+
+    /// Decode the metadata from its binary representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata is truncated or contains invalid UTF-8.
+    pub fn decode(mut bytes: Bytes) -> Result<Self, Report<MetadataError>> {
+        fn decode_part(bytes: &mut Bytes) -> Result<String, Report<MetadataError>> {
+            if bytes.remaining() < 2 {
+                return Err(Report::new(MetadataError::Malformed));
+            }
+
+            let length = usize::from(bytes.get_u16());
+            if bytes.remaining() < length {
+                return Err(Report::new(MetadataError::Malformed));
+            }
+
+            let part = bytes.split_to(length);
+
+            String::from_utf8(part.to_vec()).map_err(|_| Report::new(MetadataError::InvalidUtf8))
+        }
+
+        let mut entries = BTreeMap::new();
+
+        while bytes.has_remaining() {
+            let key = decode_part(&mut bytes)?;
+            let value = decode_part(&mut bytes)?;
+
+            entries.insert(key, value);
+        }
+
+        Ok(Self { entries })
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Metadata
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self {
+            entries: iter
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        }
+    }
+}
+
+// Let OpenTelemetry pick the keys, so that the trace context is propagated in the same format as
+// it is over HTTP.
+impl opentelemetry::propagation::Extractor for Metadata {
+    fn get(&self, key: &str) -> Option<&str> {
+        Self::get(self, key)
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        Self::keys(self).collect()
+    }
+}
+
+impl opentelemetry::propagation::Injector for Metadata {
+    fn set(&mut self, key: &str, value: String) {
+        self.insert(key, value);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+
+    use super::{Metadata, MetadataError};
+
+    #[test]
+    fn encode_decode() {
+        let metadata: Metadata = [
+            (
+                "traceparent",
+                "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+            ),
+            ("tracestate", ""),
+        ]
+        .into_iter()
+        .collect();
+
+        let bytes = metadata
+            .encode()
+            .expect("should be able to encode metadata");
+        let decoded = Metadata::decode(bytes).expect("should be able to decode metadata");
+
+        assert_eq!(decoded, metadata);
+    }
+
+    #[test]
+    fn decode_empty() {
+        let metadata = Metadata::decode(Bytes::new()).expect("should be able to decode metadata");
+
+        assert!(metadata.is_empty());
+    }
+
+    #[test]
+    fn decode_truncated() {
+        let report = Metadata::decode(Bytes::from_static(b"\x00\x03key\x00\x05val"))
+            .expect_err("should not be able to decode truncated metadata");
+
+        assert_eq!(*report.current_context(), MetadataError::Malformed);
+    }
+
+    #[test]
+    fn decode_invalid_utf8() {
+        let report = Metadata::decode(Bytes::from_static(b"\x00\x01\xFF\x00\x00"))
+            .expect_err("should not be able to decode invalid utf-8");
+
+        assert_eq!(*report.current_context(), MetadataError::InvalidUtf8);
+    }
+}
@@ -1,7 +1,7 @@
 use harpc_net::session::server::{SessionId, transaction::TransactionContext};
 use harpc_wire_protocol::request::{procedure::ProcedureDescriptor, service::ServiceDescriptor};
 
-use crate::{body::Body, extensions::Extensions};
+use crate::{body::Body, extensions::Extensions, metadata::Metadata};
 
 /// Component parts of a harpc `Request`.
 #[derive(Debug, Clone)]
@@ -11,17 +11,39 @@ pub struct Parts {
 
     pub session: SessionId,
 
+    /// Requests received by a server carry the [`CancellationToken`] of their transaction, which
+    /// is cancelled once the client has cancelled the transaction, as well as the [`Metadata`]
+    /// sent by the client, if any.
+    ///
+    /// [`CancellationToken`]: tokio_util::sync::CancellationToken
     pub extensions: Extensions,
 }
 
 impl Parts {
+    /// Create the parts of a request from the context of a transaction.
+    ///
+    /// If the client has sent [`Metadata`] alongside the request, it is decoded and inserted into
+    /// the extensions. Malformed metadata is ignored.
     #[must_use]
     pub fn from_transaction(context: &TransactionContext) -> Self {
+        let mut extensions = Extensions::new();
+
+        if let Some(metadata) = context.metadata() {
+            match Metadata::decode(metadata.clone()) {
+                Ok(metadata) => {
+                    extensions.insert(metadata);
+                }
+                Err(error) => {
+                    tracing::warn!(?error, "unable to decode request metadata, ignoring...");
+                }
+            }
+        }
+
         Self {
             service: context.service(),
             procedure: context.procedure(),
             session: context.session(),
-            extensions: Extensions::new(),
+            extensions,
         }
     }
 }
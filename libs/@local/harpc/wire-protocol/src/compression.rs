@@ -0,0 +1,17 @@
+/// Compression scheme applied to the payload of the packets of a transaction.
+///
+/// The client proposes a compression scheme for a transaction through the flags of its request
+/// packets. The server may accept the proposal, in which case it uses the same scheme for the
+/// payloads of its response packets, or decline it and respond uncompressed.
+///
+/// Every packet is compressed independently of the others, so that the body can still be streamed
+/// frame by frame. Packets whose payload doesn't benefit from compression are sent uncompressed,
+/// which is indicated by the absence of the `Compressed` flag on the packet.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(test, derive(test_strategy::Arbitrary))]
+pub enum Compression {
+    /// [Zstandard](https://facebook.github.io/zstd/), favours compression ratio.
+    Zstd,
+    /// [LZ4](https://lz4.org/) block format, favours speed.
+    Lz4,
+}
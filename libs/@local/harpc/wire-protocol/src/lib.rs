@@ -18,6 +18,7 @@
 )]
 
 pub mod codec;
+pub mod compression;
 pub mod flags;
 pub mod payload;
 pub mod protocol;
@@ -5,6 +5,7 @@ use error_stack::Result;
 use super::body::RequestBody;
 use crate::{
     codec::{Buffer, BufferError, Decode, Encode},
+    compression::Compression,
     flags::BitFlagsOp,
 };
 
@@ -16,6 +17,22 @@ pub enum RequestFlag {
     BeginOfRequest = 0b1000_0000,
     // Controlled flags
     EndOfRequest = 0b0000_0001,
+    /// The client is no longer interested in the transaction, the server should stop processing
+    /// it and discard any response.
+    CancelRequest = 0b0000_0010,
+    /// The payload of the `Begin` packet is prefixed with the metadata of the request.
+    ContainsMetadata = 0b0000_0100,
+    /// The client proposes to compress the transaction using [`Compression::Zstd`].
+    ///
+    /// Set on every packet of the transaction, takes precedence over
+    /// [`Self::CompressionLz4`].
+    CompressionZstd = 0b0000_1000,
+    /// The client proposes to compress the transaction using [`Compression::Lz4`].
+    ///
+    /// Set on every packet of the transaction.
+    CompressionLz4 = 0b0001_0000,
+    /// The payload of the packet is compressed using the compression of the transaction.
+    Compressed = 0b0010_0000,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -33,6 +50,30 @@ impl RequestFlags {
             matches!(body, RequestBody::Begin(_)),
         )
     }
+
+    /// The compression proposed for the transaction, if any.
+    #[must_use]
+    pub fn compression(self) -> Option<Compression> {
+        if self.contains(RequestFlag::CompressionZstd) {
+            Some(Compression::Zstd)
+        } else if self.contains(RequestFlag::CompressionLz4) {
+            Some(Compression::Lz4)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn with_compression(self, compression: Option<Compression>) -> Self {
+        self.set(
+            RequestFlag::CompressionZstd,
+            compression == Some(Compression::Zstd),
+        )
+        .set(
+            RequestFlag::CompressionLz4,
+            compression == Some(Compression::Lz4),
+        )
+    }
 }
 
 impl BitFlagsOp for RequestFlags {
@@ -91,6 +132,7 @@ mod test {
 
     use crate::{
         codec::test::{assert_codec, assert_decode, assert_encode},
+        compression::Compression,
         flags::BitFlagsOp,
         request::flags::{RequestFlag, RequestFlags},
     };
@@ -119,6 +161,33 @@ mod test {
                 0x01
             "#
         ]]);
+
+        assert_encode(
+            &RequestFlags::from(RequestFlag::CancelRequest | RequestFlag::EndOfRequest),
+            expect![[r#"
+                0x03
+            "#]],
+        );
+
+        assert_encode(
+            &RequestFlags::from(RequestFlag::BeginOfRequest | RequestFlag::ContainsMetadata),
+            expect![[r#"
+                0x84
+            "#]],
+        );
+
+        assert_encode(
+            &RequestFlags::from(RequestFlag::CompressionZstd | RequestFlag::Compressed),
+            expect![[r#"
+                0x28
+            "#]],
+        );
+
+        assert_encode(&RequestFlags::from(RequestFlag::CompressionLz4), expect![[
+            r#"
+                0x10
+            "#
+        ]]);
     }
 
     #[test]
@@ -142,6 +211,33 @@ mod test {
             &RequestFlags::from(RequestFlag::EndOfRequest),
             (),
         );
+
+        assert_decode(
+            &[0b0000_0011_u8] as &[_],
+            &RequestFlags::from(RequestFlag::CancelRequest | RequestFlag::EndOfRequest),
+            (),
+        );
+    }
+
+    #[test]
+    fn compression() {
+        assert_eq!(RequestFlags::EMPTY.compression(), None);
+
+        for compression in [None, Some(Compression::Zstd), Some(Compression::Lz4)] {
+            let flags =
+                RequestFlags::from(RequestFlag::BeginOfRequest).with_compression(compression);
+
+            assert_eq!(flags.compression(), compression);
+            assert!(flags.contains(RequestFlag::BeginOfRequest));
+        }
+
+        // zstd takes precedence if both are set
+        let flags = RequestFlags::from(RequestFlag::CompressionZstd | RequestFlag::CompressionLz4);
+        assert_eq!(flags.compression(), Some(Compression::Zstd));
+
+        // overriding the compression removes the previous one
+        let flags = flags.with_compression(Some(Compression::Lz4));
+        assert_eq!(flags.value(), RequestFlag::CompressionLz4);
     }
 
     #[test_strategy::proptest]
@@ -91,6 +91,23 @@ pub struct RequestDecodeError;
 /// * Payload (up to 65504 bytes)
 /// total 32 bytes to 64 KiB
 /// ```
+///
+/// If the `ContainsMetadata` bit is set in the `Flags` field of a `Begin` packet, the payload is
+/// prefixed with the metadata of the request:
+///
+/// ```text
+/// * Metadata Length (2 bytes)
+/// * Metadata (up to 65502 bytes)
+/// ```
+///
+/// The metadata is opaque to the protocol and needs to fit into the `Begin` packet.
+///
+/// # Cancellation
+///
+/// A client can abort an in-flight transaction by sending a `Frame` packet with the
+/// `CancelRequest` and `EndOfRequest` bits set in the `Flags` field. The payload of a cancel packet
+/// is empty and is ignored by the server, which stops processing the transaction without sending a
+/// response.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(test, derive(test_strategy::Arbitrary))]
 pub struct Request {
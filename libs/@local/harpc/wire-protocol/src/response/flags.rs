@@ -5,6 +5,7 @@ use error_stack::Result;
 use super::body::ResponseBody;
 use crate::{
     codec::{Buffer, BufferError, Decode, Encode},
+    compression::Compression,
     flags::BitFlagsOp,
 };
 
@@ -16,6 +17,13 @@ pub enum ResponseFlag {
     BeginOfResponse = 0b1000_0000,
     // Controlled flags
     EndOfResponse = 0b0000_0001,
+    /// The payload of the packet is compressed using the compression indicated by
+    /// [`Self::CompressionZstd`] or [`Self::CompressionLz4`].
+    Compressed = 0b0000_0010,
+    /// The server has accepted [`Compression::Zstd`] for the transaction.
+    CompressionZstd = 0b0000_0100,
+    /// The server has accepted [`Compression::Lz4`] for the transaction.
+    CompressionLz4 = 0b0000_1000,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -33,6 +41,30 @@ impl ResponseFlags {
             matches!(body, ResponseBody::Begin(_)),
         )
     }
+
+    /// The compression accepted for the transaction, if any.
+    #[must_use]
+    pub fn compression(self) -> Option<Compression> {
+        if self.contains(ResponseFlag::CompressionZstd) {
+            Some(Compression::Zstd)
+        } else if self.contains(ResponseFlag::CompressionLz4) {
+            Some(Compression::Lz4)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn with_compression(self, compression: Option<Compression>) -> Self {
+        self.set(
+            ResponseFlag::CompressionZstd,
+            compression == Some(Compression::Zstd),
+        )
+        .set(
+            ResponseFlag::CompressionLz4,
+            compression == Some(Compression::Lz4),
+        )
+    }
 }
 
 impl BitFlagsOp for ResponseFlags {
@@ -92,6 +124,7 @@ mod test {
     use super::ResponseFlags;
     use crate::{
         codec::test::{assert_codec, assert_decode, assert_encode},
+        compression::Compression,
         flags::BitFlagsOp,
         request::flags::{RequestFlag, RequestFlags},
         response::flags::ResponseFlag,
@@ -115,6 +148,29 @@ mod test {
             &ResponseFlags::from(ResponseFlag::EndOfResponse),
             (),
         );
+
+        assert_decode(
+            &[0x07_u8] as &[_],
+            &ResponseFlags::from(
+                ResponseFlag::EndOfResponse
+                    | ResponseFlag::Compressed
+                    | ResponseFlag::CompressionZstd,
+            ),
+            (),
+        );
+    }
+
+    #[test]
+    fn compression() {
+        assert_eq!(ResponseFlags::EMPTY.compression(), None);
+
+        for compression in [None, Some(Compression::Zstd), Some(Compression::Lz4)] {
+            let flags =
+                ResponseFlags::from(ResponseFlag::EndOfResponse).with_compression(compression);
+
+            assert_eq!(flags.compression(), compression);
+            assert!(flags.contains(ResponseFlag::EndOfResponse));
+        }
     }
 
     #[test_strategy::proptest]
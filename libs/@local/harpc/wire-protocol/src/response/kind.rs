@@ -9,6 +9,14 @@ use crate::codec::{Buffer, BufferError, Decode, Encode};
 #[cfg_attr(test, derive(test_strategy::Arbitrary))]
 pub struct ErrorCode(NonZero<u16>);
 
+impl ErrorCode {
+    // 0xFF_C0..=0xFF_CF are server layer errors, emitted by the middleware of the server before
+    // or instead of the service producing a response
+    pub const CONCURRENCY_LIMIT_REACHED: Self = Self(NonZero::new(0xFF_C1).expect("infallible"));
+    pub const DEADLINE_EXCEEDED: Self = Self(NonZero::new(0xFF_C0).expect("infallible"));
+    pub const RATE_LIMIT_REACHED: Self = Self(NonZero::new(0xFF_C2).expect("infallible"));
+}
+
 impl ErrorCode {
     // 0xFF_D0..=0xFF_DF are client layer errors
     pub const NOT_FOUND: Self = Self(NonZero::new(0xFF_D0).expect("infallible"));
@@ -20,6 +28,7 @@ impl ErrorCode {
     pub const CONNECTION_SHUTDOWN: Self = Self(NonZero::new(0xFF_E1).expect("infallible"));
     pub const CONNECTION_TRANSACTION_LIMIT_REACHED: Self =
         Self(NonZero::new(0xFF_E2).expect("infallible"));
+    pub const DECOMPRESSION_FAILED: Self = Self(NonZero::new(0xFF_E5).expect("infallible"));
     pub const INSTANCE_TRANSACTION_LIMIT_REACHED: Self =
         Self(NonZero::new(0xFF_E3).expect("infallible"));
     pub const TRANSACTION_LAGGING: Self = Self(NonZero::new(0xFF_E4).expect("infallible"));
@@ -28,6 +37,7 @@ impl ErrorCode {
 impl ErrorCode {
     // 0xFF_F0..=0xFF_FF are generic errors
     pub const INTERNAL_SERVER_ERROR: Self = Self(NonZero::new(0xFF_F0).expect("infallible"));
+    pub const UNAUTHENTICATED: Self = Self(NonZero::new(0xFF_F1).expect("infallible"));
 }
 
 impl ErrorCode {
@@ -40,6 +50,30 @@ impl ErrorCode {
     pub const fn value(self) -> NonZero<u16> {
         self.0
     }
+
+    /// Returns `true` if the code is in the range of server layer errors.
+    #[must_use]
+    pub const fn is_server_layer(self) -> bool {
+        matches!(self.0.get(), 0xFF_C0..=0xFF_CF)
+    }
+
+    /// Returns `true` if the code is in the range of client layer errors.
+    #[must_use]
+    pub const fn is_client_layer(self) -> bool {
+        matches!(self.0.get(), 0xFF_D0..=0xFF_DF)
+    }
+
+    /// Returns `true` if the code is in the range of session layer errors.
+    #[must_use]
+    pub const fn is_session_layer(self) -> bool {
+        matches!(self.0.get(), 0xFF_E0..=0xFF_EF)
+    }
+
+    /// Returns `true` if the code is in the range of generic errors.
+    #[must_use]
+    pub const fn is_generic(self) -> bool {
+        matches!(self.0.get(), 0xFF_F0..=0xFF_FF)
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -147,6 +181,34 @@ mod test {
         );
     }
 
+    #[test]
+    fn ranges() {
+        for code in [
+            ErrorCode::DEADLINE_EXCEEDED,
+            ErrorCode::CONCURRENCY_LIMIT_REACHED,
+            ErrorCode::RATE_LIMIT_REACHED,
+        ] {
+            assert!(code.is_server_layer());
+        }
+
+        assert!(ErrorCode::NOT_FOUND.is_client_layer());
+
+        for code in [
+            ErrorCode::CONNECTION_CLOSED,
+            ErrorCode::CONNECTION_SHUTDOWN,
+            ErrorCode::CONNECTION_TRANSACTION_LIMIT_REACHED,
+            ErrorCode::INSTANCE_TRANSACTION_LIMIT_REACHED,
+            ErrorCode::TRANSACTION_LAGGING,
+            ErrorCode::DECOMPRESSION_FAILED,
+        ] {
+            assert!(code.is_session_layer());
+        }
+
+        for code in [ErrorCode::INTERNAL_SERVER_ERROR, ErrorCode::UNAUTHENTICATED] {
+            assert!(code.is_generic());
+        }
+    }
+
     #[test_strategy::proptest]
     #[cfg_attr(miri, ignore)]
     fn codec(kind: ResponseKind) {
@@ -1,5 +1,6 @@
+use alloc::sync::Arc;
 use core::borrow::Borrow;
-use std::collections::{HashSet, hash_map::RawEntryMut};
+use std::collections::{HashMap, HashSet, hash_map::RawEntryMut};
 
 use error_stack::{Report, ReportSink, ResultExt};
 use futures::{StreamExt, TryStreamExt, stream};
@@ -12,7 +13,6 @@ use graph_types::{
             PropertyWithMetadataValue, ValueMetadata,
             visitor::{
                 EntityVisitor, TraversalError, walk_array, walk_object, walk_one_of_property_value,
-                walk_value,
             },
         },
     },
@@ -25,9 +25,9 @@ use serde_json::Value as JsonValue;
 use thiserror::Error;
 use type_system::{
     schema::{
-        ClosedEntityType, DataTypeReference, JsonSchemaValueType, PropertyObjectSchema,
-        PropertyType, PropertyTypeReference, PropertyValueArray, PropertyValueSchema,
-        PropertyValues, ValueOrArray,
+        ClosedDataType, ClosedEntityType, DataTypeReference, JsonSchemaValueType,
+        PropertyObjectSchema, PropertyType, PropertyTypeReference, PropertyValueArray,
+        PropertyValueSchema, PropertyValues, ValueOrArray,
     },
     url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
 };
@@ -260,6 +260,39 @@ pub struct EntityPreprocessor {
     pub components: ValidateEntityComponents,
 }
 
+/// Resolves all data types `data_type` inherits from, directly or transitively.
+async fn close_data_type<P>(
+    data_type: &DataTypeWithMetadata,
+    type_provider: &P,
+) -> Result<ClosedDataType, Report<TraversalError>>
+where
+    P: DataTypeProvider + Sync,
+{
+    let mut definitions = HashMap::new();
+    let mut parents: Vec<_> = data_type.schema.all_of.clone();
+
+    while let Some(parent) = parents.pop() {
+        if parent.url == data_type.schema.id || definitions.contains_key(&parent.url) {
+            continue;
+        }
+
+        let parent_type = type_provider
+            .provide_type(&parent.url)
+            .await
+            .change_context_lazy(|| TraversalError::DataTypeRetrieval { id: parent.clone() })?;
+        let parent_type: &DataTypeWithMetadata = parent_type.borrow();
+
+        parents.extend(parent_type.schema.all_of.iter().cloned());
+        definitions.insert(parent.url, Arc::new(parent_type.schema.clone()));
+    }
+
+    Ok(ClosedDataType {
+        schema: Arc::new(data_type.schema.clone()),
+        definitions,
+    })
+}
+
+/// Validates a value against the constraints of a data type, including the inherited ones.
 struct ValueValidator;
 
 impl EntityVisitor for ValueValidator {
@@ -268,16 +301,16 @@ impl EntityVisitor for ValueValidator {
         data_type: &DataTypeWithMetadata,
         value: &mut JsonValue,
         metadata: &mut ValueMetadata,
-        _: &P,
+        type_provider: &P,
     ) -> Result<(), Report<[TraversalError]>>
     where
         P: DataTypeProvider + Sync,
     {
         let mut status = ReportSink::new();
 
+        let closed_data_type = close_data_type(data_type, type_provider).await?;
         status.attempt(
-            data_type
-                .schema
+            closed_data_type
                 .validate_constraints(value)
                 .change_context(TraversalError::ConstraintUnfulfilled),
         );
@@ -352,15 +385,6 @@ impl EntityVisitor for EntityPreprocessor {
             status.append(error);
         }
 
-        walk_value(
-            &mut ValueValidator,
-            data_type,
-            value,
-            metadata,
-            type_provider,
-        )
-        .await?;
-
         status.finish()
     }
 
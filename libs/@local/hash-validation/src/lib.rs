@@ -400,7 +400,7 @@ mod tests {
     pub(crate) async fn validate_data(
         mut value: JsonValue,
         data_type: &str,
-        data_types: impl IntoIterator<Item = &'static str> + Send,
+        data_types: impl IntoIterator<Item = &str> + Send,
         components: ValidateEntityComponents,
     ) -> Result<PropertyWithMetadataValue, Report<[TraversalError]>> {
         install_error_stack_hooks();
@@ -227,6 +227,67 @@ async fn meter() {
     .expect_err("validation succeeded");
 }
 
+#[tokio::test]
+async fn inherited_constraints() {
+    let non_negative_type = serde_json::to_string(&json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://localhost:4000/@alice/types/data-type/non-negative/v/1",
+        "title": "Non-negative",
+        "allOf": [{ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/value/v/1" }],
+        "type": "number",
+        "minimum": 0,
+    }))
+    .expect("failed to serialize non-negative type");
+    let percentage_type = serde_json::to_string(&json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://localhost:4000/@alice/types/data-type/percentage/v/1",
+        "title": "Percentage",
+        "allOf": [{ "$ref": "https://localhost:4000/@alice/types/data-type/non-negative/v/1" }],
+        "type": "number",
+        "maximum": 100,
+    }))
+    .expect("failed to serialize percentage type");
+    let integer_percentage_type = serde_json::to_string(&json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://localhost:4000/@alice/types/data-type/integer-percentage/v/1",
+        "title": "Integer Percentage",
+        "allOf": [{ "$ref": "https://localhost:4000/@alice/types/data-type/percentage/v/1" }],
+        "type": "number",
+        "multipleOf": 1,
+    }))
+    .expect("failed to serialize integer percentage type");
+
+    let data_types = [
+        graph_test_data::data_type::VALUE_V1,
+        non_negative_type.as_str(),
+        percentage_type.as_str(),
+    ];
+
+    validate_data(
+        json!(50),
+        &integer_percentage_type,
+        data_types,
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    // the constraints of the parents and grandparents apply as well
+    for value in [json!(50.5), json!(101), json!(-1)] {
+        _ = validate_data(
+            value,
+            &integer_percentage_type,
+            data_types,
+            ValidateEntityComponents::full(),
+        )
+        .await
+        .expect_err("validation succeeded");
+    }
+}
+
 #[tokio::test]
 async fn uri() {
     let url_type = serde_json::to_string(&json!({
@@ -267,6 +328,569 @@ async fn uri() {
     .expect_err("validation succeeded");
 }
 
+#[tokio::test]
+async fn uri_reference() {
+    let uri_reference_type = serde_json::to_string(&json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://localhost:4000/@alice/types/data-type/uri-reference/v/1",
+        "title": "Uri Reference",
+        "type": "string",
+        "format": "uri-reference",
+    }))
+    .expect("failed to serialize uri reference type");
+
+    validate_data(
+        json!("https://example.com/path?query#fragment"),
+        &uri_reference_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("../relative/path"),
+        &uri_reference_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("#fragment"),
+        &uri_reference_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("//example.com/path%20with%20space"),
+        &uri_reference_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    _ = validate_data(
+        json!("path with space"),
+        &uri_reference_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("%zz"),
+        &uri_reference_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("https://example.com/\u{fc}mlaut"),
+        &uri_reference_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+}
+
+#[tokio::test]
+async fn iri() {
+    let iri_type = serde_json::to_string(&json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://localhost:4000/@alice/types/data-type/iri/v/1",
+        "title": "Iri",
+        "type": "string",
+        "format": "iri",
+    }))
+    .expect("failed to serialize iri type");
+
+    validate_data(
+        json!("https://example.com/\u{fc}mlaut"),
+        &iri_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("urn:isbn:0451450523"),
+        &iri_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    _ = validate_data(
+        json!("../relative/path"),
+        &iri_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("https://example.com/<path>"),
+        &iri_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+}
+
+#[tokio::test]
+async fn iri_reference() {
+    let iri_reference_type = serde_json::to_string(&json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://localhost:4000/@alice/types/data-type/iri-reference/v/1",
+        "title": "Iri Reference",
+        "type": "string",
+        "format": "iri-reference",
+    }))
+    .expect("failed to serialize iri reference type");
+
+    validate_data(
+        json!("https://example.com/\u{fc}mlaut"),
+        &iri_reference_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("../\u{fc}mlaut"),
+        &iri_reference_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("#fragment"),
+        &iri_reference_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    _ = validate_data(
+        json!("path with space"),
+        &iri_reference_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("{}"),
+        &iri_reference_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+}
+
+#[tokio::test]
+async fn uri_template() {
+    let uri_template_type = serde_json::to_string(&json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://localhost:4000/@alice/types/data-type/uri-template/v/1",
+        "title": "Uri Template",
+        "type": "string",
+        "format": "uri-template",
+    }))
+    .expect("failed to serialize uri template type");
+
+    validate_data(
+        json!("https://example.com/{id}"),
+        &uri_template_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("/search{?query,page}"),
+        &uri_template_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("{+path:6}/here"),
+        &uri_template_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("{/list*}"),
+        &uri_template_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("plain"),
+        &uri_template_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    _ = validate_data(
+        json!("https://example.com/{id"),
+        &uri_template_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("{id}}"),
+        &uri_template_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("{na me}"),
+        &uri_template_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("{var:0}"),
+        &uri_template_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("{var:10000}"),
+        &uri_template_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+}
+
+#[tokio::test]
+async fn json_pointer() {
+    let json_pointer_type = serde_json::to_string(&json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://localhost:4000/@alice/types/data-type/json-pointer/v/1",
+        "title": "Json Pointer",
+        "type": "string",
+        "format": "json-pointer",
+    }))
+    .expect("failed to serialize json pointer type");
+
+    validate_data(
+        json!(""),
+        &json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("/"),
+        &json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("/foo/0"),
+        &json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("/a~1b/m~0n"),
+        &json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    _ = validate_data(
+        json!("foo"),
+        &json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("/foo~"),
+        &json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("/foo~2"),
+        &json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+}
+
+#[tokio::test]
+async fn relative_json_pointer() {
+    let relative_json_pointer_type = serde_json::to_string(&json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://localhost:4000/@alice/types/data-type/relative-json-pointer/v/1",
+        "title": "Relative Json Pointer",
+        "type": "string",
+        "format": "relative-json-pointer",
+    }))
+    .expect("failed to serialize relative json pointer type");
+
+    validate_data(
+        json!("0"),
+        &relative_json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("1/foo"),
+        &relative_json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("2#"),
+        &relative_json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("0+1/bar"),
+        &relative_json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("3-2"),
+        &relative_json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    _ = validate_data(
+        json!(""),
+        &relative_json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("/foo"),
+        &relative_json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("01/foo"),
+        &relative_json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("1foo"),
+        &relative_json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("1~0"),
+        &relative_json_pointer_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+}
+
+#[tokio::test]
+async fn idn_hostname() {
+    let idn_hostname_type = serde_json::to_string(&json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://localhost:4000/@alice/types/data-type/idn-hostname/v/1",
+        "title": "Idn Hostname",
+        "type": "string",
+        "format": "idn-hostname",
+    }))
+    .expect("failed to serialize idn hostname type");
+
+    validate_data(
+        json!("example.com"),
+        &idn_hostname_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("b\u{fc}cher.example"),
+        &idn_hostname_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    _ = validate_data(
+        json!("localhost:3455"),
+        &idn_hostname_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("::1"),
+        &idn_hostname_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+}
+
+#[tokio::test]
+async fn idn_email() {
+    let idn_email_type = serde_json::to_string(&json!({
+        "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+        "kind": "dataType",
+        "$id": "https://localhost:4000/@alice/types/data-type/idn-email/v/1",
+        "title": "Idn Email",
+        "type": "string",
+        "format": "idn-email",
+    }))
+    .expect("failed to serialize idn email type");
+
+    validate_data(
+        json!("alice@example.com"),
+        &idn_email_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    validate_data(
+        json!("\u{7528}\u{6237}@example.com"),
+        &idn_email_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect("validation failed");
+
+    _ = validate_data(
+        json!("alice"),
+        &idn_email_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+
+    _ = validate_data(
+        json!("alice@"),
+        &idn_email_type,
+        [graph_test_data::data_type::VALUE_V1],
+        ValidateEntityComponents::full(),
+    )
+    .await
+    .expect_err("validation succeeded");
+}
+
 #[tokio::test]
 async fn uuid() {
     let uuid_type = serde_json::to_string(&json!({
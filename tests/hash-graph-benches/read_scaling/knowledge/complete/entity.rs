@@ -216,6 +216,7 @@ pub fn bench_get_entity_by_id<A: AuthorizationApi>(
                 .get_entity_subgraph(actor_id, GetEntitySubgraphParams {
                     filter: Filter::for_entity_by_entity_id(entity_record_id.entity_id),
                     graph_resolve_depths,
+                    traversal_paths: Vec::new(),
                     temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
                         pinned: PinnedTemporalAxisUnresolved::new(None),
                         variable: VariableTemporalAxisUnresolved::new(
@@ -226,9 +227,11 @@ pub fn bench_get_entity_by_id<A: AuthorizationApi>(
                     sorting: EntityQuerySorting {
                         paths: Vec::new(),
                         cursor: None,
+                        relevance: None,
                     },
                     limit: None,
                     conversions: Vec::new(),
+                    projection: None,
                     include_count: false,
                     include_drafts: false,
                     include_web_ids: false,
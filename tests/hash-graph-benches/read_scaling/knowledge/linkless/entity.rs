@@ -167,9 +167,11 @@ pub fn bench_get_entity_by_id<A: AuthorizationApi>(
                     sorting: EntityQuerySorting {
                         paths: Vec::new(),
                         cursor: None,
+                        relevance: None,
                     },
                     limit: None,
                     conversions: Vec::new(),
+                    projection: None,
                     include_count: false,
                     include_drafts: false,
                     include_web_ids: false,
@@ -58,9 +58,11 @@ pub fn bench_get_entity_by_id<A: AuthorizationApi>(
                     sorting: EntityQuerySorting {
                         paths: Vec::new(),
                         cursor: None,
+                        relevance: None,
                     },
                     limit: None,
                     conversions: Vec::new(),
+                    projection: None,
                     include_count: false,
                     include_drafts: false,
                     include_web_ids: false,
@@ -101,6 +103,7 @@ pub fn bench_get_entities_by_property<A: AuthorizationApi>(
             .get_entity_subgraph(actor_id, GetEntitySubgraphParams {
                 filter,
                 graph_resolve_depths,
+                traversal_paths: Vec::new(),
                 temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(
@@ -111,9 +114,11 @@ pub fn bench_get_entities_by_property<A: AuthorizationApi>(
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
                     cursor: None,
+                    relevance: None,
                 },
                 limit: None,
                 conversions: Vec::new(),
+                projection: None,
                 include_count: false,
                 include_drafts: false,
                 include_web_ids: false,
@@ -156,6 +161,7 @@ pub fn bench_get_link_by_target_by_property<A: AuthorizationApi>(
             .get_entity_subgraph(actor_id, GetEntitySubgraphParams {
                 filter,
                 graph_resolve_depths,
+                traversal_paths: Vec::new(),
                 temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(
@@ -166,9 +172,11 @@ pub fn bench_get_link_by_target_by_property<A: AuthorizationApi>(
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
                     cursor: None,
+                    relevance: None,
                 },
                 limit: None,
                 conversions: Vec::new(),
+                projection: None,
                 include_count: false,
                 include_drafts: false,
                 include_web_ids: false,
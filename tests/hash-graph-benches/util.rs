@@ -16,8 +16,9 @@ use graph::{
         DatabaseType, EntityTypeStore, PostgresStore, PostgresStorePool, PropertyTypeStore,
         StoreMigration, StorePool,
         ontology::{
-            CreateDataTypeParams, CreateEntityTypeParams, CreatePropertyTypeParams,
-            UpdateDataTypesParams, UpdateEntityTypesParams, UpdatePropertyTypesParams,
+            BreakingChangeBehavior, CreateDataTypeParams, CreateEntityTypeParams,
+            CreatePropertyTypeParams, UpdateDataTypesParams, UpdateEntityTypesParams,
+            UpdatePropertyTypesParams,
         },
     },
 };
@@ -321,6 +322,7 @@ pub async fn seed<D, P, E, C, A>(
                                 level: 0,
                             }],
                             provenance: ProvidedOntologyEditionProvenance::default(),
+                            breaking_changes: BreakingChangeBehavior::Allow,
                             conversions: HashMap::new(),
                         })
                         .await
@@ -362,6 +364,7 @@ pub async fn seed<D, P, E, C, A>(
                                 level: 0,
                             }],
                             provenance: ProvidedOntologyEditionProvenance::default(),
+                            breaking_changes: BreakingChangeBehavior::Allow,
                         })
                         .await
                         .expect("failed to update property type");
@@ -412,6 +415,7 @@ pub async fn seed<D, P, E, C, A>(
                                 },
                             ],
                             provenance: ProvidedOntologyEditionProvenance::default(),
+                            breaking_changes: BreakingChangeBehavior::Allow,
                         })
                         .await
                         .expect("failed to update entity type");
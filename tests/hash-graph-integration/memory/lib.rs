@@ -0,0 +1,106 @@
+#![feature(assert_matches)]
+#![expect(
+    clippy::missing_panics_doc,
+    clippy::missing_errors_doc,
+    clippy::unwrap_used
+)]
+#![expect(
+    clippy::significant_drop_tightening,
+    reason = "This should be enabled but it's currently too noisy"
+)]
+
+extern crate alloc;
+extern crate core;
+
+#[path = "../postgres/api.rs"]
+mod api;
+#[path = "../postgres/data_type.rs"]
+mod data_type;
+#[path = "../postgres/drafts.rs"]
+mod drafts;
+#[path = "../postgres/entity.rs"]
+mod entity;
+#[path = "../postgres/entity_type.rs"]
+mod entity_type;
+#[path = "../postgres/interconnected_graph.rs"]
+mod interconnected_graph;
+#[path = "../postgres/links.rs"]
+mod links;
+#[path = "../postgres/multi_type.rs"]
+mod multi_type;
+#[path = "../postgres/partial_updates.rs"]
+mod partial_updates;
+#[path = "../postgres/property_metadata.rs"]
+mod property_metadata;
+#[path = "../postgres/property_type.rs"]
+mod property_type;
+#[path = "../postgres/sorting.rs"]
+mod sorting;
+
+use authorization::{AuthorizationApi, NoAuthorization};
+use error_stack::Result;
+use graph::{
+    Environment, load_env,
+    store::{InsertionError, MemoryStore, MemoryStorePool, StorePool},
+};
+use hash_tracing::logging::env_filter;
+
+pub struct DatabaseTestWrapper<A: AuthorizationApi> {
+    pool: MemoryStorePool,
+    authorization_api: A,
+}
+
+// The lifetime is unused as the store is not bound to a transaction, it is kept so the tests can
+// name the API the same way for every store.
+pub type DatabaseApi<'pool, A> = api::DatabaseApi<MemoryStore<A>>;
+
+pub fn init_logging() {
+    // It's likely that the initialization failed due to a previous initialization attempt. In this
+    // case, we can ignore the error.
+    let _: core::result::Result<_, _> = tracing_subscriber::fmt()
+        .with_ansi(true)
+        .with_env_filter(env_filter(None))
+        .with_file(true)
+        .with_line_number(true)
+        .with_test_writer()
+        .try_init();
+}
+
+impl DatabaseTestWrapper<NoAuthorization> {
+    pub async fn new() -> Self {
+        load_env(Environment::Test);
+        init_logging();
+
+        Self {
+            pool: MemoryStorePool::new(),
+            authorization_api: NoAuthorization,
+        }
+    }
+}
+
+impl<A: AuthorizationApi> DatabaseTestWrapper<A> {
+    pub async fn seed<D, P, E>(
+        &mut self,
+        data_types: D,
+        property_types: P,
+        entity_types: E,
+    ) -> Result<DatabaseApi<'_, &mut A>, InsertionError>
+    where
+        D: IntoIterator<Item = &'static str, IntoIter: Send> + Send,
+        P: IntoIterator<Item = &'static str, IntoIter: Send> + Send,
+        E: IntoIterator<Item = &'static str, IntoIter: Send> + Send,
+    {
+        let store = self
+            .pool
+            .acquire(&mut self.authorization_api, None)
+            .await
+            .expect("could not acquire a store");
+
+        DatabaseApi::seed(store, data_types, property_types, entity_types).await
+    }
+}
+
+#[tokio::test]
+async fn can_create_store() {
+    DatabaseTestWrapper::new().await;
+}
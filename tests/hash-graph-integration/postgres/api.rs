@@ -0,0 +1,754 @@
+use std::collections::HashMap;
+
+use authorization::{
+    schema::{
+        DataTypeRelationAndSubject, DataTypeViewerSubject, EntityRelationAndSubject,
+        EntityTypeInstantiatorSubject, EntityTypeRelationAndSubject, EntityTypeSetting,
+        EntityTypeSettingSubject, EntityTypeViewerSubject, PropertyTypeRelationAndSubject,
+        PropertyTypeSetting, PropertyTypeSettingSubject, PropertyTypeViewerSubject,
+        WebOwnerSubject,
+    },
+    zanzibar::Consistency,
+};
+use error_stack::Result;
+use futures::Stream;
+use graph::store::{
+    ChangeFeedStore, DataTypeStore, EntityStore, EntityTypeStore, InsertionError,
+    PropertyTypeStore, QueryError, UpdateError,
+    change_feed::{ReadChangesParams, ReadChangesResponse},
+    knowledge::{
+        AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+        CreateEntityParams, FindEntityPathsParams, FindEntityPathsResponse, GetEntitiesParams,
+        GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse, PatchEntityParams,
+        UpdateEntityEmbeddingsParams, ValidateEntityError, ValidateEntityParams,
+    },
+    ontology::{
+        ArchiveDataTypeParams, ArchiveEntityTypeParams, ArchivePropertyTypeParams,
+        CountDataTypesParams, CountEntityTypesParams, CountPropertyTypesParams,
+        CreateDataTypeParams, CreateEntityTypeParams, CreatePropertyTypeParams,
+        GetDataTypeSubgraphParams, GetDataTypeSubgraphResponse, GetDataTypesParams,
+        GetDataTypesResponse, GetEntityTypeSubgraphParams, GetEntityTypeSubgraphResponse,
+        GetEntityTypesParams, GetEntityTypesResponse, GetPropertyTypeSubgraphParams,
+        GetPropertyTypeSubgraphResponse, GetPropertyTypesParams, GetPropertyTypesResponse,
+        UnarchiveDataTypeParams, UnarchiveEntityTypeParams, UnarchivePropertyTypeParams,
+        UpdateDataTypeEmbeddingParams, UpdateDataTypesParams, UpdateDataTypesResponse,
+        UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams, UpdateEntityTypesResponse,
+        UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams, UpdatePropertyTypesResponse,
+    },
+};
+use graph_types::{
+    account::AccountId,
+    knowledge::entity::{Entity, EntityId},
+    ontology::{
+        DataTypeMetadata, EntityTypeMetadata, OntologyTemporalMetadata,
+        OntologyTypeClassificationMetadata, PropertyTypeMetadata,
+        ProvidedOntologyEditionProvenance,
+    },
+    owned_by_id::OwnedById,
+};
+use hash_graph_store::{
+    ConflictBehavior,
+    account::{AccountStore, InsertAccountIdParams, InsertWebIdParams},
+};
+use temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
+use type_system::schema::{DataType, EntityType, PropertyType};
+use uuid::Uuid;
+
+/// Wraps a store to check the consistency of its results in every test.
+///
+/// Queries which return a count are compared against the corresponding count query, all other
+/// operations are passed through to the store.
+pub struct DatabaseApi<S> {
+    store: S,
+    pub account_id: AccountId,
+}
+
+const fn data_type_relationships() -> [DataTypeRelationAndSubject; 1] {
+    [DataTypeRelationAndSubject::Viewer {
+        subject: DataTypeViewerSubject::Public,
+        level: 0,
+    }]
+}
+
+const fn property_type_relationships() -> [PropertyTypeRelationAndSubject; 2] {
+    [
+        PropertyTypeRelationAndSubject::Setting {
+            subject: PropertyTypeSettingSubject::Setting {
+                id: PropertyTypeSetting::UpdateFromWeb,
+            },
+            level: 0,
+        },
+        PropertyTypeRelationAndSubject::Viewer {
+            subject: PropertyTypeViewerSubject::Public,
+            level: 0,
+        },
+    ]
+}
+
+const fn entity_type_relationships() -> [EntityTypeRelationAndSubject; 3] {
+    [
+        EntityTypeRelationAndSubject::Setting {
+            subject: EntityTypeSettingSubject::Setting {
+                id: EntityTypeSetting::UpdateFromWeb,
+            },
+            level: 0,
+        },
+        EntityTypeRelationAndSubject::Viewer {
+            subject: EntityTypeViewerSubject::Public,
+            level: 0,
+        },
+        EntityTypeRelationAndSubject::Instantiator {
+            subject: EntityTypeInstantiatorSubject::Public,
+            level: 0,
+        },
+    ]
+}
+
+impl<S> DatabaseApi<S>
+where
+    S: AccountStore + DataTypeStore + PropertyTypeStore + EntityTypeStore + Send,
+{
+    /// Creates a new account with its own web in `store` and seeds the store with the given types
+    /// owned by that web.
+    pub async fn seed<D, P, E>(
+        mut store: S,
+        data_types: D,
+        property_types: P,
+        entity_types: E,
+    ) -> Result<Self, InsertionError>
+    where
+        D: IntoIterator<Item = &'static str, IntoIter: Send> + Send,
+        P: IntoIterator<Item = &'static str, IntoIter: Send> + Send,
+        E: IntoIterator<Item = &'static str, IntoIter: Send> + Send,
+    {
+        let account_id = AccountId::new(Uuid::new_v4());
+        store
+            .insert_account_id(account_id, InsertAccountIdParams { account_id })
+            .await
+            .expect("could not insert account id");
+        store
+            .insert_web_id(account_id, InsertWebIdParams {
+                owned_by_id: OwnedById::new(account_id.into_uuid()),
+                owner: WebOwnerSubject::Account { id: account_id },
+            })
+            .await
+            .expect("could not create web id");
+
+        store
+            .create_data_types(
+                account_id,
+                data_types.into_iter().map(|data_type_str| {
+                    let schema: DataType = serde_json::from_str(data_type_str)
+                        .expect("could not parse data type representation");
+                    CreateDataTypeParams {
+                        schema,
+                        classification: OntologyTypeClassificationMetadata::Owned {
+                            owned_by_id: OwnedById::new(account_id.into_uuid()),
+                        },
+                        relationships: data_type_relationships(),
+                        conflict_behavior: ConflictBehavior::Skip,
+                        provenance: ProvidedOntologyEditionProvenance::default(),
+                        conversions: HashMap::new(),
+                    }
+                }),
+            )
+            .await?;
+
+        store
+            .create_property_types(
+                account_id,
+                property_types.into_iter().map(|property_type_str| {
+                    let schema: PropertyType = serde_json::from_str(property_type_str)
+                        .expect("could not property data type representation");
+                    CreatePropertyTypeParams {
+                        schema,
+                        classification: OntologyTypeClassificationMetadata::Owned {
+                            owned_by_id: OwnedById::new(account_id.into_uuid()),
+                        },
+                        relationships: property_type_relationships(),
+                        conflict_behavior: ConflictBehavior::Skip,
+                        provenance: ProvidedOntologyEditionProvenance::default(),
+                    }
+                }),
+            )
+            .await?;
+
+        store
+            .create_entity_types(
+                account_id,
+                entity_types.into_iter().map(|entity_type_str| {
+                    let schema: EntityType = serde_json::from_str(entity_type_str)
+                        .expect("could not entity data type representation");
+                    CreateEntityTypeParams {
+                        schema,
+                        classification: OntologyTypeClassificationMetadata::Owned {
+                            owned_by_id: OwnedById::new(account_id.into_uuid()),
+                        },
+                        label_property: None,
+                        icon: None,
+                        relationships: entity_type_relationships(),
+                        conflict_behavior: ConflictBehavior::Skip,
+                        provenance: ProvidedOntologyEditionProvenance::default(),
+                    }
+                }),
+            )
+            .await?;
+
+        Ok(Self { store, account_id })
+    }
+}
+
+impl<S> ChangeFeedStore for DatabaseApi<S>
+where
+    S: ChangeFeedStore + Send + Sync,
+{
+    async fn read_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadChangesParams,
+    ) -> Result<ReadChangesResponse, QueryError> {
+        self.store.read_changes(actor_id, params).await
+    }
+}
+
+impl<S> DataTypeStore for DatabaseApi<S>
+where
+    S: DataTypeStore + Send + Sync,
+{
+    async fn create_data_types<P, R>(
+        &mut self,
+        actor_id: AccountId,
+        params: P,
+    ) -> Result<Vec<DataTypeMetadata>, InsertionError>
+    where
+        P: IntoIterator<Item = CreateDataTypeParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = DataTypeRelationAndSubject> + Send + Sync,
+    {
+        self.store.create_data_types(actor_id, params).await
+    }
+
+    async fn count_data_types(
+        &self,
+        actor_id: AccountId,
+        params: CountDataTypesParams<'_>,
+    ) -> Result<usize, QueryError> {
+        self.store.count_data_types(actor_id, params).await
+    }
+
+    async fn get_data_types(
+        &self,
+        actor_id: AccountId,
+        mut params: GetDataTypesParams<'_>,
+    ) -> Result<GetDataTypesResponse, QueryError> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_data_types(actor_id, CountDataTypesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self.store.get_data_types(actor_id, params).await?;
+
+        // We can ensure that `count_data_types` and `get_data_types` return the same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of data types returned
+        if !has_limit {
+            assert_eq!(count, response.data_types.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn get_data_type_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetDataTypeSubgraphParams<'_>,
+    ) -> Result<GetDataTypeSubgraphResponse, QueryError> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_data_types(actor_id, CountDataTypesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self.store.get_data_type_subgraph(actor_id, params).await?;
+
+        // We can ensure that `count_data_types` and `get_data_type_subgraph` return the same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of data types returned
+        if !has_limit {
+            assert_eq!(count, response.subgraph.roots.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn update_data_type<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateDataTypesParams<R>,
+    ) -> Result<UpdateDataTypesResponse, UpdateError>
+    where
+        R: IntoIterator<Item = DataTypeRelationAndSubject> + Send + Sync,
+    {
+        self.store.update_data_type(actor_id, params).await
+    }
+
+    async fn archive_data_type(
+        &mut self,
+        actor_id: AccountId,
+        params: ArchiveDataTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        self.store.archive_data_type(actor_id, params).await
+    }
+
+    async fn unarchive_data_type(
+        &mut self,
+        actor_id: AccountId,
+        params: UnarchiveDataTypeParams,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        self.store.unarchive_data_type(actor_id, params).await
+    }
+
+    async fn update_data_type_embeddings(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateDataTypeEmbeddingParams<'_>,
+    ) -> Result<(), UpdateError> {
+        self.store
+            .update_data_type_embeddings(actor_id, params)
+            .await
+    }
+
+    async fn reindex_cache(&mut self) -> Result<(), UpdateError> {
+        self.store.reindex_cache().await
+    }
+}
+
+impl<S> PropertyTypeStore for DatabaseApi<S>
+where
+    S: PropertyTypeStore + Send + Sync,
+{
+    async fn create_property_types<P, R>(
+        &mut self,
+        actor_id: AccountId,
+        params: P,
+    ) -> Result<Vec<PropertyTypeMetadata>, InsertionError>
+    where
+        P: IntoIterator<Item = CreatePropertyTypeParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = PropertyTypeRelationAndSubject> + Send + Sync,
+    {
+        self.store.create_property_types(actor_id, params).await
+    }
+
+    async fn count_property_types(
+        &self,
+        actor_id: AccountId,
+        params: CountPropertyTypesParams<'_>,
+    ) -> Result<usize, QueryError> {
+        self.store.count_property_types(actor_id, params).await
+    }
+
+    async fn get_property_types(
+        &self,
+        actor_id: AccountId,
+        mut params: GetPropertyTypesParams<'_>,
+    ) -> Result<GetPropertyTypesResponse, QueryError> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_property_types(actor_id, CountPropertyTypesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self.store.get_property_types(actor_id, params).await?;
+
+        // We can ensure that `count_property_types` and `get_property_types` return the same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of property types
+        // returned
+        if !has_limit {
+            assert_eq!(count, response.property_types.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn get_property_type_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetPropertyTypeSubgraphParams<'_>,
+    ) -> Result<GetPropertyTypeSubgraphResponse, QueryError> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_property_types(actor_id, CountPropertyTypesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self
+            .store
+            .get_property_type_subgraph(actor_id, params)
+            .await?;
+
+        // We can ensure that `count_property_types` and `get_property_type_subgraph` return the
+        // same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of property types
+        // returned
+        if !has_limit {
+            assert_eq!(count, response.subgraph.roots.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn update_property_type<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdatePropertyTypesParams<R>,
+    ) -> Result<UpdatePropertyTypesResponse, UpdateError>
+    where
+        R: IntoIterator<Item = PropertyTypeRelationAndSubject> + Send + Sync,
+    {
+        self.store.update_property_type(actor_id, params).await
+    }
+
+    async fn archive_property_type(
+        &mut self,
+        actor_id: AccountId,
+        params: ArchivePropertyTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        self.store.archive_property_type(actor_id, params).await
+    }
+
+    async fn unarchive_property_type(
+        &mut self,
+        actor_id: AccountId,
+        params: UnarchivePropertyTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        self.store.unarchive_property_type(actor_id, params).await
+    }
+
+    async fn update_property_type_embeddings(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdatePropertyTypeEmbeddingParams<'_>,
+    ) -> Result<(), UpdateError> {
+        self.store
+            .update_property_type_embeddings(actor_id, params)
+            .await
+    }
+}
+
+impl<S> EntityTypeStore for DatabaseApi<S>
+where
+    S: EntityTypeStore + Send + Sync,
+{
+    async fn create_entity_types<P, R>(
+        &mut self,
+        actor_id: AccountId,
+        params: P,
+    ) -> Result<Vec<EntityTypeMetadata>, InsertionError>
+    where
+        P: IntoIterator<Item = CreateEntityTypeParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = EntityTypeRelationAndSubject> + Send + Sync,
+    {
+        self.store.create_entity_types(actor_id, params).await
+    }
+
+    async fn count_entity_types(
+        &self,
+        actor_id: AccountId,
+        params: CountEntityTypesParams<'_>,
+    ) -> Result<usize, QueryError> {
+        self.store.count_entity_types(actor_id, params).await
+    }
+
+    async fn get_entity_types(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntityTypesParams<'_>,
+    ) -> Result<GetEntityTypesResponse, QueryError> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_entity_types(actor_id, CountEntityTypesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self.store.get_entity_types(actor_id, params).await?;
+
+        // We can ensure that `count_entity_types` and `get_entity_types` return the same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of entity types returned
+        if !has_limit {
+            assert_eq!(count, response.entity_types.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn get_entity_type_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntityTypeSubgraphParams<'_>,
+    ) -> Result<GetEntityTypeSubgraphResponse, QueryError> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_entity_types(actor_id, CountEntityTypesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self
+            .store
+            .get_entity_type_subgraph(actor_id, params)
+            .await?;
+
+        // We can ensure that `count_entity_types` and `get_entity_type_subgraph` return the same
+        // count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of entity types returned
+        if !has_limit {
+            assert_eq!(count, response.subgraph.roots.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn update_entity_type<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateEntityTypesParams<R>,
+    ) -> Result<UpdateEntityTypesResponse, UpdateError>
+    where
+        R: IntoIterator<Item = EntityTypeRelationAndSubject> + Send + Sync,
+    {
+        self.store.update_entity_type(actor_id, params).await
+    }
+
+    async fn archive_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: ArchiveEntityTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        self.store.archive_entity_type(actor_id, params).await
+    }
+
+    async fn unarchive_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: UnarchiveEntityTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, UpdateError> {
+        self.store.unarchive_entity_type(actor_id, params).await
+    }
+
+    async fn update_entity_type_embeddings(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateEntityTypeEmbeddingParams<'_>,
+    ) -> Result<(), UpdateError> {
+        self.store
+            .update_entity_type_embeddings(actor_id, params)
+            .await
+    }
+}
+
+impl<S> EntityStore for DatabaseApi<S>
+where
+    S: EntityStore + Send + Sync,
+{
+    async fn create_entities<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: Vec<CreateEntityParams<R>>,
+    ) -> Result<Vec<Entity>, InsertionError>
+    where
+        R: IntoIterator<Item = EntityRelationAndSubject> + Send,
+    {
+        self.store.create_entities(actor_id, params).await
+    }
+
+    async fn validate_entities(
+        &self,
+        actor_id: AccountId,
+        consistency: Consistency<'_>,
+        params: Vec<ValidateEntityParams<'_>>,
+    ) -> Result<(), ValidateEntityError> {
+        self.store
+            .validate_entities(actor_id, consistency, params)
+            .await
+    }
+
+    async fn get_entities(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntitiesParams<'_>,
+    ) -> Result<GetEntitiesResponse<'static>, QueryError> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_entities(actor_id, CountEntitiesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self.store.get_entities(actor_id, params).await?;
+
+        // We can ensure that `count_entities` and `get_entity` return the same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of entities returned
+        if !has_limit {
+            assert_eq!(count, response.entities.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn stream_entities<'s>(
+        &'s self,
+        actor_id: AccountId,
+        params: GetEntitiesParams<'s>,
+    ) -> Result<impl Stream<Item = Result<Entity, QueryError>> + Send + 's, QueryError> {
+        self.store.stream_entities(actor_id, params).await
+    }
+
+    async fn get_entity_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntitySubgraphParams<'_>,
+    ) -> Result<GetEntitySubgraphResponse<'static>, QueryError> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_entities(actor_id, CountEntitiesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+        let mut response = self.store.get_entity_subgraph(actor_id, params).await?;
+
+        // We can ensure that `count_entities` and `get_entity` return the same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of entities returned
+        if !has_limit {
+            assert_eq!(count, response.subgraph.roots.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn count_entities(
+        &self,
+        actor_id: AccountId,
+        params: CountEntitiesParams<'_>,
+    ) -> Result<usize, QueryError> {
+        self.store.count_entities(actor_id, params).await
+    }
+
+    async fn aggregate_entities(
+        &self,
+        actor_id: AccountId,
+        params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, QueryError> {
+        self.store.aggregate_entities(actor_id, params).await
+    }
+
+    async fn find_entity_paths(
+        &self,
+        actor_id: AccountId,
+        params: FindEntityPathsParams<'_>,
+    ) -> Result<FindEntityPathsResponse, QueryError> {
+        self.store.find_entity_paths(actor_id, params).await
+    }
+
+    async fn get_entity_by_id(
+        &self,
+        actor_id: AccountId,
+        entity_id: EntityId,
+        transaction_time: Option<Timestamp<TransactionTime>>,
+        decision_time: Option<Timestamp<DecisionTime>>,
+    ) -> Result<Entity, QueryError> {
+        self.store
+            .get_entity_by_id(actor_id, entity_id, transaction_time, decision_time)
+            .await
+    }
+
+    async fn patch_entity(
+        &mut self,
+        actor_id: AccountId,
+        params: PatchEntityParams,
+    ) -> Result<Entity, UpdateError> {
+        self.store.patch_entity(actor_id, params).await
+    }
+
+    async fn update_entity_embeddings(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateEntityEmbeddingsParams<'_>,
+    ) -> Result<(), UpdateError> {
+        self.store.update_entity_embeddings(actor_id, params).await
+    }
+}
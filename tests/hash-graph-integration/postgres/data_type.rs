@@ -5,7 +5,9 @@ use graph::store::{
     BaseUrlAlreadyExists, DataTypeStore, EntityStore,
     error::{OntologyTypeIsNotOwned, OntologyVersionDoesNotExist, VersionedUrlAlreadyExists},
     knowledge::CreateEntityParams,
-    ontology::{CreateDataTypeParams, GetDataTypesParams, UpdateDataTypesParams},
+    ontology::{
+        BreakingChangeBehavior, CreateDataTypeParams, GetDataTypesParams, UpdateDataTypesParams,
+    },
 };
 use graph_types::{
     knowledge::{
@@ -252,6 +254,7 @@ async fn inheritance() {
         schema: centimeter_dt_v2.clone(),
         relationships: data_type_relationships(),
         provenance: ProvidedOntologyEditionProvenance::default(),
+        breaking_changes: BreakingChangeBehavior::Allow,
         conversions: HashMap::new(),
     })
     .await
@@ -405,6 +408,7 @@ async fn update() {
         schema: object_dt_v2.clone(),
         relationships: data_type_relationships(),
         provenance: ProvidedOntologyEditionProvenance::default(),
+        breaking_changes: BreakingChangeBehavior::Allow,
         conversions: HashMap::new(),
     })
     .await
@@ -569,6 +573,7 @@ async fn wrong_update_order() {
             schema: object_dt_v1.clone(),
             relationships: data_type_relationships(),
             provenance: ProvidedOntologyEditionProvenance::default(),
+            breaking_changes: BreakingChangeBehavior::Allow,
             conversions: HashMap::new(),
         })
         .await
@@ -596,6 +601,7 @@ async fn wrong_update_order() {
             schema: object_dt_v1.clone(),
             relationships: data_type_relationships(),
             provenance: ProvidedOntologyEditionProvenance::default(),
+            breaking_changes: BreakingChangeBehavior::Allow,
             conversions: HashMap::new(),
         })
         .await
@@ -609,6 +615,7 @@ async fn wrong_update_order() {
         schema: object_dt_v2.clone(),
         relationships: data_type_relationships(),
         provenance: ProvidedOntologyEditionProvenance::default(),
+        breaking_changes: BreakingChangeBehavior::Allow,
         conversions: HashMap::new(),
     })
     .await
@@ -619,6 +626,7 @@ async fn wrong_update_order() {
             schema: object_dt_v2.clone(),
             relationships: data_type_relationships(),
             provenance: ProvidedOntologyEditionProvenance::default(),
+            breaking_changes: BreakingChangeBehavior::Allow,
             conversions: HashMap::new(),
         })
         .await
@@ -661,6 +669,7 @@ async fn update_external_with_owned() {
             schema: object_dt_v2.clone(),
             relationships: data_type_relationships(),
             provenance: ProvidedOntologyEditionProvenance::default(),
+            breaking_changes: BreakingChangeBehavior::Allow,
             conversions: HashMap::new(),
         })
         .await
@@ -688,6 +697,7 @@ async fn update_external_with_owned() {
             schema: object_dt_v2,
             relationships: data_type_relationships(),
             provenance: ProvidedOntologyEditionProvenance::default(),
+            breaking_changes: BreakingChangeBehavior::Allow,
             conversions: HashMap::new(),
         })
         .await
@@ -127,6 +127,7 @@ async fn initial_draft() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: PropertyPath::default(),
                 property: PropertyWithMetadata::from_parts(Property::Object(bob()), None)
@@ -175,6 +176,7 @@ async fn initial_draft() {
     let updated_live_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: updated_entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: PropertyPath::default(),
                 property: PropertyWithMetadata::from_parts(Property::Object(charles()), None)
@@ -296,6 +298,7 @@ async fn no_initial_draft() {
         let updated_entity = api
             .patch_entity(api.account_id, PatchEntityParams {
                 entity_id: entity.metadata.record_id.entity_id,
+                expected_edition_id: None,
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     property: PropertyWithMetadata::from_parts(Property::Object(bob()), None)
@@ -349,6 +352,7 @@ async fn no_initial_draft() {
         let updated_live_entity = api
             .patch_entity(api.account_id, PatchEntityParams {
                 entity_id: updated_entity.metadata.record_id.entity_id,
+                expected_edition_id: None,
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     property: PropertyWithMetadata::from_parts(Property::Object(charles()), None)
@@ -447,6 +451,7 @@ async fn multiple_drafts() {
         let updated_entity = api
             .patch_entity(api.account_id, PatchEntityParams {
                 entity_id: entity.metadata.record_id.entity_id,
+                expected_edition_id: None,
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     property: PropertyWithMetadata::from_parts(Property::Object(bob()), None)
@@ -503,6 +508,7 @@ async fn multiple_drafts() {
         let updated_live_entity = api
             .patch_entity(api.account_id, PatchEntityParams {
                 entity_id: draft,
+                expected_edition_id: None,
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     property: PropertyWithMetadata::from_parts(Property::Object(charles()), None)
@@ -1,8 +1,15 @@
 use std::collections::HashSet;
 
+use futures::TryStreamExt;
 use graph::store::{
-    EntityQuerySorting, EntityStore,
-    knowledge::{CountEntitiesParams, CreateEntityParams, GetEntitiesParams, PatchEntityParams},
+    ChangeFeedStore, EntityQuerySorting, EntityStore, EntityTypeStore,
+    change_feed::{ChangeKind, ChangedRecord, ReadChangesParams},
+    error::EntityEditionConflict,
+    knowledge::{
+        CountEntitiesParams, CreateEntityParams, EntityProjection, GetEntitiesParams,
+        MigrateEntitiesParams, PatchEntityParams, PropertyMapping,
+    },
+    ontology::{BreakingChangeBehavior, UpdateEntityTypesParams},
 };
 use graph_test_data::{data_type, entity, entity_type, property_type};
 use graph_types::{
@@ -13,6 +20,7 @@ use graph_types::{
             PropertyWithMetadataObject,
         },
     },
+    ontology::ProvidedOntologyEditionProvenance,
     owned_by_id::OwnedById,
 };
 use hash_graph_store::{
@@ -22,9 +30,12 @@ use hash_graph_store::{
     },
 };
 use temporal_versioning::{ClosedTemporalBound, LimitedTemporalBound, TemporalBound};
-use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+use type_system::{
+    schema::EntityType,
+    url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+};
 
-use crate::DatabaseTestWrapper;
+use crate::{DatabaseTestWrapper, entity_type_relationships};
 
 #[tokio::test]
 async fn insert() {
@@ -90,9 +101,11 @@ async fn insert() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: true,
             include_drafts: false,
             include_web_ids: false,
@@ -108,6 +121,187 @@ async fn insert() {
     assert_eq!(entities[0].properties, person);
 }
 
+#[tokio::test]
+async fn projection() {
+    let person: PropertyObject =
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::NAME_V1, property_type::AGE_V1],
+            [entity_type::PERSON_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let entity = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }]),
+            properties: PropertyWithMetadataObject::from_parts(person.clone(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+
+    let name_path: PropertyPath<'static> =
+        serde_json::from_str(r#"["https://blockprotocol.org/@alice/types/property-type/name/"]"#)
+            .expect("could not parse property path");
+    let age_path: PropertyPath<'static> =
+        serde_json::from_str(r#"["https://blockprotocol.org/@alice/types/property-type/age/"]"#)
+            .expect("could not parse property path");
+
+    for (paths, expected_properties) in [
+        (vec![name_path.clone()], person.clone()),
+        (vec![name_path, age_path.clone()], person),
+        (vec![age_path], PropertyObject::empty()),
+        (Vec::new(), PropertyObject::empty()),
+    ] {
+        let entities = api
+            .get_entities(api.account_id, GetEntitiesParams {
+                filter: Filter::for_entity_by_entity_id(entity.metadata.record_id.entity_id),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(
+                        Some(TemporalBound::Unbounded),
+                        None,
+                    ),
+                },
+                sorting: EntityQuerySorting {
+                    paths: Vec::new(),
+                    cursor: None,
+                    relevance: None,
+                },
+                limit: None,
+                conversions: Vec::new(),
+                projection: Some(EntityProjection {
+                    properties: Some(paths),
+                    property_metadata: false,
+                    link_metadata: false,
+                }),
+                include_count: false,
+                include_drafts: false,
+                include_web_ids: false,
+                include_created_by_ids: false,
+                include_edition_created_by_ids: false,
+                include_type_ids: false,
+            })
+            .await
+            .expect("could not get entity")
+            .entities;
+
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities[0].properties, expected_properties);
+        assert_eq!(
+            entities[0].metadata.record_id, entity.metadata.record_id,
+            "the record id should not be affected by the projection"
+        );
+    }
+}
+
+#[tokio::test]
+async fn stream() {
+    let person: PropertyObject =
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::NAME_V1, property_type::AGE_V1],
+            [entity_type::PERSON_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let mut entity_ids = HashSet::new();
+    for _ in 0..3 {
+        let entity = api
+            .create_entity(api.account_id, CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: HashSet::from([VersionedUrl {
+                    base_url: BaseUrl::new(
+                        "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+                    )
+                    .expect("couldn't construct Base URL"),
+                    version: OntologyTypeVersion::new(1),
+                }]),
+                properties: PropertyWithMetadataObject::from_parts(person.clone(), None)
+                    .expect("could not create property with metadata object"),
+                confidence: None,
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            })
+            .await
+            .expect("could not create entity");
+        entity_ids.insert(entity.metadata.record_id.entity_id);
+    }
+
+    for limit in [None, Some(2)] {
+        let entities = api
+            .stream_entities(api.account_id, GetEntitiesParams {
+                filter: Filter::Any(
+                    entity_ids
+                        .iter()
+                        .copied()
+                        .map(Filter::for_entity_by_entity_id)
+                        .collect(),
+                ),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(
+                        Some(TemporalBound::Unbounded),
+                        None,
+                    ),
+                },
+                sorting: EntityQuerySorting {
+                    paths: Vec::new(),
+                    cursor: None,
+                    relevance: None,
+                },
+                limit,
+                conversions: Vec::new(),
+                projection: None,
+                include_count: false,
+                include_drafts: false,
+                include_web_ids: false,
+                include_created_by_ids: false,
+                include_edition_created_by_ids: false,
+                include_type_ids: false,
+            })
+            .await
+            .expect("could not stream entities")
+            .try_collect::<Vec<_>>()
+            .await
+            .expect("could not read streamed entities");
+
+        assert_eq!(entities.len(), limit.unwrap_or(entity_ids.len()));
+        for entity in entities {
+            assert!(entity_ids.contains(&entity.metadata.record_id.entity_id));
+            assert_eq!(entity.properties, person);
+        }
+    }
+}
+
 #[tokio::test]
 async fn query() {
     let organization: PropertyObject =
@@ -156,9 +350,11 @@ async fn query() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: true,
             include_drafts: false,
             include_web_ids: false,
@@ -218,6 +414,7 @@ async fn update() {
     let v2_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: v1_entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: PropertyPath::default(),
                 property: PropertyWithMetadata::from_parts(Property::Object(page_v2.clone()), None)
@@ -256,9 +453,11 @@ async fn update() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: false,
             include_drafts: false,
             include_web_ids: false,
@@ -290,9 +489,11 @@ async fn update() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: true,
             include_drafts: false,
             include_web_ids: false,
@@ -321,9 +522,11 @@ async fn update() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: true,
             include_drafts: false,
             include_web_ids: false,
@@ -337,3 +540,404 @@ async fn update() {
     let entity_v2 = response_v2.entities.pop().expect("no entity found");
     assert_eq!(entity_v2.properties.properties(), page_v2.properties());
 }
+
+#[tokio::test]
+async fn change_feed() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    // Skip all changes written before the entity is created.
+    let mut position = None;
+    loop {
+        let response = api
+            .read_changes(api.account_id, ReadChangesParams {
+                after: position,
+                limit: 100,
+            })
+            .await
+            .expect("could not read changes");
+        if response.position == position {
+            break;
+        }
+        position = response.position;
+    }
+
+    let v1_entity = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }]),
+            properties: PropertyWithMetadataObject::from_parts(page_v1, None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+
+    let v2_entity = api
+        .patch_entity(api.account_id, PatchEntityParams {
+            entity_id: v1_entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
+            properties: vec![PropertyPatchOperation::Replace {
+                path: PropertyPath::default(),
+                property: PropertyWithMetadata::from_parts(Property::Object(page_v2), None)
+                    .expect("could not create property with metadata"),
+            }],
+            entity_type_ids: HashSet::new(),
+            archived: None,
+            draft: None,
+            decision_time: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not update entity");
+
+    let v3_entity = api
+        .patch_entity(api.account_id, PatchEntityParams {
+            entity_id: v1_entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
+            properties: Vec::new(),
+            entity_type_ids: HashSet::new(),
+            archived: Some(true),
+            draft: None,
+            decision_time: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not archive entity");
+
+    let response = api
+        .read_changes(api.account_id, ReadChangesParams {
+            after: position,
+            limit: 100,
+        })
+        .await
+        .expect("could not read changes");
+    let changes = response
+        .changes
+        .iter()
+        .map(|change| (change.kind, change.record.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(changes, [
+        (
+            ChangeKind::Created,
+            ChangedRecord::Entity(v1_entity.metadata.record_id)
+        ),
+        (
+            ChangeKind::Updated,
+            ChangedRecord::Entity(v2_entity.metadata.record_id)
+        ),
+        (
+            ChangeKind::Archived,
+            ChangedRecord::Entity(v3_entity.metadata.record_id)
+        ),
+    ]);
+    assert_eq!(
+        response.position,
+        response.changes.last().map(|change| change.position)
+    );
+
+    let resumed = api
+        .read_changes(api.account_id, ReadChangesParams {
+            after: response.changes.first().map(|change| change.position),
+            limit: 1,
+        })
+        .await
+        .expect("could not read changes");
+    assert_eq!(resumed.changes.len(), 1);
+    assert_eq!(resumed.changes[0], response.changes[1]);
+}
+
+#[tokio::test]
+async fn update_expected_edition() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    let v1_entity = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }]),
+            properties: PropertyWithMetadataObject::from_parts(page_v1.clone(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+
+    let v2_entity = api
+        .patch_entity(api.account_id, PatchEntityParams {
+            entity_id: v1_entity.metadata.record_id.entity_id,
+            expected_edition_id: Some(v1_entity.metadata.record_id.edition_id),
+            properties: vec![PropertyPatchOperation::Replace {
+                path: PropertyPath::default(),
+                property: PropertyWithMetadata::from_parts(Property::Object(page_v2), None)
+                    .expect("could not create property with metadata"),
+            }],
+            entity_type_ids: HashSet::new(),
+            archived: None,
+            draft: None,
+            decision_time: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not update entity");
+
+    let report = api
+        .patch_entity(api.account_id, PatchEntityParams {
+            entity_id: v1_entity.metadata.record_id.entity_id,
+            expected_edition_id: Some(v1_entity.metadata.record_id.edition_id),
+            properties: vec![PropertyPatchOperation::Replace {
+                path: PropertyPath::default(),
+                property: PropertyWithMetadata::from_parts(Property::Object(page_v1), None)
+                    .expect("could not create property with metadata"),
+            }],
+            entity_type_ids: HashSet::new(),
+            archived: None,
+            draft: None,
+            decision_time: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect_err("entity was updated based on an outdated edition");
+    assert!(report.contains::<EntityEditionConflict>());
+
+    let entity = api
+        .get_entity_by_id(
+            api.account_id,
+            v1_entity.metadata.record_id.entity_id,
+            None,
+            None,
+        )
+        .await
+        .expect("could not get entity");
+    assert_eq!(entity.metadata.record_id, v2_entity.metadata.record_id);
+}
+
+#[tokio::test]
+async fn migrate_entities() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let mut page_et_v2: EntityType = serde_json::from_str(entity_type::PAGE_V2)
+        .expect("could not parse entity type representation");
+    let text_property =
+        BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/text/".to_owned())
+            .expect("couldn't construct Base URL");
+    page_et_v2.required.insert(text_property.clone());
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::TEXT_V1,
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::WRITTEN_BY_V1,
+                entity_type::link::CONTAINS_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+                entity_type::BLOCK_V1,
+                entity_type::PAGE_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let page_v1_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let page_v2_id = page_et_v2.id.clone();
+
+    let page_with_text = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([page_v1_id.clone()]),
+            properties: PropertyWithMetadataObject::from_parts(page_v1, None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+
+    let page_without_text = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([page_v1_id.clone()]),
+            properties: PropertyWithMetadataObject::from_parts(PropertyObject::empty(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+
+    api.update_entity_type(api.account_id, UpdateEntityTypesParams {
+        schema: page_et_v2,
+        label_property: None,
+        icon: None,
+        relationships: entity_type_relationships(),
+        provenance: ProvidedOntologyEditionProvenance::default(),
+        breaking_changes: BreakingChangeBehavior::Allow,
+    })
+    .await
+    .expect("could not update entity type");
+
+    // The page without a text does not satisfy the new entity type, but this does not prevent
+    // migrating the other page.
+    let response = api
+        .migrate_entities(api.account_id, MigrateEntitiesParams {
+            filter: Filter::All(Vec::new()),
+            from_entity_type_id: page_v1_id.clone(),
+            to_entity_type_id: page_v2_id.clone(),
+            property_mappings: Vec::new(),
+            include_drafts: false,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not migrate entities");
+    assert_eq!(response.migrated.len(), 1);
+    assert_eq!(
+        response.migrated[0].entity_id,
+        page_with_text.metadata.record_id.entity_id
+    );
+    assert_eq!(response.failures.len(), 1);
+    assert_eq!(
+        response.failures[0].entity_id,
+        page_without_text.metadata.record_id.entity_id
+    );
+
+    let migrated_page = api
+        .get_entity_by_id(
+            api.account_id,
+            page_with_text.metadata.record_id.entity_id,
+            None,
+            None,
+        )
+        .await
+        .expect("could not get entity");
+    assert_eq!(
+        migrated_page.metadata.entity_type_ids,
+        HashSet::from([page_v2_id.clone()])
+    );
+    assert_eq!(migrated_page.properties, page_with_text.properties);
+
+    // Only the remaining page is still of the old entity type.
+    let response = api
+        .migrate_entities(api.account_id, MigrateEntitiesParams {
+            filter: Filter::All(Vec::new()),
+            from_entity_type_id: page_v1_id,
+            to_entity_type_id: page_v2_id.clone(),
+            property_mappings: vec![PropertyMapping::Default {
+                property: text_property.clone(),
+                value: PropertyWithMetadata::from_parts(
+                    Property::Value(serde_json::Value::String("Untitled".to_owned())),
+                    None,
+                )
+                .expect("could not create property with metadata"),
+            }],
+            include_drafts: false,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not migrate entities");
+    assert_eq!(response.migrated.len(), 1);
+    assert!(response.failures.is_empty());
+
+    let migrated_page = api
+        .get_entity_by_id(
+            api.account_id,
+            page_without_text.metadata.record_id.entity_id,
+            None,
+            None,
+        )
+        .await
+        .expect("could not get entity");
+    assert_eq!(
+        migrated_page.metadata.entity_type_ids,
+        HashSet::from([page_v2_id])
+    );
+    assert_eq!(
+        migrated_page.properties.properties().get(&text_property),
+        Some(&Property::Value(serde_json::Value::String(
+            "Untitled".to_owned()
+        )))
+    );
+}
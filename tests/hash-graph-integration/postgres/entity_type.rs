@@ -1,10 +1,16 @@
 use graph::store::{
     EntityTypeStore,
-    ontology::{CreateEntityTypeParams, GetEntityTypesParams, UpdateEntityTypesParams},
+    error::BreakingOntologyTypeChange,
+    ontology::{
+        BreakingChangeBehavior, CreateEntityTypeParams, GetEntityTypesParams,
+        UpdateEntityTypesParams,
+    },
 };
 use graph_test_data::{data_type, entity_type, property_type};
 use graph_types::{
-    ontology::{OntologyTypeClassificationMetadata, ProvidedOntologyEditionProvenance},
+    ontology::{
+        OntologyTypeClassificationMetadata, OntologyTypeRecordId, ProvidedOntologyEditionProvenance,
+    },
     owned_by_id::OwnedById,
 };
 use hash_graph_store::{
@@ -15,7 +21,10 @@ use hash_graph_store::{
     },
 };
 use temporal_versioning::TemporalBound;
-use type_system::schema::EntityType;
+use type_system::{
+    schema::{EntityType, TypeChange},
+    url::BaseUrl,
+};
 
 use crate::{DatabaseTestWrapper, entity_type_relationships};
 
@@ -174,6 +183,7 @@ async fn update() {
         icon: None,
         relationships: entity_type_relationships(),
         provenance: ProvidedOntologyEditionProvenance::default(),
+        breaking_changes: BreakingChangeBehavior::Allow,
     })
     .await
     .expect("could not update entity type");
@@ -221,3 +231,175 @@ async fn update() {
     assert_eq!(page_et_v1.id, returned_page_et_v1.schema.id);
     assert_eq!(page_et_v2.id, returned_page_et_v2.schema.id);
 }
+
+#[tokio::test]
+async fn update_breaking_changes() {
+    let page_et_v1: EntityType = serde_json::from_str(entity_type::PAGE_V1)
+        .expect("could not parse entity type representation");
+
+    let page_et_v2: EntityType = serde_json::from_str(entity_type::PAGE_V2)
+        .expect("could not parse entity type representation");
+
+    let mut page_et_v2_required_text = page_et_v2.clone();
+    page_et_v2_required_text.required.insert(
+        BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/text/".to_owned())
+            .expect("invalid base URL"),
+    );
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::TEXT_V1,
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::WRITTEN_BY_V1,
+                entity_type::link::CONTAINS_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+                entity_type::BLOCK_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database:");
+
+    api.create_entity_type(api.account_id, CreateEntityTypeParams {
+        schema: page_et_v1.clone(),
+        classification: OntologyTypeClassificationMetadata::Owned {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+        },
+        label_property: None,
+        icon: None,
+        relationships: entity_type_relationships(),
+        conflict_behavior: ConflictBehavior::Fail,
+        provenance: ProvidedOntologyEditionProvenance::default(),
+    })
+    .await
+    .expect("could not create entity type");
+
+    // Making an optional property required invalidates existing entities without it.
+    let report = api
+        .update_entity_type(api.account_id, UpdateEntityTypesParams {
+            schema: page_et_v2_required_text,
+            label_property: None,
+            icon: None,
+            relationships: entity_type_relationships(),
+            provenance: ProvidedOntologyEditionProvenance::default(),
+            breaking_changes: BreakingChangeBehavior::Reject,
+        })
+        .await
+        .expect_err("could update entity type with breaking changes");
+    assert!(
+        report.contains::<BreakingOntologyTypeChange>(),
+        "wrong error, expected `BreakingOntologyTypeChange`, got {report:?}"
+    );
+
+    // Adding links is backwards-compatible.
+    api.update_entity_type(api.account_id, UpdateEntityTypesParams {
+        schema: page_et_v2.clone(),
+        label_property: None,
+        icon: None,
+        relationships: entity_type_relationships(),
+        provenance: ProvidedOntologyEditionProvenance::default(),
+        breaking_changes: BreakingChangeBehavior::Reject,
+    })
+    .await
+    .expect("could not update entity type");
+}
+
+#[tokio::test]
+async fn report_breaking_changes() {
+    let page_et_v1: EntityType = serde_json::from_str(entity_type::PAGE_V1)
+        .expect("could not parse entity type representation");
+
+    let text_property =
+        BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/text/".to_owned())
+            .expect("invalid base URL");
+    let mut page_et_v2_required_text: EntityType = serde_json::from_str(entity_type::PAGE_V2)
+        .expect("could not parse entity type representation");
+    page_et_v2_required_text
+        .required
+        .insert(text_property.clone());
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::TEXT_V1,
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::WRITTEN_BY_V1,
+                entity_type::link::CONTAINS_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+                entity_type::BLOCK_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database:");
+
+    api.create_entity_type(api.account_id, CreateEntityTypeParams {
+        schema: page_et_v1,
+        classification: OntologyTypeClassificationMetadata::Owned {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+        },
+        label_property: None,
+        icon: None,
+        relationships: entity_type_relationships(),
+        conflict_behavior: ConflictBehavior::Fail,
+        provenance: ProvidedOntologyEditionProvenance::default(),
+    })
+    .await
+    .expect("could not create entity type");
+
+    // Reported breaking changes don't prevent the update.
+    let response = api
+        .update_entity_type(api.account_id, UpdateEntityTypesParams {
+            schema: page_et_v2_required_text.clone(),
+            label_property: None,
+            icon: None,
+            relationships: entity_type_relationships(),
+            provenance: ProvidedOntologyEditionProvenance::default(),
+            breaking_changes: BreakingChangeBehavior::Report,
+        })
+        .await
+        .expect("could not update entity type");
+
+    assert_eq!(
+        response.metadata.record_id,
+        OntologyTypeRecordId::from(page_et_v2_required_text.id)
+    );
+    assert!(
+        response.changes.contains(&TypeChange::PropertyRequired {
+            property: text_property
+        }),
+        "expected the required property to be reported, got {:?}",
+        response.changes
+    );
+}
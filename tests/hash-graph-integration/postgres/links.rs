@@ -3,7 +3,11 @@ use std::collections::HashSet;
 
 use graph::store::{
     EntityQuerySorting, EntityStore,
-    knowledge::{CountEntitiesParams, CreateEntityParams, GetEntitiesParams, PatchEntityParams},
+    knowledge::{
+        CountEntitiesParams, CreateEntityParams, EntityPath, EntityPathHop, EntityTraversalHop,
+        EntityTraversalPath, FindEntityPathsParams, GetEntitiesParams, GetEntitySubgraphParams,
+        LinkTraversalDirection, PatchEntityParams,
+    },
 };
 use graph_test_data::{data_type, entity, entity_type, property_type};
 use graph_types::{
@@ -19,7 +23,7 @@ use hash_graph_store::{
     entity_type::EntityTypeQueryPath,
     filter::{Filter, FilterExpression, Parameter},
     subgraph::{
-        edges::{EdgeDirection, KnowledgeGraphEdgeKind, SharedEdgeKind},
+        edges::{EdgeDirection, GraphResolveDepths, KnowledgeGraphEdgeKind, SharedEdgeKind},
         temporal_axes::{
             PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
             VariableTemporalAxisUnresolved,
@@ -216,9 +220,11 @@ async fn insert() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: true,
             include_drafts: false,
             include_web_ids: false,
@@ -430,9 +436,11 @@ async fn get_entity_links() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: false,
             include_drafts: false,
             include_web_ids: false,
@@ -476,6 +484,393 @@ async fn get_entity_links() {
     ));
 }
 
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn traverse_link_paths() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob = serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+    let charles = serde_json::from_str(entity::PERSON_CHARLES_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let friend_link_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let acquaintance_link_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/acquaintance-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let mut person_ids = Vec::new();
+    for person in [alice, bob, charles] {
+        let metadata = api
+            .create_entity(api.account_id, CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: HashSet::from([person_type_id.clone()]),
+                properties: PropertyWithMetadataObject::from_parts(person, None)
+                    .expect("could not create property with metadata object"),
+                link_data: None,
+                draft: false,
+                relationships: [],
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            })
+            .await
+            .expect("could not create entity")
+            .metadata;
+        person_ids.push(metadata.record_id.entity_id);
+    }
+    let [alice_id, bob_id, charles_id] = person_ids[..] else {
+        panic!("expected three persons");
+    };
+
+    let mut link_ids = Vec::new();
+    for (link_type_id, right_entity_id) in [
+        (&friend_link_type_id, bob_id),
+        (&acquaintance_link_type_id, charles_id),
+    ] {
+        let metadata = api
+            .create_entity(api.account_id, CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: HashSet::from([link_type_id.clone()]),
+                properties: PropertyWithMetadataObject::from_parts(PropertyObject::empty(), None)
+                    .expect("could not create property with metadata object"),
+                link_data: Some(LinkData {
+                    left_entity_id: alice_id,
+                    right_entity_id,
+                    left_entity_confidence: None,
+                    left_entity_provenance: PropertyProvenance::default(),
+                    right_entity_confidence: None,
+                    right_entity_provenance: PropertyProvenance::default(),
+                }),
+                draft: false,
+                relationships: [],
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            })
+            .await
+            .expect("could not create link")
+            .metadata;
+        link_ids.push(metadata.record_id.entity_id);
+    }
+    let [friend_link_id, _] = link_ids[..] else {
+        panic!("expected two links");
+    };
+
+    let traverse = |root_id, direction, link_filter| GetEntitySubgraphParams {
+        filter: Filter::for_entity_by_entity_id(root_id),
+        temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(None, None),
+        },
+        graph_resolve_depths: GraphResolveDepths::default(),
+        traversal_paths: vec![EntityTraversalPath {
+            hops: vec![EntityTraversalHop {
+                direction,
+                link_filter,
+                endpoint_filter: Filter::All(Vec::new()),
+            }],
+        }],
+        sorting: EntityQuerySorting {
+            paths: Vec::new(),
+            cursor: None,
+            relevance: None,
+        },
+        limit: None,
+        conversions: Vec::new(),
+        projection: None,
+        include_count: false,
+        include_drafts: false,
+        include_web_ids: false,
+        include_created_by_ids: false,
+        include_edition_created_by_ids: false,
+        include_type_ids: false,
+    };
+
+    let friend_filter = Filter::Equal(
+        Some(FilterExpression::Path {
+            path: EntityQueryPath::EntityTypeEdge {
+                edge_kind: SharedEdgeKind::IsOfType,
+                path: EntityTypeQueryPath::BaseUrl,
+                inheritance_depth: Some(0),
+            },
+        }),
+        Some(FilterExpression::Parameter {
+            parameter: Parameter::Text(Cow::Borrowed(friend_link_type_id.base_url.as_str())),
+            convert: None,
+        }),
+    );
+
+    let outgoing_friends = api
+        .get_entity_subgraph(
+            api.account_id,
+            traverse(alice_id, LinkTraversalDirection::Outgoing, friend_filter),
+        )
+        .await
+        .expect("could not get entity subgraph")
+        .subgraph;
+    assert_eq!(
+        outgoing_friends
+            .vertices
+            .entities
+            .keys()
+            .map(|vertex_id| vertex_id.base_id)
+            .collect::<HashSet<_>>(),
+        HashSet::from([alice_id, friend_link_id, bob_id])
+    );
+
+    let incoming_links = api
+        .get_entity_subgraph(
+            api.account_id,
+            traverse(
+                charles_id,
+                LinkTraversalDirection::Incoming,
+                Filter::All(Vec::new()),
+            ),
+        )
+        .await
+        .expect("could not get entity subgraph")
+        .subgraph;
+    let incoming_entity_ids = incoming_links
+        .vertices
+        .entities
+        .keys()
+        .map(|vertex_id| vertex_id.base_id)
+        .collect::<HashSet<_>>();
+    assert_eq!(incoming_entity_ids.len(), 3);
+    assert!(incoming_entity_ids.contains(&alice_id));
+    assert!(!incoming_entity_ids.contains(&bob_id));
+}
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn find_entity_paths() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob = serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+    let charles = serde_json::from_str(entity::PERSON_CHARLES_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let friend_link_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let acquaintance_link_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/acquaintance-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let mut person_ids = Vec::new();
+    for person in [alice, bob, charles] {
+        let metadata = api
+            .create_entity(api.account_id, CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: HashSet::from([person_type_id.clone()]),
+                properties: PropertyWithMetadataObject::from_parts(person, None)
+                    .expect("could not create property with metadata object"),
+                link_data: None,
+                draft: false,
+                relationships: [],
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            })
+            .await
+            .expect("could not create entity")
+            .metadata;
+        person_ids.push(metadata.record_id.entity_id);
+    }
+    let [alice_id, bob_id, charles_id] = person_ids[..] else {
+        panic!("expected three persons");
+    };
+
+    let mut link_ids = Vec::new();
+    for (link_type_id, right_entity_id) in [
+        (&friend_link_type_id, bob_id),
+        (&acquaintance_link_type_id, charles_id),
+    ] {
+        let metadata = api
+            .create_entity(api.account_id, CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: HashSet::from([link_type_id.clone()]),
+                properties: PropertyWithMetadataObject::from_parts(PropertyObject::empty(), None)
+                    .expect("could not create property with metadata object"),
+                link_data: Some(LinkData {
+                    left_entity_id: alice_id,
+                    right_entity_id,
+                    left_entity_confidence: None,
+                    left_entity_provenance: PropertyProvenance::default(),
+                    right_entity_confidence: None,
+                    right_entity_provenance: PropertyProvenance::default(),
+                }),
+                draft: false,
+                relationships: [],
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            })
+            .await
+            .expect("could not create link")
+            .metadata;
+        link_ids.push(metadata.record_id.entity_id);
+    }
+    let [friend_link_id, acquaintance_link_id] = link_ids[..] else {
+        panic!("expected two links");
+    };
+
+    let find_paths = |max_length, link_filter| FindEntityPathsParams {
+        source_entity_id: bob_id,
+        target_entity_id: charles_id,
+        max_length,
+        link_filter,
+        temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(None, None),
+        },
+        include_drafts: false,
+        limit: None,
+    };
+
+    let paths = api
+        .find_entity_paths(api.account_id, find_paths(2, None))
+        .await
+        .expect("could not find entity paths")
+        .paths;
+    assert_eq!(paths, [EntityPath {
+        hops: vec![
+            EntityPathHop {
+                link_entity_id: friend_link_id,
+                direction: LinkTraversalDirection::Incoming,
+                entity_id: alice_id,
+            },
+            EntityPathHop {
+                link_entity_id: acquaintance_link_id,
+                direction: LinkTraversalDirection::Outgoing,
+                entity_id: charles_id,
+            },
+        ],
+    }]);
+
+    let too_short_paths = api
+        .find_entity_paths(api.account_id, find_paths(1, None))
+        .await
+        .expect("could not find entity paths")
+        .paths;
+    assert!(too_short_paths.is_empty());
+
+    let friend_paths = api
+        .find_entity_paths(
+            api.account_id,
+            find_paths(
+                2,
+                Some(Filter::Equal(
+                    Some(FilterExpression::Path {
+                        path: EntityQueryPath::EntityTypeEdge {
+                            edge_kind: SharedEdgeKind::IsOfType,
+                            path: EntityTypeQueryPath::BaseUrl,
+                            inheritance_depth: Some(0),
+                        },
+                    }),
+                    Some(FilterExpression::Parameter {
+                        parameter: Parameter::Text(Cow::Borrowed(
+                            friend_link_type_id.base_url.as_str(),
+                        )),
+                        convert: None,
+                    }),
+                )),
+            ),
+        )
+        .await
+        .expect("could not find entity paths")
+        .paths;
+    assert!(friend_paths.is_empty());
+}
+
 #[tokio::test]
 #[expect(clippy::too_many_lines)]
 async fn remove_link() {
@@ -628,6 +1023,7 @@ async fn remove_link() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id: link_entity_metadata.metadata.record_id.entity_id,
+        expected_edition_id: None,
         decision_time: None,
         archived: Some(true),
         draft: None,
@@ -137,9 +137,11 @@ async fn initial_person() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: true,
             include_drafts: false,
             include_web_ids: false,
@@ -156,6 +158,7 @@ async fn initial_person() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: HashSet::from([person_entity_type_id(), org_entity_type_id()]),
             properties: vec![],
@@ -190,9 +193,11 @@ async fn initial_person() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: true,
             include_drafts: false,
             include_web_ids: false,
@@ -214,9 +219,11 @@ async fn initial_person() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: true,
             include_drafts: false,
             include_web_ids: false,
@@ -282,9 +289,11 @@ async fn create_multi() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: true,
             include_drafts: false,
             include_web_ids: false,
@@ -306,9 +315,11 @@ async fn create_multi() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: true,
             include_drafts: false,
             include_web_ids: false,
@@ -326,6 +337,7 @@ async fn create_multi() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: HashSet::from([person_entity_type_id()]),
             properties: vec![],
@@ -354,9 +366,11 @@ async fn create_multi() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: true,
             include_drafts: false,
             include_web_ids: false,
@@ -108,6 +108,7 @@ async fn properties_add() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: HashSet::new(),
         properties: vec![
@@ -156,9 +157,11 @@ async fn properties_add() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: false,
             include_drafts: false,
             include_web_ids: false,
@@ -203,6 +206,7 @@ async fn properties_remove() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: HashSet::new(),
         properties: vec![PropertyPatchOperation::Remove {
@@ -226,9 +230,11 @@ async fn properties_remove() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: false,
             include_drafts: false,
             include_web_ids: false,
@@ -271,6 +277,7 @@ async fn properties_replace() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: HashSet::new(),
         properties: vec![PropertyPatchOperation::Replace {
@@ -304,9 +311,11 @@ async fn properties_replace() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: false,
             include_drafts: false,
             include_web_ids: false,
@@ -351,6 +360,7 @@ async fn type_ids() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: HashSet::new(),
         properties: vec![],
@@ -372,9 +382,11 @@ async fn type_ids() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: false,
             include_drafts: false,
             include_web_ids: false,
@@ -397,6 +409,7 @@ async fn type_ids() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: HashSet::from([person_entity_type_id(), org_entity_type_id()]),
         properties: vec![],
@@ -418,9 +431,11 @@ async fn type_ids() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: false,
             include_drafts: false,
             include_web_ids: false,
@@ -445,6 +460,7 @@ async fn type_ids() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: HashSet::from([person_entity_type_id()]),
         properties: vec![],
@@ -466,9 +482,11 @@ async fn type_ids() {
             sorting: EntityQuerySorting {
                 paths: Vec::new(),
                 cursor: None,
+                relevance: None,
             },
             limit: None,
             conversions: Vec::new(),
+            projection: None,
             include_count: false,
             include_drafts: false,
             include_web_ids: false,
@@ -210,6 +210,7 @@ async fn initial_metadata() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: once(PropertyPathElement::Property(Cow::Owned(
                     name_property_type_id(),
@@ -255,6 +256,7 @@ async fn initial_metadata() {
     let new_updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: HashSet::new(),
             archived: None,
@@ -313,6 +315,7 @@ async fn no_initial_metadata() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: HashSet::new(),
             archived: None,
@@ -329,6 +332,7 @@ async fn no_initial_metadata() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: HashSet::new(),
             archived: None,
@@ -357,6 +361,7 @@ async fn no_initial_metadata() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: once(PropertyPathElement::from(name_property_type_id())).collect(),
                 property: PropertyWithMetadata::Value(PropertyWithMetadataValue {
@@ -397,6 +402,7 @@ async fn no_initial_metadata() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: HashSet::new(),
             archived: None,
@@ -454,6 +460,7 @@ async fn properties_add() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: HashSet::new(),
             properties: vec![PropertyPatchOperation::Add {
@@ -538,6 +545,7 @@ async fn properties_remove() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: HashSet::new(),
             properties: vec![
@@ -609,6 +617,7 @@ async fn properties_remove() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: HashSet::new(),
             properties: vec![PropertyPatchOperation::Remove {
@@ -1,6 +1,9 @@
 use graph::store::{
     PropertyTypeStore,
-    ontology::{CreatePropertyTypeParams, GetPropertyTypesParams, UpdatePropertyTypesParams},
+    ontology::{
+        BreakingChangeBehavior, CreatePropertyTypeParams, GetPropertyTypesParams,
+        UpdatePropertyTypesParams,
+    },
 };
 use graph_test_data::{data_type, property_type};
 use graph_types::{
@@ -125,6 +128,7 @@ async fn update() {
         schema: user_id_pt_v2.clone(),
         relationships: property_type_relationships(),
         provenance: ProvidedOntologyEditionProvenance::default(),
+        breaking_changes: BreakingChangeBehavior::Allow,
     })
     .await
     .expect("could not update property type");
@@ -3,8 +3,12 @@ use std::collections::HashSet;
 
 use authorization::AuthorizationApi;
 use graph::store::{
-    EntityQuerySorting, EntityQuerySortingRecord, EntityStore, NullOrdering, Ordering,
-    knowledge::{CreateEntityParams, GetEntitiesParams, GetEntitiesResponse},
+    AggregationFunction, CursorField, EntityQuerySorting, EntityQuerySortingRecord, EntityStore,
+    NullOrdering, Ordering,
+    knowledge::{
+        AggregateEntitiesParams, CreateEntityParams, EntityAggregation, GetEntitiesParams,
+        GetEntitiesResponse,
+    },
 };
 use graph_test_data::{data_type, entity, entity_type, property_type};
 use graph_types::{
@@ -16,7 +20,7 @@ use graph_types::{
 };
 use hash_graph_store::{
     entity::EntityQueryPath,
-    filter::{Filter, JsonPath, PathToken},
+    filter::{Filter, FilterExpression, JsonPath, Parameter, PathToken, validate_pattern},
     subgraph::temporal_axes::{
         PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
     },
@@ -76,9 +80,11 @@ async fn test_root_sorting<A: AuthorizationApi>(
                 sorting: EntityQuerySorting {
                     paths: sorting_paths.clone(),
                     cursor: cursor.take(),
+                    relevance: None,
                 },
                 limit: Some(chunk_size),
                 conversions: Vec::new(),
+                projection: None,
                 include_count: true,
                 include_drafts: false,
                 include_web_ids: false,
@@ -777,3 +783,318 @@ async fn age_descending_first_name_descending_first() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn full_text_search_relevance() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = insert(&mut database).await;
+
+    let filter = Filter::FullTextSearch(
+        FilterExpression::Path {
+            path: EntityQueryPath::Properties(None),
+        },
+        FilterExpression::Parameter {
+            parameter: Parameter::Text(Cow::Borrowed("charles")),
+            convert: None,
+        },
+    );
+
+    let entities = api
+        .get_entities(api.account_id, GetEntitiesParams {
+            filter,
+            temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(None),
+                variable: VariableTemporalAxisUnresolved::new(None, None),
+            },
+            sorting: EntityQuerySorting {
+                paths: vec![EntityQuerySortingRecord {
+                    path: EntityQueryPath::Uuid,
+                    ordering: Ordering::Ascending,
+                    nulls: None,
+                }],
+                cursor: None,
+                relevance: Some(Cow::Borrowed("charles")),
+            },
+            limit: None,
+            conversions: Vec::new(),
+            projection: None,
+            include_count: false,
+            include_drafts: false,
+            include_web_ids: false,
+            include_created_by_ids: false,
+            include_edition_created_by_ids: false,
+            include_type_ids: false,
+        })
+        .await
+        .expect("could not get entities")
+        .entities;
+
+    assert_eq!(
+        entities
+            .iter()
+            .map(|entity| &entity.properties)
+            .collect::<Vec<_>>(),
+        [&charles()]
+    );
+}
+
+#[tokio::test]
+async fn full_text_search_relevance_paging() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = insert(&mut database).await;
+
+    let page_entity_type = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let page_with_text = |text: &str| -> PropertyObject {
+        serde_json::from_value(serde_json::json!({
+            "https://blockprotocol.org/@alice/types/property-type/text/": text,
+        }))
+        .expect("could not parse entity")
+    };
+
+    // The more often the query occurs, the higher the relevance. `page_v1` and `page_v2` both
+    // contain the query once, so they are ordered by their UUID.
+    let frequent = page_with_text("page page page");
+    let repeated = page_with_text("page page");
+    for (idx, properties) in [&frequent, &repeated].into_iter().enumerate() {
+        api.create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: Some(EntityUuid::new(Uuid::from_u128(idx as u128 + 5))),
+            decision_time: None,
+            entity_type_ids: HashSet::from([page_entity_type.clone()]),
+            properties: PropertyWithMetadataObject::from_parts(properties.clone(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+    }
+
+    let chunk_size = 2;
+    let mut cursor = None;
+    let mut entities = Vec::new();
+
+    loop {
+        let response = api
+            .get_entities(api.account_id, GetEntitiesParams {
+                filter: Filter::FullTextSearch(
+                    FilterExpression::Path {
+                        path: EntityQueryPath::Properties(None),
+                    },
+                    FilterExpression::Parameter {
+                        parameter: Parameter::Text(Cow::Borrowed("page")),
+                        convert: None,
+                    },
+                ),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                sorting: EntityQuerySorting {
+                    paths: vec![EntityQuerySortingRecord {
+                        path: EntityQueryPath::Uuid,
+                        ordering: Ordering::Ascending,
+                        nulls: None,
+                    }],
+                    cursor: cursor.take(),
+                    relevance: Some(Cow::Borrowed("page")),
+                },
+                limit: Some(chunk_size),
+                conversions: Vec::new(),
+                projection: None,
+                include_count: false,
+                include_drafts: false,
+                include_web_ids: false,
+                include_created_by_ids: false,
+                include_edition_created_by_ids: false,
+                include_type_ids: false,
+            })
+            .await
+            .expect("could not get entities");
+
+        let num_entities = response.entities.len();
+        entities.extend(response.entities);
+        if num_entities < chunk_size {
+            break;
+        }
+
+        let new_cursor = response.cursor.expect("a full page should return a cursor");
+        assert!(
+            matches!(new_cursor.values.first(), Some(CursorField::F64(_))),
+            "the relevance should be the first value of the cursor: {new_cursor:?}"
+        );
+        cursor = Some(new_cursor);
+    }
+
+    assert_eq!(
+        entities
+            .iter()
+            .map(|entity| &entity.properties)
+            .collect::<Vec<_>>(),
+        [&frequent, &repeated, &page_v1(), &page_v2()]
+    );
+}
+
+/// Returns the properties of the entities whose name matches `pattern`, ordered by their UUID.
+///
+/// The pattern is validated the same way as when it's passed through the API.
+async fn matching_names<A: AuthorizationApi>(
+    api: &DatabaseApi<'_, &mut A>,
+    pattern: &'static str,
+    ignore_case: bool,
+) -> Vec<PropertyObject> {
+    validate_pattern(pattern).expect("pattern should be supported by all stores");
+
+    let lhs = FilterExpression::Path {
+        path: name_property_path(),
+    };
+    let rhs = FilterExpression::Parameter {
+        parameter: Parameter::Text(Cow::Borrowed(pattern)),
+        convert: None,
+    };
+    let filter = if ignore_case {
+        Filter::MatchesIgnoreCase(lhs, rhs)
+    } else {
+        Filter::Matches(lhs, rhs)
+    };
+
+    api.get_entities(api.account_id, GetEntitiesParams {
+        filter,
+        temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(None, None),
+        },
+        sorting: EntityQuerySorting {
+            paths: vec![EntityQuerySortingRecord {
+                path: EntityQueryPath::Uuid,
+                ordering: Ordering::Ascending,
+                nulls: None,
+            }],
+            cursor: None,
+            relevance: None,
+        },
+        limit: None,
+        conversions: Vec::new(),
+        projection: None,
+        include_count: false,
+        include_drafts: false,
+        include_web_ids: false,
+        include_created_by_ids: false,
+        include_edition_created_by_ids: false,
+        include_type_ids: false,
+    })
+    .await
+    .expect("could not get entities")
+    .entities
+    .into_iter()
+    .map(|entity| entity.properties)
+    .collect()
+}
+
+#[tokio::test]
+async fn matches_pattern() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = insert(&mut database).await;
+
+    assert_eq!(matching_names(&api, "^A", false).await, [alice()]);
+    assert_eq!(matching_names(&api, r"^[[:upper:]]\w{2}$", false).await, [
+        bob()
+    ]);
+    assert!(
+        matching_names(&api, "^(?:b|c)[[:alpha:]]+$", false)
+            .await
+            .is_empty()
+    );
+    assert_eq!(matching_names(&api, "^(?:b|c)[[:alpha:]]+$", true).await, [
+        bob(),
+        charles()
+    ]);
+}
+
+#[tokio::test]
+async fn aggregate_by_age() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = insert(&mut database).await;
+
+    let response = api
+        .aggregate_entities(api.account_id, AggregateEntitiesParams {
+            filter: Filter::All(Vec::new()),
+            temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(None),
+                variable: VariableTemporalAxisUnresolved::new(None, None),
+            },
+            include_drafts: false,
+            group_by: vec![age_property_path()],
+            aggregations: vec![
+                EntityAggregation {
+                    function: AggregationFunction::Count,
+                    path: None,
+                },
+                EntityAggregation {
+                    function: AggregationFunction::Sum,
+                    path: Some(age_property_path()),
+                },
+            ],
+        })
+        .await
+        .expect("could not aggregate entities");
+
+    assert_eq!(
+        response
+            .groups
+            .into_iter()
+            .map(|group| (group.key, group.values))
+            .collect::<Vec<_>>(),
+        [
+            (vec![serde_json::json!(42)], vec![Some(2.0), Some(84.0)]),
+            (vec![serde_json::Value::Null], vec![Some(3.0), None]),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn aggregate_ignores_non_numbers() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = insert(&mut database).await;
+
+    let response = api
+        .aggregate_entities(api.account_id, AggregateEntitiesParams {
+            filter: Filter::All(Vec::new()),
+            temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(None),
+                variable: VariableTemporalAxisUnresolved::new(None, None),
+            },
+            include_drafts: false,
+            group_by: Vec::new(),
+            aggregations: vec![
+                EntityAggregation {
+                    function: AggregationFunction::Count,
+                    path: None,
+                },
+                EntityAggregation {
+                    function: AggregationFunction::Sum,
+                    path: Some(name_property_path()),
+                },
+            ],
+        })
+        .await
+        .expect("aggregating non-numeric values should not fail");
+
+    assert_eq!(
+        response
+            .groups
+            .into_iter()
+            .map(|group| (group.key, group.values))
+            .collect::<Vec<_>>(),
+        [(Vec::new(), vec![Some(5.0), None])]
+    );
+}